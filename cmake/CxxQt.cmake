@@ -59,6 +59,13 @@ function(cxx_qt_generate_cpp GEN_SOURCES)
     # Now we can read the list of C++ files that cargo produced
     file(STRINGS "${CMAKE_CURRENT_SOURCE_DIR}/target/cxx-qt-gen/cpp_sources.txt" CPP_GEN_SOURCES)
 
+    # Add the qmldir and qmltypes of any generated QML modules to the import path of QML tooling, eg Qt Creator
+    if(EXISTS "${CMAKE_CURRENT_SOURCE_DIR}/target/cxx-qt-gen/qml")
+        list(APPEND QML_IMPORT_PATH "${CMAKE_CURRENT_SOURCE_DIR}/target/cxx-qt-gen/qml")
+        list(REMOVE_DUPLICATES QML_IMPORT_PATH)
+        set(QML_IMPORT_PATH ${QML_IMPORT_PATH} CACHE STRING "Import paths for QML tooling" FORCE)
+    endif()
+
     # Pass the generated sources back
     set(${GEN_SOURCES} ${CPP_GEN_SOURCES} PARENT_SCOPE)
 endfunction()
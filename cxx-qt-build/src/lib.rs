@@ -6,16 +6,20 @@
 use convert_case::{Case, Casing};
 use quote::ToTokens;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clang_format::ClangFormatStyle;
 use cxx_qt_gen::{
-    extract_qobject, generate_format, generate_qobject_cpp, generate_qobject_rs, parse_qt_file,
-    CppObject, CxxQtItem,
+    extract_qml_elements, extract_qobjects, generate_format, generate_qml_plugin_cpp,
+    generate_qml_plugin_qmldir, generate_qml_registration_cpp, generate_qmldir, generate_qmltypes,
+    generate_qobject_cpp, generate_qobject_rs, generate_qrc_init_cpp, parse_qt_file, CppObject,
+    CxxQtItem, QmlElement,
 };
+use qt_build::QtBuild;
 
 /// Representation of a generated CXX header, source, and name
 #[derive(Serialize, Deserialize)]
@@ -25,14 +29,6 @@ struct GeneratedType {
     source: String,
 }
 
-// TODO: we need to eventually support having multiple modules defined in a single file. This
-// is currently an issue because we are using the Rust file name to derive the cpp file name
-// and are blindly re-writing files.
-//
-// As we use struct names for the QObject files, we should actually be able to support multiple
-// QObject macros and at most one "raw CXX" macro per file already. For now this remains a TODO
-// as to keep things simpler. We also want to able to warn users about duplicate names eventually.
-
 fn manifest_dir() -> String {
     let mut manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("Could not get manifest dir");
     // CARGO_MANIFEST_DIR uses \ path separators on Windows, but the format! macros that
@@ -47,106 +43,65 @@ fn manifest_dir() -> String {
     manifest_dir
 }
 
-pub struct GeneratedCpp {
+/// Generated C++ header and source file contents for a single CXX or CXX-Qt bridge module
+struct GeneratedCppModule {
     cxx_qt: Option<CppObject>,
     cxx: cxx_gen::GeneratedCode,
     file_ident: String,
+    qml_elements: Vec<QmlElement>,
 }
 
-impl GeneratedCpp {
-    /// Generate QObject and cxx header/source C++ file contents
-    pub fn new(rust_file_path: &impl AsRef<std::path::Path>) -> Self {
-        let file = parse_qt_file(rust_file_path).unwrap();
-
-        let mut cxx_qt = None;
-        // TODO: later change how the resultant filename is chosen, can we match the input file like
-        // CXX does?
-        //
-        // For CXX-Qt generating one header per QObject likely makes sense, but what happens with CXX data?
-        // for now this uses the module ident
-        let mut file_ident: String = "".to_owned();
+impl GeneratedCppModule {
+    /// Generate cxx header/source C++ file contents for a CXX bridge module
+    fn from_cxx(attrs: &[syn::Attribute], module: &syn::ItemMod) -> Self {
         let mut tokens = proc_macro2::TokenStream::new();
-
-        // Add any attributes in the file into the tokenstream
-        for attr in &file.attrs {
+        for attr in attrs {
             tokens.extend(attr.into_token_stream());
         }
+        tokens.extend(module.into_token_stream());
 
-        // Loop through the items looking for any CXX or CXX-Qt blocks
-        for item in &file.items {
-            match item {
-                CxxQtItem::Cxx(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if !file_ident.is_empty() {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.as_ref().display());
-                    }
-
-                    file_ident = m.ident.to_string().to_case(Case::Snake);
-                    tokens.extend(m.into_token_stream());
-                }
-                CxxQtItem::CxxQt(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if !file_ident.is_empty() {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.as_ref().display());
-                    }
-
-                    // TODO: later we will likely have cxx_qt_gen::generate_header_and_cpp
-                    // which will take a CxxQtItemMod and respond with a C++ header and source
-                    let qobject = extract_qobject(m).unwrap();
-                    // Use the qobject ident as the output file name?
-                    file_ident = qobject.ident.to_string().to_case(Case::Snake);
-                    // TODO: we'll have to extend the C++ data here rather than overwriting
-                    // assuming we share the same file
-                    cxx_qt = Some(generate_qobject_cpp(&qobject).unwrap());
-
-                    // TODO: later we will likely have cxx_qt_gen::generate_rust
-                    // which will take a CxxQtItemMod and respond with the Rust code
-                    //
-                    // We need to do this and can't rely on the macro, as we need to generate the
-                    // CXX bridge Rust code that is then fed into the cxx_gen generation.
-                    tokens.extend(generate_qobject_rs(&qobject).unwrap());
-                }
-                CxxQtItem::Item(item) => {
-                    tokens.extend(item.into_token_stream());
-                }
-            }
+        GeneratedCppModule {
+            cxx_qt: None,
+            cxx: generate_cxx(tokens),
+            file_ident: module.ident.to_string().to_case(Case::Snake),
+            qml_elements: vec![],
         }
+    }
 
-        let opt = cxx_gen::Opt::default();
-        let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
-            .expect("Could not generate C++ from Rust file");
+    /// Generate QObject and cxx header/source C++ file contents for a CXX-Qt bridge module
+    fn from_cxx_qt(attrs: &[syn::Attribute], module: &syn::ItemMod) -> Self {
+        // TODO: later we will likely have cxx_qt_gen::generate_header_and_cpp
+        // which will take a CxxQtItemMod and respond with a C++ header and source
+        let cxx_qt_module = extract_qobjects(module).unwrap();
+        let cxx_qt = generate_qobject_cpp(&cxx_qt_module).unwrap();
 
-        GeneratedCpp {
-            cxx_qt,
-            cxx,
-            file_ident,
+        // TODO: later we will likely have cxx_qt_gen::generate_rust
+        // which will take a CxxQtItemMod and respond with the Rust code
+        //
+        // We need to do this and can't rely on the macro, as we need to generate the
+        // CXX bridge Rust code that is then fed into the cxx_gen generation.
+        let mut tokens = proc_macro2::TokenStream::new();
+        for attr in attrs {
+            tokens.extend(attr.into_token_stream());
+        }
+        tokens.extend(generate_qobject_rs(&cxx_qt_module).unwrap());
+
+        GeneratedCppModule {
+            cxx_qt: Some(cxx_qt),
+            cxx: generate_cxx(tokens),
+            // Use the stem of the first qobject as the output file name, this must match the
+            // includes that the generated code uses
+            file_ident: cxx_qt_module.cxx_stem.clone(),
+            qml_elements: extract_qml_elements(&cxx_qt_module),
         }
     }
 
     /// Write generated code to files in a directory. Returns the absolute paths of all files written.
-    pub fn write_to_directory(&self, directory: &impl AsRef<std::path::Path>) -> Vec<PathBuf> {
-        let directory = directory.as_ref();
-        if !directory.is_dir() {
-            panic!(
-                "Output directory {} is not a directory",
-                directory.display()
-            );
-        }
-
-        let include_directory_path = PathBuf::from(format!("{}/include", &directory.display()));
-        std::fs::create_dir_all(&include_directory_path)
-            .expect("Could not create cxx-qt include dir");
-
-        let source_directory_path = PathBuf::from(format!("{}/src", &directory.display()));
-        std::fs::create_dir_all(&source_directory_path)
-            .expect("Could not create cxx-qt source dir");
-
+    fn write_to_directory(
+        &self,
+        include_directory_path: &std::path::Path,
+        source_directory_path: &std::path::Path,
+    ) -> Vec<PathBuf> {
         let mut written_files = Vec::with_capacity(4);
 
         if let Some(cxx_qt_generated) = &self.cxx_qt {
@@ -198,23 +153,511 @@ impl GeneratedCpp {
     }
 }
 
+/// Generate the cxx header/source C++ file contents for the given Rust tokens
+fn generate_cxx(tokens: proc_macro2::TokenStream) -> cxx_gen::GeneratedCode {
+    let opt = cxx_gen::Opt::default();
+    cxx_gen::generate_header_and_cc(tokens, &opt).expect("Could not generate C++ from Rust file")
+}
+
+/// Generated C++ header and source file contents for all the bridge modules in a Rust file
+pub struct GeneratedCpp {
+    modules: Vec<GeneratedCppModule>,
+}
+
+impl GeneratedCpp {
+    /// Generate QObject and cxx header/source C++ file contents
+    ///
+    /// Each `#[cxx::bridge]` or `#[cxx_qt::bridge]` module in the file generates its own
+    /// header and source files. CXX modules are named after the module and CXX-Qt modules
+    /// are named after the first QObject in the module.
+    pub fn new(rust_file_path: &impl AsRef<std::path::Path>) -> Self {
+        let file = parse_qt_file(rust_file_path).unwrap();
+
+        let mut modules: Vec<GeneratedCppModule> = vec![];
+
+        // Loop through the items looking for any CXX or CXX-Qt blocks
+        for item in &file.items {
+            let module = match item {
+                CxxQtItem::Cxx(m) => GeneratedCppModule::from_cxx(&file.attrs, m),
+                CxxQtItem::CxxQt(m) => GeneratedCppModule::from_cxx_qt(&file.attrs, m),
+                CxxQtItem::Item(_) => continue,
+            };
+
+            modules.push(module);
+        }
+
+        let file_idents = modules
+            .iter()
+            .map(|module| module.file_ident.as_str())
+            .collect();
+        let rust_file_path = rust_file_path.as_ref().display().to_string();
+        if let Err(message) = check_file_idents(&[(rust_file_path.as_str(), file_idents)]) {
+            panic!("{}", message);
+        }
+
+        GeneratedCpp { modules }
+    }
+
+    /// The names of the C++ files that will be generated, without the extension
+    pub fn file_idents(&self) -> impl Iterator<Item = &str> {
+        self.modules.iter().map(|module| module.file_ident.as_str())
+    }
+
+    /// The QObjects which are registered as QML elements, in the order they were declared
+    pub fn qml_elements(&self) -> impl Iterator<Item = &QmlElement> {
+        self.modules
+            .iter()
+            .flat_map(|module| module.qml_elements.iter())
+    }
+
+    /// Write generated code to files in a directory. Returns the absolute paths of all files written.
+    pub fn write_to_directory(&self, directory: &impl AsRef<std::path::Path>) -> Vec<PathBuf> {
+        let directory = directory.as_ref();
+        if !directory.is_dir() {
+            panic!(
+                "Output directory {} is not a directory",
+                directory.display()
+            );
+        }
+
+        let include_directory_path = PathBuf::from(format!("{}/include", &directory.display()));
+        std::fs::create_dir_all(&include_directory_path)
+            .expect("Could not create cxx-qt include dir");
+
+        let source_directory_path = PathBuf::from(format!("{}/src", &directory.display()));
+        std::fs::create_dir_all(&source_directory_path)
+            .expect("Could not create cxx-qt source dir");
+
+        self.modules
+            .iter()
+            .flat_map(|module| {
+                module.write_to_directory(&include_directory_path, &source_directory_path)
+            })
+            .collect()
+    }
+}
+
 /// Generate C++ files from a given list of Rust files, returning the generated paths
-fn write_cxx_generated_files_for_cargo(rs_source: &[&'static str]) -> Vec<PathBuf> {
+/// and the QML elements of the crate
+fn write_cxx_generated_files_for_cargo(
+    rs_source: &[&'static str],
+    qml_plugin_uri: Option<&str>,
+) -> (Vec<PathBuf>, Vec<QmlElement>) {
     let manifest_dir = manifest_dir();
     let directory = format!("{}/target/cxx-qt-gen", manifest_dir);
     std::fs::create_dir_all(&directory).expect("Could not create cxx-qt code generation directory");
 
+    let generated_codes = rs_source
+        .iter()
+        .map(|rs_path| {
+            let path = format!("{}/{}", manifest_dir, rs_path);
+            println!("cargo:rerun-if-changed={}", path);
+            GeneratedCpp::new(&path)
+        })
+        .collect::<Vec<GeneratedCpp>>();
+
+    // Check that the Rust files do not generate C++ files with the same name
+    let file_idents = rs_source
+        .iter()
+        .zip(&generated_codes)
+        .map(|(rs_path, generated_code)| (*rs_path, generated_code.file_idents().collect()))
+        .collect::<Vec<(&str, Vec<&str>)>>();
+    if let Err(message) = check_file_idents(&file_idents) {
+        panic!("{}", message);
+    }
+
     let mut cpp_files = Vec::new();
+    let mut qml_elements = Vec::new();
+    for generated_code in &generated_codes {
+        qml_elements.extend(generated_code.qml_elements().cloned());
+        cpp_files.append(&mut generated_code.write_to_directory(&directory));
+    }
 
-    for rs_path in rs_source {
-        let path = format!("{}/{}", manifest_dir, rs_path);
-        println!("cargo:rerun-if-changed={}", path);
+    // Generate a single function which registers all the QML elements of the crate
+    // and the metadata of each QML module for QML tooling
+    if !qml_elements.is_empty() {
+        cpp_files.append(&mut write_qml_registration(&qml_elements, &directory));
+        write_qml_modules(&qml_elements, &directory, qml_plugin_uri);
+    }
 
-        let generated_code = GeneratedCpp::new(&path);
-        cpp_files.append(&mut generated_code.write_to_directory(&directory));
+    (cpp_files, qml_elements)
+}
+
+/// Check that no two cxx or cxx_qt modules generate C++ files with the same name,
+/// given the names of the C++ files that each Rust file generates.
+///
+/// An error message is returned for the first name which collides, either between
+/// two modules of the same Rust file or between modules of two Rust files.
+fn check_file_idents(rust_files: &[(&str, Vec<&str>)]) -> Result<(), String> {
+    // Track which Rust file generated each file name, so that we can detect collisions
+    let mut file_idents: HashMap<&str, &str> = HashMap::new();
+    for (rs_path, idents) in rust_files {
+        for file_ident in idents {
+            match file_idents.insert(file_ident, rs_path) {
+                Some(other_rs_path) if other_rs_path == *rs_path => {
+                    return Err(format!(
+                        "The file {} has more than one cxx or cxx_qt module which would generate C++ files named {}.
+                        Rename one of the modules or the first #[cxx_qt::qobject] struct in one of the modules.",
+                        rs_path, file_ident
+                    ));
+                }
+                Some(other_rs_path) => {
+                    return Err(format!(
+                        "The files {} and {} both have a cxx or cxx_qt module which would generate C++ files named {}.
+                        Rename one of the modules or the first #[cxx_qt::qobject] struct in one of the modules.",
+                        other_rs_path, rs_path, file_ident
+                    ));
+                }
+                None => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The name of the crate being built in snake case, which is used for the crate wide generated files
+fn crate_ident() -> String {
+    env::var("CARGO_PKG_NAME")
+        .expect("Could not get package name")
+        .to_case(Case::Snake)
+}
+
+/// Write the C++ function which registers the given QML elements, returning the generated paths
+fn write_qml_registration(qml_elements: &[QmlElement], directory: &str) -> Vec<PathBuf> {
+    let crate_ident = crate_ident();
+    let generated = generate_qml_registration_cpp(qml_elements, &crate_ident);
+
+    let header_path = PathBuf::from(format!("{}/include/{}.qml.h", directory, crate_ident));
+    let mut header = File::create(&header_path).expect("Could not create QML registration header");
+    header
+        .write_all(generated.header.as_bytes())
+        .expect("Could not write QML registration header");
+
+    let cpp_path = PathBuf::from(format!("{}/src/{}.qml.cpp", directory, crate_ident));
+    let mut cpp = File::create(&cpp_path).expect("Could not create QML registration source");
+    cpp.write_all(generated.source.as_bytes())
+        .expect("Could not write QML registration source");
+
+    vec![header_path, cpp_path]
+}
+
+/// Write a qmldir and .qmltypes file for each QML module that the given QML elements are in
+///
+/// These are written to qml/{uri as a path}, eg qml/com/kdab for com.kdab, so that the qml
+/// directory can be added to the import path of QML tooling such as qmllint and Qt Creator.
+///
+/// The qmldir of the module which is built as a plugin refers to the plugin library,
+/// which is found in the directory that cargo writes the library to.
+fn write_qml_modules(qml_elements: &[QmlElement], directory: &str, qml_plugin_uri: Option<&str>) {
+    let mut modules: BTreeMap<&str, Vec<QmlElement>> = BTreeMap::new();
+    for element in qml_elements {
+        modules
+            .entry(element.uri())
+            .or_default()
+            .push(element.clone());
+    }
+
+    let qmltypes_file_name = format!("{}.qmltypes", crate_ident());
+    for (uri, elements) in modules {
+        let module_path = format!("{}/qml/{}", directory, uri.replace('.', "/"));
+        std::fs::create_dir_all(&module_path).expect("Could not create QML module dir");
+
+        let mut qmltypes = File::create(format!("{}/{}", module_path, qmltypes_file_name))
+            .expect("Could not create qmltypes file");
+        qmltypes
+            .write_all(generate_qmltypes(&elements).as_bytes())
+            .expect("Could not write qmltypes file");
+
+        let mut qmldir =
+            File::create(format!("{}/qmldir", module_path)).expect("Could not create qmldir file");
+        let contents = if qml_plugin_uri == Some(uri) {
+            generate_qml_plugin_qmldir(
+                uri,
+                &qmltypes_file_name,
+                &plugin_library_name(),
+                &plugin_library_dir(),
+            )
+        } else {
+            generate_qmldir(uri, &qmltypes_file_name)
+        };
+        qmldir
+            .write_all(contents.as_bytes())
+            .expect("Could not write qmldir file");
+    }
+}
+
+/// The name of the shared library of the crate without any prefix or suffix,
+/// cargo uses the package name with - replaced by _ unless the library is renamed
+fn plugin_library_name() -> String {
+    env::var("CARGO_PKG_NAME")
+        .expect("Could not get package name")
+        .replace('-', "_")
+}
+
+/// The directory which cargo writes the shared library of the crate to
+fn plugin_library_dir() -> String {
+    // OUT_DIR is {target dir}/{profile}/build/{package}-{hash}/out
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("Could not get OUT_DIR"));
+    let library_dir = out_dir
+        .ancestors()
+        .nth(3)
+        .expect("Could not find the output directory of the crate from OUT_DIR");
+    // qmldir files use / path separators
+    library_dir.display().to_string().replace('\\', "/")
+}
+
+/// Write the QQmlExtensionPlugin of the QML module with the given uri, returning the generated paths
+fn write_qml_plugin(qml_elements: &[QmlElement], uri: &str) -> Vec<PathBuf> {
+    if qml_elements.is_empty() {
+        panic!(
+            "The QML plugin {} has no QML elements, add qml_uri = \"{}\" to a #[cxx_qt::qobject]",
+            uri, uri
+        );
+    }
+    if let Some(element) = qml_elements.iter().find(|element| element.uri() != uri) {
+        panic!(
+            "The crate is built as the QML plugin {} but also has QML elements in {}.
+            All the QML elements of a QML plugin must be in the same QML module.",
+            uri,
+            element.uri()
+        );
     }
 
-    cpp_files
+    let directory = format!("{}/target/cxx-qt-gen", manifest_dir());
+    let crate_ident = crate_ident();
+    let generated = generate_qml_plugin_cpp(uri, &crate_ident);
+
+    let header_path = PathBuf::from(format!("{}/include/{}.qmlplugin.h", directory, crate_ident));
+    let mut header = File::create(&header_path).expect("Could not create QML plugin header");
+    header
+        .write_all(generated.header.as_bytes())
+        .expect("Could not write QML plugin header");
+
+    let cpp_path = PathBuf::from(format!("{}/src/{}.qmlplugin.cpp", directory, crate_ident));
+    let mut cpp = File::create(&cpp_path).expect("Could not create QML plugin source");
+    cpp.write_all(generated.source.as_bytes())
+        .expect("Could not write QML plugin source");
+
+    vec![header_path, cpp_path]
+}
+
+/// The Rust functions which export the entry points of a Qt plugin from the shared library
+///
+/// A Rust cdylib only exports the symbols of Rust functions, so the entry points generated by
+/// moc are renamed with defines when compiling and then called from these functions.
+///
+/// Qt 5 returns the plugin metadata as a string. Qt 6 returns a QPluginMetaData instead,
+/// which moc exports as qt_plugin_query_metadata_v2 from Qt 6.3 onwards.
+fn qml_plugin_rs(qtbuild: &QtBuild) -> String {
+    let version = qtbuild.version();
+    if version.major >= 6 {
+        let query_metadata = if version.minor >= 3 {
+            "qt_plugin_query_metadata_v2"
+        } else {
+            "qt_plugin_query_metadata"
+        };
+
+        format!(
+            r#"// This file was automatically generated by cxx-qt-build.
+
+#[repr(C)]
+pub struct QPluginMetaData {{
+    data: *const u8,
+    size: usize,
+}}
+
+extern "C" {{
+    fn cxx_qt_plugin_instance() -> *mut ::std::ffi::c_void;
+    fn cxx_{query_metadata}() -> QPluginMetaData;
+}}
+
+#[no_mangle]
+pub extern "C" fn qt_plugin_instance() -> *mut ::std::ffi::c_void {{
+    unsafe {{ cxx_qt_plugin_instance() }}
+}}
+
+#[no_mangle]
+pub extern "C" fn {query_metadata}() -> QPluginMetaData {{
+    unsafe {{ cxx_{query_metadata}() }}
+}}
+"#,
+            query_metadata = query_metadata
+        )
+    } else {
+        r#"// This file was automatically generated by cxx-qt-build.
+
+extern "C" {
+    fn cxx_qt_plugin_instance() -> *mut ::std::ffi::c_void;
+    fn cxx_qt_plugin_query_metadata() -> *const ::std::os::raw::c_char;
+}
+
+#[no_mangle]
+pub extern "C" fn qt_plugin_instance() -> *mut ::std::ffi::c_void {
+    unsafe { cxx_qt_plugin_instance() }
+}
+
+#[no_mangle]
+pub extern "C" fn qt_plugin_query_metadata() -> *const ::std::os::raw::c_char {
+    unsafe { cxx_qt_plugin_query_metadata() }
+}
+"#
+        .to_owned()
+    }
+}
+
+/// Compile the generated C++ files and any extra C++ files of the crate into a static library
+/// which is linked into the crate, and tell cargo to link the Qt modules
+///
+/// Generated headers which declare a QObject and the extra headers are processed by moc.
+/// When a QML plugin is built its entry points are renamed, as they are exported from Rust.
+fn compile_cpp(
+    qtbuild: &QtBuild,
+    generated_paths: &[PathBuf],
+    extra_paths: &[PathBuf],
+    qml_plugin: bool,
+) {
+    qtbuild.cargo_link_libraries();
+
+    let manifest_dir = manifest_dir();
+    let mut builder = cc::Build::new();
+    builder.cpp(true);
+    for include_path in qtbuild.include_paths() {
+        builder.include(include_path);
+    }
+    // The generated files include each other as cxx-qt-gen/include/...
+    builder.include(format!("{}/target", manifest_dir));
+    builder.include(format!("{}/target/cxx-qt-gen/statics", manifest_dir));
+    // Headers exported by dependencies which use cxx_build, such as cxx-qt-lib
+    for (key, value) in env::vars() {
+        if key.starts_with("DEP_") && key.contains("_CXXBRIDGE_DIR") {
+            builder.include(value);
+        }
+    }
+    // Rename the entry points of a QML plugin, they are exported from Rust instead
+    if qml_plugin {
+        builder.define("qt_plugin_instance", "cxx_qt_plugin_instance");
+        builder.define("qt_plugin_query_metadata", "cxx_qt_plugin_query_metadata");
+        builder.define(
+            "qt_plugin_query_metadata_v2",
+            "cxx_qt_plugin_query_metadata_v2",
+        );
+    }
+    // MSVC
+    builder.flag_if_supported("/std:c++17");
+    builder.flag_if_supported("/Zc:__cplusplus");
+    builder.flag_if_supported("/permissive-");
+    // GCC + Clang
+    builder.flag_if_supported("-std=c++17");
+
+    for path in generated_paths {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if file_name.ends_with(".cpp") {
+            builder.file(path);
+        } else if file_name.ends_with(".cxxqt.h") || file_name.ends_with(".qmlplugin.h") {
+            builder.file(qtbuild.moc(path));
+        }
+    }
+    for path in extra_paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("h") | Some("hpp") => builder.file(qtbuild.moc(path)),
+            _ => builder.file(path),
+        };
+    }
+    builder.compile(&format!("{}-cxxqt-generated", crate_ident()));
+}
+
+/// Run rcc on the given .qrc files and qmlcachegen on the given QML files, then write the
+/// function which initialises the resources and the QML cache, returning the generated paths
+fn write_qrc_resources(
+    qtbuild: &QtBuild,
+    qrc_files: &[&'static str],
+    qml_cache_files: &[(&'static str, String)],
+) -> Vec<PathBuf> {
+    let manifest_dir = manifest_dir();
+    let crate_ident = crate_ident();
+    let mut paths = Vec::with_capacity(qrc_files.len() + qml_cache_files.len() + 3);
+    let mut resource_names: Vec<String> = Vec::with_capacity(qrc_files.len() + 1);
+
+    for qrc_file in qrc_files {
+        let qrc_path = PathBuf::from(format!("{}/{}", manifest_dir, qrc_file));
+        // The resources are named after the crate and the .qrc file so that they are unique
+        // when multiple crates are linked together, the name needs to be a C++ identifier
+        let name = format!(
+            "{}_{}",
+            crate_ident,
+            qrc_path
+                .file_stem()
+                .expect("qrc file has no file name")
+                .to_string_lossy()
+        )
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+        if resource_names.contains(&name) {
+            panic!(
+                "More than one .qrc file would generate resources named {}, rename one of the .qrc files.",
+                name
+            );
+        }
+
+        paths.push(qtbuild.rcc(&qrc_path, &name));
+        resource_names.push(name);
+    }
+
+    if !qml_cache_files.is_empty() {
+        let qml_files = qml_cache_files
+            .iter()
+            .map(|(qml_file, resource_path)| {
+                (
+                    PathBuf::from(format!("{}/{}", manifest_dir, qml_file)),
+                    resource_path.clone(),
+                )
+            })
+            .collect::<Vec<(PathBuf, String)>>();
+        for (qml_path, resource_path) in &qml_files {
+            paths.push(qtbuild.qmlcachegen(qml_path, resource_path));
+        }
+
+        // The loader registers the compiled QML files and is initialised like a resource
+        let name = format!("qmlcache_{}", crate_ident);
+        paths.push(qtbuild.qmlcachegen_loader(&name, &qml_files));
+        resource_names.push(name);
+    }
+
+    let directory = format!("{}/target/cxx-qt-gen", manifest_dir);
+    std::fs::create_dir_all(format!("{}/include", directory))
+        .expect("Could not create cxx-qt include dir");
+    std::fs::create_dir_all(format!("{}/src", directory))
+        .expect("Could not create cxx-qt source dir");
+    let generated = generate_qrc_init_cpp(&resource_names, &crate_ident);
+
+    let header_path = PathBuf::from(format!("{}/include/{}.qrc.h", directory, crate_ident));
+    let mut header = File::create(&header_path).expect("Could not create qrc header");
+    header
+        .write_all(generated.header.as_bytes())
+        .expect("Could not write qrc header");
+    paths.push(header_path);
+
+    let cpp_path = PathBuf::from(format!("{}/src/{}.qrc.cpp", directory, crate_ident));
+    let mut cpp = File::create(&cpp_path).expect("Could not create qrc source");
+    cpp.write_all(generated.source.as_bytes())
+        .expect("Could not write qrc source");
+    paths.push(cpp_path);
+
+    paths
+}
+
+/// Write the Rust exports of the QML plugin, which are included by the cxx_qt::qml_plugin macro
+fn write_qml_plugin_rs(qtbuild: &QtBuild) {
+    let out_dir = env::var("OUT_DIR").expect("Could not get OUT_DIR");
+    let rs_directory = Path::new(&out_dir).join("cxx-qt-gen");
+    std::fs::create_dir_all(&rs_directory).expect("Could not create QML plugin Rust dir");
+    let mut rs = File::create(rs_directory.join("qml_plugin.rs"))
+        .expect("Could not create QML plugin Rust file");
+    rs.write_all(qml_plugin_rs(qtbuild).as_bytes())
+        .expect("Could not write QML plugin Rust file");
 }
 
 /// Write the list of C++ paths to the file
@@ -250,6 +693,12 @@ pub struct CxxQtBuilder {
     cpp_format: Option<ClangFormatStyle>,
     rust_sources: Vec<&'static str>,
     qt_enabled: bool,
+    qml_plugin_uri: Option<String>,
+    compile_cpp: bool,
+    cpp_files: Vec<&'static str>,
+    qrc_files: Vec<&'static str>,
+    qml_cache_files: Vec<(&'static str, String)>,
+    qt_modules: Vec<String>,
 }
 
 impl CxxQtBuilder {
@@ -259,6 +708,12 @@ impl CxxQtBuilder {
             cpp_format: None,
             rust_sources: vec![],
             qt_enabled: true,
+            qml_plugin_uri: None,
+            compile_cpp: false,
+            cpp_files: vec![],
+            qrc_files: vec![],
+            qml_cache_files: vec![],
+            qt_modules: vec!["Core".to_owned(), "Gui".to_owned(), "Qml".to_owned()],
         }
     }
 
@@ -284,6 +739,80 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Compile the generated C++ with cc and link it into the crate, rather than listing
+    /// the generated files for a C++ build system such as CMake
+    ///
+    /// Together with [CxxQtBuilder::cpp_file] this allows for building a Qt application
+    /// with cargo alone.
+    pub fn compile_cpp(mut self) -> Self {
+        self.compile_cpp = true;
+        self
+    }
+
+    /// Specify an extra C++ file to compile together with the generated C++,
+    /// headers are processed by moc so that they can declare QObjects
+    ///
+    /// Currently the path should be relative to CARGO_MANIFEST_DIR
+    pub fn cpp_file(mut self, cpp_source: &'static str) -> Self {
+        self.cpp_files.push(cpp_source);
+        self
+    }
+
+    /// Specify a Qt resource (.qrc) file to compile with rcc
+    ///
+    /// The compiled resources are compiled together with the generated C++, and as they are
+    /// linked from a static library they need to be initialised by calling
+    /// `cxx_qt::{crate name in snake case}::initResources()` from `cxx-qt-gen/include/{crate name in snake case}.qrc.h`.
+    /// The build script is rerun when the .qrc file or any of the files it lists change.
+    ///
+    /// Currently the path should be relative to CARGO_MANIFEST_DIR
+    pub fn qrc(mut self, qrc_file: &'static str) -> Self {
+        self.qrc_files.push(qrc_file);
+        self
+    }
+
+    /// Specify a QML file to compile ahead of time with qmlcachegen, so that it does not
+    /// need to be compiled when the application starts
+    ///
+    /// The `resource_path` is the path of the QML file in the Qt resources, eg `/qml/main.qml`
+    /// for a file which is loaded from `qrc:/qml/main.qml`, so the file also needs to be listed
+    /// in a .qrc file which is given to [CxxQtBuilder::qrc]. The compiled QML is initialised
+    /// together with the resources by `initResources()`.
+    ///
+    /// Currently the path should be relative to CARGO_MANIFEST_DIR
+    pub fn qml_cache(mut self, qml_file: &'static str, resource_path: &str) -> Self {
+        let resource_path = if resource_path.starts_with('/') {
+            resource_path.to_owned()
+        } else {
+            format!("/{}", resource_path)
+        };
+        self.qml_cache_files.push((qml_file, resource_path));
+        self
+    }
+
+    /// Link an extra Qt module when compiling the C++, omitting the `Qt` prefix (`"Quick"`
+    /// rather than `"QtQuick"`), Core, Gui, and Qml are always linked
+    pub fn qt_module(mut self, module: &str) -> Self {
+        if !self.qt_modules.iter().any(|other| other == module) {
+            self.qt_modules.push(module.to_owned());
+        }
+        self
+    }
+
+    /// Build the crate as the QQmlExtensionPlugin of the QML module with the given uri
+    ///
+    /// The plugin class and the qmldir of the module are generated, and the generated C++ is
+    /// compiled as with [CxxQtBuilder::compile_cpp] and linked into the crate, which needs to have the `cdylib` crate type and
+    /// to call the [cxx_qt::qml_plugin](https://docs.rs/cxx-qt/latest/cxx_qt/macro.qml_plugin.html)
+    /// macro once. All the QML elements of the crate must be in this QML module.
+    ///
+    /// The qmldir is written to `target/cxx-qt-gen/qml/{uri as a path}` and refers to the
+    /// library in the output directory of cargo, so that directory can be used as a QML import path.
+    pub fn qml_plugin(mut self, uri: &str) -> Self {
+        self.qml_plugin_uri = Some(uri.to_owned());
+        self
+    }
+
     // TODO: support globs with files("src/**/*.rs")
 
     /// Perform the build task, for example parsing and generating sources
@@ -296,8 +825,27 @@ impl CxxQtBuilder {
         // TODO: somewhere check that we don't have duplicate class names
         // TODO: later use the module::object to turn into module/object.h
 
+        // Qt is needed to run rcc and qmlcachegen, and to compile the C++
+        let build_cpp = self.compile_cpp || self.qml_plugin_uri.is_some();
+        let build_resources = !self.qrc_files.is_empty() || !self.qml_cache_files.is_empty();
+        let qtbuild = if build_cpp || build_resources {
+            Some(QtBuild::new(self.qt_modules.clone()).expect("Could not find Qt installation"))
+        } else {
+            None
+        };
+
         // Generate files
-        let cpp_paths = write_cxx_generated_files_for_cargo(&self.rust_sources);
+        let (mut cpp_paths, qml_elements) =
+            write_cxx_generated_files_for_cargo(&self.rust_sources, self.qml_plugin_uri.as_deref());
+        if let Some(qtbuild) = &qtbuild {
+            if build_resources {
+                cpp_paths.append(&mut write_qrc_resources(
+                    qtbuild,
+                    &self.qrc_files,
+                    &self.qml_cache_files,
+                ));
+            }
+        }
 
         // TODO: in large projects where where CXX-Qt is used in multiple individual
         // components that end up being linked together, having these same static
@@ -306,5 +854,68 @@ impl CxxQtBuilder {
 
         // TODO: find a way to only do this when cargo is called during the config stage of CMake
         write_cpp_sources_list(&cpp_paths);
+
+        // Build the C++ with cargo, rather than by a C++ build system
+        if let (true, Some(qtbuild)) = (build_cpp, &qtbuild) {
+            let mut generated_paths = cpp_paths;
+            if let Some(uri) = &self.qml_plugin_uri {
+                generated_paths.append(&mut write_qml_plugin(&qml_elements, uri));
+            }
+
+            let manifest_dir = manifest_dir();
+            let extra_paths = self
+                .cpp_files
+                .iter()
+                .map(|path| PathBuf::from(format!("{}/{}", manifest_dir, path)))
+                .collect::<Vec<PathBuf>>();
+            compile_cpp(
+                qtbuild,
+                &generated_paths,
+                &extra_paths,
+                self.qml_plugin_uri.is_some(),
+            );
+
+            if self.qml_plugin_uri.is_some() {
+                write_qml_plugin_rs(qtbuild);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_file_idents_unique() {
+        assert!(check_file_idents(&[
+            ("src/a.rs", vec!["ffi", "my_object"]),
+            ("src/b.rs", vec!["other_object"]),
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn test_check_file_idents_same_file() {
+        let message = check_file_idents(&[
+            ("src/a.rs", vec!["ffi"]),
+            ("src/b.rs", vec!["my_object", "my_object"]),
+        ])
+        .unwrap_err();
+        assert!(message.starts_with(
+            "The file src/b.rs has more than one cxx or cxx_qt module which would generate C++ files named my_object."
+        ));
+    }
+
+    #[test]
+    fn test_check_file_idents_cross_file() {
+        let message = check_file_idents(&[
+            ("src/a.rs", vec!["ffi", "my_object"]),
+            ("src/b.rs", vec!["my_object"]),
+        ])
+        .unwrap_err();
+        assert!(message.starts_with(
+            "The files src/a.rs and src/b.rs both have a cxx or cxx_qt module which would generate C++ files named my_object."
+        ));
     }
 }
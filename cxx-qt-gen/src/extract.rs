@@ -3,15 +3,27 @@
 // SPDX-FileContributor: Gerhard de Clercq <gerhard.declercq@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::parser::{signals::ParsedSignalsEnum, Parser};
+use crate::gen_cpp::CppType;
+use crate::parser::{
+    cpp_method::ParsedCppMethod,
+    invokable::{ParsedQInvokable, ParsedQInvokableSpecifiers},
+    property::{ParsedComputedQProperty, ParsedQPropertyOptions},
+    qenum::ParsedQEnum,
+    qgadget::ParsedQGadget,
+    qobject::{ParsedQObject, QmlMetadata},
+    signals::ParsedSignalsEnum,
+    Parser,
+};
+use crate::syntax::{attribute::attribute_find_path, path::path_compare_str};
 use convert_case::{Case, Casing};
 use derivative::*;
 use proc_macro2::{Span, TokenStream};
+use std::collections::HashSet;
 use std::result::Result;
 use syn::{spanned::Spanned, token::Brace, *};
 
 /// Describes an ident which has a different name in C++ and Rust
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct CppRustIdent {
     /// The ident for C++
     pub(crate) cpp_ident: Ident,
@@ -20,7 +32,7 @@ pub(crate) struct CppRustIdent {
 }
 
 /// Describes a Qt type
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) enum QtTypes {
     Bool,
     /// A CppObj which is being passed as a parameter in a method
@@ -49,9 +61,45 @@ pub(crate) enum QtTypes {
     I8,
     I16,
     I32,
+    QByteArray,
     QColor,
     QDate,
     QDateTime,
+    /// A #[cxx_qt::qenum] which has been declared in the module
+    QEnum {
+        /// The ident of the enum, which is the same in C++ and Rust
+        ident: Ident,
+        /// A cache of the ident as a string for C++
+        ident_string: String,
+    },
+    QModelIndex,
+    /// A #[cxx_qt::qgadget] which has been declared in the module
+    QGadget {
+        /// The ident of the struct, which is the same in C++ and Rust
+        ident: Ident,
+        /// A cache of the ident as a string for C++
+        ident_string: String,
+    },
+    /// A QHash<K, V> from cxx_qt_lib, which is named QHash_K_V in the CXX bridge
+    QHash {
+        /// The type of the keys in the hash
+        key: Box<QtTypes>,
+        /// The type of the values in the hash
+        value: Box<QtTypes>,
+        /// The ident of the type in the CXX bridge, eg QHash_i32_QByteArray
+        ident: Ident,
+        /// A cache of the C++ type as a string, eg "QHash<qint32, QByteArray>"
+        cpp_type_string: String,
+    },
+    /// A QList<T> from cxx_qt_lib, which is named QList_T in the CXX bridge
+    QList {
+        /// The type of the elements in the list
+        element: Box<QtTypes>,
+        /// The ident of the type in the CXX bridge, eg QList_i32
+        ident: Ident,
+        /// A cache of the C++ type as a string, eg "QList<qint32>"
+        cpp_type_string: String,
+    },
     QPoint,
     QPointF,
     QRect,
@@ -59,9 +107,21 @@ pub(crate) enum QtTypes {
     QSize,
     QSizeF,
     QString,
+    QStringList,
     QTime,
     QUrl,
     QVariant,
+    QVariantHash,
+    QVariantMap,
+    /// A QVector<T> from cxx_qt_lib, which is named QVector_T in the CXX bridge
+    QVector {
+        /// The type of the elements in the vector
+        element: Box<QtTypes>,
+        /// The ident of the type in the CXX bridge, eg QVector_i32
+        ident: Ident,
+        /// A cache of the C++ type as a string, eg "QVector<qint32>"
+        cpp_type_string: String,
+    },
     U8,
     U16,
     U32,
@@ -91,7 +151,7 @@ impl QtTypes {
 }
 
 /// Describes a type
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct ParameterType {
     /// If this parameter is mutable
     pub(crate) is_mut: bool,
@@ -102,7 +162,7 @@ pub(crate) struct ParameterType {
 }
 
 /// Describes a function parameter
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct Parameter {
     /// The ident of the parameter
     pub(crate) ident: Ident,
@@ -121,9 +181,26 @@ pub(crate) struct Invokable {
     /// The parameters that the function takes in
     pub(crate) parameters: Vec<Parameter>,
     /// The return type information
+    ///
+    /// When the invokable returns a Result this is the type of the Ok value
     pub(crate) return_type: Option<ParameterType>,
+    /// If the invokable returns a Result, this is the type of the error
+    ///
+    /// The error is converted to a C++ exception by CXX which is then thrown to QML
+    pub(crate) error_type: Option<Type>,
     /// Whether this invokable is using mut self or not
     pub(crate) mutable: bool,
+    /// If the invokable is an async fn, the signal which is emitted with the output
+    /// of the future once it has completed
+    ///
+    /// The invokable then has no return type and is given the CppObj as its first parameter
+    pub(crate) finished_signal: Option<Signal>,
+    /// Any C++ specifiers of the invokable, eg override
+    pub(crate) specifiers: HashSet<ParsedQInvokableSpecifiers>,
+    /// Whether the method is exposed as a Q_INVOKABLE
+    ///
+    /// The getter and setter of a computed property are called from C++ but are not invokable
+    pub(crate) is_qinvokable: bool,
     /// The original Rust method for the invokable
     #[derivative(Debug = "ignore")]
     pub(crate) original_method: ImplItemMethod,
@@ -139,20 +216,46 @@ pub(crate) struct Property {
     /// The getter ident of the property (used for READ)
     pub(crate) getter: Option<CppRustIdent>,
     /// The setter ident of the property (used for WRITE)
+    ///
+    /// For a constant property this is an initialise method, which only sets the value
+    /// from the Data struct during construction
     pub(crate) setter: Option<CppRustIdent>,
     /// The notify ident of the property (used for NOTIFY)
     pub(crate) notify: Option<CppRustIdent>,
-    // TODO: later we will further possibilities such as CONSTANT or FINAL
+    /// The reset ident of the property (used for RESET)
+    pub(crate) reset: Option<CppRustIdent>,
+    /// Whether the setter can be used from QML (used for WRITE)
+    ///
+    /// Note that the setter always exists so that Rust can change the value,
+    /// unless the property is constant
+    pub(crate) writable: bool,
+    /// Whether the property never changes after construction (used for CONSTANT)
+    pub(crate) constant: bool,
+    /// Whether the getter and setter are user written methods rather than generated
+    /// for a field of the Data struct
+    pub(crate) computed: bool,
+    // TODO: later we will further possibilities such as FINAL
 }
 
 /// Describes a signal that can be used from QML
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct Signal {
+    /// The C++ and Rust names of the free function which calls the handler of a connection
+    /// eg callDataChangedHandler and call_my_object_data_changed_handler
+    pub(crate) call_handler_ident: CppRustIdent,
+    /// The C++ and Rust names of the method to connect a closure to the signal
+    /// eg connectDataChanged and connect_data_changed
+    pub(crate) connect_ident: CppRustIdent,
     /// The C++ and Rust names of the method to emit the signal as queued
     /// eg emitDataChanged and emit_data_changed
     pub(crate) emit_ident: CppRustIdent,
     /// The Rust name of the enum entry, eg DataChanged
-    pub(crate) enum_ident: Ident,
+    ///
+    /// This is None for the finished signal of an async invokable as it is emitted by generated code
+    pub(crate) enum_ident: Option<Ident>,
+    /// The C++ and Rust names of the type which holds the closure of a connection
+    /// eg DataChangedHandler and MyObjectDataChangedHandler
+    pub(crate) handler_ident: CppRustIdent,
     /// The parameters of the Signal
     pub(crate) parameters: Vec<Parameter>,
     /// The C++ and Rust names of the method to emit the signal as immediate
@@ -165,6 +268,10 @@ pub(crate) struct Signal {
 pub struct QObject {
     /// The ident of the C++ class that represents the QObject
     pub ident: Ident,
+    /// The C++ base class of the QObject, eg QObject or QAbstractListModel
+    pub(crate) base_class: String,
+    /// The QML element that the QObject is registered as, if any
+    pub(crate) qml_metadata: Option<QmlMetadata>,
     /// All the methods that can also be invoked from QML
     pub(crate) invokables: Vec<Invokable>,
     /// All the methods that cannot be invoked from QML or C++, but are in the context of C++
@@ -175,12 +282,12 @@ pub struct QObject {
     pub(crate) signals: Vec<Signal>,
     /// The name of the signals enum that is used
     pub(crate) signal_ident: Option<Ident>,
+    /// All the enums that are registered with Q_ENUM on the QObject
+    pub(crate) qenums: Vec<ParsedQEnum>,
+    /// All the methods of the C++ class, or its base class, that can be called from Rust
+    pub(crate) cpp_methods: Vec<ParsedCppMethod>,
     /// The namespace to use for C++
     pub(crate) namespace: String,
-    /// Items we just pass through to the CXX bridge
-    pub(crate) cxx_items: Vec<Item>,
-    /// The original Rust mod for the struct
-    pub(crate) original_mod: ItemMod,
     /// The original Data struct that the object was generated from
     pub(crate) original_data_struct: ItemStruct,
     /// The original Rust struct that the object was generated from
@@ -193,6 +300,68 @@ pub struct QObject {
     pub(crate) handle_updates_impl: Option<ItemImpl>,
 }
 
+impl QObject {
+    /// Whether the base class of the QObject is one of the Qt item models,
+    /// which means that the protected model helpers (eg beginInsertRows) are available
+    pub(crate) fn is_item_model(&self) -> bool {
+        matches!(
+            self.base_class.as_str(),
+            "QAbstractItemModel" | "QAbstractListModel" | "QAbstractTableModel"
+        )
+    }
+}
+
+/// Describes a field of a Q_GADGET which is exposed as a Q_PROPERTY
+#[derive(Debug)]
+pub(crate) struct QGadgetField {
+    /// The ident of the property and the ident of the field
+    ///
+    /// Note that the C++ member keeps the name of the Rust field as it is a shared struct
+    pub(crate) ident: CppRustIdent,
+    /// The type of the field
+    pub(crate) type_ident: ParameterType,
+}
+
+/// Describes a Q_GADGET value type which is a shared struct between C++ and Rust
+#[derive(Debug)]
+pub(crate) struct QGadget {
+    /// The ident of the struct, which is the same in C++ and Rust
+    pub(crate) ident: Ident,
+    /// The fields of the struct which are exposed as properties
+    pub(crate) fields: Vec<QGadgetField>,
+    /// The original struct with the CXX-Qt attribute removed
+    pub(crate) original_struct: ItemStruct,
+}
+
+/// Describes a CXX-Qt bridge module and all of the QObject classes it declares
+#[derive(Debug)]
+pub struct CxxQtModule {
+    /// The stem of the file names used for the generated C++ header and source
+    ///
+    /// This is the snake case ident of the first QObject in the module
+    pub cxx_stem: String,
+    /// All the QObjects in the order they were declared in the module
+    pub qobjects: Vec<QObject>,
+    /// All the Q_GADGET value types in the order they were declared in the module
+    pub(crate) qgadgets: Vec<QGadget>,
+    /// All the enums that are registered with Q_ENUM_NS on the namespace of the module
+    pub(crate) qenums: Vec<ParsedQEnum>,
+    /// The namespace to use for C++
+    pub(crate) namespace: String,
+    /// Items we just pass through to the CXX bridge
+    pub(crate) cxx_items: Vec<Item>,
+    /// The original Rust mod for the struct
+    pub(crate) original_mod: ItemMod,
+}
+
+/// The idents of the types which are declared in the CXX-Qt module and can be used by the QObjects
+pub(crate) struct DeclaredTypes {
+    /// The idents of any #[cxx_qt::qenum] enums
+    pub(crate) qenums: Vec<Ident>,
+    /// The idents of any #[cxx_qt::qgadget] structs
+    pub(crate) qgadgets: Vec<Ident>,
+}
+
 /// Describe the error type from extract_qt_type and extract_type_ident
 enum ExtractTypeIdentError {
     /// We do not support AngleBracketed or Parenthesized rust types
@@ -210,6 +379,7 @@ fn extract_qt_type(
     idents: &[Ident],
     original_ty: &syn::Type,
     qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
 ) -> Result<QtTypes, ExtractTypeIdentError> {
     // TODO: can we support generic Qt types as well eg like QObject or QAbstractListModel?
     // so that QML can set a C++/QML type into the property ? or is that not useful?
@@ -233,9 +403,11 @@ fn extract_qt_type(
             "i8" => Ok(QtTypes::I8),
             "i16" => Ok(QtTypes::I16),
             "i32" => Ok(QtTypes::I32),
+            "QByteArray" => Ok(QtTypes::QByteArray),
             "QColor" => Ok(QtTypes::QColor),
             "QDate" => Ok(QtTypes::QDate),
             "QDateTime" => Ok(QtTypes::QDateTime),
+            "QModelIndex" => Ok(QtTypes::QModelIndex),
             "QPoint" => Ok(QtTypes::QPoint),
             "QPointF" => Ok(QtTypes::QPointF),
             "QRect" => Ok(QtTypes::QRect),
@@ -243,18 +415,41 @@ fn extract_qt_type(
             "QSize" => Ok(QtTypes::QSize),
             "QSizeF" => Ok(QtTypes::QSizeF),
             "QString" => Ok(QtTypes::QString),
+            "QStringList" => Ok(QtTypes::QStringList),
             "QTime" => Ok(QtTypes::QTime),
             "QUrl" => Ok(QtTypes::QUrl),
             "QVariant" => Ok(QtTypes::QVariant),
+            "QVariantHash" => Ok(QtTypes::QVariantHash),
+            "QVariantMap" => Ok(QtTypes::QVariantMap),
             "u8" => Ok(QtTypes::U8),
             "u16" => Ok(QtTypes::U16),
             "u32" => Ok(QtTypes::U32),
+            other
+                if other.starts_with("QHash_")
+                    || other.starts_with("QList_")
+                    || other.starts_with("QVector_") =>
+            {
+                extract_container_type(&idents[0], original_ty, qt_ident, declared_types)
+            }
+            _other if declared_types.qenums.contains(&idents[0]) => Ok(QtTypes::QEnum {
+                ident: idents[0].clone(),
+                ident_string: idents[0].to_string(),
+            }),
+            _other if declared_types.qgadgets.contains(&idents[0]) => Ok(QtTypes::QGadget {
+                ident: idents[0].clone(),
+                ident_string: idents[0].to_string(),
+            }),
             _other => Ok(QtTypes::Unknown),
         }
     // This is a UniquePtr<T> field
     } else if idents.len() > 1 && idents.first().unwrap().to_string().as_str() == "UniquePtr" {
         Ok(QtTypes::UniquePtr {
-            inner: Box::new(extract_qt_type(&idents[1..], original_ty, qt_ident)?),
+            inner: Box::new(extract_qt_type(
+                &idents[1..],
+                original_ty,
+                qt_ident,
+                declared_types,
+            )?),
         })
     // This is an unknown type that did not start with crate and has multiple parts
     } else {
@@ -263,6 +458,114 @@ fn extract_qt_type(
     }
 }
 
+/// Extract the Qt type of a QHash_K_V, QList_T, or QVector_T container from cxx_qt_lib
+fn extract_container_type(
+    ident: &Ident,
+    original_ty: &syn::Type,
+    qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
+) -> Result<QtTypes, ExtractTypeIdentError> {
+    let ident_string = ident.to_string();
+    // We can assume there is an underscore as the caller checked the prefix
+    let (container, element) = ident_string.split_once('_').unwrap();
+    if container == "QHash" {
+        return extract_hash_type(ident, element, original_ty, qt_ident, declared_types);
+    }
+
+    let element = extract_qt_type(
+        &[quote::format_ident!("{}", element)],
+        original_ty,
+        qt_ident,
+        declared_types,
+    )?;
+
+    // cxx_qt_lib only provides containers for these element types
+    if !matches!(
+        element,
+        QtTypes::Bool
+            | QtTypes::F32
+            | QtTypes::F64
+            | QtTypes::I8
+            | QtTypes::I16
+            | QtTypes::I32
+            | QtTypes::QColor
+            | QtTypes::QDate
+            | QtTypes::QDateTime
+            | QtTypes::QPoint
+            | QtTypes::QPointF
+            | QtTypes::QRect
+            | QtTypes::QRectF
+            | QtTypes::QSize
+            | QtTypes::QSizeF
+            | QtTypes::QString
+            | QtTypes::QTime
+            | QtTypes::QUrl
+            | QtTypes::QVariant
+            | QtTypes::U8
+            | QtTypes::U16
+            | QtTypes::U32
+    ) {
+        return Ok(QtTypes::Unknown);
+    }
+
+    let cpp_type_string = format!("{}<{}>", container, element.type_ident());
+    let element = Box::new(element);
+    let ident = ident.clone();
+    if container == "QList" {
+        Ok(QtTypes::QList {
+            element,
+            ident,
+            cpp_type_string,
+        })
+    } else {
+        Ok(QtTypes::QVector {
+            element,
+            ident,
+            cpp_type_string,
+        })
+    }
+}
+
+/// Extract the Qt type of a QHash_K_V container from cxx_qt_lib
+fn extract_hash_type(
+    ident: &Ident,
+    pair: &str,
+    original_ty: &syn::Type,
+    qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
+) -> Result<QtTypes, ExtractTypeIdentError> {
+    let (key, value) = if let Some(pair) = pair.split_once('_') {
+        pair
+    } else {
+        return Ok(QtTypes::Unknown);
+    };
+    let key = extract_qt_type(
+        &[quote::format_ident!("{}", key)],
+        original_ty,
+        qt_ident,
+        declared_types,
+    )?;
+    let value = extract_qt_type(
+        &[quote::format_ident!("{}", value)],
+        original_ty,
+        qt_ident,
+        declared_types,
+    )?;
+
+    // cxx_qt_lib only provides the QHash<int, QByteArray> of QAbstractItemModel::roleNames,
+    // QVariantHash is declared with its own name
+    if !matches!((&key, &value), (QtTypes::I32, QtTypes::QByteArray)) {
+        return Ok(QtTypes::Unknown);
+    }
+
+    Ok(QtTypes::QHash {
+        cpp_type_string: format!("QHash<{}, {}>", key.type_ident(), value.type_ident()),
+        key: Box::new(key),
+        value: Box::new(value),
+        ident: ident.clone(),
+    })
+}
+
 /// Converts a given path to a vector of idents
 fn path_to_idents(path: &syn::Path) -> Result<Vec<Ident>, ExtractTypeIdentError> {
     // We do support UniquePtr<T> for now
@@ -299,6 +602,7 @@ fn path_to_idents(path: &syn::Path) -> Result<Vec<Ident>, ExtractTypeIdentError>
 fn extract_type_ident(
     ty: &syn::Type,
     qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
 ) -> Result<ParameterType, ExtractTypeIdentError> {
     // Temporary storage of the current syn::TypePath if one is found
     let ty_path;
@@ -333,7 +637,7 @@ fn extract_type_ident(
 
     let idents = path_to_idents(&ty_path.path)?;
     // Extract the Qt type this is used in C++ and Rust generation
-    let qt_type = extract_qt_type(&idents, ty, qt_ident)?;
+    let qt_type = extract_qt_type(&idents, ty, qt_ident, declared_types)?;
 
     // Check if this Qt type is allowed to be a ref mut
     if is_mut && is_ref && !qt_type.ref_mut_is_valid() {
@@ -352,6 +656,7 @@ fn extract_type_ident(
 pub(crate) fn extract_method_params(
     method: &ImplItemMethod,
     qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
 ) -> Result<Vec<Parameter>, TokenStream> {
     method.sig.inputs
         .iter()
@@ -378,7 +683,7 @@ pub(crate) fn extract_method_params(
                 };
 
                 // Try to extract the type of the parameter
-                match extract_type_ident(ty, qt_ident) {
+                match extract_type_ident(ty, qt_ident, declared_types) {
                     Ok(result) => type_ident = result,
                     Err(ExtractTypeIdentError::InvalidArguments(span)) => {
                         return Err(Error::new(
@@ -431,16 +736,61 @@ fn is_method_mutable(method: &ImplItemMethod) -> bool {
     false
 }
 
-fn extract_invokable(method: &ImplItemMethod, qt_ident: &Ident) -> Result<Invokable, TokenStream> {
+/// If the given type is a Result<T, E> then return the types of the Ok and Err values
+fn extract_result_types(ty: &Type) -> Option<(&Type, &Type)> {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        let segment = path.segments.last()?;
+        if segment.ident != "Result" {
+            return None;
+        }
+
+        if let PathArguments::AngleBracketed(angles) = &segment.arguments {
+            let mut types = angles.args.iter().filter_map(|arg| {
+                if let GenericArgument::Type(ty) = arg {
+                    Some(ty)
+                } else {
+                    None
+                }
+            });
+            if let (Some(ok_type), Some(err_type), None) =
+                (types.next(), types.next(), types.next())
+            {
+                return Some((ok_type, err_type));
+            }
+        }
+    }
+
+    None
+}
+
+fn extract_invokable(
+    invokable: &ParsedQInvokable,
+    qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
+) -> Result<Invokable, TokenStream> {
+    let method = &invokable.method;
     let method_ident = &method.sig.ident;
     let output = &method.sig.output;
 
     let mutable = is_method_mutable(method);
-    let parameters = extract_method_params(method, qt_ident)?;
+    let mut parameters = extract_method_params(method, qt_ident, declared_types)?;
+    let is_async = method.sig.asyncness.is_some();
+
+    // If the output is a Result then the Ok type is the return type and the error is stored
+    let (output, error_type) = match output {
+        ReturnType::Type(_, ty) => match extract_result_types(ty) {
+            Some((Type::Tuple(TypeTuple { elems, .. }), err_type)) if elems.is_empty() => {
+                (None, Some(err_type.clone()))
+            }
+            Some((ok_type, err_type)) => (Some(ok_type), Some(err_type.clone())),
+            None => (Some(&**ty), None),
+        },
+        ReturnType::Default => (None, None),
+    };
 
-    let return_type = if let ReturnType::Type(_, ty) = output {
+    let return_type = if let Some(ty) = output {
         // This output has a return type, so extract the type
-        match extract_type_ident(ty, qt_ident) {
+        match extract_type_ident(ty, qt_ident, declared_types) {
             Ok(result) => Some(result),
             Err(ExtractTypeIdentError::InvalidArguments(span)) => {
                 return Err(Error::new(
@@ -476,10 +826,79 @@ fn extract_invokable(method: &ImplItemMethod, qt_ident: &Ident) -> Result<Invoka
         rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
     };
 
+    // The future of an async invokable is polled after the invokable has returned,
+    // so it cannot borrow the Rust object, the CppObj, or any of the parameters
+    let (return_type, finished_signal) = if is_async {
+        if let Some(FnArg::Receiver(receiver)) = method.sig.inputs.first() {
+            return Err(Error::new(
+                receiver.span(),
+                "An async qinvokable cannot take self, return a value instead which is given to the finished signal.",
+            )
+            .to_compile_error());
+        }
+        if parameters.iter().any(|parameter| {
+            parameter.type_ident.is_ref
+                || matches!(parameter.type_ident.qt_type, QtTypes::CppObj { .. })
+        }) {
+            return Err(Error::new(
+                method.sig.inputs.span(),
+                "The parameters of an async qinvokable must be passed by value and cannot be the CppObj.",
+            )
+            .to_compile_error());
+        }
+        if error_type.is_some() || !invokable.specifiers.is_empty() {
+            return Err(Error::new(
+                method.sig.span(),
+                "An async qinvokable cannot return a Result or have specifiers.",
+            )
+            .to_compile_error());
+        }
+        if matches!(&return_type, Some(return_type) if return_type.is_ref) {
+            return Err(Error::new(
+                method.sig.output.span(),
+                "The return type of an async qinvokable cannot be a reference.",
+            )
+            .to_compile_error());
+        }
+
+        // The CppObj is used to spawn the future onto the thread of the QObject
+        let cpp_obj_type: Type = syn::parse_quote! { &mut CppObj };
+        parameters.insert(
+            0,
+            Parameter {
+                ident: quote::format_ident!("cpp"),
+                type_ident: extract_type_ident(&cpp_obj_type, qt_ident, declared_types).map_err(
+                    |_| {
+                        Error::new(method.sig.span(), "Failed to build the CppObj parameter.")
+                            .to_compile_error()
+                    },
+                )?,
+            },
+        );
+
+        // The output of the future is given to the finished signal instead
+        let finished_signal = build_signal(
+            qt_ident,
+            &format!("{}_finished", ident_str.to_case(Case::Snake)),
+            None,
+            return_type
+                .into_iter()
+                .map(|type_ident| Parameter {
+                    ident: quote::format_ident!("value"),
+                    type_ident,
+                })
+                .collect(),
+        );
+        (None, Some(finished_signal))
+    } else {
+        (return_type, None)
+    };
+
     // We need a wrapper for any opaque types or pointers in the parameters or return types
-    let ident_wrapper = if return_type
-        .as_ref()
-        .map_or(false, |return_type| return_type.qt_type.is_opaque())
+    //
+    // The future of an async invokable is always spawned from a wrapper
+    let ident_wrapper = if is_async
+        || matches!(&return_type, Some(return_type) if return_type.qt_type.is_opaque())
         || parameters
             .iter()
             .any(|parameter| parameter.type_ident.qt_type.is_opaque())
@@ -496,14 +915,22 @@ fn extract_invokable(method: &ImplItemMethod, qt_ident: &Ident) -> Result<Invoka
         ident: ident_method,
         ident_wrapper,
         mutable,
+        finished_signal,
+        specifiers: invokable.specifiers.iter().cloned().collect(),
+        is_qinvokable: true,
         parameters,
         return_type,
+        error_type,
         original_method: method.to_owned(), // TODO: remove to_owned once extract_invokable is split
     })
 }
 
 /// Extracts all the attributes from a struct and generates properties from them
-fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>, TokenStream> {
+fn extract_properties(
+    s: &ItemStruct,
+    qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
+) -> Result<Vec<Property>, TokenStream> {
     let mut properties = Vec::new();
 
     // TODO: we need to set up an exclude list of properties names and give
@@ -523,8 +950,7 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
         for name in named {
             // Extract only fields with an ident (should be all as these are named fields).
             if let Field {
-                // TODO: later we'll need to read the attributes (eg qt_property) here
-                // attrs,
+                attrs,
                 ident: Some(ident),
                 ty,
                 ..
@@ -533,7 +959,7 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
                 // Extract the type of the field
                 let type_ident;
 
-                match extract_type_ident(ty, qt_ident) {
+                match extract_type_ident(ty, qt_ident, declared_types) {
                     Ok(result) => type_ident = result,
                     Err(ExtractTypeIdentError::InvalidArguments(span)) => {
                         return Err(Error::new(
@@ -555,9 +981,35 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
                     }
                 }
 
+                // Read any options from a #[qproperty(...)] attribute on the field
+                let options = if let Some(index) = attribute_find_path(attrs, &["qproperty"]) {
+                    ParsedQPropertyOptions::parse(&attrs[index])
+                        .map_err(|err| err.to_compile_error())?
+                } else {
+                    ParsedQPropertyOptions::default()
+                };
+                // A nested object can always be taken or given from Rust, so it cannot be constant
+                if options.constant && matches!(type_ident.qt_type, QtTypes::CppObj { .. }) {
+                    return Err(Error::new(
+                        ty.span(),
+                        "A constant qproperty cannot be a nested object.",
+                    )
+                    .to_compile_error());
+                }
+
+                // The setter of a field is always generated
+                if let Some(write) = &options.write {
+                    return Err(Error::new(
+                        write.span(),
+                        "A write method is only supported on a computed qproperty.",
+                    )
+                    .to_compile_error());
+                }
+
                 // Build the getter/setter/notify idents with their Rust and C++ idents
                 //
-                // TODO: later these can be optional and have custom names from macro attributes
+                // Note that the getter and setter always exist as Rust needs to be able to
+                // read and write the value, the options only change what QML can access
                 //
                 // TODO: we might also need to store whether a custom method is already implemented
                 // or whether a method needs to be auto generated on the rust side
@@ -572,14 +1024,49 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
                     cpp_ident: quote::format_ident!("get{}", ident_str.to_case(Case::Pascal)),
                     rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
                 });
-                let setter = Some(CppRustIdent {
-                    cpp_ident: quote::format_ident!("set{}", ident_str.to_case(Case::Pascal)),
-                    rust_ident: quote::format_ident!("set_{}", ident_str.to_case(Case::Snake)),
+                // A constant property can only be set from the Data struct during construction,
+                // so it has an initialise method instead of a public setter
+                let setter = Some(if options.constant {
+                    CppRustIdent {
+                        cpp_ident: quote::format_ident!(
+                            "initialise{}",
+                            ident_str.to_case(Case::Pascal)
+                        ),
+                        rust_ident: quote::format_ident!(
+                            "initialise_{}",
+                            ident_str.to_case(Case::Snake)
+                        ),
+                    }
+                } else {
+                    CppRustIdent {
+                        cpp_ident: quote::format_ident!("set{}", ident_str.to_case(Case::Pascal)),
+                        rust_ident: quote::format_ident!("set_{}", ident_str.to_case(Case::Snake)),
+                    }
                 });
-                let notify = Some(CppRustIdent {
-                    cpp_ident: quote::format_ident!("{}Changed", ident_str.to_case(Case::Camel)),
-                    // TODO: rust doesn't have notify on it's side?
-                    rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
+                let notify = if options.constant {
+                    None
+                } else if let Some(notify) = &options.notify {
+                    let notify_str = notify.to_string();
+                    Some(CppRustIdent {
+                        cpp_ident: quote::format_ident!("{}", notify_str.to_case(Case::Camel)),
+                        rust_ident: notify.clone(),
+                    })
+                } else {
+                    Some(CppRustIdent {
+                        cpp_ident: quote::format_ident!(
+                            "{}Changed",
+                            ident_str.to_case(Case::Camel)
+                        ),
+                        // TODO: rust doesn't have notify on it's side?
+                        rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
+                    })
+                };
+                let reset = options.reset.as_ref().map(|reset| {
+                    let reset_str = reset.to_string();
+                    CppRustIdent {
+                        cpp_ident: quote::format_ident!("{}", reset_str.to_case(Case::Camel)),
+                        rust_ident: reset.clone(),
+                    }
                 });
 
                 // Build and push the property
@@ -589,6 +1076,10 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
                     getter,
                     setter,
                     notify,
+                    reset,
+                    writable: !options.read_only && !options.constant,
+                    constant: options.constant,
+                    computed: false,
                 });
             }
         }
@@ -597,6 +1088,122 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
     Ok(properties)
 }
 
+/// Extracts a computed property from its getter method and an optional write method
+///
+/// The getter and write method are returned as invokables which are not exposed as Q_INVOKABLE
+fn extract_computed_property(
+    computed: &ParsedComputedQProperty,
+    methods: &mut Vec<ImplItemMethod>,
+    qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
+) -> Result<(Property, Vec<Invokable>), TokenStream> {
+    // A helper which extracts a method into an invokable which is not a Q_INVOKABLE
+    let extract_method = |method: &ImplItemMethod| -> Result<Invokable, TokenStream> {
+        let parsed = ParsedQInvokable {
+            method: method.clone(),
+            specifiers: HashSet::new(),
+        };
+        let mut invokable = extract_invokable(&parsed, qt_ident, declared_types)?;
+        invokable.is_qinvokable = false;
+        Ok(invokable)
+    };
+    // The only parameter that the getter can have, and which the setter can have
+    // in addition to the value, is the CppObj
+    let is_cpp_obj =
+        |parameter: &Parameter| matches!(parameter.type_ident.qt_type, QtTypes::CppObj { .. });
+    // The getter can return a UniquePtr<T> where the setter takes a &T
+    let inner_type = |qt_type: &QtTypes| match qt_type {
+        QtTypes::UniquePtr { inner } => inner.as_ref().clone(),
+        others => others.clone(),
+    };
+
+    // The getter defines the name and type of the property
+    let getter = extract_method(&computed.method)?;
+    let type_ident = match &getter.return_type {
+        Some(return_type)
+            if getter.error_type.is_none() && getter.parameters.iter().all(is_cpp_obj) =>
+        {
+            return_type.clone()
+        }
+        _others => {
+            return Err(Error::new(
+                computed.method.sig.span(),
+                "The getter of a computed qproperty must return a value and have no parameters other than the CppObj.",
+            )
+            .to_compile_error())
+        }
+    };
+
+    // Find the write method in the normal methods as it is now called from C++
+    let setter = if let Some(write) = &computed.options.write {
+        let index = methods
+            .iter()
+            .position(|method| method.sig.ident == *write)
+            .ok_or_else(|| {
+                Error::new(
+                    write.span(),
+                    "The write method of a computed qproperty must be a method without #[qinvokable] in the same impl block.",
+                )
+                .to_compile_error()
+            })?;
+        let setter = extract_method(&methods.remove(index))?;
+
+        // The setter must take a single value of the same type as the property
+        let values = setter
+            .parameters
+            .iter()
+            .filter(|parameter| !is_cpp_obj(parameter))
+            .collect::<Vec<&Parameter>>();
+        if setter.return_type.is_some()
+            || setter.error_type.is_some()
+            || values.len() != 1
+            || inner_type(&values[0].type_ident.qt_type) != inner_type(&type_ident.qt_type)
+        {
+            return Err(Error::new(
+                write.span(),
+                "The write method of a computed qproperty must take a value of the property type and have no return type.",
+            )
+            .to_compile_error());
+        }
+
+        Some(setter)
+    } else {
+        None
+    };
+
+    let ident_str = computed.method.sig.ident.to_string();
+    let property = Property {
+        ident: CppRustIdent {
+            cpp_ident: quote::format_ident!("{}", ident_str.to_case(Case::Camel)),
+            rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
+        },
+        type_ident,
+        getter: Some(CppRustIdent {
+            cpp_ident: getter.ident.cpp_ident.clone(),
+            rust_ident: getter.ident.rust_ident.clone(),
+        }),
+        setter: setter.as_ref().map(|setter| CppRustIdent {
+            cpp_ident: setter.ident.cpp_ident.clone(),
+            rust_ident: setter.ident.rust_ident.clone(),
+        }),
+        // A computed property has no generated setter to emit the notify signal,
+        // so this is checked later to be a signal that Rust can emit
+        notify: computed.options.notify.as_ref().map(|notify| CppRustIdent {
+            cpp_ident: quote::format_ident!("{}", notify.to_string().to_case(Case::Camel)),
+            rust_ident: notify.clone(),
+        }),
+        reset: computed.options.reset.as_ref().map(|reset| CppRustIdent {
+            cpp_ident: quote::format_ident!("{}", reset.to_string().to_case(Case::Camel)),
+            rust_ident: reset.clone(),
+        }),
+        writable: setter.is_some(),
+        constant: computed.options.constant,
+        computed: true,
+    };
+
+    Ok((property, std::iter::once(getter).chain(setter).collect()))
+}
+
 /// Extracts all the fields from an enum and generates signals from them
 //
 // TODO: for now we still extract the ParsedSignals into a Signal blocks
@@ -604,19 +1211,17 @@ fn extract_properties(s: &ItemStruct, qt_ident: &Ident) -> Result<Vec<Property>,
 fn extract_signals(
     signals: &ParsedSignalsEnum,
     qt_ident: &Ident,
+    declared_types: &DeclaredTypes,
 ) -> Result<Vec<Signal>, TokenStream> {
     signals.signals.iter().map(|signal| {
-        let ident_str = signal.ident.to_string();
-        Ok(Signal {
-            emit_ident: CppRustIdent {
-                cpp_ident: quote::format_ident!("emit{}", ident_str.to_case(Case::Pascal)),
-                rust_ident: quote::format_ident!("emit_{}", ident_str.to_case(Case::Snake)),
-            },
-            enum_ident: signal.ident.clone(),
-            parameters: signal.parameters.iter().map(|parameter| {
+        Ok(build_signal(
+            qt_ident,
+            &signal.ident.to_string(),
+            Some(signal.ident.clone()),
+            signal.parameters.iter().map(|parameter| {
                 Ok(Parameter {
                     ident: parameter.ident.clone(),
-                    type_ident: match extract_type_ident(&parameter.ty, qt_ident) {
+                    type_ident: match extract_type_ident(&parameter.ty, qt_ident, declared_types) {
                         Ok(result) => result,
                         Err(ExtractTypeIdentError::InvalidArguments(span)) => {
                             return Err(Error::new(
@@ -639,105 +1244,209 @@ fn extract_signals(
                     },
                 })
             }).collect::<Result<Vec<Parameter>, TokenStream>>()?,
-            signal_ident: CppRustIdent {
-                cpp_ident: quote::format_ident!("{}", ident_str.to_case(Case::Camel)),
-                rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
-            },
-        })
+        ))
     }).collect()
 }
 
-/// Parses a module in order to extract a QObject description from it
-pub fn extract_qobject(module: &ItemMod) -> Result<QObject, TokenStream> {
-    // Build a parser for the given ItemMod
-    //
-    // TODO: in the future steps from this extract.rs file will be moved into module parts
-    // of Parser
-    let mut parser = Parser::from(module.to_owned()).map_err(|err| err.to_compile_error())?;
-
-    // TODO: for now we only support one QObject per ItemMod block
-    // so extract the first qobject we find
-    if parser.cxx_qt_data.qobjects.len() != 1 {
-        return Err(Error::new(
-            module.span(),
-            "Only one QObject is currently supported in the ItemMod.",
-        )
-        .to_compile_error());
+/// Build the idents of a signal with the given name
+fn build_signal(
+    qt_ident: &Ident,
+    ident_str: &str,
+    enum_ident: Option<Ident>,
+    parameters: Vec<Parameter>,
+) -> Signal {
+    Signal {
+        call_handler_ident: CppRustIdent {
+            cpp_ident: quote::format_ident!("call{}Handler", ident_str.to_case(Case::Pascal)),
+            rust_ident: quote::format_ident!(
+                "call_{}_{}_handler",
+                qt_ident.to_string().to_case(Case::Snake),
+                ident_str.to_case(Case::Snake)
+            ),
+        },
+        connect_ident: CppRustIdent {
+            cpp_ident: quote::format_ident!("connect{}", ident_str.to_case(Case::Pascal)),
+            rust_ident: quote::format_ident!("connect_{}", ident_str.to_case(Case::Snake)),
+        },
+        emit_ident: CppRustIdent {
+            cpp_ident: quote::format_ident!("emit{}", ident_str.to_case(Case::Pascal)),
+            rust_ident: quote::format_ident!("emit_{}", ident_str.to_case(Case::Snake)),
+        },
+        enum_ident,
+        handler_ident: CppRustIdent {
+            cpp_ident: quote::format_ident!("{}Handler", ident_str.to_case(Case::Pascal)),
+            rust_ident: quote::format_ident!(
+                "{}{}Handler",
+                qt_ident,
+                ident_str.to_case(Case::Pascal)
+            ),
+        },
+        parameters,
+        signal_ident: CppRustIdent {
+            cpp_ident: quote::format_ident!("{}", ident_str.to_case(Case::Camel)),
+            rust_ident: quote::format_ident!("{}", ident_str.to_case(Case::Snake)),
+        },
     }
-    let (qt_ident, qobject) = parser.cxx_qt_data.qobjects.drain().take(1).next().unwrap();
-
-    // Find the items from the module
-    let original_mod = parser.passthrough_module.clone();
+}
 
+/// Extract a QObject description from a ParsedQObject
+fn extract_qobject(
+    qt_ident: Ident,
+    qobject: ParsedQObject,
+    uses: &[Item],
+    namespace: &str,
+    declared_types: &DeclaredTypes,
+) -> Result<QObject, TokenStream> {
     // Prepare variables to store struct, invokables, and other data
     //
     // The original Data Item::Struct if one is found
-    let original_data_struct = qobject.data_struct;
+    let mut original_data_struct = qobject.data_struct;
     // The original #[cxx_qt::qobject] marked struct Item::Struct if one is found
     //
     // qobject_struct will always exist if we have a qobject, so unwrap for now
     let original_rust_struct = qobject.qobject_struct.unwrap();
 
     // A list of the invokables for the struct
-    let object_invokables = qobject
+    let mut object_invokables = qobject
         .invokables
         .iter()
-        .map(|invokable| extract_invokable(invokable, &qt_ident))
+        .map(|invokable| extract_invokable(invokable, &qt_ident, declared_types))
         .collect::<Result<Vec<Invokable>, TokenStream>>()?;
     // A list of the normal methods (i.e. not invokables) for the struct
-    let object_methods = qobject.methods.to_vec();
+    let mut object_methods = qobject.methods.to_vec();
+    // Read the computed properties, any methods they use are moved into the invokables
+    let mut computed_properties = vec![];
+    for computed in &qobject.computed_properties {
+        let (property, mut invokables) =
+            extract_computed_property(computed, &mut object_methods, &qt_ident, declared_types)?;
+        computed_properties.push(property);
+        object_invokables.append(&mut invokables);
+    }
     // A list of insignificant declarations for the mod that will be directly passed through (eg `use crate::thing`)
-    let original_passthrough_decls = parser
-        .cxx_qt_data
-        .uses
+    let original_passthrough_decls = uses
         .iter()
         .chain(qobject.others.iter())
         .cloned()
         .collect::<Vec<Item>>();
-    // A list of items we will pass through to the CXX bridge
-    //
-    // TODO: for now this just includes ItemForeignMod but later this will switch to all non CXX-Qt items
-    let cxx_items = parser
-        .passthrough_module
-        .content
-        .unwrap_or((Brace::default(), vec![]))
-        .1;
 
     // Determines if (and how) this object can respond to update requests
     let handle_updates_impl = qobject.update_requester_handler;
 
+    // Properties are only generated from the Data struct for now,
+    // so error rather than ignore a #[qproperty] on the QObject struct
+    if let Some(property) = qobject.properties.first() {
+        return Err(Error::new(
+            property.ident.span(),
+            "A qproperty must be declared on a field of the cxx_qt::data(T) struct.",
+        )
+        .to_compile_error());
+    }
+
     // Read properties from the Data struct
     let object_properties = if let Some(ref original_struct) = original_data_struct {
-        extract_properties(original_struct, &qt_ident)?
+        extract_properties(original_struct, &qt_ident, declared_types)?
     } else {
         vec![]
-    };
+    }
+    .into_iter()
+    .chain(computed_properties)
+    .collect::<Vec<Property>>();
+
+    // Remove any #[qproperty] attributes from the Data struct now that they have been read
+    if let Some(ItemStruct {
+        fields: Fields::Named(FieldsNamed { named, .. }),
+        ..
+    }) = &mut original_data_struct
+    {
+        for field in named.iter_mut() {
+            field
+                .attrs
+                .retain(|attr| !path_compare_str(&attr.path, &["qproperty"]));
+        }
+    }
 
     // Read signals from the Signal enum
     //
     // TODO: for now we still extract the ParsedSignals into a Signal blocks
     // later when we have the generate phase this will be removed
-    let object_signals = if let Some(signals) = &qobject.signals {
-        extract_signals(signals, &qt_ident)?
+    let mut object_signals = if let Some(signals) = &qobject.signals {
+        extract_signals(signals, &qt_ident, declared_types)?
     } else {
         vec![]
     };
+    // Async invokables have a generated signal which is emitted when their future completes
+    object_signals.extend(
+        object_invokables
+            .iter()
+            .filter_map(|invokable| invokable.finished_signal.clone()),
+    );
     let signal_ident = qobject
         .signals
         .as_ref()
         .map(|signals| signals.ident.clone());
     let original_signal_enum = qobject.signals.map(|signals| signals.item);
 
+    // Check that the options of the properties refer to valid signals and invokables
+    for property in &object_properties {
+        // A notify signal can be shared with the Signals enum, but it is emitted without
+        // any parameters so the signal cannot have any
+        if let Some(notify) = &property.notify {
+            if object_signals.iter().any(|signal| {
+                signal.signal_ident.cpp_ident == notify.cpp_ident && !signal.parameters.is_empty()
+            }) {
+                return Err(Error::new(
+                    notify.rust_ident.span(),
+                    "The notify signal of a qproperty cannot have any parameters.",
+                )
+                .to_compile_error());
+            }
+
+            // There is no generated setter to emit the signal of a computed property,
+            // so Rust needs to be able to emit it from the Signals enum
+            if property.computed
+                && !object_signals
+                    .iter()
+                    .any(|signal| signal.signal_ident.cpp_ident == notify.cpp_ident)
+            {
+                return Err(Error::new(
+                    notify.rust_ident.span(),
+                    "The notify signal of a computed qproperty must be declared in the Signals enum.",
+                )
+                .to_compile_error());
+            }
+        }
+
+        // A reset method must be an invokable as C++ calls it when the property is reset,
+        // the only parameter it can have is the CppObj
+        if let Some(reset) = &property.reset {
+            if !object_invokables.iter().any(|invokable| {
+                invokable.ident.rust_ident == reset.rust_ident
+                    && invokable.parameters.iter().all(|parameter| {
+                        matches!(parameter.type_ident.qt_type, QtTypes::CppObj { .. })
+                    })
+                    && invokable.return_type.is_none()
+            }) {
+                return Err(Error::new(
+                    reset.rust_ident.span(),
+                    "The reset method of a qproperty must be a qinvokable without parameters or a return type.",
+                )
+                .to_compile_error());
+            }
+        }
+    }
+
     Ok(QObject {
         ident: qt_ident,
+        base_class: qobject.base_class.unwrap_or_else(|| "QObject".to_owned()),
+        qml_metadata: qobject.qml_metadata,
         invokables: object_invokables,
         methods: object_methods,
         properties: object_properties,
         signals: object_signals,
         signal_ident,
-        namespace: parser.cxx_qt_data.namespace,
-        cxx_items,
-        original_mod,
+        qenums: qobject.qenums,
+        cpp_methods: qobject.cpp_methods,
+        namespace: namespace.to_owned(),
         original_data_struct: original_data_struct
             .unwrap_or_else(|| syn::parse_str("pub struct Data;").unwrap()),
         original_signal_enum,
@@ -747,17 +1456,182 @@ pub fn extract_qobject(module: &ItemMod) -> Result<QObject, TokenStream> {
     })
 }
 
+/// Extracts the fields of a qgadget, these must be types which have the same layout in C++ and Rust
+fn extract_qgadget(
+    qgadget: ParsedQGadget,
+    declared_types: &DeclaredTypes,
+) -> Result<QGadget, TokenStream> {
+    let fields = qgadget
+        .fields
+        .iter()
+        .map(|field| {
+            let type_ident = extract_type_ident(&field.ty, &qgadget.ident, declared_types)
+                .ok()
+                .filter(|type_ident| {
+                    !type_ident.is_ref
+                        && matches!(
+                            type_ident.qt_type,
+                            QtTypes::Bool
+                                | QtTypes::F32
+                                | QtTypes::F64
+                                | QtTypes::I8
+                                | QtTypes::I16
+                                | QtTypes::I32
+                                | QtTypes::QEnum { .. }
+                                | QtTypes::U8
+                                | QtTypes::U16
+                                | QtTypes::U32
+                        )
+                })
+                .ok_or_else(|| {
+                    Error::new(
+                        field.ty.span(),
+                        "Only primitive types and qenums are supported as fields of a qgadget.",
+                    )
+                    .to_compile_error()
+                })?;
+
+            let ident_str = field.ident.to_string();
+            Ok(QGadgetField {
+                ident: CppRustIdent {
+                    cpp_ident: quote::format_ident!("{}", ident_str.to_case(Case::Camel)),
+                    rust_ident: field.ident.clone(),
+                },
+                type_ident,
+            })
+        })
+        .collect::<Result<Vec<QGadgetField>, TokenStream>>()?;
+
+    Ok(QGadget {
+        ident: qgadget.ident,
+        fields,
+        original_struct: qgadget.item,
+    })
+}
+
+/// Parses a module in order to extract the QObject descriptions from it
+pub fn extract_qobjects(module: &ItemMod) -> Result<CxxQtModule, TokenStream> {
+    // Build a parser for the given ItemMod
+    //
+    // TODO: in the future steps from this extract.rs file will be moved into module parts
+    // of Parser
+    let parser = Parser::from(module.to_owned()).map_err(|err| err.to_compile_error())?;
+
+    if parser.cxx_qt_data.qobjects.is_empty() {
+        return Err(Error::new(
+            module.span(),
+            "At least one #[cxx_qt::qobject] struct is required in the ItemMod.",
+        )
+        .to_compile_error());
+    }
+
+    // Find the items from the module
+    let original_mod = parser.passthrough_module.clone();
+
+    // A list of items we will pass through to the CXX bridge
+    //
+    // TODO: for now this just includes ItemForeignMod but later this will switch to all non CXX-Qt items
+    let cxx_items = parser
+        .passthrough_module
+        .content
+        .unwrap_or((Brace::default(), vec![]))
+        .1;
+
+    let namespace = parser.cxx_qt_data.namespace;
+    let uses = parser.cxx_qt_data.uses;
+    // Any QObject can use the qenums and qgadgets declared in the module as types
+    let declared_types = DeclaredTypes {
+        qenums: parser
+            .cxx_qt_data
+            .qobjects
+            .values()
+            .flat_map(|qobject| qobject.qenums.iter())
+            .chain(parser.cxx_qt_data.qenums.iter())
+            .map(|qenum| qenum.ident.clone())
+            .collect(),
+        qgadgets: parser
+            .cxx_qt_data
+            .qgadgets
+            .iter()
+            .map(|qgadget| qgadget.ident.clone())
+            .collect(),
+    };
+    let qgadgets = parser
+        .cxx_qt_data
+        .qgadgets
+        .into_iter()
+        .map(|qgadget| extract_qgadget(qgadget, &declared_types))
+        .collect::<Result<Vec<QGadget>, TokenStream>>()?;
+    let qobjects = parser
+        .cxx_qt_data
+        .qobjects
+        .into_iter()
+        .map(|(qt_ident, qobject)| {
+            extract_qobject(qt_ident, qobject, &uses, &namespace, &declared_types)
+        })
+        .collect::<Result<Vec<QObject>, TokenStream>>()?;
+
+    Ok(CxxQtModule {
+        // We can assume that there is a first QObject as we checked that the list is not empty
+        cxx_stem: qobjects[0].ident.to_string().to_case(Case::Snake),
+        qobjects,
+        qgadgets,
+        qenums: parser.cxx_qt_data.qenums,
+        namespace,
+        cxx_items,
+        original_mod,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn parses_custom_base() {
+        let source = include_str!("../test_inputs/custom_base.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+
+        // Check that the base class was found and is an item model
+        assert_eq!(qobject.base_class, "QAbstractListModel");
+        assert!(qobject.is_item_model());
+
+        // Check that the specifiers were found for the invokables
+        assert_eq!(qobject.invokables.len(), 5);
+        assert!(qobject.invokables[0].specifiers.is_empty());
+        assert_eq!(qobject.invokables[1].specifiers.len(), 1);
+        assert!(qobject.invokables[1]
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Override));
+        assert_eq!(qobject.invokables[2].specifiers.len(), 2);
+        assert!(qobject.invokables[2]
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Final));
+        assert!(qobject.invokables[2]
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Override));
+        assert_eq!(qobject.invokables[3].specifiers.len(), 1);
+        assert!(qobject.invokables[3]
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Virtual));
+
+        // Check that the roleNames hash type was found
+        assert!(matches!(
+            &qobject.invokables[4].return_type.as_ref().unwrap().qt_type,
+            QtTypes::UniquePtr { inner } if matches!(**inner, QtTypes::QHash { .. })
+        ));
+    }
+
     #[test]
     fn parses_custom_default() {
         let source = include_str!("../test_inputs/custom_default.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         // Check that it got the invokables and properties
         assert_eq!(qobject.invokables.len(), 0);
@@ -794,17 +1668,18 @@ mod tests {
     fn parses_invokables() {
         let source = include_str!("../test_inputs/invokables.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         // Check that it got the names right
         assert_eq!(qobject.ident.to_string(), "MyObject");
-        assert_eq!(qobject.original_mod.ident.to_string(), "ffi");
+        assert_eq!(cxx_qt_module.original_mod.ident.to_string(), "ffi");
         assert_eq!(qobject.original_rust_struct.ident.to_string(), "MyObject");
 
         // Check that it got the invokables
         assert_eq!(qobject.invokables.len(), 9);
 
-        let mut invokables = qobject.invokables.into_iter();
+        let mut invokables = qobject.invokables.iter();
         // Check empty invokable ident
         let invokable = invokables.next().unwrap();
         assert_eq!(invokable.ident.cpp_ident.to_string(), "invokable");
@@ -959,7 +1834,8 @@ mod tests {
     fn parsing_naming() {
         let source = include_str!("../test_inputs/naming.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         // Check that it got the properties and that the idents are correct
         assert_eq!(qobject.properties.len(), 1);
@@ -1002,11 +1878,12 @@ mod tests {
     fn parses_passthrough() {
         let source = include_str!("../test_inputs/passthrough.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         // Check that it got the names right
         assert_eq!(qobject.ident.to_string(), "MyObject");
-        assert_eq!(qobject.original_mod.ident.to_string(), "ffi");
+        assert_eq!(cxx_qt_module.original_mod.ident.to_string(), "ffi");
         assert_eq!(qobject.original_rust_struct.ident.to_string(), "MyObject");
 
         // Check that it got the inovkables and properties
@@ -1018,14 +1895,15 @@ mod tests {
         assert_eq!(qobject.original_passthrough_decls.len(), 4);
 
         // Check that we have a CXX passthrough item
-        assert_eq!(qobject.cxx_items.len(), 18);
+        assert_eq!(cxx_qt_module.cxx_items.len(), 18);
     }
 
     #[test]
     fn parses_properties() {
         let source = include_str!("../test_inputs/properties.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         // Check that it got the properties and that the idents are correct
         assert_eq!(qobject.properties.len(), 2);
@@ -1076,11 +1954,488 @@ mod tests {
         assert_eq!(notify.rust_ident.to_string(), "opaque");
     }
 
+    #[test]
+    fn parses_qenums() {
+        let source = include_str!("../test_inputs/qenums.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+
+        assert_eq!(qobject.qenums.len(), 1);
+        assert_eq!(qobject.qenums[0].ident.to_string(), "State");
+
+        // Check that the qenum is used as the type of the property, invokable and signal
+        let state_type = QtTypes::QEnum {
+            ident: quote::format_ident!("State"),
+            ident_string: "State".to_owned(),
+        };
+        assert_eq!(qobject.properties.len(), 1);
+        assert_eq!(qobject.properties[0].type_ident.qt_type, state_type);
+        assert_eq!(qobject.invokables.len(), 1);
+        assert_eq!(
+            qobject.invokables[0].parameters[1].type_ident.qt_type,
+            state_type
+        );
+        assert_eq!(
+            qobject.invokables[0].return_type.as_ref().unwrap().qt_type,
+            state_type
+        );
+        assert_eq!(qobject.signals.len(), 1);
+        assert_eq!(
+            qobject.signals[0].parameters[0].type_ident.qt_type,
+            state_type
+        );
+    }
+
+    #[test]
+    fn parses_qenums_namespace() {
+        let source = include_str!("../test_inputs/qenums_namespace.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+
+        // The qenum is on the module rather than the QObject
+        assert_eq!(cxx_qt_module.qenums.len(), 1);
+        assert_eq!(cxx_qt_module.qenums[0].ident.to_string(), "Direction");
+        assert!(qobject.qenums.is_empty());
+
+        // Check that the QObject can still use the qenum as a type
+        let direction_type = QtTypes::QEnum {
+            ident: quote::format_ident!("Direction"),
+            ident_string: "Direction".to_owned(),
+        };
+        assert_eq!(qobject.properties.len(), 1);
+        assert_eq!(qobject.properties[0].type_ident.qt_type, direction_type);
+        assert_eq!(
+            qobject.invokables[0].return_type.as_ref().unwrap().qt_type,
+            direction_type
+        );
+    }
+
+    #[test]
+    fn parses_qgadgets() {
+        let source = include_str!("../test_inputs/qgadgets.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        assert_eq!(cxx_qt_module.qgadgets.len(), 1);
+        let qgadget = &cxx_qt_module.qgadgets[0];
+        assert_eq!(qgadget.ident.to_string(), "Reading");
+        assert_eq!(qgadget.fields.len(), 3);
+        assert_eq!(qgadget.fields[0].ident.cpp_ident.to_string(), "sensorId");
+        assert_eq!(qgadget.fields[0].ident.rust_ident.to_string(), "sensor_id");
+        assert_eq!(qgadget.fields[0].type_ident.qt_type, QtTypes::I32);
+        assert_eq!(qgadget.fields[1].type_ident.qt_type, QtTypes::F64);
+        assert_eq!(qgadget.fields[2].type_ident.qt_type, QtTypes::F64);
+
+        // Check that the qgadget is used as the type of the property, invokable and signal
+        let qobject = &cxx_qt_module.qobjects[0];
+        let reading_type = QtTypes::QGadget {
+            ident: quote::format_ident!("Reading"),
+            ident_string: "Reading".to_owned(),
+        };
+        assert_eq!(qobject.properties[0].type_ident.qt_type, reading_type);
+        assert_eq!(
+            qobject.invokables[0].parameters[1].type_ident.qt_type,
+            reading_type
+        );
+        assert_eq!(
+            qobject.signals[0].parameters[0].type_ident.qt_type,
+            reading_type
+        );
+    }
+
+    #[test]
+    fn parses_invokables_result() {
+        let source = include_str!("../test_inputs/invokables_result.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+        assert_eq!(qobject.invokables.len(), 3);
+
+        // Result<(), E> has no return type
+        let invokable = &qobject.invokables[0];
+        assert!(invokable.return_type.is_none());
+        assert!(invokable.error_type.is_some());
+        assert!(invokable.ident_wrapper.is_none());
+
+        // Result<T, E> uses the Ok type as the return type
+        let invokable = &qobject.invokables[1];
+        assert_eq!(
+            invokable.return_type.as_ref().unwrap().qt_type,
+            QtTypes::I32
+        );
+        let error_type = invokable.error_type.as_ref().unwrap();
+        assert_eq!(quote::quote! { #error_type }.to_string(), "String");
+
+        // An opaque Ok type still requires a wrapper
+        let invokable = &qobject.invokables[2];
+        assert!(invokable.return_type.as_ref().unwrap().qt_type.is_opaque());
+        assert!(invokable.ident_wrapper.is_some());
+    }
+
+    #[test]
+    fn parses_invokables_async() {
+        let source = include_str!("../test_inputs/invokables_async.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+        assert_eq!(qobject.invokables.len(), 3);
+
+        // An async invokable has no return type and is given the CppObj to spawn the future
+        let invokable = &qobject.invokables[1];
+        assert!(invokable.return_type.is_none());
+        assert!(invokable.ident_wrapper.is_some());
+        assert_eq!(invokable.parameters.len(), 2);
+        assert!(matches!(
+            invokable.parameters[0].type_ident.qt_type,
+            QtTypes::CppObj { .. }
+        ));
+        assert_eq!(invokable.parameters[1].ident.to_string(), "value");
+
+        // The output of the future is emitted with the finished signal
+        let finished_signal = invokable.finished_signal.as_ref().unwrap();
+        assert!(finished_signal.enum_ident.is_none());
+        assert_eq!(
+            finished_signal.signal_ident.cpp_ident.to_string(),
+            "invokableAsyncPrimitiveFinished"
+        );
+        assert_eq!(finished_signal.parameters.len(), 1);
+        assert_eq!(
+            finished_signal.parameters[0].type_ident.qt_type,
+            QtTypes::I32
+        );
+
+        // The finished signals are added to the signals of the QObject
+        assert_eq!(qobject.signals.len(), 3);
+        assert!(qobject.signals[0].parameters.is_empty());
+        assert_eq!(
+            qobject.signals[2].emit_ident.rust_ident.to_string(),
+            "emit_invokable_async_opaque_finished"
+        );
+    }
+
+    #[test]
+    fn parses_invokables_async_invalid() {
+        // The future cannot borrow self
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                #[derive(Default)]
+                pub struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qinvokable]
+                    pub async fn invokable(&self) {}
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+
+        // The parameters must be passed by value
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                #[derive(Default)]
+                pub struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qinvokable]
+                    pub async fn invokable(string: &QString) {}
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+
+        // A Result cannot be returned
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                #[derive(Default)]
+                pub struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qinvokable]
+                    pub async fn invokable() -> Result<i32, String> {
+                        Ok(1)
+                    }
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+    }
+
+    #[test]
+    fn parses_computed_properties() {
+        let source = include_str!("../test_inputs/computed_properties.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+
+        // The stored property is first followed by the computed properties
+        assert_eq!(qobject.properties.len(), 3);
+        assert!(!qobject.properties[0].computed);
+
+        let prop = &qobject.properties[1];
+        assert!(prop.computed);
+        assert!(prop.writable);
+        assert_eq!(prop.ident.cpp_ident.to_string(), "doubled");
+        assert_eq!(prop.type_ident.qt_type, QtTypes::I32);
+        assert_eq!(
+            prop.getter.as_ref().unwrap().cpp_ident.to_string(),
+            "doubled"
+        );
+        assert_eq!(
+            prop.setter.as_ref().unwrap().cpp_ident.to_string(),
+            "setDoubled"
+        );
+        assert_eq!(
+            prop.notify.as_ref().unwrap().cpp_ident.to_string(),
+            "valuesChanged"
+        );
+
+        let prop = &qobject.properties[2];
+        assert!(prop.computed);
+        assert!(!prop.writable);
+        assert!(prop.setter.is_none());
+        assert_eq!(prop.ident.cpp_ident.to_string(), "label");
+
+        // The getters and setter are moved from the methods into invokables which are not Q_INVOKABLE
+        assert_eq!(qobject.methods.len(), 0);
+        assert_eq!(qobject.invokables.len(), 3);
+        assert!(qobject
+            .invokables
+            .iter()
+            .all(|invokable| !invokable.is_qinvokable));
+        assert_eq!(
+            qobject.invokables[1].ident.rust_ident.to_string(),
+            "set_doubled"
+        );
+    }
+
+    #[test]
+    fn parses_computed_properties_invalid() {
+        // The getter must return a value
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qproperty]
+                    pub fn value(&self) {}
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+
+        // The write method must exist
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qproperty(write = set_value)]
+                    pub fn value(&self) -> i32 {
+                        1
+                    }
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+
+        // The write method must take a value of the property type
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qproperty(write = set_value)]
+                    pub fn value(&self) -> i32 {
+                        1
+                    }
+
+                    pub fn set_value(&self, value: f64) {}
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+
+        // The notify signal must be in the Signals enum
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qproperty(notify = value_changed)]
+                    pub fn value(&self) -> i32 {
+                        1
+                    }
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+    }
+
+    #[test]
+    fn parses_property_options() {
+        let source = include_str!("../test_inputs/property_options.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
+
+        assert_eq!(qobject.properties.len(), 4);
+
+        // read_only
+        let prop = &qobject.properties[0];
+        assert!(!prop.writable);
+        assert!(!prop.constant);
+        assert!(prop.setter.is_some());
+        assert_eq!(
+            prop.notify.as_ref().unwrap().cpp_ident.to_string(),
+            "countChanged"
+        );
+
+        // constant
+        let prop = &qobject.properties[1];
+        assert!(!prop.writable);
+        assert!(prop.constant);
+        assert!(prop.notify.is_none());
+        assert_eq!(
+            prop.setter.as_ref().unwrap().cpp_ident.to_string(),
+            "initialiseLimit"
+        );
+        assert_eq!(
+            prop.setter.as_ref().unwrap().rust_ident.to_string(),
+            "initialise_limit"
+        );
+
+        // notify and reset
+        let prop = &qobject.properties[2];
+        assert!(prop.writable);
+        assert_eq!(
+            prop.notify.as_ref().unwrap().cpp_ident.to_string(),
+            "valuesChanged"
+        );
+        assert_eq!(
+            prop.reset.as_ref().unwrap().cpp_ident.to_string(),
+            "resetValue"
+        );
+        assert_eq!(
+            prop.reset.as_ref().unwrap().rust_ident.to_string(),
+            "reset_value"
+        );
+
+        // shared notify
+        let prop = &qobject.properties[3];
+        assert_eq!(
+            prop.notify.as_ref().unwrap().cpp_ident.to_string(),
+            "valuesChanged"
+        );
+        assert!(prop.reset.is_none());
+
+        // The attributes are removed from the Data struct
+        assert!(qobject
+            .original_data_struct
+            .fields
+            .iter()
+            .all(|field| field.attrs.is_empty()));
+    }
+
+    #[test]
+    fn parses_property_options_invalid_reset() {
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[derive(Default)]
+                pub struct Data {
+                    #[qproperty(reset = reset_value)]
+                    value: f64,
+                }
+
+                #[cxx_qt::qobject]
+                struct MyObject;
+
+                impl cxx_qt::QObject<MyObject> {
+                    #[qinvokable]
+                    pub fn reset_value(&self, value: f64) {}
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+    }
+
+    #[test]
+    fn parses_property_options_invalid_notify() {
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[derive(Default)]
+                pub struct Data {
+                    #[qproperty(notify = value_updated)]
+                    value: f64,
+                }
+
+                #[cxx_qt::qobject]
+                struct MyObject;
+
+                #[cxx_qt::signals(MyObject)]
+                enum MySignals {
+                    ValueUpdated { value: f64 },
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+    }
+
+    #[test]
+    fn parses_property_on_qobject_struct_invalid() {
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qobject]
+                struct MyObject {
+                    #[qproperty]
+                    value: f64,
+                }
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+    }
+
+    #[test]
+    fn parses_qgadgets_invalid_field() {
+        let module: ItemMod = syn::parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #[cxx_qt::qgadget]
+                struct Reading {
+                    name: UniquePtr<QString>,
+                }
+
+                #[cxx_qt::qobject]
+                struct MyObject;
+            }
+        };
+        assert!(extract_qobjects(&module).is_err());
+    }
+
     #[test]
     fn parses_signals() {
         let source = include_str!("../test_inputs/signals.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         assert_eq!(qobject.properties.len(), 0);
         assert_eq!(qobject.invokables.len(), 1);
@@ -1094,7 +2449,10 @@ mod tests {
             qobject.signals[0].emit_ident.rust_ident.to_string(),
             "emit_ready"
         );
-        assert_eq!(qobject.signals[0].enum_ident.to_string(), "Ready");
+        assert_eq!(
+            qobject.signals[0].enum_ident.as_ref().unwrap().to_string(),
+            "Ready"
+        );
         assert_eq!(qobject.signals[0].parameters.len(), 0);
         assert_eq!(
             qobject.signals[0].signal_ident.cpp_ident.to_string(),
@@ -1113,7 +2471,10 @@ mod tests {
             qobject.signals[1].emit_ident.rust_ident.to_string(),
             "emit_data_changed"
         );
-        assert_eq!(qobject.signals[1].enum_ident.to_string(), "DataChanged");
+        assert_eq!(
+            qobject.signals[1].enum_ident.as_ref().unwrap().to_string(),
+            "DataChanged"
+        );
         assert_eq!(qobject.signals[1].parameters.len(), 3);
         assert_eq!(qobject.signals[1].parameters[0].ident.to_string(), "first");
         assert_eq!(qobject.signals[1].parameters[1].ident.to_string(), "second");
@@ -1126,13 +2487,38 @@ mod tests {
             qobject.signals[1].signal_ident.rust_ident.to_string(),
             "data_changed"
         );
+        assert_eq!(
+            qobject.signals[1].connect_ident.cpp_ident.to_string(),
+            "connectDataChanged"
+        );
+        assert_eq!(
+            qobject.signals[1].connect_ident.rust_ident.to_string(),
+            "connect_data_changed"
+        );
+        assert_eq!(
+            qobject.signals[1].handler_ident.cpp_ident.to_string(),
+            "DataChangedHandler"
+        );
+        assert_eq!(
+            qobject.signals[1].handler_ident.rust_ident.to_string(),
+            "MyObjectDataChangedHandler"
+        );
+        assert_eq!(
+            qobject.signals[1].call_handler_ident.cpp_ident.to_string(),
+            "callDataChangedHandler"
+        );
+        assert_eq!(
+            qobject.signals[1].call_handler_ident.rust_ident.to_string(),
+            "call_my_object_data_changed_handler"
+        );
     }
 
     #[test]
     fn parses_types_primitive_property() {
         let source = include_str!("../test_inputs/types_primitive_property.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let qobject = &cxx_qt_module.qobjects[0];
 
         // Check that it got the inovkables and properties
         assert_eq!(qobject.invokables.len(), 0);
@@ -7,14 +7,21 @@ use clang_format::{ClangFormatStyle, CLANG_FORMAT_STYLE};
 use convert_case::{Case, Casing};
 use indoc::formatdoc;
 use proc_macro2::TokenStream;
+use std::collections::HashSet;
 use syn::Ident;
 
-use crate::extract::{Invokable, Parameter, ParameterType, Property, QObject, QtTypes, Signal};
-use crate::generator::cpp::{fragment::CppFragmentPair, GeneratedCppBlocks};
+use crate::extract::{
+    CxxQtModule, Invokable, Parameter, ParameterType, Property, QGadget, QObject, QtTypes, Signal,
+};
+use crate::generator::cpp::{
+    fragment::CppFragmentPair, GeneratedCppBlocks, GeneratedCppQGadgetBlocks,
+    GeneratedCppQObjectBlocks,
+};
+use crate::parser::{invokable::ParsedQInvokableSpecifiers, qenum::ParsedQEnum};
 use crate::writer::cpp::write_cpp;
 
 /// A trait which we implement on QtTypes allowing retrieval of attributes of the enum value.
-trait CppType {
+pub(crate) trait CppType {
     /// String representation of the const part of this type
     fn as_const_str(&self) -> &str;
     /// String representation of the pointer part of this type
@@ -73,9 +80,15 @@ impl CppType for QtTypes {
             Self::CppObj { .. } => false,
             Self::F32 | Self::F64 => false,
             Self::I8 | Self::I16 | Self::I32 => false,
+            Self::QByteArray => true,
             Self::QColor => true,
             Self::QDate => true,
             Self::QDateTime => true,
+            Self::QEnum { .. } => false,
+            Self::QGadget { .. } => false,
+            Self::QHash { .. } => true,
+            Self::QList { .. } => true,
+            Self::QModelIndex => true,
             Self::QPoint => true,
             Self::QPointF => true,
             Self::QRect => true,
@@ -83,9 +96,13 @@ impl CppType for QtTypes {
             Self::QSize => true,
             Self::QSizeF => true,
             Self::QString => true,
+            Self::QStringList => true,
             Self::QTime => true,
             Self::QUrl => true,
             Self::QVariant => true,
+            Self::QVariantHash => true,
+            Self::QVariantMap => true,
+            Self::QVector { .. } => true,
             Self::U8 | Self::U16 | Self::U32 => false,
             Self::UniquePtr { .. } => true,
             _other => unreachable!(),
@@ -123,9 +140,15 @@ impl CppType for QtTypes {
             Self::CppObj { .. } => false,
             Self::F32 | Self::F64 => false,
             Self::I8 | Self::I16 | Self::I32 => false,
+            Self::QByteArray => true,
             Self::QColor => true,
             Self::QDate => true,
             Self::QDateTime => true,
+            Self::QEnum { .. } => false,
+            Self::QGadget { .. } => false,
+            Self::QHash { .. } => true,
+            Self::QList { .. } => true,
+            Self::QModelIndex => true,
             Self::QPoint => true,
             Self::QPointF => true,
             Self::QRect => true,
@@ -133,9 +156,13 @@ impl CppType for QtTypes {
             Self::QSize => true,
             Self::QSizeF => true,
             Self::QString => true,
+            Self::QStringList => true,
             Self::QTime => true,
             Self::QUrl => true,
             Self::QVariant => true,
+            Self::QVariantHash => true,
+            Self::QVariantMap => true,
+            Self::QVector { .. } => true,
             Self::U8 | Self::U16 | Self::U32 => false,
             Self::UniquePtr { .. } => true,
             _other => unreachable!(),
@@ -164,9 +191,19 @@ impl CppType for QtTypes {
             Self::I8 => "qint8",
             Self::I16 => "qint16",
             Self::I32 => "qint32",
+            Self::QByteArray => "QByteArray",
             Self::QColor => "QColor",
             Self::QDate => "QDate",
             Self::QDateTime => "QDateTime",
+            Self::QEnum { ident_string, .. } => ident_string,
+            Self::QGadget { ident_string, .. } => ident_string,
+            Self::QHash {
+                cpp_type_string, ..
+            } => cpp_type_string,
+            Self::QList {
+                cpp_type_string, ..
+            } => cpp_type_string,
+            Self::QModelIndex => "QModelIndex",
             Self::QPoint => "QPoint",
             Self::QPointF => "QPointF",
             Self::QRect => "QRect",
@@ -174,9 +211,15 @@ impl CppType for QtTypes {
             Self::QSize => "QSize",
             Self::QSizeF => "QSizeF",
             Self::QString => "QString",
+            Self::QStringList => "QStringList",
             Self::QTime => "QTime",
             Self::QUrl => "QUrl",
             Self::QVariant => "QVariant",
+            Self::QVariantHash => "QVariantHash",
+            Self::QVariantMap => "QVariantMap",
+            Self::QVector {
+                cpp_type_string, ..
+            } => cpp_type_string,
             Self::U8 => "quint8",
             Self::U16 => "quint16",
             Self::U32 => "quint32",
@@ -191,6 +234,42 @@ impl CppType for QtTypes {
     }
 }
 
+/// The protected methods of QAbstractItemModel which are made public when the base class is an item model
+const ITEM_MODEL_HELPERS: &[&str] = &[
+    "beginInsertColumns",
+    "endInsertColumns",
+    "beginInsertRows",
+    "endInsertRows",
+    "beginMoveColumns",
+    "endMoveColumns",
+    "beginMoveRows",
+    "endMoveRows",
+    "beginRemoveColumns",
+    "endRemoveColumns",
+    "beginRemoveRows",
+    "endRemoveRows",
+    "beginResetModel",
+    "endResetModel",
+];
+
+/// The default arguments of the QAbstractItemModel methods which are commonly overridden,
+/// as default arguments are not inherited they need to be repeated on the override
+///
+/// Each entry is the name of the method, its number of parameters, and the default of the last one
+const ITEM_MODEL_DEFAULT_ARGUMENTS: &[(&str, usize, &str)] = &[
+    ("columnCount", 1, "QModelIndex()"),
+    ("data", 2, "Qt::DisplayRole"),
+    ("hasChildren", 1, "QModelIndex()"),
+    ("headerData", 3, "Qt::DisplayRole"),
+    ("index", 3, "QModelIndex()"),
+    ("insertColumns", 3, "QModelIndex()"),
+    ("insertRows", 3, "QModelIndex()"),
+    ("removeColumns", 3, "QModelIndex()"),
+    ("removeRows", 3, "QModelIndex()"),
+    ("rowCount", 1, "QModelIndex()"),
+    ("setData", 3, "Qt::EditRole"),
+];
+
 /// Describes a C++ parameter, which is a name combined with a type
 #[derive(Debug)]
 struct CppParameter<'a> {
@@ -245,7 +324,7 @@ pub struct CppObject {
 }
 
 /// Generate a string of parameters with their type in C++ style from a given list of rust parameters
-fn generate_parameters_cpp(parameters: &[Parameter]) -> Result<Vec<CppParameter>, TokenStream> {
+fn generate_parameters_cpp(parameters: &[Parameter]) -> Result<Vec<CppParameter<'_>>, TokenStream> {
     let mut items: Vec<CppParameter> = vec![];
 
     // Extract the ident and type_ident from each parameter
@@ -271,6 +350,7 @@ fn generate_parameters_cpp(parameters: &[Parameter]) -> Result<Vec<CppParameter>
 fn generate_invokables_cpp(
     struct_ident: &Ident,
     invokables: &[Invokable],
+    is_item_model: bool,
 ) -> Result<Vec<CppInvokable>, TokenStream> {
     let mut items: Vec<CppInvokable> = vec![];
 
@@ -329,6 +409,32 @@ fn generate_invokables_cpp(
         // Cache an argument line of all the parameters as this is used in both header and source
         let parameter_arg_line = parameters.args.join(", ");
 
+        // An override of an item model method keeps the default argument of the base class
+        // in the header, so that eg rowCount() can still be called without a parent
+        let default_argument = if is_item_model
+            && invokable
+                .specifiers
+                .contains(&ParsedQInvokableSpecifiers::Override)
+        {
+            ITEM_MODEL_DEFAULT_ARGUMENTS
+                .iter()
+                .find(|(name, count, _)| {
+                    invokable.ident.cpp_ident == *name && parameters.args.len() == *count
+                })
+                .map(|(_, _, default)| default)
+        } else {
+            None
+        };
+        let header_parameter_arg_line = if let Some(default) = default_argument {
+            let mut args = parameters.args.clone();
+            // The table only contains methods with parameters so there is a last argument
+            let last = args.last_mut().unwrap();
+            *last = format!("{} = {}", last, default);
+            args.join(", ")
+        } else {
+            parameter_arg_line.clone()
+        };
+
         // Extract the return type of the invokable if there is one
         let return_type = invokable
             .return_type
@@ -354,39 +460,108 @@ fn generate_invokables_cpp(
             "void"
         };
 
+        // Decide if the body needs a return or converter
+        let body = if let Some(return_type) = return_type {
+            format!(
+                "return rust::cxxqtlib1::cxx_qt_convert<{output_type}, {input_type}>{{}}({body});",
+                body = body,
+                output_type = return_ident,
+                input_type = if return_type.is_opaque() {
+                    format!("std::unique_ptr<{}>", return_ident)
+                } else {
+                    return_ident.to_owned()
+                }
+            )
+        } else {
+            format!("{};", body)
+        };
+
+        // If the invokable returns a Result then CXX throws the error as a rust::Error,
+        // catch this and throw it to the QML engine instead so that it can be caught in JavaScript
+        let body = if invokable.error_type.is_some() {
+            formatdoc! {
+                r#"
+                try {{
+                    {body}
+                }} catch (const rust::Error& error) {{
+                    rust::cxxqtlib1::throwError(this, QString::fromUtf8(error.what()));{return_default}
+                }}"#,
+                body = body,
+                return_default = if return_type.is_some() {
+                    "\n    return {};"
+                } else {
+                    ""
+                },
+            }
+        } else {
+            body
+        };
+
+        // Invokables with specifiers need to match the signature of the base class method,
+        // so they are const if they are &self and do not pass *this across to Rust
+        //
+        // TODO: detect if all methods are const from whether we have &self or &mut self in rust
+        let is_const = if !invokable.specifiers.is_empty()
+            && !invokable.mutable
+            && !invokable
+                .parameters
+                .iter()
+                .any(|parameter| parameter.type_ident.qt_type.is_this())
+        {
+            " const"
+        } else {
+            ""
+        };
+
+        // Build the specifiers that are placed before and after the method declaration
+        let is_virtual = if invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Virtual)
+        {
+            "virtual "
+        } else {
+            ""
+        };
+        let mut specifiers = vec![];
+        if invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Override)
+        {
+            specifiers.push(" override");
+        }
+        if invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Final)
+        {
+            specifiers.push(" final");
+        }
+
         // Prepare the CppInvokable
         items.push(CppInvokable {
-            // TODO: detect if method is const from whether we have &self or &mut self in rust
-            // TODO: also needs to consider if there is a Pin<&mut T> as we need non-const if
-            // we are passing *this across for cpp objects in rust.
             header: format!(
-                "Q_INVOKABLE {return_ident} {ident}({parameter_types});",
+                "{is_qinvokable}{is_virtual}{return_ident} {ident}({parameter_types}){is_const}{specifiers};",
                 ident = invokable.ident.cpp_ident,
-                parameter_types = parameter_arg_line,
+                is_qinvokable = if invokable.is_qinvokable {
+                    "Q_INVOKABLE "
+                } else {
+                    ""
+                },
+                is_const = is_const,
+                is_virtual = is_virtual,
+                parameter_types = header_parameter_arg_line,
                 return_ident = return_ident,
+                specifiers = specifiers.join(""),
             ),
             source: formatdoc! {
                 r#"
-                {return_ident} {struct_ident}::{ident}({parameter_types})
+                {return_ident} {struct_ident}::{ident}({parameter_types}){is_const}
                 {{
-                    const std::lock_guard<std::mutex> guard(m_rustObjMutex);
-                    {body};
+                    const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+                    {body}
                 }}
                 "#,
-                // Decide if the body needs a return or converter
-                body = if let Some(return_type) = return_type {
-                    format!("return rust::cxxqtlib1::cxx_qt_convert<{output_type}, {input_type}>{{}}({body})",
-                        body = body,
-                        output_type = return_ident,
-                        input_type = if return_type.is_opaque() {
-                            format!("std::unique_ptr<{}>", return_ident)
-                        } else {
-                            return_ident.to_owned()
-                        }
-                    )
-                } else {
-                    body
-                },
+                is_const = is_const,
+                body = body,
                 ident = invokable.ident.cpp_ident.to_string(),
                 parameter_types = parameter_arg_line,
                 struct_ident = struct_ident.to_string(),
@@ -402,8 +577,15 @@ fn generate_invokables_cpp(
 fn generate_properties_cpp(
     struct_ident: &Ident,
     properties: &[Property],
+    signals: &[Signal],
 ) -> Result<Vec<CppProperty>, TokenStream> {
     let mut items: Vec<CppProperty> = vec![];
+    // Notify signals can be shared between properties or with the Signals enum,
+    // so track which have been declared to avoid declaring them twice
+    let mut declared_signals = signals
+        .iter()
+        .map(|signal| signal.signal_ident.cpp_ident.to_string())
+        .collect::<HashSet<String>>();
 
     for property in properties {
         // Build a CppParameter for the name and type of the property
@@ -414,10 +596,16 @@ fn generate_properties_cpp(
 
         // Collect the C++ idents for the getter, setter, notify of the property
         //
-        // TODO: for now we assume that all properties have a getter/setter/notify
+        // TODO: for now we assume that all properties have a getter
         let ident_getter = property.getter.as_ref().unwrap().cpp_ident.to_string();
-        let ident_setter = property.setter.as_ref().unwrap().cpp_ident.to_string();
-        let ident_changed = property.notify.as_ref().unwrap().cpp_ident.to_string();
+        let ident_setter = property
+            .setter
+            .as_ref()
+            .map(|setter| setter.cpp_ident.to_string());
+        let ident_changed = property
+            .notify
+            .as_ref()
+            .map(|notify| notify.cpp_ident.to_string());
 
         // We shouldn't currently have a case where ref and ptr together makes any sense
         assert!(!(parameter.type_ident.is_ref() && parameter.type_ident.is_ptr()));
@@ -430,37 +618,104 @@ fn generate_properties_cpp(
         // Cache the type ident of the property as this is used multiple times
         let type_ident = parameter.type_ident.type_ident();
 
+        // Build the Q_PROPERTY for the C++ class, the getter is always used for READ
+        let mut meta = format!(
+            "Q_PROPERTY({type_ident}{is_ptr} {ident} READ {ident_getter}",
+            ident = parameter.ident,
+            ident_getter = ident_getter,
+            is_ptr = is_ptr,
+            type_ident = type_ident,
+        );
+        if let Some(ident_setter) = ident_setter.as_ref().filter(|_| property.writable) {
+            meta.push_str(&format!(" WRITE {}", ident_setter));
+        }
+        if let Some(reset) = &property.reset {
+            meta.push_str(&format!(" RESET {}", reset.cpp_ident));
+        }
+        if let Some(ident_changed) = &ident_changed {
+            meta.push_str(&format!(" NOTIFY {}", ident_changed));
+        }
+        if property.constant {
+            meta.push_str(" CONSTANT");
+        }
+        meta.push(')');
+
+        // Only declare the notify signal if it is not already declared
+        let header_signals = match &ident_changed {
+            Some(ident_changed) if declared_signals.insert(ident_changed.clone()) => {
+                vec![format!(
+                    "void {ident_changed}();",
+                    ident_changed = ident_changed
+                )]
+            }
+            _others => vec![],
+        };
+
+        // A computed property uses the methods that are implemented in Rust as its getter and setter,
+        // so only the Q_PROPERTY and any notify signal are needed
+        if property.computed {
+            items.push(CppProperty {
+                header_members: vec![],
+                header_meta: vec![meta],
+                header_public: vec![],
+                header_signals,
+                header_slots: vec![],
+                source: vec![],
+            });
+            continue;
+        }
+
+        // Properties from the Data struct always have a generated setter
+        let ident_setter = ident_setter.unwrap();
+
+        // The setter is always available to Rust, but only a slot if QML can write the property
+        let setter = format!(
+            "void {ident_setter}({is_const} {type_ident}{is_ref}{is_ptr} value);",
+            ident_setter = ident_setter,
+            is_const = is_const,
+            is_ref = is_ref,
+            is_ptr = is_ptr,
+            type_ident = type_ident,
+        );
+        let (header_public_setter, header_slots) = if property.writable {
+            (None, vec![setter])
+        } else {
+            (Some(setter), vec![])
+        };
+
+        // Build the source to emit the notify signal when the value changes
+        let emit_changed = ident_changed
+            .as_ref()
+            .map(|ident_changed| {
+                format!(
+                    "\n\n        const auto signalSuccess = QMetaObject::invokeMethod(this, \"{ident_changed}\", Qt::QueuedConnection);\n        Q_ASSERT(signalSuccess);",
+                    ident_changed = ident_changed,
+                )
+            })
+            .unwrap_or_default();
+
         // Build a basic C++ property with parts that are defined if the property is a pointer or not
         let mut cpp_property = CppProperty {
             // Members are defined later for only the pointer
             header_members: vec![],
             // Set the Q_PROPERTY for the C++ class
-            header_meta: vec![format!("Q_PROPERTY({type_ident}{is_ptr} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_changed})",
-                ident = parameter.ident,
-                ident_changed = ident_changed,
-                ident_getter = ident_getter,
-                ident_setter = ident_setter,
-                is_ptr = is_ptr,
-                type_ident = type_ident,
-            )],
+            header_meta: vec![meta],
             // Set basic getter, more are added later for only pointer
-            header_public: vec![format!("{is_const} {type_ident}{is_ptr}{is_ref} {ident_getter}() const;",
+            header_public: vec![format!(
+                "{is_const} {type_ident}{is_ptr}{is_ref} {ident_getter}() const;",
                 ident_getter = ident_getter,
                 is_const = is_const,
                 is_ptr = is_ptr,
                 is_ref = is_ref,
                 type_ident = type_ident,
-            )],
+            )]
+            .into_iter()
+            .chain(header_public_setter)
+            .collect(),
             // Set the notify signals
-            header_signals: vec![format!("void {ident_changed}();", ident_changed = ident_changed)],
+            header_signals,
             // Set the slots for the setter
-            header_slots: vec![format!("void {ident_setter}({is_const} {type_ident}{is_ref}{is_ptr} value);",
-                ident_setter = ident_setter,
-                is_const = is_const,
-                is_ref = is_ref,
-                is_ptr = is_ptr,
-                type_ident = type_ident,
-            )],
+            header_slots,
             // The source is created later
             source: vec![],
         };
@@ -494,14 +749,11 @@ fn generate_properties_cpp(
                             {member_owned_ident}.reset();
                         }}
 
-                        {member_ident} = value;
-
-                        const auto signalSuccess = QMetaObject::invokeMethod(this, "{ident_changed}", Qt::QueuedConnection);
-                        Q_ASSERT(signalSuccess);
+                        {member_ident} = value;{emit_changed}
                     }}
                 }}
                 "#,
-                ident_changed = ident_changed,
+                emit_changed = emit_changed,
                 ident_getter = ident_getter,
                 ident_setter = ident_setter,
                 is_const = is_const,
@@ -557,13 +809,10 @@ fn generate_properties_cpp(
                   Q_ASSERT(value.get() != {member_ident});
 
                   {member_owned_ident} = std::move(value);
-                  {member_ident} = {member_owned_ident}.get();
-
-                  const auto signalSuccess = QMetaObject::invokeMethod(this, "{ident_changed}", Qt::QueuedConnection);
-                  Q_ASSERT(signalSuccess);
+                  {member_ident} = {member_owned_ident}.get();{emit_changed}
                 }}
                 "#,
-                ident_changed = ident_changed,
+                emit_changed = emit_changed,
                 ident_pascal = parameter_ident_pascal,
                 ident_setter = ident_setter,
                 member_ident = member_ident,
@@ -572,6 +821,31 @@ fn generate_properties_cpp(
                 type_ident = type_ident,
             ));
         } else {
+            // A constant property can only be initialised from the Data struct during construction
+            let setter_body = if property.constant {
+                formatdoc! {
+                    r#"
+                    if (!m_initialised) {{
+                        {member_ident} = value;
+                    }}"#,
+                    member_ident = format!("m_{}", parameter.ident),
+                }
+            } else {
+                formatdoc! {
+                    r#"
+                    if (!m_initialised) {{
+                        {member_ident} = value;
+                        return;
+                    }}
+
+                    if (value != {member_ident}) {{
+                        {member_ident} = value;{emit_changed}
+                    }}"#,
+                    emit_changed = emit_changed,
+                    member_ident = format!("m_{}", parameter.ident),
+                }
+            };
+
             cpp_property.source.push(formatdoc! {
                 r#"
                 {is_const} {type_ident}{is_ptr}{is_ref}
@@ -583,20 +857,10 @@ fn generate_properties_cpp(
                 void
                 {struct_ident}::{ident_setter}({is_const} {type_ident}{is_ref}{is_ptr} value)
                 {{
-                    if (!m_initialised) {{
-                        {member_ident} = value;
-                        return;
-                    }}
-
-                    if (value != {member_ident}) {{
-                        {member_ident} = value;
-
-                        const auto signalSuccess = QMetaObject::invokeMethod(this, "{ident_changed}", Qt::QueuedConnection);
-                        Q_ASSERT(signalSuccess);
-                    }}
+                    {setter_body}
                 }}
                 "#,
-                ident_changed = ident_changed,
+                setter_body = setter_body,
                 ident_getter = ident_getter,
                 ident_setter = ident_setter,
                 is_const = is_const,
@@ -627,6 +891,7 @@ fn generate_properties_cpp(
 fn generate_signals_cpp(
     struct_ident: &Ident,
     signals: &[Signal],
+    namespace_internals: &str,
 ) -> Result<Vec<CppSignal>, TokenStream> {
     let mut items: Vec<CppSignal> = vec![];
 
@@ -727,21 +992,143 @@ fn generate_signals_cpp(
             parameter_values = parameter_values.join(", "),
         };
 
+        // The handler is moved into the lambda, so it is destroyed when the connection is
+        let connect_ident_cpp = signal.connect_ident.cpp_ident.to_string();
+        let handler_ident_cpp = format!(
+            "rust::Box<{namespace_internals}::{handler_ident}>",
+            namespace_internals = namespace_internals,
+            handler_ident = signal.handler_ident.cpp_ident,
+        );
+        header_public.push(format!(
+            "std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> {ident}({handler_ident} handler, rust::cxxqtlib1::ConnectionType type);",
+            ident = connect_ident_cpp,
+            handler_ident = handler_ident_cpp,
+        ));
+        // The overload with a sender connects to the signal of another instance, this is
+        // still the context of the connection so the handler is called on the thread of this
+        header_public.push(format!(
+            "std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> {ident}({struct_ident}& sender, {handler_ident} handler, rust::cxxqtlib1::ConnectionType type);",
+            ident = connect_ident_cpp,
+            handler_ident = handler_ident_cpp,
+            struct_ident = struct_ident,
+        ));
+        let parameter_idents = signal
+            .parameters
+            .iter()
+            .map(|parameter| format!(", {}", parameter.ident))
+            .collect::<Vec<String>>()
+            .join("");
+        let connect_source = formatdoc! {
+            r#"
+            std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+            {struct_ident}::{connect_ident_cpp}({handler_ident} handler, rust::cxxqtlib1::ConnectionType type)
+            {{
+                return {connect_ident_cpp}(*this, std::move(handler), type);
+            }}
+
+            std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+            {struct_ident}::{connect_ident_cpp}({struct_ident}& sender, {handler_ident} handler, rust::cxxqtlib1::ConnectionType type)
+            {{
+                return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(QObject::connect(
+                    &sender,
+                    &{struct_ident}::{signal_ident_cpp},
+                    this,
+                    [this, handler = std::move(handler)]({parameters}) mutable {{
+                        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+                        {namespace_internals}::{call_handler_ident}(*handler, *this{parameter_idents});
+                    }},
+                    static_cast<Qt::ConnectionType>(type)));
+            }}
+            "#,
+            call_handler_ident = signal.call_handler_ident.cpp_ident,
+            connect_ident_cpp = connect_ident_cpp,
+            handler_ident = handler_ident_cpp,
+            namespace_internals = namespace_internals,
+            parameter_idents = parameter_idents,
+            parameters = parameters_with_type,
+            signal_ident_cpp = signal_ident_cpp,
+            struct_ident = struct_ident,
+        };
+
         items.push(CppSignal {
             header_public,
             header_signals,
-            source,
+            source: format!("{}\n{}", source, connect_source),
         })
     }
 
     Ok(items)
 }
 
-/// Generate a CppObject object containing the header and source of a given rust QObject
-pub fn generate_qobject_cpp(obj: &QObject) -> Result<CppObject, TokenStream> {
+/// Generate the C++ enum definition of a given qenum
+fn generate_qenum_definition_cpp(qenum: &ParsedQEnum) -> String {
+    formatdoc! {r#"
+        enum class {ident} : ::std::int32_t
+        {{
+          {variants}
+        }};"#,
+    ident = qenum.ident,
+    variants = qenum
+        .variants
+        .iter()
+        .map(|variant| format!("{ident} = {value},", ident = variant.ident, value = variant.value))
+        .collect::<Vec<String>>()
+        .join("\n  "),
+    }
+}
+
+/// Generate the Q_ENUM registration of a given qenum on a QObject
+///
+/// The enum is defined in the namespace so that CXX can refer to it, but moc requires the
+/// enum to be defined in the class, so moc is given a copy and the compiler an alias.
+fn generate_qenum_registration_cpp(qenum: &ParsedQEnum, namespace: &str) -> String {
+    formatdoc! {r#"
+        #ifdef Q_MOC_RUN
+        {definition}
+        #else
+        using {ident} = {namespace}::{ident};
+        #endif
+        Q_ENUM({ident})"#,
+    definition = generate_qenum_definition_cpp(qenum),
+    ident = qenum.ident,
+    namespace = if namespace.is_empty() {
+        "".to_owned()
+    } else {
+        format!("::{}", namespace)
+    },
+    }
+}
+
+/// Generate the Q_ENUM_NS registration of a given qenum on the namespace of the module
+///
+/// Unlike Q_ENUM this is beside the definition, as moc and the compiler see the same namespace.
+fn generate_qenum_namespace_registration_cpp(qenum: &ParsedQEnum) -> String {
+    formatdoc! {r#"
+        {definition}
+        Q_ENUM_NS({ident})"#,
+    definition = generate_qenum_definition_cpp(qenum),
+    ident = qenum.ident,
+    }
+}
+
+/// Generate the C++ blocks of a given rust QObject
+fn generate_qobject_cpp_blocks(obj: &QObject) -> Result<GeneratedCppQObjectBlocks, TokenStream> {
     let struct_ident_str = obj.ident.to_string();
     let rust_struct_ident = format!("{}Rust", struct_ident_str);
 
+    // Create the namespace for internal use
+    //
+    // TODO: when we move to generator share this with gen_rs
+    let mut namespace_internals = vec![];
+    if !obj.namespace.is_empty() {
+        namespace_internals.push(obj.namespace.to_owned());
+    }
+    namespace_internals.push(format!(
+        "cxx_qt_{}",
+        obj.ident.to_string().to_case(Case::Snake)
+    ));
+    let namespace_internals = namespace_internals.join("::");
+
     // TODO: For now we proxy the gen_cpp code into what the writer phase expects
     // later this code will be moved into a generator phase
     let mut members: Vec<String> = vec![];
@@ -750,7 +1137,16 @@ pub fn generate_qobject_cpp(obj: &QObject) -> Result<CppObject, TokenStream> {
     let mut signals: Vec<String> = vec![];
     let mut slots: Vec<CppFragmentPair> = vec![];
 
-    for mut property in generate_properties_cpp(&obj.ident, &obj.properties)?.drain(..) {
+    for qenum in &obj.qenums {
+        methods.push(CppFragmentPair {
+            header: generate_qenum_registration_cpp(qenum, &obj.namespace),
+            source: "".to_owned(),
+        });
+    }
+
+    for mut property in
+        generate_properties_cpp(&obj.ident, &obj.properties, &obj.signals)?.drain(..)
+    {
         members.append(&mut property.header_members);
         metaobjects.append(&mut property.header_meta);
         methods.append(
@@ -786,14 +1182,18 @@ pub fn generate_qobject_cpp(obj: &QObject) -> Result<CppObject, TokenStream> {
         );
     }
 
-    for invokable in generate_invokables_cpp(&obj.ident, &obj.invokables)?.drain(..) {
+    for invokable in
+        generate_invokables_cpp(&obj.ident, &obj.invokables, obj.is_item_model())?.drain(..)
+    {
         methods.push(CppFragmentPair {
             header: invokable.header,
             source: invokable.source,
         });
     }
 
-    for mut signal in generate_signals_cpp(&obj.ident, &obj.signals)?.drain(..) {
+    for mut signal in
+        generate_signals_cpp(&obj.ident, &obj.signals, &namespace_internals)?.drain(..)
+    {
         methods.append(
             &mut signal
                 .header_public
@@ -828,7 +1228,7 @@ pub fn generate_qobject_cpp(obj: &QObject) -> Result<CppObject, TokenStream> {
             header: "Q_INVOKABLE void updateState();".to_owned(),
             source: formatdoc! {r#"
                     void {ident}::updateState() {{
-                        const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+                        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
                         m_rustObj->handleUpdateRequest(*this);
                     }}
                 "#,
@@ -837,30 +1237,125 @@ pub fn generate_qobject_cpp(obj: &QObject) -> Result<CppObject, TokenStream> {
         });
     }
 
-    // Create the namespace for internal use
-    //
-    // TODO: when we move to generator share this with gen_rs
-    let mut namespace_internals = vec![];
-    if !obj.namespace.is_empty() {
-        namespace_internals.push(obj.namespace.to_owned());
+    // Item models have protected helpers which need to be called when the model changes,
+    // so make these public to allow for them to be called from Rust
+    if obj.is_item_model() {
+        for helper in ITEM_MODEL_HELPERS {
+            methods.push(CppFragmentPair {
+                header: format!(
+                    "using {base_class}::{helper};",
+                    base_class = obj.base_class,
+                    helper = helper
+                ),
+                source: "".to_owned(),
+            });
+        }
     }
-    namespace_internals.push(format!(
-        "cxx_qt_{}",
-        obj.ident.to_string().to_case(Case::Snake)
-    ));
 
-    // For now convert our gen_cpp code into the GeneratedCppBlocks struct
-    let generated = GeneratedCppBlocks {
-        cxx_stem: struct_ident_str.to_case(Case::Snake),
+    // For now convert our gen_cpp code into the GeneratedCppQObjectBlocks struct
+    Ok(GeneratedCppQObjectBlocks {
         ident: struct_ident_str,
+        base_class: obj.base_class.clone(),
         rust_ident: rust_struct_ident,
         namespace: obj.namespace.clone(),
-        namespace_internals: namespace_internals.join("::"),
+        namespace_internals,
+        forward_declares: obj
+            .qenums
+            .iter()
+            .map(generate_qenum_definition_cpp)
+            .collect(),
         metaobjects,
         methods,
         slots,
         signals,
         members,
+    })
+}
+
+/// Generate the C++ blocks of a given rust QGadget
+fn generate_qgadget_cpp_blocks(gadget: &QGadget, namespace: &str) -> GeneratedCppQGadgetBlocks {
+    let ident = gadget.ident.to_string();
+
+    // The members use the Rust names of the fields as CXX expects the same names,
+    // then the properties use the camel case name with MEMBER
+    let metaobjects = gadget
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "Q_PROPERTY({type_ident} {ident} MEMBER {member})",
+                type_ident = field.type_ident.qt_type.type_ident(),
+                ident = field.ident.cpp_ident,
+                member = field.ident.rust_ident,
+            )
+        })
+        .collect();
+    let members = gadget
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{type_ident} {member};",
+                type_ident = field.type_ident.qt_type.type_ident(),
+                member = field.ident.rust_ident,
+            )
+        })
+        .collect();
+
+    // Value types need to be comparable so that property setters can detect changes
+    let methods = vec![
+        formatdoc! {r#"
+            bool operator==(const {ident}& other) const
+            {{
+              return {comparisons};
+            }}"#,
+        ident = ident,
+        comparisons = gadget
+            .fields
+            .iter()
+            .map(|field| format!("{member} == other.{member}", member = field.ident.rust_ident))
+            .collect::<Vec<String>>()
+            .join(" && "),
+        },
+        formatdoc! {r#"
+            bool operator!=(const {ident}& other) const
+            {{
+              return !(*this == other);
+            }}"#,
+        ident = ident,
+        },
+    ];
+
+    GeneratedCppQGadgetBlocks {
+        ident,
+        namespace: namespace.to_owned(),
+        metaobjects,
+        members,
+        methods,
+    }
+}
+
+/// Generate a CppObject object containing the header and source of all the QObjects in a given CXX-Qt module
+pub fn generate_qobject_cpp(module: &CxxQtModule) -> Result<CppObject, TokenStream> {
+    // For now convert our gen_cpp code into the GeneratedCppBlocks struct
+    let generated = GeneratedCppBlocks {
+        cxx_stem: module.cxx_stem.clone(),
+        namespace: module.namespace.clone(),
+        qenums: module
+            .qenums
+            .iter()
+            .map(generate_qenum_namespace_registration_cpp)
+            .collect(),
+        qgadgets: module
+            .qgadgets
+            .iter()
+            .map(|gadget| generate_qgadget_cpp_blocks(gadget, &module.namespace))
+            .collect(),
+        qobjects: module
+            .qobjects
+            .iter()
+            .map(generate_qobject_cpp_blocks)
+            .collect::<Result<Vec<GeneratedCppQObjectBlocks>, TokenStream>>()?,
     };
 
     // Use our writer phase to convert to a string
@@ -882,21 +1377,66 @@ pub fn generate_format(style: Option<ClangFormatStyle>) -> Result<(), ClangForma
 mod tests {
     use super::*;
 
-    use crate::extract_qobject;
+    use crate::extract_qobjects;
     use clang_format::clang_format;
 
     use pretty_assertions::assert_str_eq;
     use syn::ItemMod;
 
+    #[test]
+    fn generates_computed_properties() {
+        let source = include_str!("../test_inputs/computed_properties.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/computed_properties.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/computed_properties.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_custom_base() {
+        let source = include_str!("../test_inputs/custom_base.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header = clang_format(include_str!("../test_outputs/custom_base.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/custom_base.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_item_model_default_arguments() {
+        let source = include_str!("../test_inputs/custom_base.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        // The default arguments are only in the header, as they cannot be repeated in the source
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert!(cpp_object
+            .header
+            .contains("rowCount(const QModelIndex& _parent = QModelIndex())"));
+        assert!(cpp_object.header.contains("qint32 _role = Qt::DisplayRole"));
+        assert!(!cpp_object.source.contains("QModelIndex()"));
+        assert!(!cpp_object.source.contains("Qt::DisplayRole"));
+    }
+
     #[test]
     fn generates_handlers() {
         let source = include_str!("../test_inputs/handlers.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header = clang_format(include_str!("../test_outputs/handlers.h")).unwrap();
         let expected_source = clang_format(include_str!("../test_outputs/handlers.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -905,11 +1445,56 @@ mod tests {
     fn generates_invokables() {
         let source = include_str!("../test_inputs/invokables.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header = clang_format(include_str!("../test_outputs/invokables.h")).unwrap();
         let expected_source = clang_format(include_str!("../test_outputs/invokables.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_invokables_async() {
+        let source = include_str!("../test_inputs/invokables_async.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/invokables_async.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/invokables_async.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_invokables_result() {
+        let source = include_str!("../test_inputs/invokables_result.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/invokables_result.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/invokables_result.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_multi_qobjects() {
+        let source = include_str!("../test_inputs/multi_qobjects.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/multi_qobjects.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/multi_qobjects.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -918,11 +1503,11 @@ mod tests {
     fn generates_naming() {
         let source = include_str!("../test_inputs/naming.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header = clang_format(include_str!("../test_outputs/naming.h")).unwrap();
         let expected_source = clang_format(include_str!("../test_outputs/naming.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -931,11 +1516,79 @@ mod tests {
     fn generates_properties() {
         let source = include_str!("../test_inputs/properties.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header = clang_format(include_str!("../test_outputs/properties.h")).unwrap();
         let expected_source = clang_format(include_str!("../test_outputs/properties.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_qenums() {
+        let source = include_str!("../test_inputs/qenums.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header = clang_format(include_str!("../test_outputs/qenums.h")).unwrap();
+        let expected_source = clang_format(include_str!("../test_outputs/qenums.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_qenums_namespace() {
+        let source = include_str!("../test_inputs/qenums_namespace.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/qenums_namespace.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/qenums_namespace.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_property_options() {
+        let source = include_str!("../test_inputs/property_options.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/property_options.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/property_options.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_no_setter_for_constant_property() {
+        let source = include_str!("../test_inputs/property_options.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert!(!cpp_object.header.contains("setLimit"));
+        assert!(!cpp_object.source.contains("setLimit"));
+        assert!(cpp_object.header.contains("initialiseLimit"));
+    }
+
+    #[test]
+    fn generates_qgadgets() {
+        let source = include_str!("../test_inputs/qgadgets.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header = clang_format(include_str!("../test_outputs/qgadgets.h")).unwrap();
+        let expected_source = clang_format(include_str!("../test_outputs/qgadgets.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -944,11 +1597,26 @@ mod tests {
     fn generates_signals() {
         let source = include_str!("../test_inputs/signals.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header = clang_format(include_str!("../test_outputs/signals.h")).unwrap();
         let expected_source = clang_format(include_str!("../test_outputs/signals.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
+        assert_str_eq!(cpp_object.header, expected_header);
+        assert_str_eq!(cpp_object.source, expected_source);
+    }
+
+    #[test]
+    fn generates_signals_sender() {
+        let source = include_str!("../test_inputs/signals_sender.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_header =
+            clang_format(include_str!("../test_outputs/signals_sender.h")).unwrap();
+        let expected_source =
+            clang_format(include_str!("../test_outputs/signals_sender.cpp")).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -957,13 +1625,13 @@ mod tests {
     fn generates_types_primitive_property() {
         let source = include_str!("../test_inputs/types_primitive_property.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header =
             clang_format(include_str!("../test_outputs/types_primitive_property.h")).unwrap();
         let expected_source =
             clang_format(include_str!("../test_outputs/types_primitive_property.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -972,13 +1640,13 @@ mod tests {
     fn generates_types_qt_property() {
         let source = include_str!("../test_inputs/types_qt_property.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header =
             clang_format(include_str!("../test_outputs/types_qt_property.h")).unwrap();
         let expected_source =
             clang_format(include_str!("../test_outputs/types_qt_property.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -987,13 +1655,13 @@ mod tests {
     fn generates_types_qt_invokable() {
         let source = include_str!("../test_inputs/types_qt_invokable.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_header =
             clang_format(include_str!("../test_outputs/types_qt_invokable.h")).unwrap();
         let expected_source =
             clang_format(include_str!("../test_outputs/types_qt_invokable.cpp")).unwrap();
-        let cpp_object = generate_qobject_cpp(&qobject).unwrap();
+        let cpp_object = generate_qobject_cpp(&cxx_qt_module).unwrap();
         assert_str_eq!(cpp_object.header, expected_header);
         assert_str_eq!(cpp_object.source, expected_source);
     }
@@ -0,0 +1,775 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use clang_format::clang_format;
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+
+use crate::extract::{CxxQtModule, Parameter, QObject, QtTypes};
+use crate::gen_cpp::CppObject;
+use crate::parser::qobject::{QmlElementKind, QmlMetadata};
+
+/// Describes a typed value of a QML component, eg a property or parameter
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct QmlComponentValue {
+    /// The name of the value in QML
+    pub(crate) name: String,
+    /// The C++ type name of the value as used by QML tooling, eg int or QString
+    pub(crate) type_name: String,
+}
+
+/// Describes a property of a QML component
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct QmlComponentProperty {
+    /// The name and type of the property
+    pub(crate) value: QmlComponentValue,
+    /// Whether the property cannot be written from QML
+    pub(crate) read_only: bool,
+}
+
+/// Describes a signal or method of a QML component
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct QmlComponentMethod {
+    /// The name of the signal or method
+    pub(crate) name: String,
+    /// The C++ return type of the method, or None if it returns void
+    pub(crate) return_type: Option<String>,
+    /// The parameters of the signal or method
+    pub(crate) parameters: Vec<QmlComponentValue>,
+}
+
+/// Describes an enum of a QML component
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct QmlComponentEnum {
+    /// The name of the enum
+    pub(crate) name: String,
+    /// The names and values of the variants of the enum
+    pub(crate) values: Vec<(String, i32)>,
+}
+
+/// Describes the properties, signals, methods, and enums of a QObject
+/// which are visible to QML tooling in a .qmltypes file
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct QmlComponent {
+    /// The C++ base class of the QObject
+    pub(crate) prototype: String,
+    /// The Q_PROPERTY's of the QObject
+    pub(crate) properties: Vec<QmlComponentProperty>,
+    /// The Q_SIGNALS of the QObject, including the notify signals of properties
+    pub(crate) signals: Vec<QmlComponentMethod>,
+    /// The Q_INVOKABLE's of the QObject
+    pub(crate) methods: Vec<QmlComponentMethod>,
+    /// The Q_ENUM's of the QObject
+    pub(crate) enums: Vec<QmlComponentEnum>,
+}
+
+/// Describes a QObject which is registered as a QML element
+///
+/// These are collected from all the CXX-Qt modules of a crate so that
+/// the QML elements can be registered with a single call.
+#[derive(Clone, Debug)]
+pub struct QmlElement {
+    /// The fully qualified C++ class of the QObject, eg ::cxx_qt::my_object::MyObject
+    pub(crate) cpp_class: String,
+    /// The stem of the generated header which declares the C++ class
+    pub(crate) cxx_stem: String,
+    /// How the QObject is registered as a QML element
+    pub(crate) metadata: QmlMetadata,
+    /// The description of the QObject for QML tooling
+    pub(crate) component: QmlComponent,
+}
+
+impl QmlElement {
+    /// The import uri of the QML module that the element is registered in
+    pub fn uri(&self) -> &str {
+        &self.metadata.uri
+    }
+}
+
+/// The C++ type name of a QtTypes as it is normalised by the Qt meta-object system,
+/// this is the name that QML tooling uses to refer to the type
+fn qml_type_name(qt_type: &QtTypes) -> String {
+    match qt_type {
+        QtTypes::Bool => "bool".to_owned(),
+        QtTypes::F32 => "float".to_owned(),
+        QtTypes::F64 => "double".to_owned(),
+        QtTypes::I8 => "signed char".to_owned(),
+        QtTypes::I16 => "short".to_owned(),
+        QtTypes::I32 => "int".to_owned(),
+        QtTypes::QByteArray => "QByteArray".to_owned(),
+        QtTypes::QColor => "QColor".to_owned(),
+        QtTypes::QDate => "QDate".to_owned(),
+        QtTypes::QDateTime => "QDateTime".to_owned(),
+        QtTypes::QEnum { ident_string, .. } => ident_string.clone(),
+        QtTypes::QGadget { ident_string, .. } => ident_string.clone(),
+        QtTypes::QHash { key, value, .. } => {
+            format!("QHash<{}, {}>", qml_type_name(key), qml_type_name(value))
+        }
+        QtTypes::QList { element, .. } => format!("QList<{}>", qml_type_name(element)),
+        QtTypes::QModelIndex => "QModelIndex".to_owned(),
+        QtTypes::QPoint => "QPoint".to_owned(),
+        QtTypes::QPointF => "QPointF".to_owned(),
+        QtTypes::QRect => "QRect".to_owned(),
+        QtTypes::QRectF => "QRectF".to_owned(),
+        QtTypes::QSize => "QSize".to_owned(),
+        QtTypes::QSizeF => "QSizeF".to_owned(),
+        QtTypes::QString => "QString".to_owned(),
+        QtTypes::QStringList => "QStringList".to_owned(),
+        QtTypes::QTime => "QTime".to_owned(),
+        QtTypes::QUrl => "QUrl".to_owned(),
+        QtTypes::QVariant => "QVariant".to_owned(),
+        QtTypes::QVariantHash => "QVariantHash".to_owned(),
+        QtTypes::QVariantMap => "QVariantMap".to_owned(),
+        QtTypes::QVector { element, .. } => format!("QVector<{}>", qml_type_name(element)),
+        QtTypes::U8 => "uchar".to_owned(),
+        QtTypes::U16 => "ushort".to_owned(),
+        QtTypes::U32 => "uint".to_owned(),
+        QtTypes::UniquePtr { inner } => qml_type_name(inner),
+        _others => unreachable!(),
+    }
+}
+
+/// The parameters of a signal or method which are visible to QML, the CppObj is skipped
+fn qml_component_parameters(parameters: &[Parameter]) -> Vec<QmlComponentValue> {
+    parameters
+        .iter()
+        .filter(|parameter| !matches!(parameter.type_ident.qt_type, QtTypes::CppObj { .. }))
+        .map(|parameter| QmlComponentValue {
+            name: parameter.ident.to_string(),
+            type_name: qml_type_name(&parameter.type_ident.qt_type),
+        })
+        .collect()
+}
+
+/// Build the description of a QObject for QML tooling
+fn qml_component(qobject: &QObject) -> QmlComponent {
+    let properties = qobject
+        .properties
+        .iter()
+        .map(|property| QmlComponentProperty {
+            value: QmlComponentValue {
+                name: property.ident.cpp_ident.to_string(),
+                type_name: qml_type_name(&property.type_ident.qt_type),
+            },
+            read_only: !property.writable || property.constant,
+        })
+        .collect();
+
+    let mut signals: Vec<QmlComponentMethod> = qobject
+        .signals
+        .iter()
+        .map(|signal| QmlComponentMethod {
+            name: signal.signal_ident.cpp_ident.to_string(),
+            return_type: None,
+            parameters: qml_component_parameters(&signal.parameters),
+        })
+        .collect();
+    // Notify signals of properties are declared unless they are already in the Signals enum
+    for property in &qobject.properties {
+        if let Some(notify) = &property.notify {
+            let name = notify.cpp_ident.to_string();
+            if !signals.iter().any(|signal| signal.name == name) {
+                signals.push(QmlComponentMethod {
+                    name,
+                    return_type: None,
+                    parameters: vec![],
+                });
+            }
+        }
+    }
+
+    let methods = qobject
+        .invokables
+        .iter()
+        .filter(|invokable| invokable.is_qinvokable)
+        .map(|invokable| QmlComponentMethod {
+            name: invokable.ident.cpp_ident.to_string(),
+            return_type: invokable
+                .return_type
+                .as_ref()
+                .map(|return_type| qml_type_name(&return_type.qt_type)),
+            parameters: qml_component_parameters(&invokable.parameters),
+        })
+        .collect();
+
+    let enums = qobject
+        .qenums
+        .iter()
+        .map(|qenum| QmlComponentEnum {
+            name: qenum.ident.to_string(),
+            values: qenum
+                .variants
+                .iter()
+                .map(|variant| (variant.ident.to_string(), variant.value))
+                .collect(),
+        })
+        .collect();
+
+    QmlComponent {
+        prototype: qobject.base_class.clone(),
+        properties,
+        signals,
+        methods,
+        enums,
+    }
+}
+
+/// Collect the QObjects of a CXX-Qt module which have QML element options
+pub fn extract_qml_elements(module: &CxxQtModule) -> Vec<QmlElement> {
+    module
+        .qobjects
+        .iter()
+        .filter_map(|qobject| {
+            qobject.qml_metadata.as_ref().map(|metadata| QmlElement {
+                cpp_class: if qobject.namespace.is_empty() {
+                    format!("::{}", qobject.ident)
+                } else {
+                    format!("::{}::{}", qobject.namespace, qobject.ident)
+                },
+                cxx_stem: module.cxx_stem.clone(),
+                metadata: metadata.clone(),
+                component: qml_component(qobject),
+            })
+        })
+        .collect()
+}
+
+/// Escape a string so that it can be used as a C++ string literal
+fn escape_cpp_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Generate the registration call of a single QML element
+fn generate_qml_element_registration(element: &QmlElement) -> String {
+    let metadata = &element.metadata;
+    let args = format!(
+        "\"{uri}\", {major}, {minor}, \"{name}\"",
+        uri = metadata.uri,
+        major = metadata.version_major,
+        minor = metadata.version_minor,
+        name = metadata.name,
+    );
+
+    match &metadata.kind {
+        QmlElementKind::Creatable => format!(
+            "qmlRegisterType<{class}>({args});",
+            class = element.cpp_class,
+            args = args
+        ),
+        QmlElementKind::Singleton => formatdoc! {r#"
+            qmlRegisterSingletonType<{class}>(
+              {args},
+              [](QQmlEngine*, QJSEngine*) -> QObject* {{ return new {class}(); }});"#,
+            class = element.cpp_class,
+            args = args,
+        },
+        QmlElementKind::Uncreatable(reason) => formatdoc! {r#"
+            qmlRegisterUncreatableType<{class}>(
+              {args},
+              QStringLiteral("{reason}"));"#,
+            class = element.cpp_class,
+            args = args,
+            reason = escape_cpp_string(reason),
+        },
+    }
+}
+
+/// Generate a C++ header and source with a function which registers all the given QML elements
+///
+/// The function is declared as `void registerQmlTypes()` in the namespace `cxx_qt::{crate_ident}`,
+/// and the files are expected to be written as `{crate_ident}.qml.h` and `{crate_ident}.qml.cpp`.
+pub fn generate_qml_registration_cpp(elements: &[QmlElement], crate_ident: &str) -> CppObject {
+    let header = formatdoc! {r#"
+        #pragma once
+
+        namespace cxx_qt::{crate_ident} {{
+
+        // Register all the QObjects of the crate which are QML elements
+        void
+        registerQmlTypes();
+
+        }} // namespace cxx_qt::{crate_ident}
+    "#,
+    crate_ident = crate_ident,
+    };
+
+    let mut cxx_stems = elements
+        .iter()
+        .map(|element| element.cxx_stem.as_str())
+        .collect::<Vec<&str>>();
+    cxx_stems.dedup();
+
+    let source = formatdoc! {r#"
+        #include "cxx-qt-gen/include/{crate_ident}.qml.h"
+
+        #include <QtQml/QQmlEngine>
+
+        {includes}
+
+        namespace cxx_qt::{crate_ident} {{
+
+        void
+        registerQmlTypes()
+        {{
+          {registrations}
+        }}
+
+        }} // namespace cxx_qt::{crate_ident}
+    "#,
+    crate_ident = crate_ident,
+    includes = cxx_stems
+        .iter()
+        .map(|cxx_stem| format!("#include \"cxx-qt-gen/include/{}.cxxqt.h\"", cxx_stem))
+        .collect::<Vec<String>>()
+        .join("\n"),
+    registrations = elements
+        .iter()
+        .map(generate_qml_element_registration)
+        .collect::<Vec<String>>()
+        .join("\n")
+        .replace('\n', "\n  "),
+    };
+
+    CppObject {
+        header: clang_format(&header).unwrap_or(header),
+        source: clang_format(&source).unwrap_or(source),
+    }
+}
+
+/// Generate the block of a signal or method in a .qmltypes file
+fn generate_qmltypes_method(kind: &str, method: &QmlComponentMethod) -> String {
+    if method.return_type.is_none() && method.parameters.is_empty() {
+        return format!("        {} {{ name: \"{}\" }}\n", kind, method.name);
+    }
+
+    let mut block = format!("        {} {{\n", kind);
+    block.push_str(&format!("            name: \"{}\"\n", method.name));
+    if let Some(return_type) = &method.return_type {
+        block.push_str(&format!("            type: \"{}\"\n", return_type));
+    }
+    for parameter in &method.parameters {
+        block.push_str(&format!(
+            "            Parameter {{ name: \"{}\"; type: \"{}\" }}\n",
+            parameter.name, parameter.type_name
+        ));
+    }
+    block.push_str("        }\n");
+    block
+}
+
+/// Generate the Component block of a QML element in a .qmltypes file
+fn generate_qmltypes_component(element: &QmlElement) -> String {
+    let metadata = &element.metadata;
+    let component = &element.component;
+
+    let mut block = "    Component {\n".to_owned();
+    block.push_str(&format!(
+        "        name: \"{}\"\n",
+        element.cpp_class.trim_start_matches("::")
+    ));
+    block.push_str(&format!("        prototype: \"{}\"\n", component.prototype));
+    block.push_str(&format!(
+        "        exports: [\"{}/{} {}.{}\"]\n",
+        metadata.uri, metadata.name, metadata.version_major, metadata.version_minor
+    ));
+    match metadata.kind {
+        QmlElementKind::Creatable => {}
+        QmlElementKind::Singleton => block.push_str("        isSingleton: true\n"),
+        QmlElementKind::Uncreatable(_) => block.push_str("        isCreatable: false\n"),
+    }
+    block.push_str("        exportMetaObjectRevisions: [0]\n");
+
+    for qenum in &component.enums {
+        block.push_str("        Enum {\n");
+        block.push_str(&format!("            name: \"{}\"\n", qenum.name));
+        block.push_str("            values: {\n");
+        block.push_str(
+            &qenum
+                .values
+                .iter()
+                .map(|(name, value)| format!("                \"{}\": {}", name, value))
+                .collect::<Vec<String>>()
+                .join(",\n"),
+        );
+        block.push_str("\n            }\n");
+        block.push_str("        }\n");
+    }
+    for property in &component.properties {
+        block.push_str(&format!(
+            "        Property {{ name: \"{}\"; type: \"{}\"{} }}\n",
+            property.value.name,
+            property.value.type_name,
+            if property.read_only {
+                "; isReadonly: true"
+            } else {
+                ""
+            }
+        ));
+    }
+    for signal in &component.signals {
+        block.push_str(&generate_qmltypes_method("Signal", signal));
+    }
+    for method in &component.methods {
+        block.push_str(&generate_qmltypes_method("Method", method));
+    }
+
+    block.push_str("    }\n");
+    block
+}
+
+/// Generate a .qmltypes file which describes the given QML elements for QML tooling,
+/// such as qmllint and Qt Creator, the elements are expected to be in the same QML module
+pub fn generate_qmltypes(elements: &[QmlElement]) -> String {
+    formatdoc! {r#"
+        import QtQuick.tooling 1.2
+
+        // This file describes the QML elements which are defined in Rust using CXX-Qt.
+        // It is used for QML tooling purposes only.
+        //
+        // This file was automatically generated by CXX-Qt.
+
+        Module {{
+            dependencies: []
+        {components}}}
+    "#,
+    components = elements
+        .iter()
+        .map(generate_qmltypes_component)
+        .collect::<Vec<String>>()
+        .concat(),
+    }
+}
+
+/// Generate a qmldir file for the QML module with the given uri,
+/// which refers to the .qmltypes file with the given file name
+pub fn generate_qmldir(uri: &str, qmltypes_file_name: &str) -> String {
+    formatdoc! {r#"
+        module {uri}
+        typeinfo {qmltypes_file_name}
+    "#,
+    uri = uri,
+    qmltypes_file_name = qmltypes_file_name,
+    }
+}
+
+/// Generate a qmldir file for the QML module with the given uri which is loaded from a plugin,
+/// the plugin library is searched for in the given directory, or next to the qmldir if it is empty
+pub fn generate_qml_plugin_qmldir(
+    uri: &str,
+    qmltypes_file_name: &str,
+    plugin_name: &str,
+    plugin_path: &str,
+) -> String {
+    let mut qmldir = generate_qmldir(uri, qmltypes_file_name);
+    if plugin_path.is_empty() {
+        qmldir.push_str(&format!("plugin {}\n", plugin_name));
+    } else {
+        qmldir.push_str(&format!("plugin {} {}\n", plugin_name, plugin_path));
+    }
+    qmldir
+}
+
+/// The name of the generated QQmlExtensionPlugin class for the QML module with the given uri,
+/// eg com.kdab.cxx_qt.demo becomes ComKdabCxxQtDemoPlugin
+pub fn qml_plugin_class_name(uri: &str) -> String {
+    format!("{}Plugin", uri.replace('.', "_").to_case(Case::Pascal))
+}
+
+/// Generate a C++ header and source with a QQmlExtensionPlugin for the QML module with the given uri,
+/// its registerTypes calls the registration function generated by [generate_qml_registration_cpp]
+///
+/// The files are expected to be written as `{crate_ident}.qmlplugin.h` and `{crate_ident}.qmlplugin.cpp`,
+/// and the header needs to be processed by moc as the class is a QObject.
+pub fn generate_qml_plugin_cpp(uri: &str, crate_ident: &str) -> CppObject {
+    let header = formatdoc! {r#"
+        #pragma once
+
+        #include <QtQml/QQmlExtensionPlugin>
+
+        namespace cxx_qt::{crate_ident} {{
+
+        // The plugin of the {uri} QML module, which registers the QML elements of the crate
+        class {class_name} : public QQmlExtensionPlugin
+        {{
+          Q_OBJECT
+          Q_PLUGIN_METADATA(IID QQmlExtensionInterface_iid)
+
+        public:
+          void registerTypes(const char* uri) override;
+        }};
+
+        }} // namespace cxx_qt::{crate_ident}
+    "#,
+    class_name = qml_plugin_class_name(uri),
+    crate_ident = crate_ident,
+    uri = uri,
+    };
+
+    let source = formatdoc! {r#"
+        #include "cxx-qt-gen/include/{crate_ident}.qmlplugin.h"
+
+        #include "cxx-qt-gen/include/{crate_ident}.qml.h"
+
+        namespace cxx_qt::{crate_ident} {{
+
+        void
+        {class_name}::registerTypes(const char* uri)
+        {{
+          Q_ASSERT(QLatin1String(uri) == QLatin1String("{uri}"));
+          Q_UNUSED(uri);
+
+          registerQmlTypes();
+        }}
+
+        }} // namespace cxx_qt::{crate_ident}
+    "#,
+    class_name = qml_plugin_class_name(uri),
+    crate_ident = crate_ident,
+    uri = uri,
+    };
+
+    CppObject {
+        header: clang_format(&header).unwrap_or(header),
+        source: clang_format(&source).unwrap_or(source),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::extract_qobjects;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::ItemMod;
+
+    #[test]
+    fn extracts_qml_elements() {
+        let source = include_str!("../test_inputs/qml_elements.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let elements = extract_qml_elements(&cxx_qt_module);
+
+        // The QObject without a qml_uri is not a QML element
+        assert_eq!(elements.len(), 3);
+
+        assert_eq!(elements[0].cpp_class, "::cxx_qt::my_object::MyObject");
+        assert_eq!(elements[0].cxx_stem, "my_object");
+        assert_eq!(elements[0].uri(), "com.kdab.cxx_qt.demo");
+        assert_eq!(elements[0].metadata.version_major, 1);
+        assert_eq!(elements[0].metadata.version_minor, 0);
+        assert_eq!(elements[0].metadata.name, "MyObject");
+        assert_eq!(elements[0].metadata.kind, QmlElementKind::Creatable);
+
+        assert_eq!(elements[1].metadata.version_major, 2);
+        assert_eq!(elements[1].metadata.version_minor, 1);
+        assert_eq!(elements[1].metadata.name, "Settings");
+        assert_eq!(elements[1].metadata.kind, QmlElementKind::Singleton);
+
+        assert_eq!(elements[2].metadata.name, "Reading");
+        assert_eq!(
+            elements[2].metadata.kind,
+            QmlElementKind::Uncreatable("Readings are created by the \"sensor\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn generates_qmltypes() {
+        let source = include_str!("../test_inputs/qml_elements.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let elements = extract_qml_elements(&cxx_qt_module);
+
+        let expected = indoc! {r#"
+            import QtQuick.tooling 1.2
+
+            // This file describes the QML elements which are defined in Rust using CXX-Qt.
+            // It is used for QML tooling purposes only.
+            //
+            // This file was automatically generated by CXX-Qt.
+
+            Module {
+                dependencies: []
+                Component {
+                    name: "cxx_qt::my_object::MyObject"
+                    prototype: "QObject"
+                    exports: ["com.kdab.cxx_qt.demo/MyObject 1.0"]
+                    exportMetaObjectRevisions: [0]
+                    Enum {
+                        name: "State"
+                        values: {
+                            "Idle": 0,
+                            "Running": 5
+                        }
+                    }
+                    Property { name: "name"; type: "QString" }
+                    Property { name: "state"; type: "State"; isReadonly: true }
+                    Signal { name: "ready" }
+                    Signal {
+                        name: "transitioned"
+                        Parameter { name: "state"; type: "State" }
+                        Parameter { name: "count"; type: "int" }
+                    }
+                    Signal { name: "nameChanged" }
+                    Signal { name: "stateChanged" }
+                    Method { name: "start" }
+                    Method {
+                        name: "add"
+                        type: "double"
+                        Parameter { name: "a"; type: "int" }
+                        Parameter { name: "b"; type: "double" }
+                    }
+                }
+                Component {
+                    name: "cxx_qt::my_object::SettingsObject"
+                    prototype: "QObject"
+                    exports: ["com.kdab.cxx_qt.demo/Settings 2.1"]
+                    isSingleton: true
+                    exportMetaObjectRevisions: [0]
+                }
+                Component {
+                    name: "cxx_qt::my_object::Reading"
+                    prototype: "QObject"
+                    exports: ["com.kdab.cxx_qt.demo/Reading 1.0"]
+                    isCreatable: false
+                    exportMetaObjectRevisions: [0]
+                }
+            }
+        "#};
+        assert_str_eq!(generate_qmltypes(&elements), expected);
+    }
+
+    #[test]
+    fn generates_qmldir() {
+        assert_str_eq!(
+            generate_qmldir("com.kdab.cxx_qt.demo", "qml_features.qmltypes"),
+            "module com.kdab.cxx_qt.demo\ntypeinfo qml_features.qmltypes\n"
+        );
+    }
+
+    #[test]
+    fn generates_qml_registration() {
+        let source = include_str!("../test_inputs/qml_elements.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+        let elements = extract_qml_elements(&cxx_qt_module);
+        let cpp = generate_qml_registration_cpp(&elements, "qml_features");
+
+        let expected_header = indoc! {r#"
+            #pragma once
+
+            namespace cxx_qt::qml_features {
+
+            // Register all the QObjects of the crate which are QML elements
+            void
+            registerQmlTypes();
+
+            } // namespace cxx_qt::qml_features
+        "#};
+        let expected_source = indoc! {r#"
+            #include "cxx-qt-gen/include/qml_features.qml.h"
+
+            #include <QtQml/QQmlEngine>
+
+            #include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+            namespace cxx_qt::qml_features {
+
+            void
+            registerQmlTypes()
+            {
+              qmlRegisterType<::cxx_qt::my_object::MyObject>("com.kdab.cxx_qt.demo", 1, 0, "MyObject");
+              qmlRegisterSingletonType<::cxx_qt::my_object::SettingsObject>(
+                "com.kdab.cxx_qt.demo", 2, 1, "Settings",
+                [](QQmlEngine*, QJSEngine*) -> QObject* { return new ::cxx_qt::my_object::SettingsObject(); });
+              qmlRegisterUncreatableType<::cxx_qt::my_object::Reading>(
+                "com.kdab.cxx_qt.demo", 1, 0, "Reading",
+                QStringLiteral("Readings are created by the \"sensor\""));
+            }
+
+            } // namespace cxx_qt::qml_features
+        "#};
+        assert_str_eq!(
+            cpp.header,
+            clang_format(expected_header).unwrap_or_else(|_| expected_header.to_owned())
+        );
+        assert_str_eq!(
+            cpp.source,
+            clang_format(expected_source).unwrap_or_else(|_| expected_source.to_owned())
+        );
+    }
+
+    #[test]
+    fn generates_qml_plugin_qmldir() {
+        assert_str_eq!(
+            generate_qml_plugin_qmldir(
+                "com.kdab.cxx_qt.demo",
+                "core.qmltypes",
+                "core",
+                "/build/target/debug"
+            ),
+            "module com.kdab.cxx_qt.demo\ntypeinfo core.qmltypes\nplugin core /build/target/debug\n"
+        );
+        assert_str_eq!(
+            generate_qml_plugin_qmldir("com.kdab.cxx_qt.demo", "core.qmltypes", "core", ""),
+            "module com.kdab.cxx_qt.demo\ntypeinfo core.qmltypes\nplugin core\n"
+        );
+    }
+
+    #[test]
+    fn generates_qml_plugin() {
+        assert_eq!(
+            qml_plugin_class_name("com.kdab.cxx_qt.demo"),
+            "ComKdabCxxQtDemoPlugin"
+        );
+
+        let cpp = generate_qml_plugin_cpp("com.kdab.cxx_qt.demo", "core");
+
+        let expected_header = indoc! {r#"
+            #pragma once
+
+            #include <QtQml/QQmlExtensionPlugin>
+
+            namespace cxx_qt::core {
+
+            // The plugin of the com.kdab.cxx_qt.demo QML module, which registers the QML elements of the crate
+            class ComKdabCxxQtDemoPlugin : public QQmlExtensionPlugin
+            {
+              Q_OBJECT
+              Q_PLUGIN_METADATA(IID QQmlExtensionInterface_iid)
+
+            public:
+              void registerTypes(const char* uri) override;
+            };
+
+            } // namespace cxx_qt::core
+        "#};
+        let expected_source = indoc! {r#"
+            #include "cxx-qt-gen/include/core.qmlplugin.h"
+
+            #include "cxx-qt-gen/include/core.qml.h"
+
+            namespace cxx_qt::core {
+
+            void
+            ComKdabCxxQtDemoPlugin::registerTypes(const char* uri)
+            {
+              Q_ASSERT(QLatin1String(uri) == QLatin1String("com.kdab.cxx_qt.demo"));
+              Q_UNUSED(uri);
+
+              registerQmlTypes();
+            }
+
+            } // namespace cxx_qt::core
+        "#};
+        assert_str_eq!(
+            cpp.header,
+            clang_format(expected_header).unwrap_or_else(|_| expected_header.to_owned())
+        );
+        assert_str_eq!(
+            cpp.source,
+            clang_format(expected_source).unwrap_or_else(|_| expected_source.to_owned())
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use clang_format::clang_format;
+use indoc::formatdoc;
+
+use crate::gen_cpp::CppObject;
+
+/// Generate a C++ header and source with a function which initialises the given Qt resources
+///
+/// Resources which are linked from a static library are not registered automatically, so
+/// `Q_INIT_RESOURCE` needs to be called for each of them. As `Q_INIT_RESOURCE` cannot be used
+/// in a namespace, it is called from a function in the global namespace which is then called by
+/// `void initResources()` in the namespace `cxx_qt::{crate_ident}`.
+///
+/// The files are expected to be written as `{crate_ident}.qrc.h` and `{crate_ident}.qrc.cpp`.
+pub fn generate_qrc_init_cpp(resource_names: &[String], crate_ident: &str) -> CppObject {
+    let header = formatdoc! {r#"
+        #pragma once
+
+        namespace cxx_qt::{crate_ident} {{
+
+        // Initialise the Qt resources of the crate, this needs to be called before they are used
+        void
+        initResources();
+
+        }} // namespace cxx_qt::{crate_ident}
+    "#,
+    crate_ident = crate_ident,
+    };
+
+    let source = formatdoc! {r#"
+        #include "cxx-qt-gen/include/{crate_ident}.qrc.h"
+
+        #include <QtCore/QDir>
+
+        static void
+        cxxQtInitResources()
+        {{
+          {init_resources}
+        }}
+
+        namespace cxx_qt::{crate_ident} {{
+
+        void
+        initResources()
+        {{
+          cxxQtInitResources();
+        }}
+
+        }} // namespace cxx_qt::{crate_ident}
+    "#,
+    crate_ident = crate_ident,
+    init_resources = resource_names
+        .iter()
+        .map(|name| format!("Q_INIT_RESOURCE({});", name))
+        .collect::<Vec<String>>()
+        .join("\n  "),
+    };
+
+    CppObject {
+        header: clang_format(&header).unwrap_or(header),
+        source: clang_format(&source).unwrap_or(source),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn generates_qrc_init() {
+        let cpp = generate_qrc_init_cpp(&["qml".to_owned(), "images".to_owned()], "my_app");
+
+        let expected_header = indoc! {r#"
+            #pragma once
+
+            namespace cxx_qt::my_app {
+
+            // Initialise the Qt resources of the crate, this needs to be called before they are used
+            void
+            initResources();
+
+            } // namespace cxx_qt::my_app
+        "#};
+        let expected_source = indoc! {r#"
+            #include "cxx-qt-gen/include/my_app.qrc.h"
+
+            #include <QtCore/QDir>
+
+            static void
+            cxxQtInitResources()
+            {
+              Q_INIT_RESOURCE(qml);
+              Q_INIT_RESOURCE(images);
+            }
+
+            namespace cxx_qt::my_app {
+
+            void
+            initResources()
+            {
+              cxxQtInitResources();
+            }
+
+            } // namespace cxx_qt::my_app
+        "#};
+        assert_str_eq!(
+            cpp.header,
+            clang_format(expected_header).unwrap_or_else(|_| expected_header.to_owned())
+        );
+        assert_str_eq!(
+            cpp.source,
+            clang_format(expected_source).unwrap_or_else(|_| expected_source.to_owned())
+        );
+    }
+}
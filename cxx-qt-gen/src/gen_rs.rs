@@ -6,10 +6,10 @@
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use syn::ItemMod;
+use syn::{FnArg, ItemMod, PatType};
 
-use crate::extract::{Invokable, QObject, QtTypes};
-use crate::generator::rust::GeneratedRustBlocks;
+use crate::extract::{CxxQtModule, Invokable, QObject, QtTypes, Signal};
+use crate::generator::rust::{GeneratedRustBlocks, GeneratedRustQObjectBlocks};
 use crate::writer::rust::write_rust;
 
 /// A trait which we implement on QtTypes allowing retrieval of attributes of the enum value.
@@ -26,9 +26,13 @@ impl RustType for QtTypes {
     /// Whether this type should be a reference when used in Rust methods
     fn is_ref(&self) -> bool {
         match self {
+            Self::QByteArray => true,
             Self::QColor => true,
             Self::QDate => true,
             Self::QDateTime => true,
+            Self::QHash { .. } => true,
+            Self::QList { .. } => true,
+            Self::QModelIndex => true,
             Self::QPoint => true,
             Self::QPointF => true,
             Self::QRect => true,
@@ -37,8 +41,12 @@ impl RustType for QtTypes {
             Self::QSizeF => true,
             Self::QTime => true,
             Self::QString => true,
+            Self::QStringList => true,
             Self::QUrl => true,
             Self::QVariant => true,
+            Self::QVariantHash => true,
+            Self::QVariantMap => true,
+            Self::QVector { .. } => true,
             Self::UniquePtr { .. } => true,
             _others => false,
         }
@@ -56,9 +64,15 @@ impl RustType for QtTypes {
             Self::I8 => format_ident!("i8"),
             Self::I16 => format_ident!("i16"),
             Self::I32 => format_ident!("i32"),
+            Self::QByteArray => format_ident!("QByteArray"),
             Self::QColor => format_ident!("QColor"),
             Self::QDate => format_ident!("QDate"),
             Self::QDateTime => format_ident!("QDateTime"),
+            Self::QEnum { ident, .. } => ident.clone(),
+            Self::QGadget { ident, .. } => ident.clone(),
+            Self::QHash { ident, .. } => ident.clone(),
+            Self::QList { ident, .. } => ident.clone(),
+            Self::QModelIndex => format_ident!("QModelIndex"),
             Self::QPoint => format_ident!("QPoint"),
             Self::QPointF => format_ident!("QPointF"),
             Self::QRect => format_ident!("QRect"),
@@ -66,9 +80,13 @@ impl RustType for QtTypes {
             Self::QSize => format_ident!("QSize"),
             Self::QSizeF => format_ident!("QSizeF"),
             Self::QString => format_ident!("QString"),
+            Self::QStringList => format_ident!("QStringList"),
             Self::QTime => format_ident!("QTime"),
             Self::QUrl => format_ident!("QUrl"),
             Self::QVariant => format_ident!("QVariant"),
+            Self::QVariantHash => format_ident!("QVariantHash"),
+            Self::QVariantMap => format_ident!("QVariantMap"),
+            Self::QVector { ident, .. } => ident.clone(),
             Self::U8 => format_ident!("u8"),
             Self::U16 => format_ident!("u16"),
             Self::U32 => format_ident!("u32"),
@@ -86,9 +104,15 @@ impl RustType for QtTypes {
             Self::I8 => quote! {i8},
             Self::I16 => quote! {i16},
             Self::I32 => quote! {i32},
+            Self::QByteArray => quote! {cxx_qt_lib::QByteArray},
             Self::QColor => quote! {cxx_qt_lib::QColor},
             Self::QDate => quote! {cxx_qt_lib::QDate},
             Self::QDateTime => quote! {cxx_qt_lib::QDateTime},
+            Self::QEnum { ident, .. } => quote! {#ident},
+            Self::QGadget { ident, .. } => quote! {#ident},
+            Self::QHash { ident, .. } => quote! {cxx_qt_lib::#ident},
+            Self::QList { ident, .. } => quote! {cxx_qt_lib::#ident},
+            Self::QModelIndex => quote! {cxx_qt_lib::QModelIndex},
             Self::QPoint => quote! {cxx_qt_lib::QPoint},
             Self::QPointF => quote! {cxx_qt_lib::QPointF},
             Self::QRect => quote! {cxx_qt_lib::QRect},
@@ -96,9 +120,13 @@ impl RustType for QtTypes {
             Self::QSize => quote! {cxx_qt_lib::QSize},
             Self::QSizeF => quote! {cxx_qt_lib::QSizeF},
             Self::QString => quote! {cxx_qt_lib::QString},
+            Self::QStringList => quote! {cxx_qt_lib::QStringList},
             Self::QTime => quote! {cxx_qt_lib::QTime},
             Self::QUrl => quote! {cxx_qt_lib::QUrl},
             Self::QVariant => quote! {cxx_qt_lib::QVariant},
+            Self::QVariantHash => quote! {cxx_qt_lib::QVariantHash},
+            Self::QVariantMap => quote! {cxx_qt_lib::QVariantMap},
+            Self::QVector { ident, .. } => quote! {cxx_qt_lib::#ident},
             Self::U8 => quote! {u8},
             Self::U16 => quote! {u16},
             Self::U32 => quote! {u32},
@@ -111,8 +139,108 @@ impl RustType for QtTypes {
     }
 }
 
-/// Generate Rust code that used CXX to interact with the C++ code generated for a QObject
-pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
+/// Generate the CXX bridge declarations for the protected item model helpers
+///
+/// These are made public on the C++ side when the base class is an item model
+fn generate_item_model_cxx(rust_class_name_cpp: &Ident) -> TokenStream {
+    quote! {
+        #[rust_name = "begin_insert_columns"]
+        fn beginInsertColumns(self: Pin<&mut #rust_class_name_cpp>, parent: &QModelIndex, first: i32, last: i32);
+        #[rust_name = "end_insert_columns"]
+        fn endInsertColumns(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "begin_insert_rows"]
+        fn beginInsertRows(self: Pin<&mut #rust_class_name_cpp>, parent: &QModelIndex, first: i32, last: i32);
+        #[rust_name = "end_insert_rows"]
+        fn endInsertRows(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "begin_move_columns"]
+        fn beginMoveColumns(self: Pin<&mut #rust_class_name_cpp>, source_parent: &QModelIndex, source_first: i32, source_last: i32, destination_parent: &QModelIndex, destination_child: i32) -> bool;
+        #[rust_name = "end_move_columns"]
+        fn endMoveColumns(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "begin_move_rows"]
+        fn beginMoveRows(self: Pin<&mut #rust_class_name_cpp>, source_parent: &QModelIndex, source_first: i32, source_last: i32, destination_parent: &QModelIndex, destination_child: i32) -> bool;
+        #[rust_name = "end_move_rows"]
+        fn endMoveRows(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "begin_remove_columns"]
+        fn beginRemoveColumns(self: Pin<&mut #rust_class_name_cpp>, parent: &QModelIndex, first: i32, last: i32);
+        #[rust_name = "end_remove_columns"]
+        fn endRemoveColumns(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "begin_remove_rows"]
+        fn beginRemoveRows(self: Pin<&mut #rust_class_name_cpp>, parent: &QModelIndex, first: i32, last: i32);
+        #[rust_name = "end_remove_rows"]
+        fn endRemoveRows(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "begin_reset_model"]
+        fn beginResetModel(self: Pin<&mut #rust_class_name_cpp>);
+        #[rust_name = "end_reset_model"]
+        fn endResetModel(self: Pin<&mut #rust_class_name_cpp>);
+    }
+}
+
+/// Generate the CppObj methods which call the protected item model helpers
+///
+/// These are unsafe as like emit_immediate the model can call back into the Rust object,
+/// eg a view could call the data() invokable immediately from endInsertRows.
+fn generate_item_model_methods_rs() -> TokenStream {
+    quote! {
+        pub unsafe fn begin_insert_columns(&mut self, parent: &cxx_qt_lib::QModelIndex, first: i32, last: i32) {
+            self.cpp.as_mut().begin_insert_columns(parent, first, last);
+        }
+
+        pub unsafe fn end_insert_columns(&mut self) {
+            self.cpp.as_mut().end_insert_columns();
+        }
+
+        pub unsafe fn begin_insert_rows(&mut self, parent: &cxx_qt_lib::QModelIndex, first: i32, last: i32) {
+            self.cpp.as_mut().begin_insert_rows(parent, first, last);
+        }
+
+        pub unsafe fn end_insert_rows(&mut self) {
+            self.cpp.as_mut().end_insert_rows();
+        }
+
+        pub unsafe fn begin_move_columns(&mut self, source_parent: &cxx_qt_lib::QModelIndex, source_first: i32, source_last: i32, destination_parent: &cxx_qt_lib::QModelIndex, destination_child: i32) -> bool {
+            self.cpp.as_mut().begin_move_columns(source_parent, source_first, source_last, destination_parent, destination_child)
+        }
+
+        pub unsafe fn end_move_columns(&mut self) {
+            self.cpp.as_mut().end_move_columns();
+        }
+
+        pub unsafe fn begin_move_rows(&mut self, source_parent: &cxx_qt_lib::QModelIndex, source_first: i32, source_last: i32, destination_parent: &cxx_qt_lib::QModelIndex, destination_child: i32) -> bool {
+            self.cpp.as_mut().begin_move_rows(source_parent, source_first, source_last, destination_parent, destination_child)
+        }
+
+        pub unsafe fn end_move_rows(&mut self) {
+            self.cpp.as_mut().end_move_rows();
+        }
+
+        pub unsafe fn begin_remove_columns(&mut self, parent: &cxx_qt_lib::QModelIndex, first: i32, last: i32) {
+            self.cpp.as_mut().begin_remove_columns(parent, first, last);
+        }
+
+        pub unsafe fn end_remove_columns(&mut self) {
+            self.cpp.as_mut().end_remove_columns();
+        }
+
+        pub unsafe fn begin_remove_rows(&mut self, parent: &cxx_qt_lib::QModelIndex, first: i32, last: i32) {
+            self.cpp.as_mut().begin_remove_rows(parent, first, last);
+        }
+
+        pub unsafe fn end_remove_rows(&mut self) {
+            self.cpp.as_mut().end_remove_rows();
+        }
+
+        pub unsafe fn begin_reset_model(&mut self) {
+            self.cpp.as_mut().begin_reset_model();
+        }
+
+        pub unsafe fn end_reset_model(&mut self) {
+            self.cpp.as_mut().end_reset_model();
+        }
+    }
+}
+
+/// Generate the CXX bridge blocks for the C++ code generated for a QObject
+fn generate_qobject_cxx_blocks(obj: &QObject, cxx_stem: &str) -> TokenStream {
     // Cache the original and rust class names, these are used multiple times later
     let class_name = &obj.ident;
     let rust_class_name_cpp = format_ident!("{}Qt", class_name);
@@ -124,6 +252,16 @@ pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
     // TODO: Abstract this calculation to make it common to gen_rs and gen_cpp
     let ident_snake = class_name.to_string().to_case(Case::Snake);
 
+    // Create the namespace for internal use
+    //
+    // TODO: when we move to generator share this with gen_cpp
+    let mut namespace_internals = vec![];
+    if !obj.namespace.is_empty() {
+        namespace_internals.push(obj.namespace.to_owned());
+    }
+    namespace_internals.push(format!("cxx_qt_{}", ident_snake));
+    let namespace_internals = namespace_internals.join("::");
+
     // Lists of functions we generate for the CXX bridge
     let mut cpp_functions = Vec::new();
     let mut rs_functions = Vec::new();
@@ -157,30 +295,33 @@ pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
         // As a first step we could maybe just add a `cpp: Pin<&mut FFICppObj>`
         // argument to invokables so that users can manually wrap it.
 
-        // Determine if the invokable has any parameter
-        if parameters.is_empty() {
-            // Determine if there is a return type
-            if let Some(return_type) = &i.return_type {
-                // Cache and build the return type
-                let type_ident = &return_type.qt_type.cxx_bridge_type_ident();
-                let type_ident = if return_type.qt_type.is_opaque() {
-                    quote! { UniquePtr<#type_ident> }
-                } else if return_type.is_ref {
-                    quote! { &#type_ident }
-                } else {
-                    quote! { #type_ident }
-                };
-
-                rs_functions.push(quote! {
-                    #[cxx_name = #ident_cpp_str]
-                    fn #ident(self: &#mutablility #rust_class_name) -> #type_ident;
-                });
+        // Determine if there is a return type and if it's a reference
+        let return_type = i.return_type.as_ref().map(|return_type| {
+            // Cache and build the return type
+            let type_ident = &return_type.qt_type.cxx_bridge_type_ident();
+            if return_type.qt_type.is_opaque() {
+                quote! { UniquePtr<#type_ident> }
+            } else if return_type.is_ref {
+                quote! { &#type_ident }
             } else {
-                rs_functions.push(quote! {
-                    #[cxx_name = #ident_cpp_str]
-                    fn #ident(self: &#mutablility #rust_class_name);
-                });
+                quote! { #type_ident }
             }
+        });
+        // A Result is declared to CXX without the error type, as CXX converts the error
+        // into an exception using Display
+        let return_type = match (return_type, &i.error_type) {
+            (Some(return_type), Some(_)) => Some(quote! { -> Result<#return_type> }),
+            (None, Some(_)) => Some(quote! { -> Result<()> }),
+            (Some(return_type), None) => Some(quote! { -> #return_type }),
+            (None, None) => None,
+        };
+
+        // Determine if the invokable has any parameter
+        if parameters.is_empty() {
+            rs_functions.push(quote! {
+                #[cxx_name = #ident_cpp_str]
+                fn #ident(self: &#mutablility #rust_class_name) #return_type;
+            });
         } else {
             // Build a list of quotes of the parameter name and type
             let mut parameters_quotes = Vec::new();
@@ -216,42 +357,29 @@ pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
                 };
             }
 
-            // Determine if there is a return type and if it's a reference
-            if let Some(return_type) = &i.return_type {
-                // Cache and build the return type
-                let type_ident = &return_type.qt_type.cxx_bridge_type_ident();
-                let type_ident = if return_type.qt_type.is_opaque() {
-                    quote! { UniquePtr<#type_ident> }
-                } else if return_type.is_ref {
-                    quote! { &#type_ident }
-                } else {
-                    quote! { #type_ident }
-                };
-
-                rs_functions.push(quote! {
-                    #[cxx_name = #ident_cpp_str]
-                    fn #ident(self: &#mutablility #rust_class_name, #(#parameters_quotes),*) -> #type_ident;
-                });
-            } else {
-                rs_functions.push(quote! {
-                    #[cxx_name = #ident_cpp_str]
-                    fn #ident(self: &#mutablility #rust_class_name, #(#parameters_quotes),*);
-                });
-            }
+            rs_functions.push(quote! {
+                #[cxx_name = #ident_cpp_str]
+                fn #ident(self: &#mutablility #rust_class_name, #(#parameters_quotes),*) #return_type;
+            });
         }
     }
 
     // Add getters/setters/notify from properties
-    for property in &obj.properties {
+    //
+    // Computed properties use the methods that are implemented in Rust, so are skipped
+    for property in obj.properties.iter().filter(|property| !property.computed) {
         // cache the snake and pascal case
         let property_ident_snake = property.ident.rust_ident.to_string().to_case(Case::Snake);
         let property_ident_pascal = property.ident.rust_ident.to_string().to_case(Case::Pascal);
 
         // Build the C++ method declarations names
+        //
+        // Note that the setter of a constant property is an initialise method
         let getter_str = &property_ident_snake;
         let getter_cpp = format_ident!("get{}", property_ident_pascal);
-        let setter_str = format!("set_{}", property_ident_snake);
-        let setter_cpp = format_ident!("set{}", property_ident_pascal);
+        let setter = property.setter.as_ref().unwrap();
+        let setter_str = setter.rust_ident.to_string();
+        let setter_cpp = &setter.cpp_ident;
 
         let qt_type = &property.type_ident.qt_type;
         let param_type = qt_type.cxx_bridge_type_ident();
@@ -329,6 +457,64 @@ pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
                 fn #queued_ident_cpp(self: Pin<&mut #rust_class_name_cpp>, #(#parameters_queued),*);
             });
         }
+
+        // The closure of a connection is held by C++ in a Box of a Rust type, when the
+        // signal is emitted C++ calls a free function with the handler and the parameters
+        let connect_ident_cpp = &signal.connect_ident.cpp_ident;
+        let connect_ident_rust_str = format!("{}_handler", signal.connect_ident.rust_ident);
+        let handler_ident_cpp_str = &signal.handler_ident.cpp_ident.to_string();
+        let handler_ident_rust = &signal.handler_ident.rust_ident;
+        let call_handler_ident_cpp_str = &signal.call_handler_ident.cpp_ident.to_string();
+        let call_handler_ident_rust = &signal.call_handler_ident.rust_ident;
+        let parameters = signal
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let ident = &parameter.ident;
+                let param_type = parameter.type_ident.qt_type.cxx_bridge_type_ident();
+                if parameter.type_ident.qt_type.is_ref() {
+                    quote! { #ident: &#param_type }
+                } else {
+                    quote! { #ident: #param_type }
+                }
+            })
+            .collect::<Vec<TokenStream>>();
+        let connect_from_ident_rust_str =
+            format!("{}_from_handler", signal.connect_ident.rust_ident);
+        cpp_functions.push(quote! {
+            #[rust_name = #connect_ident_rust_str]
+            fn #connect_ident_cpp(self: Pin<&mut #rust_class_name_cpp>, handler: Box<#handler_ident_rust>, connection_type: ConnectionType) -> UniquePtr<QMetaObjectConnection>;
+            #[rust_name = #connect_from_ident_rust_str]
+            fn #connect_ident_cpp(self: Pin<&mut #rust_class_name_cpp>, sender: Pin<&mut #rust_class_name_cpp>, handler: Box<#handler_ident_rust>, connection_type: ConnectionType) -> UniquePtr<QMetaObjectConnection>;
+        });
+        rs_functions.push(quote! {
+            #[cxx_name = #handler_ident_cpp_str]
+            #[namespace = #namespace_internals]
+            type #handler_ident_rust;
+
+            #[cxx_name = #call_handler_ident_cpp_str]
+            #[namespace = #namespace_internals]
+            fn #call_handler_ident_rust(handler: &mut #handler_ident_rust, cpp: Pin<&mut #rust_class_name_cpp>, #(#parameters),*);
+        });
+    }
+
+    // Add the methods of the C++ class which have been declared to be called from Rust
+    for cpp_method in &obj.cpp_methods {
+        let mut method = cpp_method.method.clone();
+        let self_param = if cpp_method.mutable {
+            quote! { self: Pin<&mut #rust_class_name_cpp> }
+        } else {
+            quote! { self: &#rust_class_name_cpp }
+        };
+        method.sig.inputs.insert(
+            0,
+            syn::parse2(self_param).expect("Could not build self parameter"),
+        );
+        // The method is declared in an unsafe extern block, so mark it as unsafe if it wasn't safe
+        if !cpp_method.safe {
+            method.sig.unsafety = Some(Default::default());
+        }
+        cpp_functions.push(quote! { #method });
     }
 
     // Define a function to handle update requests if we have one
@@ -341,14 +527,6 @@ pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
         quote! {}
     };
 
-    let update_requester_type = if obj.handle_updates_impl.is_some() {
-        quote! {
-            #[namespace = "rust::cxxqtlib1"]
-            type UpdateRequester = cxx_qt_lib::UpdateRequesterCpp;
-        }
-    } else {
-        quote! {}
-    };
     let request_updater_method = if obj.handle_updates_impl.is_some() {
         quote! {
             #[rust_name = "update_requester"]
@@ -359,92 +537,235 @@ pub fn generate_qobject_cxx(obj: &QObject) -> Result<ItemMod, TokenStream> {
     };
 
     // Build the import path for the C++ header
-    let import_path = format!("cxx-qt-gen/include/{}.cxxqt.h", ident_snake);
+    let import_path = format!("cxx-qt-gen/include/{}.cxxqt.h", cxx_stem);
 
-    // Build the module ident
-    let mod_attrs = &obj.original_mod.attrs;
-    let mod_ident = &obj.original_mod.ident;
-    let mod_vis = &obj.original_mod.vis;
-
-    // Retrieve the passthrough items to CXX
-    let cxx_items = &obj.cxx_items;
-
-    // For now we always include QObject, but when a base class can be specified this will
-    // become None and require the include to be in the extern "C++" block
+    // Include QObject when it is the base class, otherwise the include for the base class
+    // is required to be in an extern "C++" block of the module
     //
     // Note that quote formats this with spaces but it is valid syntax for CXX
     // https://github.com/dtolnay/cxx/blob/1862c5dad56c3da71420c5dca6e80ab788bb193d/syntax/parse.rs#L1101
-    let qt_include = quote! { <QtCore/QObject> };
+    let qt_include = if obj.base_class == "QObject" {
+        quote! { include!(<QtCore/QObject>); }
+    } else {
+        quote! {}
+    };
 
-    // Add an include for the UpdateRequester if it is used
-    let update_requester_include = if obj.handle_updates_impl.is_some() {
-        quote! { include!("cxx-qt-lib/include/update_requester.h"); }
+    // Invokables returning a Result throw their errors to the QML engine using a helper
+    let throw_error_include = if obj
+        .invokables
+        .iter()
+        .any(|invokable| invokable.error_type.is_some())
+    {
+        quote! { include!("cxx-qt-lib/include/throw_error.h"); }
     } else {
         quote! {}
     };
 
-    // Create the namespace for internal use
-    //
-    // TODO: when we move to generator share this with gen_cpp
-    let mut namespace_internals = vec![];
-    if !obj.namespace.is_empty() {
-        namespace_internals.push(obj.namespace.to_owned());
+    // Expose the protected item model helpers if the base class is an item model
+    if obj.is_item_model() {
+        cpp_functions.push(generate_item_model_cxx(&rust_class_name_cpp));
     }
-    namespace_internals.push(format!(
-        "cxx_qt_{}",
-        obj.ident.to_string().to_case(Case::Snake)
-    ));
-    let namespace_internals = namespace_internals.join("::");
 
-    // Build the CXX bridge
+    // The Rust names of the free functions need to be unique within the CXX bridge,
+    // so suffix them with the snake case name of the QObject
+    let new_cpp_object_str = format!("new_cpp_object_{}", ident_snake);
+    let create_rs_ident = format_ident!("create_rs_{}", ident_snake);
+    let initialise_cpp_ident = format_ident!("initialise_cpp_{}", ident_snake);
+
+    // Each qenum is a shared enum in the CXX bridge which is also declared as an extern type,
+    // this means CXX uses the enum class that we define in C++ and checks the values match
+    let qenum_items = obj
+        .qenums
+        .iter()
+        .map(|qenum| {
+            let item = &qenum.item;
+            quote! {
+                #[repr(i32)]
+                #item
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+    let qenum_idents = obj
+        .qenums
+        .iter()
+        .map(|qenum| &qenum.ident)
+        .collect::<Vec<&Ident>>();
+
+    // Build the CXX bridge blocks
     let class_name_str = class_name.to_string();
     let cxx_class_name_rust_str = cxx_class_name_rust.to_string();
-    let output = quote! {
-        #(#mod_attrs)*
-        #mod_vis mod #mod_ident {
-            unsafe extern "C++" {
-                include!(#import_path);
-                include!("cxx-qt-lib/include/convert.h");
-                #update_requester_include
-                include!(#qt_include);
+    let cxx_qt_thread_ident = format_ident!("{}CxxQtThread", class_name);
+    let cxx_qt_thread_queued_fn_ident = format_ident!("{}CxxQtThreadQueuedFn", class_name);
+    quote! {
+        #(#qenum_items)*
+
+        unsafe extern "C++" {
+            include!(#import_path);
+            include!("cxx-qt-lib/include/convert.h");
+            #throw_error_include
+            #qt_include
+
+            #[cxx_name = #class_name_str]
+            type #rust_class_name_cpp;
+
+            #(type #qenum_idents;)*
+
+            #(#cpp_functions)*
+
+            #[cxx_name = "unsafeRust"]
+            fn rust(self: &#rust_class_name_cpp) -> &#rust_class_name;
+            #[rust_name = #new_cpp_object_str]
+            #[namespace = #namespace_internals]
+            fn newCppObject() -> UniquePtr<#rust_class_name_cpp>;
+
+            type #cxx_qt_thread_ident;
+
+            #[cxx_name = "qtThread"]
+            fn qt_thread(self: &#rust_class_name_cpp) -> UniquePtr<#cxx_qt_thread_ident>;
+            #[cxx_name = "queue"]
+            fn queue_boxed_fn(
+                self: &#cxx_qt_thread_ident,
+                func: fn(Pin<&mut #rust_class_name_cpp>, Box<#cxx_qt_thread_queued_fn_ident>),
+                arg: Box<#cxx_qt_thread_queued_fn_ident>,
+            ) -> Result<()>;
+
+            #request_updater_method
+        }
+
+        extern "C++" {
+            #[cxx_name = "unsafeRustMut"]
+            unsafe fn rust_mut(self: Pin<&mut #rust_class_name_cpp>) -> Pin<&mut #rust_class_name>;
+        }
+
+        extern "Rust" {
+            #[cxx_name = #cxx_class_name_rust_str]
+            type #rust_class_name;
 
-                #[cxx_name = #class_name_str]
-                type #rust_class_name_cpp;
+            #(#rs_functions)*
 
-                #update_requester_type
+            #[cxx_name = "createRs"]
+            #[namespace = #namespace_internals]
+            fn #create_rs_ident() -> Box<#rust_class_name>;
 
-                #(#cpp_functions)*
+            #[cxx_name = "initialiseCpp"]
+            #[namespace = #namespace_internals]
+            fn #initialise_cpp_ident(cpp: Pin<&mut #rust_class_name_cpp>);
 
-                #[cxx_name = "unsafeRust"]
-                fn rust(self: &#rust_class_name_cpp) -> &#rust_class_name;
-                #[rust_name = "new_cpp_object"]
-                #[namespace = #namespace_internals]
-                fn newCppObject() -> UniquePtr<#rust_class_name_cpp>;
+            #[namespace = #namespace_internals]
+            type #cxx_qt_thread_queued_fn_ident;
+
+            #handle_update_request
+        }
+    }
+}
+
+/// Generate Rust code that used CXX to interact with the C++ code generated for the QObjects of a CXX-Qt module
+pub fn generate_qobject_cxx(module: &CxxQtModule) -> Result<ItemMod, TokenStream> {
+    // Build the CXX bridge blocks for each of the QObjects
+    let qobject_blocks = module
+        .qobjects
+        .iter()
+        .map(|obj| generate_qobject_cxx_blocks(obj, &module.cxx_stem))
+        .collect::<Vec<TokenStream>>();
+
+    // The UpdateRequester type can only be defined once in the CXX bridge,
+    // so add it if any of the QObjects use it
+    let update_requester_block = if module
+        .qobjects
+        .iter()
+        .any(|obj| obj.handle_updates_impl.is_some())
+    {
+        quote! {
+            unsafe extern "C++" {
+                include!("cxx-qt-lib/include/update_requester.h");
 
-                #request_updater_method
+                #[namespace = "rust::cxxqtlib1"]
+                type UpdateRequester = cxx_qt_lib::UpdateRequesterCpp;
             }
+        }
+    } else {
+        quote! {}
+    };
 
-            extern "C++" {
-                #[cxx_name = "unsafeRustMut"]
-                unsafe fn rust_mut(self: Pin<&mut #rust_class_name_cpp>) -> Pin<&mut #rust_class_name>;
+    // The connection types can only be defined once in the CXX bridge,
+    // so add them if any of the QObjects have signals
+    let connection_block = if module.qobjects.iter().any(|obj| !obj.signals.is_empty()) {
+        quote! {
+            unsafe extern "C++" {
+                include!("cxx-qt-lib/include/connection.h");
+
+                #[namespace = "rust::cxxqtlib1"]
+                type ConnectionType = cxx_qt_lib::ConnectionType;
+                #[namespace = "rust::cxxqtlib1"]
+                type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnectionCpp;
             }
+        }
+    } else {
+        quote! {}
+    };
 
-            extern "Rust" {
-                #[cxx_name = #cxx_class_name_rust_str]
-                type #rust_class_name;
+    // Each qgadget is a shared struct in the CXX bridge which is also declared as an extern type,
+    // this means CXX uses the Q_GADGET struct that we define in C++
+    let qgadget_block = if module.qgadgets.is_empty() {
+        quote! {}
+    } else {
+        let import_path = format!("cxx-qt-gen/include/{}.cxxqt.h", module.cxx_stem);
+        let qgadget_items = module.qgadgets.iter().map(|gadget| &gadget.original_struct);
+        let qgadget_idents = module.qgadgets.iter().map(|gadget| &gadget.ident);
+        quote! {
+            #(#qgadget_items)*
 
-                #(#rs_functions)*
+            unsafe extern "C++" {
+                include!(#import_path);
 
-                #[cxx_name = "createRs"]
-                #[namespace = #namespace_internals]
-                fn create_rs() -> Box<#rust_class_name>;
+                #(type #qgadget_idents;)*
+            }
+        }
+    };
 
-                #[cxx_name = "initialiseCpp"]
-                #[namespace = #namespace_internals]
-                fn initialise_cpp(cpp: Pin<&mut #rust_class_name_cpp>);
+    // The qenums of the namespace are shared enums which are declared as extern types,
+    // in the same way as the qenums of a QObject
+    let qenum_block = if module.qenums.is_empty() {
+        quote! {}
+    } else {
+        let import_path = format!("cxx-qt-gen/include/{}.cxxqt.h", module.cxx_stem);
+        let qenum_items = module.qenums.iter().map(|qenum| &qenum.item);
+        let qenum_idents = module.qenums.iter().map(|qenum| &qenum.ident);
+        quote! {
+            #(
+                #[repr(i32)]
+                #qenum_items
+            )*
 
-                #handle_update_request
+            unsafe extern "C++" {
+                include!(#import_path);
+
+                #(type #qenum_idents;)*
             }
+        }
+    };
+
+    // Build the module ident
+    let mod_attrs = &module.original_mod.attrs;
+    let mod_ident = &module.original_mod.ident;
+    let mod_vis = &module.original_mod.vis;
+
+    // Retrieve the passthrough items to CXX
+    let cxx_items = &module.cxx_items;
+
+    // Build the CXX bridge
+    let output = quote! {
+        #(#mod_attrs)*
+        #mod_vis mod #mod_ident {
+            #qenum_block
+
+            #qgadget_block
+
+            #(#qobject_blocks)*
+
+            #update_requester_block
+
+            #connection_block
 
             #(#cxx_items)*
         }
@@ -458,7 +779,8 @@ fn generate_property_methods_rs(obj: &QObject) -> Result<Vec<TokenStream>, Token
     // Build a list of property methods impls
     let mut property_methods = Vec::new();
 
-    for property in &obj.properties {
+    // Computed properties are read and written with the methods that are implemented in Rust
+    for property in obj.properties.iter().filter(|property| !property.computed) {
         let qt_type = if let QtTypes::UniquePtr { inner } = &property.type_ident.qt_type {
             &**inner
         } else {
@@ -504,7 +826,9 @@ fn generate_property_methods_rs(obj: &QObject) -> Result<Vec<TokenStream>, Token
                 });
             }
 
-            if let Some(setter) = &property.setter {
+            // A constant property is only set from the Data struct in grab_values_from_data,
+            // so there is no public setter
+            if let Some(setter) = property.setter.as_ref().filter(|_| !property.constant) {
                 // Generate a setter using the rust ident
                 let setter_ident = &setter.rust_ident;
                 if qt_type.is_opaque() {
@@ -533,9 +857,14 @@ fn generate_signal_methods_rs(obj: &QObject) -> Result<Vec<TokenStream>, TokenSt
     let mut immediate_cases = Vec::new();
     let ident = &obj.signal_ident;
 
+    // The finished signals of async invokables are not in the Signals enum
     for signal in &obj.signals {
+        let enum_ident = if let Some(enum_ident) = &signal.enum_ident {
+            enum_ident
+        } else {
+            continue;
+        };
         let emit_ident = &signal.emit_ident.rust_ident;
-        let enum_ident = &signal.enum_ident;
         let parameters = signal
             .parameters
             .iter()
@@ -597,9 +926,184 @@ fn generate_signal_methods_rs(obj: &QObject) -> Result<Vec<TokenStream>, TokenSt
         });
     }
 
+    // Connect a closure to each signal, these forward to the methods on the C++ object
+    for signal in &obj.signals {
+        let connect_ident = &signal.connect_ident.rust_ident;
+        let connect_queued_ident = format_ident!("{}_queued", connect_ident);
+        let connect_from_ident = format_ident!("{}_from", connect_ident);
+        let connect_from_queued_ident = format_ident!("{}_from_queued", connect_ident);
+        let parameter_types = signal_parameter_types(signal);
+
+        signal_methods.push(quote! {
+            /// Connect the given closure to the signal with a queued connection,
+            /// see the method of the same name on the C++ object.
+            pub fn #connect_queued_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(&mut self, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                self.cpp.as_mut().#connect_queued_ident(closure)
+            }
+
+            /// Connect the given closure to the signal with the given connection type,
+            /// see the method of the same name on the C++ object.
+            ///
+            /// # Safety
+            ///
+            /// A direct or auto connection can call the closure while Rust is borrowing the QObject.
+            pub unsafe fn #connect_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(&mut self, connection_type: cxx_qt_lib::ConnectionType, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                self.cpp.as_mut().#connect_ident(connection_type, closure)
+            }
+
+            /// Connect the given closure to the signal of the sender with a queued connection,
+            /// see the method of the same name on the C++ object.
+            pub fn #connect_from_queued_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(&mut self, sender: std::pin::Pin<&mut FFICppObj>, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                self.cpp.as_mut().#connect_from_queued_ident(sender, closure)
+            }
+
+            /// Connect the given closure to the signal of the sender with the given connection type,
+            /// see the method of the same name on the C++ object.
+            ///
+            /// # Safety
+            ///
+            /// A direct or auto connection can call the closure while Rust is borrowing the QObject.
+            pub unsafe fn #connect_from_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(&mut self, sender: std::pin::Pin<&mut FFICppObj>, connection_type: cxx_qt_lib::ConnectionType, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                self.cpp.as_mut().#connect_from_ident(sender, connection_type, closure)
+            }
+        });
+    }
+
     Ok(signal_methods)
 }
 
+/// The Rust types of the parameters of a signal, as they are given to a connected closure
+fn signal_parameter_types(signal: &Signal) -> Vec<TokenStream> {
+    signal
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let param_type = parameter.type_ident.qt_type.cxx_bridge_type_ident();
+            if parameter.type_ident.qt_type.is_ref() {
+                quote! { &#param_type }
+            } else {
+                quote! { #param_type }
+            }
+        })
+        .collect()
+}
+
+/// Generate the methods to connect closures to the signals on the C++ object
+///
+/// These are on the C++ object rather than the CppObj so that a signal of any
+/// generated QObject can be connected to, not only the QObject of an invokable.
+fn generate_signal_connect_rs(obj: &QObject) -> Option<TokenStream> {
+    if obj.signals.is_empty() {
+        return None;
+    }
+
+    let connect_methods = obj.signals.iter().map(|signal| {
+        let connect_ident = &signal.connect_ident.rust_ident;
+        let connect_queued_ident = format_ident!("{}_queued", connect_ident);
+        let connect_handler_ident = format_ident!("{}_handler", connect_ident);
+        let connect_from_ident = format_ident!("{}_from", connect_ident);
+        let connect_from_queued_ident = format_ident!("{}_from_queued", connect_ident);
+        let connect_from_handler_ident = format_ident!("{}_from_handler", connect_ident);
+        let handler_ident = &signal.handler_ident.rust_ident;
+        let parameter_types = signal_parameter_types(signal);
+
+        // A queued connection is safe as the closure is never called while Rust is borrowing the QObject
+        quote! {
+            /// Connect the given closure to the signal with a queued connection, the closure
+            /// is called with the parameters of the signal from the event loop of the thread
+            /// of the QObject each time that the signal is emitted.
+            ///
+            /// The connection is disconnected when the returned QMetaObjectConnection is dropped.
+            pub fn #connect_queued_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(self: std::pin::Pin<&mut Self>, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                unsafe { self.#connect_ident(cxx_qt_lib::ConnectionType::QueuedConnection, closure) }
+            }
+
+            /// Connect the given closure to the signal with the given connection type, the closure
+            /// is called with the parameters of the signal each time that it is emitted.
+            ///
+            /// The connection is disconnected when the returned QMetaObjectConnection is dropped.
+            ///
+            /// # Safety
+            ///
+            /// With a direct connection the closure is called while the signal is emitted,
+            /// so as with emit_immediate the object may already be borrowed by Rust,
+            /// eg when the signal is emitted by a setter that is called from Rust.
+            /// An auto connection is direct when the signal is emitted from the thread of the QObject,
+            /// and a blocking queued connection deadlocks when it is.
+            pub unsafe fn #connect_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(self: std::pin::Pin<&mut Self>, connection_type: cxx_qt_lib::ConnectionType, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.#connect_handler_ident(Box::new(#handler_ident(Box::new(closure))), connection_type))
+            }
+
+            /// Connect the given closure to the signal of the sender with a queued connection,
+            /// the closure is called with this QObject rather than the sender, from the event
+            /// loop of the thread of this QObject each time that the sender emits the signal.
+            ///
+            /// The connection is disconnected when the returned QMetaObjectConnection is dropped,
+            /// or when either QObject is destroyed.
+            pub fn #connect_from_queued_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(self: std::pin::Pin<&mut Self>, sender: std::pin::Pin<&mut Self>, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                unsafe { self.#connect_from_ident(sender, cxx_qt_lib::ConnectionType::QueuedConnection, closure) }
+            }
+
+            /// Connect the given closure to the signal of the sender with the given connection type,
+            /// the closure is called with this QObject rather than the sender.
+            ///
+            /// The connection is disconnected when the returned QMetaObjectConnection is dropped,
+            /// or when either QObject is destroyed.
+            ///
+            /// # Safety
+            ///
+            /// With a direct connection the closure is called on the thread that emits the signal,
+            /// which may not be the thread of this QObject, and this QObject may already be borrowed by Rust.
+            /// An auto connection is direct when the sender and this QObject are on the same thread.
+            pub unsafe fn #connect_from_ident<F: FnMut(&mut CppObj, #(#parameter_types),*) + 'static>(self: std::pin::Pin<&mut Self>, sender: std::pin::Pin<&mut Self>, connection_type: cxx_qt_lib::ConnectionType, closure: F) -> cxx_qt_lib::QMetaObjectConnection {
+                cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.#connect_from_handler_ident(sender, Box::new(#handler_ident(Box::new(closure))), connection_type))
+            }
+        }
+    });
+
+    Some(quote! {
+        impl FFICppObj {
+            #(#connect_methods)*
+        }
+    })
+}
+
+/// Generate the types which hold the closures connected to signals and the
+/// free functions which C++ calls when the signals are emitted
+fn generate_signal_handlers_rs(obj: &QObject) -> Vec<TokenStream> {
+    obj.signals
+        .iter()
+        .map(|signal| {
+            let handler_ident = &signal.handler_ident.rust_ident;
+            let call_handler_ident = &signal.call_handler_ident.rust_ident;
+            let (parameters, parameter_types): (Vec<TokenStream>, Vec<TokenStream>) = signal
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let ident = &parameter.ident;
+                    let param_type = parameter.type_ident.qt_type.cxx_bridge_type_ident();
+                    let param_type = if parameter.type_ident.qt_type.is_ref() {
+                        quote! { &#param_type }
+                    } else {
+                        quote! { #param_type }
+                    };
+                    (quote! { #ident: #param_type }, param_type)
+                })
+                .unzip();
+            let parameter_idents = signal.parameters.iter().map(|parameter| &parameter.ident);
+
+            quote! {
+                pub struct #handler_ident(Box<dyn FnMut(&mut CppObj, #(#parameter_types),*)>);
+
+                pub fn #call_handler_ident(handler: &mut #handler_ident, cpp: std::pin::Pin<&mut FFICppObj>, #(#parameters),*) {
+                    let mut cpp = CppObj::new(cpp);
+                    (handler.0)(&mut cpp, #(#parameter_idents),*);
+                }
+            }
+        })
+        .collect()
+}
+
 /// Builds a struct with th given new fields
 fn build_struct_with_fields(
     original_struct: &syn::ItemStruct,
@@ -704,7 +1208,22 @@ fn invokable_generate_wrapper(
     }
 
     // If we are an opaque return type then we need to convert into the C++ type
-    if let Some(return_type) = &invokable.return_type {
+    //
+    // A Result keeps the original error type, so that CXX can use its Display implementation
+    if let Some(error_type) = &invokable.error_type {
+        let return_type_ident = invokable
+            .return_type
+            .as_ref()
+            .map(|return_type| return_type.qt_type.cxx_qt_lib_type())
+            .unwrap_or_else(|| quote! { () });
+
+        Ok(quote! {
+            pub fn #ident_wrapper(&#mutablility self, #(#input_parameters),*) -> Result<#return_type_ident, #error_type> {
+                #(#wrappers)*
+                return self.#ident(#(#output_parameters),*);
+            }
+        })
+    } else if let Some(return_type) = &invokable.return_type {
         let return_type_ident = return_type.qt_type.cxx_qt_lib_type();
 
         Ok(quote! {
@@ -723,15 +1242,66 @@ fn invokable_generate_wrapper(
     }
 }
 
-/// Generate all the Rust code required to communicate with a QObject backed by generated C++ code
-pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
+/// Generate the wrapper method for a given async invokable
+///
+/// The future is polled immediately, and then each time that it is woken it is queued
+/// onto the thread of the QObject. Once the future completes the finished signal is emitted.
+fn invokable_generate_async_wrapper(
+    invokable: &Invokable,
+    ident_wrapper: &Ident,
+    finished_signal: &Signal,
+) -> Result<TokenStream, TokenStream> {
+    let ident = &invokable.ident.rust_ident;
+    let emit_ident = &finished_signal.emit_ident.rust_ident;
+
+    // The first parameter is the CppObj, the others are passed by value to the async fn
+    let (input_parameters, output_parameters): (Vec<TokenStream>, Vec<&Ident>) = invokable
+        .parameters
+        .iter()
+        .skip(1)
+        .map(|param| {
+            let param_ident = &param.ident;
+            let param_type = param.type_ident.qt_type.cxx_qt_lib_type();
+            (quote! { #param_ident: #param_type }, param_ident)
+        })
+        .unzip();
+
+    // Emit the output of the future, if there is one, with the finished signal
+    let emit_finished = if finished_signal.parameters.is_empty() {
+        quote! {
+            if task.poll().is_some() {
+                cpp.cpp.as_mut().#emit_ident();
+            }
+        }
+    } else {
+        quote! {
+            if let Some(value) = task.poll() {
+                cpp.cpp.as_mut().#emit_ident(value);
+            }
+        }
+    };
+
+    Ok(quote! {
+        pub fn #ident_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>, #(#input_parameters),*) {
+            let mut cpp = CppObj::new(cpp);
+            let qt_thread = std::sync::Mutex::new(cpp.qt_thread());
+            let task = cxx_qt_lib::Task::new(Self::#ident(#(#output_parameters),*), move |task| {
+                // If the QObject has been destroyed then the task is dropped
+                let _ = qt_thread.lock().unwrap().queue(move |mut cpp| {
+                    #emit_finished
+                });
+            });
+            #emit_finished
+        }
+    })
+}
+
+/// Generate the Rust blocks required to communicate with a QObject backed by generated C++ code
+fn generate_qobject_rs_blocks(obj: &QObject) -> Result<GeneratedRustQObjectBlocks, TokenStream> {
     // Cache the rust class name
     let rust_class_name = &obj.original_rust_struct.ident;
     let rust_wrapper_name = format_ident!("CppObj");
 
-    // Generate cxx block
-    let cxx_block = generate_qobject_cxx(obj)?;
-
     // Generate the data struct
     //
     // TODO: what happens with sub objects / pointers,
@@ -741,6 +1311,7 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
     let data_fields_no_ptr = obj
         .properties
         .iter()
+        .filter(|property| !property.computed)
         .zip(&obj.original_data_struct.fields)
         .filter_map(|(prop, field)| {
             if let QtTypes::CppObj { .. } = prop.type_ident.qt_type {
@@ -799,6 +1370,8 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
     // Generate property methods from the object
     let property_methods = generate_property_methods_rs(obj)?;
     let signal_methods = generate_signal_methods_rs(obj)?;
+    let signal_handlers = generate_signal_handlers_rs(obj);
+    let signal_connect = generate_signal_connect_rs(obj);
     let signal_enum = obj.original_signal_enum.as_ref();
 
     // Capture methods, trait impls, use decls so they can used by quote
@@ -806,9 +1379,13 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
         .invokables
         .iter()
         .filter_map(|i| {
-            i.ident_wrapper
-                .as_ref()
-                .map(|ident_wrapper| invokable_generate_wrapper(i, &ident_wrapper.rust_ident))
+            i.ident_wrapper.as_ref().map(|ident_wrapper| {
+                if let Some(finished_signal) = &i.finished_signal {
+                    invokable_generate_async_wrapper(i, &ident_wrapper.rust_ident, finished_signal)
+                } else {
+                    invokable_generate_wrapper(i, &ident_wrapper.rust_ident)
+                }
+            })
         })
         .collect::<Result<Vec<TokenStream>, TokenStream>>()?;
     let invokable_methods = obj
@@ -863,11 +1440,25 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
     for (qt_type, field) in &data_fields_no_ptr {
         if let Some(field_ident) = &field.ident {
             let field_name = field_ident.clone();
-            let setter_name = format_ident!("set_{}", field_name);
+
+            // A constant property has no setter on the CppObj,
+            // so its initialise method is called on the C++ object instead
+            let constant_setter = obj
+                .properties
+                .iter()
+                .find(|property| property.constant && property.ident.rust_ident == field_name)
+                .and_then(|property| property.setter.as_ref())
+                .map(|setter| &setter.rust_ident);
+            let setter = if let Some(constant_setter) = constant_setter {
+                quote! { self.cpp.as_mut().#constant_setter }
+            } else {
+                let setter_name = format_ident!("set_{}", field_name);
+                quote! { self.#setter_name }
+            };
 
             if qt_type.is_opaque() {
                 grab_values.push(quote! {
-                    self.#setter_name(data.#field_name.as_ref().unwrap());
+                    #setter(data.#field_name.as_ref().unwrap());
                 });
             } else {
                 let is_ref = if qt_type.is_ref() {
@@ -877,7 +1468,7 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
                 };
 
                 grab_values.push(quote! {
-                    self.#setter_name(#is_ref data.#field_name);
+                    #setter(#is_ref data.#field_name);
                 });
             }
         }
@@ -893,6 +1484,83 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
         quote! {}
     };
 
+    // Methods of the C++ class which have been declared are exposed on the CppObj
+    let cpp_methods = obj
+        .cpp_methods
+        .iter()
+        .map(|cpp_method| {
+            let ident = &cpp_method.rust_ident;
+            let docs = cpp_method
+                .method
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("doc"));
+            let unsafety = if cpp_method.safe {
+                None
+            } else {
+                Some(quote! { unsafe })
+            };
+            let (self_param, cpp) = if cpp_method.mutable {
+                (quote! { &mut self }, quote! { self.cpp.as_mut() })
+            } else {
+                (quote! { &self }, quote! { self.cpp })
+            };
+            let inputs = &cpp_method.method.sig.inputs;
+            let output = &cpp_method.method.sig.output;
+            let parameter_names = inputs.iter().filter_map(|input| match input {
+                FnArg::Typed(PatType { pat, .. }) => Some(pat),
+                FnArg::Receiver(_) => None,
+            });
+
+            quote! {
+                #(#docs)*
+                pub #unsafety fn #ident(#self_param, #inputs) #output {
+                    #cpp.#ident(#(#parameter_names),*)
+                }
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    let item_model_methods = if obj.is_item_model() {
+        generate_item_model_methods_rs()
+    } else {
+        quote! {}
+    };
+
+    // The handle to the thread of the QObject queues closures that are given the CppObj,
+    // the closure is boxed as CXX can only pass Box<T> of a Rust type to C++
+    let cxx_qt_thread_ident = format_ident!("{}CxxQtThread", obj.ident);
+    let cxx_qt_thread_queued_fn_ident = format_ident!("{}CxxQtThreadQueuedFn", obj.ident);
+    let cxx_qt_thread = quote! {
+        pub struct #cxx_qt_thread_queued_fn_ident {
+            inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+        }
+
+        impl #cxx_qt_thread_ident {
+            /// Queue the given closure onto the event loop of the thread of the QObject,
+            /// an error is returned if the QObject has been destroyed.
+            pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+            where
+                F: FnOnce(std::pin::Pin<&mut CppObj>),
+                F: Send + 'static,
+            {
+                #[allow(clippy::boxed_local)]
+                fn func(obj: std::pin::Pin<&mut FFICppObj>, arg: std::boxed::Box<#cxx_qt_thread_queued_fn_ident>) {
+                    let mut cpp = CppObj::new(obj);
+                    (arg.inner)(std::pin::Pin::new(&mut cpp));
+                }
+                let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
+                self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+            }
+        }
+
+        // # Safety
+        //
+        // The C++ class holds a shared pointer to the QObject which is guarded by a mutex
+        // and uses invokeMethod to queue closures, so it can be sent to other threads.
+        unsafe impl Send for #cxx_qt_thread_ident {}
+    };
+
     let wrapper_struct_impl = quote! {
         impl<'a> #rust_wrapper_name<'a> {
             pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
@@ -901,9 +1569,15 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
 
             #(#property_methods)*
             #(#signal_methods)*
+            #(#cpp_methods)*
+            #item_model_methods
 
             #update_requester
 
+            pub fn qt_thread(&self) -> UniquePtr<#cxx_qt_thread_ident> {
+                self.cpp.qt_thread()
+            }
+
             pub fn grab_values_from_data(&mut self, mut data: #data_struct_name) {
                 #(#grab_values)*
             }
@@ -933,6 +1607,12 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
 
             #wrapper_struct_impl
 
+            #cxx_qt_thread
+
+            #(#signal_handlers)*
+
+            #signal_connect
+
             #data_struct
 
             #data_struct_impl
@@ -944,15 +1624,26 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
     })
     .map_err(|err| err.to_compile_error())?;
 
-    let generated = GeneratedRustBlocks {
-        cxx_mod: cxx_block,
+    Ok(GeneratedRustQObjectBlocks {
         cxx_qt_mod_contents: cxx_qt_mod_fake
             .content
             .unwrap_or((syn::token::Brace::default(), vec![]))
             .1,
         cpp_struct_ident: format_ident!("{}Qt", obj.ident),
-        namespace: obj.namespace.to_owned(),
         rust_struct_ident: obj.ident.clone(),
+    })
+}
+
+/// Generate all the Rust code required to communicate with the QObjects of a CXX-Qt module backed by generated C++ code
+pub fn generate_qobject_rs(module: &CxxQtModule) -> Result<TokenStream, TokenStream> {
+    let generated = GeneratedRustBlocks {
+        cxx_mod: generate_qobject_cxx(module)?,
+        namespace: module.namespace.to_owned(),
+        qobjects: module
+            .qobjects
+            .iter()
+            .map(generate_qobject_rs_blocks)
+            .collect::<Result<Vec<GeneratedRustQObjectBlocks>, TokenStream>>()?,
     };
     Ok(write_rust(&generated))
 }
@@ -960,7 +1651,7 @@ pub fn generate_qobject_rs(obj: &QObject) -> Result<TokenStream, TokenStream> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::extract_qobject;
+    use crate::extract_qobjects;
 
     use pretty_assertions::assert_str_eq;
     use std::{
@@ -973,7 +1664,7 @@ mod tests {
         // NOTE: this error handling is pretty rough so should only used for tests
         let mut command = Command::new("rustfmt");
         let mut child = command
-            .args(&["--emit", "stdout"])
+            .args(["--edition", "2018", "--emit", "stdout"])
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .spawn()
@@ -993,16 +1684,61 @@ mod tests {
         output.replace("\n\n", "\n")
     }
 
+    #[test]
+    fn generates_computed_properties() {
+        let source = include_str!("../test_inputs/computed_properties.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/computed_properties.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_cpp_methods() {
+        let source = include_str!("../test_inputs/cpp_methods.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/cpp_methods.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_custom_base() {
+        let source = include_str!("../test_inputs/custom_base.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/custom_base.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
     #[test]
     fn generates_custom_default() {
         let source = include_str!("../test_inputs/custom_default.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/custom_default.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1012,12 +1748,12 @@ mod tests {
     fn generates_handlers() {
         let source = include_str!("../test_inputs/handlers.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/handlers.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1027,12 +1763,57 @@ mod tests {
     fn generates_invokables() {
         let source = include_str!("../test_inputs/invokables.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/invokables.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_invokables_async() {
+        let source = include_str!("../test_inputs/invokables_async.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/invokables_async.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_invokables_result() {
+        let source = include_str!("../test_inputs/invokables_result.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/invokables_result.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_multi_qobjects() {
+        let source = include_str!("../test_inputs/multi_qobjects.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/multi_qobjects.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1042,12 +1823,12 @@ mod tests {
     fn generates_naming() {
         let source = include_str!("../test_inputs/naming.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/naming.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1057,12 +1838,12 @@ mod tests {
     fn generates_passthrough() {
         let source = include_str!("../test_inputs/passthrough.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/passthrough.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1072,12 +1853,83 @@ mod tests {
     fn generates_properties() {
         let source = include_str!("../test_inputs/properties.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/properties.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_qenums() {
+        let source = include_str!("../test_inputs/qenums.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/qenums.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_qenums_namespace() {
+        let source = include_str!("../test_inputs/qenums_namespace.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/qenums_namespace.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_property_options() {
+        let source = include_str!("../test_inputs/property_options.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/property_options.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_no_setter_for_constant_property() {
+        let source = include_str!("../test_inputs/property_options.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        assert!(!generated_rs.contains("set_limit"));
+        assert!(generated_rs.contains("initialise_limit"));
+    }
+
+    #[test]
+    fn generates_qgadgets() {
+        let source = include_str!("../test_inputs/qgadgets.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/qgadgets.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1087,12 +1939,27 @@ mod tests {
     fn generates_signals() {
         let source = include_str!("../test_inputs/signals.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/signals.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
+        let generated_rs = format_rs_source(&generated_rs);
+
+        assert_str_eq!(generated_rs, expected_output);
+    }
+
+    #[test]
+    fn generates_signals_sender() {
+        let source = include_str!("../test_inputs/signals_sender.rs");
+        let module: ItemMod = syn::parse_str(source).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
+
+        let expected_output = include_str!("../test_outputs/signals_sender.rs");
+        let expected_output = format_rs_source(expected_output);
+
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1102,12 +1969,12 @@ mod tests {
     fn generates_types_primitive_property() {
         let source = include_str!("../test_inputs/types_primitive_property.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/types_primitive_property.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1117,12 +1984,12 @@ mod tests {
     fn generates_types_qt_property() {
         let source = include_str!("../test_inputs/types_qt_property.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/types_qt_property.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
@@ -1132,12 +1999,12 @@ mod tests {
     fn generates_types_qt_invokable() {
         let source = include_str!("../test_inputs/types_qt_invokable.rs");
         let module: ItemMod = syn::parse_str(source).unwrap();
-        let qobject = extract_qobject(&module).unwrap();
+        let cxx_qt_module = extract_qobjects(&module).unwrap();
 
         let expected_output = include_str!("../test_outputs/types_qt_invokable.rs");
         let expected_output = format_rs_source(expected_output);
 
-        let generated_rs = generate_qobject_rs(&qobject).unwrap().to_string();
+        let generated_rs = generate_qobject_rs(&cxx_qt_module).unwrap().to_string();
         let generated_rs = format_rs_source(&generated_rs);
 
         assert_str_eq!(generated_rs, expected_output);
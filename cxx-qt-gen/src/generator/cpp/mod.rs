@@ -7,18 +7,48 @@ pub mod fragment;
 
 use fragment::CppFragmentPair;
 
-/// Representation of the generated C++ code for a QObject
+/// Representation of the generated C++ code for a CXX-Qt module
 pub struct GeneratedCppBlocks {
     /// Stem of the CXX header to include
     pub cxx_stem: String,
+    /// Ident of the namespace of the module, which has the Q_NAMESPACE for the qenums
+    pub namespace: String,
+    /// Generated enums which are registered with Q_ENUM_NS in the order they were declared
+    pub qenums: Vec<String>,
+    /// Generated Q_GADGET value types in the order they were declared
+    pub qgadgets: Vec<GeneratedCppQGadgetBlocks>,
+    /// Generated QObjects in the order they were declared
+    pub qobjects: Vec<GeneratedCppQObjectBlocks>,
+}
+
+/// Representation of the generated C++ code for a Q_GADGET
+pub struct GeneratedCppQGadgetBlocks {
+    /// Ident of the C++ struct
+    pub ident: String,
+    /// Ident of the namespace of the struct
+    pub namespace: String,
+    /// List of Qt Meta Object items (eg Q_PROPERTY)
+    pub metaobjects: Vec<String>,
+    /// List of public members of the struct, these must match the layout of the Rust struct
+    pub members: Vec<String>,
+    /// List of public methods of the struct
+    pub methods: Vec<String>,
+}
+
+/// Representation of the generated C++ code for a QObject
+pub struct GeneratedCppQObjectBlocks {
     /// Ident of the C++ QObject
     pub ident: String,
+    /// Ident of the C++ base class of the QObject, eg QObject or QAbstractListModel
+    pub base_class: String,
     /// Ident of the Rust object
     pub rust_ident: String,
     /// Ident of the namespace of the QObject
     pub namespace: String,
     /// Ident of the namespace for CXX-Qt internals of the QObject
     pub namespace_internals: String,
+    /// List of items which are declared in the namespace of the QObject before the CXX header (eg enums)
+    pub forward_declares: Vec<String>,
     /// List of Qt Meta Object items (eg Q_PROPERTY)
     pub metaobjects: Vec<String>,
     /// List of public methods for the QObject
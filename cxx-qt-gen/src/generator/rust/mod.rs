@@ -5,16 +5,22 @@
 
 use syn::{Ident, Item, ItemMod};
 
-/// Representation of the generated Rust code for a QObject
+/// Representation of the generated Rust code for a CXX-Qt module
 pub struct GeneratedRustBlocks {
     /// Module for the CXX bridge
     pub cxx_mod: ItemMod,
+    /// Ident of the namespace of the QObjects
+    pub namespace: String,
+    /// Generated QObjects in the order they were declared
+    pub qobjects: Vec<GeneratedRustQObjectBlocks>,
+}
+
+/// Representation of the generated Rust code for a QObject
+pub struct GeneratedRustQObjectBlocks {
     /// Items for the CXX-Qt module
     pub cxx_qt_mod_contents: Vec<Item>,
     /// Ident of the Rust name for the C++ object
     pub cpp_struct_ident: Ident,
-    /// Ident of the namespace of the QObject
-    pub namespace: String,
     /// Ident of the Rust name for the Rust object
     pub rust_struct_ident: Ident,
 }
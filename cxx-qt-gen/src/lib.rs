@@ -5,16 +5,24 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 mod extract;
 mod gen_cpp;
+mod gen_qml;
+mod gen_qrc;
 mod gen_rs;
 mod generator;
 mod parser;
 mod syntax;
 mod writer;
 
-pub use extract::{extract_qobject, QObject};
+pub use extract::{extract_qobjects, CxxQtModule, QObject};
 pub use gen_cpp::{generate_format, generate_qobject_cpp, CppObject};
+pub use gen_qml::{
+    extract_qml_elements, generate_qml_plugin_cpp, generate_qml_plugin_qmldir,
+    generate_qml_registration_cpp, generate_qmldir, generate_qmltypes, qml_plugin_class_name,
+    QmlElement,
+};
+pub use gen_qrc::generate_qrc_init_cpp;
 pub use gen_rs::{generate_qobject_cxx, generate_qobject_rs};
-pub use syntax::{parse_qt_file, CxxQtItem};
+pub use syntax::{parse_qt_file, CxxQtFile, CxxQtItem};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_find_path;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Error, FnArg, ForeignItem, ForeignItemFn,
+    GenericArgument, Ident, ItemForeignMod, Lit, Meta, MetaNameValue, Pat, PathArguments, Receiver,
+    Result, Type, TypePath, TypeReference,
+};
+
+/// Describes a method of the C++ class of a QObject, or of its base class,
+/// which is declared in an extern "C++" block so that it can be called from Rust
+#[derive(Debug)]
+pub struct ParsedCppMethod {
+    /// The declaration of the method with the self parameter removed
+    pub method: ForeignItemFn,
+    /// The name of the method in Rust, this is the rust_name if there is one
+    pub rust_ident: Ident,
+    /// Whether the method takes self: Pin<&mut Self> or &self
+    pub mutable: bool,
+    /// Whether the method is safe to call, this is false if the method is marked unsafe
+    /// or it is declared in an extern block which is not marked unsafe
+    pub safe: bool,
+}
+
+/// Return whether the type is Pin<&mut Self>
+fn is_pin_mut_self(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            if segment.ident == "Pin" {
+                if let PathArguments::AngleBracketed(angles) = &segment.arguments {
+                    if let Some(GenericArgument::Type(Type::Reference(TypeReference {
+                        mutability: Some(_),
+                        elem,
+                        ..
+                    }))) = angles.args.first()
+                    {
+                        if let Type::Path(TypePath { path, .. }) = elem.as_ref() {
+                            return path.is_ident("Self");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    false
+}
+
+impl ParsedCppMethod {
+    /// Parse the methods from an extern "C++" block of a QObject
+    pub fn parse_foreign_mod(
+        foreign_mod: &ItemForeignMod,
+        unsafe_block: bool,
+    ) -> Result<Vec<Self>> {
+        if foreign_mod.abi.name.as_ref().map(|name| name.value()) != Some("C++".to_owned()) {
+            return Err(Error::new(
+                foreign_mod.abi.span(),
+                "C++ methods must be declared in an extern \"C++\" block.",
+            ));
+        }
+
+        foreign_mod
+            .items
+            .iter()
+            .map(|item| match item {
+                ForeignItem::Fn(method) => Self::parse(method, unsafe_block),
+                _others => Err(Error::new(
+                    item.span(),
+                    "Only methods can be declared in a block of C++ methods.",
+                )),
+            })
+            .collect()
+    }
+
+    /// Parse a method declared in an extern "C++" block, the first parameter is
+    /// either &self or self: Pin<&mut Self> which refers to the C++ class of the QObject
+    pub fn parse(method: &ForeignItemFn, unsafe_block: bool) -> Result<Self> {
+        let mutable =
+            match method.sig.inputs.first() {
+                Some(FnArg::Receiver(Receiver {
+                    reference: Some(_),
+                    mutability: None,
+                    ..
+                })) => false,
+                Some(FnArg::Typed(pat_type))
+                    if matches!(pat_type.pat.as_ref(), Pat::Ident(pat) if pat.ident == "self")
+                        && is_pin_mut_self(&pat_type.ty) =>
+                {
+                    true
+                }
+                _others => return Err(Error::new(
+                    method.sig.span(),
+                    "The first parameter of a C++ method must be &self or self: Pin<&mut Self>.",
+                )),
+            };
+
+        if method.sig.inputs.iter().skip(1).any(|input| {
+            !matches!(input, FnArg::Typed(pat_type) if matches!(pat_type.pat.as_ref(), Pat::Ident(_)))
+        }) {
+            return Err(Error::new(
+                method.sig.span(),
+                "The parameters of a C++ method must be named.",
+            ));
+        }
+
+        // CXX renames the method in Rust if it has a rust_name attribute
+        let rust_ident = if let Some(index) = attribute_find_path(&method.attrs, &["rust_name"]) {
+            match method.attrs[index].parse_meta()? {
+                Meta::NameValue(MetaNameValue {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }) => lit_str.parse::<Ident>()?,
+                others => {
+                    return Err(Error::new(
+                        others.span(),
+                        "rust_name must be a string, eg #[rust_name = \"name\"].",
+                    ))
+                }
+            }
+        } else {
+            method.sig.ident.clone()
+        };
+
+        // Remove the self parameter as this is generated for the C++ class of the QObject
+        let mut method = method.clone();
+        method.sig.inputs = method
+            .sig
+            .inputs
+            .into_iter()
+            .skip(1)
+            .collect::<Punctuated<FnArg, _>>();
+
+        Ok(Self {
+            safe: unsafe_block && method.sig.unsafety.is_none(),
+            method,
+            rust_ident,
+            mutable,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::tokens_to_syn;
+    use quote::quote;
+
+    #[test]
+    fn test_parse_foreign_mod() {
+        let foreign_mod: ItemForeignMod = tokens_to_syn(quote! {
+            extern "C++" {
+                #[rust_name = "set_object_name"]
+                fn setObjectName(self: Pin<&mut Self>, name: &QString);
+                #[cxx_name = "objectName"]
+                fn object_name(&self) -> UniquePtr<QString>;
+                unsafe fn deleteLater(self: Pin<&mut Self>);
+            }
+        });
+        let methods = ParsedCppMethod::parse_foreign_mod(&foreign_mod, true).unwrap();
+        assert_eq!(methods.len(), 3);
+
+        assert_eq!(methods[0].rust_ident, "set_object_name");
+        assert!(methods[0].mutable);
+        assert!(methods[0].safe);
+        assert_eq!(methods[0].method.sig.inputs.len(), 1);
+
+        assert_eq!(methods[1].rust_ident, "object_name");
+        assert!(!methods[1].mutable);
+        assert!(methods[1].safe);
+        assert!(methods[1].method.sig.inputs.is_empty());
+
+        assert_eq!(methods[2].rust_ident, "deleteLater");
+        assert!(methods[2].mutable);
+        assert!(!methods[2].safe);
+    }
+
+    #[test]
+    fn test_parse_foreign_mod_not_unsafe() {
+        let foreign_mod: ItemForeignMod = tokens_to_syn(quote! {
+            extern "C++" {
+                fn parent(&self) -> *mut QObject;
+            }
+        });
+        let methods = ParsedCppMethod::parse_foreign_mod(&foreign_mod, false).unwrap();
+        assert!(!methods[0].safe);
+    }
+
+    #[test]
+    fn test_parse_foreign_mod_invalid() {
+        // Only extern "C++" is supported
+        let foreign_mod: ItemForeignMod = tokens_to_syn(quote! {
+            extern "Rust" {
+                fn method(&self);
+            }
+        });
+        assert!(ParsedCppMethod::parse_foreign_mod(&foreign_mod, true).is_err());
+
+        // Types can't be declared
+        let foreign_mod: ItemForeignMod = tokens_to_syn(quote! {
+            extern "C++" {
+                type QString;
+            }
+        });
+        assert!(ParsedCppMethod::parse_foreign_mod(&foreign_mod, true).is_err());
+
+        // A self parameter is required
+        let foreign_mod: ItemForeignMod = tokens_to_syn(quote! {
+            extern "C++" {
+                fn function(value: i32);
+            }
+        });
+        assert!(ParsedCppMethod::parse_foreign_mod(&foreign_mod, true).is_err());
+
+        // &mut self is not valid with CXX
+        let foreign_mod: ItemForeignMod = tokens_to_syn(quote! {
+            extern "C++" {
+                fn method(&mut self);
+            }
+        });
+        assert!(ParsedCppMethod::parse_foreign_mod(&foreign_mod, true).is_err());
+    }
+}
@@ -3,11 +3,20 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::parser::{qobject::ParsedQObject, signals::ParsedSignalsEnum};
+use crate::parser::{
+    cpp_method::ParsedCppMethod,
+    qenum::ParsedQEnum,
+    qgadget::ParsedQGadget,
+    qobject::{ParsedQObject, ParsedQObjectOptions},
+    signals::ParsedSignalsEnum,
+};
 use crate::syntax::{
     attribute::{attribute_find_path, attribute_tokens_to_ident},
+    foreignmod::item_to_foreign_mod,
     path::{path_angled_args_to_type_path, path_compare_str, path_to_single_ident},
 };
+use indexmap::IndexMap;
+use quote::format_ident;
 use std::collections::HashMap;
 use syn::{
     spanned::Spanned, Error, Ident, Item, ItemEnum, ItemImpl, ItemStruct, Result, Type, TypePath,
@@ -16,7 +25,22 @@ use syn::{
 #[derive(Default)]
 pub struct ParsedCxxQtData {
     /// Map of the QObjects defined in the module that will be used for code generation
-    pub qobjects: HashMap<Ident, ParsedQObject>,
+    ///
+    /// These are stored in the order that they were declared in the module
+    pub qobjects: IndexMap<Ident, ParsedQObject>,
+    /// Map of the Data struct idents to the ident of the QObject that they belong to
+    ///
+    /// TODO: Once Data and "RustObj" have been merged this can be removed
+    pub data_structs: HashMap<Ident, Ident>,
+    /// List of the Q_GADGET structs defined in the module
+    ///
+    /// These are stored in the order that they were declared in the module
+    pub qgadgets: Vec<ParsedQGadget>,
+    /// List of the qenums defined in the module which are not on a QObject,
+    /// these are registered with Q_ENUM_NS on the namespace of the module
+    ///
+    /// These are stored in the order that they were declared in the module
+    pub qenums: Vec<ParsedQEnum>,
     /// The namespace of the CXX-Qt module
     pub namespace: String,
     /// Any `use` statements end up in the CXX-Qt generated module
@@ -24,27 +48,40 @@ pub struct ParsedCxxQtData {
 }
 
 impl ParsedCxxQtData {
-    /// Find the QObjects within the module and adds the keys into the qobjects HashMap
+    /// Find the QObjects within the module and adds the keys into the qobjects IndexMap
+    ///
+    /// This also finds which QObject each Data struct belongs to, either from a
+    /// #[cxx_qt::data(T)] attribute or by assuming that a struct named Data is for the first QObject
     pub fn find_qobject_keys(&mut self, items: &[Item]) -> Result<()> {
         for item in items {
             if let Item::Struct(s) = item {
                 if attribute_find_path(&s.attrs, &["cxx_qt", "qobject"]).is_some() {
-                    // TODO: for now we only support one qobject per block
-                    if !self.qobjects.is_empty() {
+                    if self.qobjects.contains_key(&s.ident) {
                         return Err(Error::new(
                             s.span(),
-                            "Only one #[cxx_qt::qobject] struct is supported per mod",
+                            "A #[cxx_qt::qobject] struct with this name has already been defined in this mod",
                         ));
                     }
 
-                    // Note that we assume a compiler error will occur later
-                    // if you had two structs with the same name
                     self.qobjects
                         .insert(s.ident.clone(), ParsedQObject::default());
+                } else if let Some(index) = attribute_find_path(&s.attrs, &["cxx_qt", "data"]) {
+                    let qobject_ident = attribute_tokens_to_ident(&s.attrs[index])?;
+                    self.data_structs.insert(s.ident.clone(), qobject_ident);
                 }
             }
         }
 
+        // TODO: for now we assume that an untagged Data struct is related to the first QObject
+        //
+        // Once Data and "RustObj" have been merged this can be removed
+        let data_ident = format_ident!("Data");
+        if !self.data_structs.contains_key(&data_ident) {
+            if let Some(qobject_ident) = self.qobjects.keys().next() {
+                self.data_structs.insert(data_ident, qobject_ident.clone());
+            }
+        }
+
         Ok(())
     }
 
@@ -61,13 +98,74 @@ impl ParsedCxxQtData {
                 self.uses.push(item);
                 Ok(None)
             }
+            Item::ForeignMod(_) | Item::Verbatim(_) => self.parse_foreign_mod(item),
             _ => Ok(Some(item)),
         }
     }
 
-    /// Parse a [syn::ItemEnum] into the qobjects if it's a CXX-Qt signal
+    /// Parse an extern block into the qobjects if it's a block of C++ methods
+    /// otherwise return as a [syn::Item] to pass through.
+    fn parse_foreign_mod(&mut self, item: Item) -> Result<Option<Item>> {
+        // Check if the extern block has cxx_qt::cpp_methods(T)
+        if let Some(parsed) = item_to_foreign_mod(&item) {
+            if let Some(index) =
+                attribute_find_path(&parsed.foreign_mod.attrs, &["cxx_qt", "cpp_methods"])
+            {
+                let ident = attribute_tokens_to_ident(&parsed.foreign_mod.attrs[index])?;
+                // Find the matching QObject for the extern block
+                if let Some(qobject) = self.qobjects.get_mut(&ident) {
+                    qobject
+                        .cpp_methods
+                        .append(&mut ParsedCppMethod::parse_foreign_mod(
+                            &parsed.foreign_mod,
+                            parsed.unsafety.is_some(),
+                        )?);
+                    return Ok(None);
+                } else {
+                    return Err(Error::new(
+                        parsed.foreign_mod.span(),
+                        "No matching QObject found for the given cxx_qt::cpp_methods(T) block.",
+                    ));
+                }
+            }
+        }
+
+        // Passthrough this unknown extern block
+        Ok(Some(item))
+    }
+
+    /// Parse a [syn::ItemEnum] into the qobjects if it's a CXX-Qt signal or qenum
     /// otherwise return as a [syn::Item] to pass through.
     fn parse_enum(&mut self, item_enum: ItemEnum) -> Result<Option<Item>> {
+        // Check if the enum has cxx_qt::qenum(T)
+        if let Some(index) = attribute_find_path(&item_enum.attrs, &["cxx_qt", "qenum"]) {
+            // Without a QObject the enum is registered with Q_ENUM_NS, which needs the
+            // Q_NAMESPACE of a C++ namespace, so the module needs a namespace
+            if item_enum.attrs[index].tokens.is_empty() {
+                if self.namespace.is_empty() {
+                    return Err(Error::new(
+                        item_enum.span(),
+                        "A cxx_qt::qenum enum without a QObject requires a namespace on the cxx_qt::bridge.",
+                    ));
+                }
+
+                self.qenums.push(ParsedQEnum::from(&item_enum, index)?);
+                return Ok(None);
+            }
+
+            let ident = attribute_tokens_to_ident(&item_enum.attrs[index])?;
+            // Find the matching QObject for the enum
+            if let Some(qobject) = self.qobjects.get_mut(&ident) {
+                qobject.qenums.push(ParsedQEnum::from(&item_enum, index)?);
+                return Ok(None);
+            } else {
+                return Err(Error::new(
+                    item_enum.span(),
+                    "No matching QObject found for the given cxx_qt::qenum<T> enum.",
+                ));
+            }
+        }
+
         // Check if the enum has cxx_qt::signals(T)
         if let Some(index) = attribute_find_path(&item_enum.attrs, &["cxx_qt", "signals"]) {
             let ident = attribute_tokens_to_ident(&item_enum.attrs[index])?;
@@ -111,16 +209,21 @@ impl ParsedCxxQtData {
                 }
                 return Ok(None);
             } else {
+                let ident = path_to_single_ident(path)?;
+
                 // TODO: Once Data and "RustObj" have been merged this can be removed
-                if !self.qobjects.is_empty() && path_compare_str(path, &["Data"]) {
-                    // TODO: for now we assume that Data is related to the only struct
-                    let qobject_ident = self.qobjects.keys().next().unwrap().clone();
-                    self.qobjects
-                        .entry(qobject_ident)
-                        .and_modify(|qobject| qobject.others.push(Item::Impl(imp)));
-                    return Ok(None);
+                if let Some(qobject_ident) = self.data_structs.get(&ident) {
+                    if let Some(qobject) = self.qobjects.get_mut(qobject_ident) {
+                        qobject.others.push(Item::Impl(imp));
+                        return Ok(None);
+                    } else {
+                        return Err(Error::new(
+                            imp.span(),
+                            "No matching QObject found for the impl of the given cxx_qt::data(T) struct.",
+                        ));
+                    }
                 // Find if we are an impl block for a qobject
-                } else if let Some(qobject) = self.qobjects.get_mut(&path_to_single_ident(path)?) {
+                } else if let Some(qobject) = self.qobjects.get_mut(&ident) {
                     // If we are the UpdateRequestHandler, then we need to store in list
                     //
                     // TODO: once impl UpdateRequestHandler is removed this block can go
@@ -143,18 +246,22 @@ impl ParsedCxxQtData {
         Ok(Some(Item::Impl(imp)))
     }
 
-    /// Parse a [syn::ItemStruct] into the qobjects if it's a CXX-Qt struct
+    /// Parse a [syn::ItemStruct] into the qobjects or qgadgets if it's a CXX-Qt struct
     /// otherwise return as a [syn::Item] to pass through.
     fn parse_struct(&mut self, s: ItemStruct) -> Result<Option<Item>> {
         // If the attribute is #[cxx_qt::qobject] then this the struct defining a qobject
         if let Some(index) = attribute_find_path(&s.attrs, &["cxx_qt", "qobject"]) {
             // Remove the macro from the struct
-            //
-            // TODO: we need to read the base class from the macro
             let mut s = s.clone();
-            s.attrs.remove(index);
+            let attr = s.attrs.remove(index);
 
             if let Some(qobject) = self.qobjects.get_mut(&s.ident) {
+                // Read the base class and QML element from the macro if there are any
+                // eg #[cxx_qt::qobject(base = "QAbstractListModel", qml_uri = "com.kdab")]
+                let options = ParsedQObjectOptions::parse(&attr, &s.ident)?;
+                qobject.base_class = options.base_class;
+                qobject.qml_metadata = options.qml_metadata;
+
                 // Parse any properties in the struct
                 // and remove the #[qproperty] attribute
                 qobject.parse_struct_fields(&mut s.fields)?;
@@ -169,19 +276,32 @@ impl ParsedCxxQtData {
             }
         }
 
-        // TODO: for now we assume that Data is related to the only struct in the qobjects
-        //
-        // Once Data and "RustObj" have been merged this can be removed
-        match s.ident.to_string().as_str() {
-            "Data" if !self.qobjects.is_empty() => {
-                let qobject_ident = self.qobjects.keys().next().unwrap().clone();
-                self.qobjects
-                    .entry(qobject_ident)
-                    .and_modify(|qobject| qobject.data_struct = Some(s.clone()));
-                Ok(None)
+        // If the attribute is #[cxx_qt::qgadget] then this is a value type for Q_GADGET
+        if let Some(index) = attribute_find_path(&s.attrs, &["cxx_qt", "qgadget"]) {
+            self.qgadgets.push(ParsedQGadget::from(&s, index)?);
+            return Ok(None);
+        }
+
+        // TODO: Once Data and "RustObj" have been merged this can be removed
+        if let Some(qobject_ident) = self.data_structs.get(&s.ident) {
+            // Remove the cxx_qt::data(T) macro from the struct if there is one
+            let mut s = s.clone();
+            if let Some(index) = attribute_find_path(&s.attrs, &["cxx_qt", "data"]) {
+                s.attrs.remove(index);
+            }
+
+            if let Some(qobject) = self.qobjects.get_mut(qobject_ident) {
+                qobject.data_struct = Some(s);
+                return Ok(None);
+            } else {
+                return Err(Error::new(
+                    s.span(),
+                    "No matching QObject found for the given cxx_qt::data(T) struct.",
+                ));
             }
-            _others => Ok(Some(Item::Struct(s))),
         }
+
+        Ok(Some(Item::Struct(s)))
     }
 }
 
@@ -204,6 +324,9 @@ mod tests {
         let mut cxx_qt_data = ParsedCxxQtData::default();
         cxx_qt_data.qobjects.entry(qobject_ident()).or_default();
         cxx_qt_data
+            .data_structs
+            .insert(format_ident!("Data"), qobject_ident());
+        cxx_qt_data
     }
 
     #[test]
@@ -240,6 +363,60 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_find_qobjects_multiple_qobject() {
+        let mut cxx_qt_data = ParsedCxxQtData::default();
+
+        let module: ItemMod = tokens_to_syn(quote! {
+            mod module {
+                struct Other;
+                #[cxx_qt::qobject]
+                struct MyObject;
+                #[cxx_qt::qobject]
+                struct SecondObject;
+            }
+        });
+        let result = cxx_qt_data.find_qobject_keys(&module.content.unwrap().1);
+        assert!(result.is_ok());
+        assert_eq!(cxx_qt_data.qobjects.len(), 2);
+        // Check that the declaration order is kept
+        let mut keys = cxx_qt_data.qobjects.keys();
+        assert_eq!(keys.next().unwrap(), &qobject_ident());
+        assert_eq!(keys.next().unwrap(), "SecondObject");
+        // Check that the untagged Data struct is assumed to be for the first QObject
+        assert_eq!(
+            cxx_qt_data.data_structs[&format_ident!("Data")],
+            qobject_ident()
+        );
+    }
+
+    #[test]
+    fn test_find_qobjects_data_structs() {
+        let mut cxx_qt_data = ParsedCxxQtData::default();
+
+        let module: ItemMod = tokens_to_syn(quote! {
+            mod module {
+                #[cxx_qt::data(SecondObject)]
+                struct SecondData;
+                #[cxx_qt::qobject]
+                struct MyObject;
+                #[cxx_qt::qobject]
+                struct SecondObject;
+            }
+        });
+        let result = cxx_qt_data.find_qobject_keys(&module.content.unwrap().1);
+        assert!(result.is_ok());
+        assert_eq!(cxx_qt_data.data_structs.len(), 2);
+        assert_eq!(
+            cxx_qt_data.data_structs[&format_ident!("SecondData")],
+            "SecondObject"
+        );
+        assert_eq!(
+            cxx_qt_data.data_structs[&format_ident!("Data")],
+            qobject_ident()
+        );
+    }
+
     #[test]
     fn test_find_qobjects_no_macro() {
         let mut cxx_qt_data = ParsedCxxQtData::default();
@@ -270,6 +447,68 @@ mod tests {
         assert!(cxx_qt_data.qobjects[&qobject_ident()].signals.is_some());
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_enum_valid_qenum() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum State {
+                Idle,
+                Running,
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+        assert_eq!(cxx_qt_data.qobjects[&qobject_ident()].qenums.len(), 1);
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_enum_valid_qenum_namespace() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+        cxx_qt_data.namespace = "cxx_qt".to_owned();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::qenum]
+            enum State {
+                Idle,
+                Running,
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+        assert_eq!(cxx_qt_data.qenums.len(), 1);
+        assert!(cxx_qt_data.qobjects[&qobject_ident()].qenums.is_empty());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_enum_qenum_namespace_missing() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::qenum]
+            enum State {
+                Idle,
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_enum_qenum_unknown_qobject() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(UnknownObj)]
+            enum State {
+                Idle,
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_enum_unknown_qobject() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -324,6 +563,42 @@ mod tests {
         assert!(cxx_qt_data.qobjects[&qobject_ident()].data_struct.is_some());
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_struct_valid_data_attribute() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+        cxx_qt_data
+            .data_structs
+            .insert(format_ident!("MyData"), qobject_ident());
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::data(MyObject)]
+            struct MyData;
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+        let data_struct = cxx_qt_data.qobjects[&qobject_ident()]
+            .data_struct
+            .as_ref()
+            .unwrap();
+        assert_eq!(data_struct.ident, "MyData");
+        assert_eq!(data_struct.attrs.len(), 0);
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_struct_unknown_data_attribute() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+        cxx_qt_data
+            .data_structs
+            .insert(format_ident!("MyData"), format_ident!("UnknownObj"));
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::data(UnknownObj)]
+            struct MyData;
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_struct_valid_rustobj() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -337,6 +612,23 @@ mod tests {
         assert!(cxx_qt_data.qobjects[&qobject_ident()]
             .qobject_struct
             .is_some());
+        assert!(cxx_qt_data.qobjects[&qobject_ident()].base_class.is_none());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_struct_valid_base_class() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::qobject(base = "QAbstractListModel")]
+            struct MyObject;
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+        assert_eq!(
+            cxx_qt_data.qobjects[&qobject_ident()].base_class.as_deref(),
+            Some("QAbstractListModel")
+        );
     }
 
     #[test]
@@ -394,6 +686,22 @@ mod tests {
         assert_eq!(cxx_qt_data.qobjects[&qobject_ident()].properties.len(), 0);
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_struct_valid_qgadget() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::qgadget]
+            struct MyGadget {
+                number: i32,
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+        assert_eq!(cxx_qt_data.qgadgets.len(), 1);
+        assert_eq!(cxx_qt_data.qgadgets[0].ident, "MyGadget");
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_struct_passthrough() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -465,6 +773,22 @@ mod tests {
         assert_eq!(cxx_qt_data.qobjects[&qobject_ident()].others.len(), 1);
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_impl_unknown_data_attribute() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+        cxx_qt_data
+            .data_structs
+            .insert(format_ident!("MyData"), format_ident!("UnknownObj"));
+
+        let item: Item = tokens_to_syn(quote! {
+            impl MyData {
+                fn method() {}
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_impl_valid_rustobj() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -507,6 +831,60 @@ mod tests {
         assert_eq!(cxx_qt_data.uses.len(), 1);
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_foreign_mod_valid_cpp_methods() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::cpp_methods(MyObject)]
+            unsafe extern "C++" {
+                #[rust_name = "set_object_name"]
+                fn setObjectName(self: Pin<&mut Self>, name: &QString);
+                #[rust_name = "signals_blocked"]
+                fn signalsBlocked(&self) -> bool;
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+
+        let cpp_methods = &cxx_qt_data.qobjects[&qobject_ident()].cpp_methods;
+        assert_eq!(cpp_methods.len(), 2);
+        assert!(cpp_methods[0].safe);
+        assert!(cpp_methods[0].mutable);
+        assert!(!cpp_methods[1].mutable);
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_foreign_mod_unknown_qobject() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[cxx_qt::cpp_methods(UnknownObj)]
+            unsafe extern "C++" {
+                fn signalsBlocked(&self) -> bool;
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_foreign_mod_passthrough() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = tokens_to_syn(quote! {
+            #[namespace = ""]
+            unsafe extern "C++" {
+                type QString = cxx_qt_lib::QString;
+            }
+        });
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_some());
+        assert!(cxx_qt_data.qobjects[&qobject_ident()]
+            .cpp_methods
+            .is_empty());
+    }
+
     #[test]
     fn test_find_and_merge_cxx_qt_item_passthrough() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
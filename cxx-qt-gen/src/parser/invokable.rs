@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_tokens_to_list;
+use std::collections::HashSet;
+use syn::{spanned::Spanned, Attribute, Error, ImplItemMethod, Result};
+
+/// Describes a C++ specifier for the Q_INVOKABLE
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParsedQInvokableSpecifiers {
+    Final,
+    Override,
+    Virtual,
+}
+
+/// Describes a single Q_INVOKABLE for a struct
+pub struct ParsedQInvokable {
+    /// The original [syn::ImplItemMethod] of the invokable
+    pub method: ImplItemMethod,
+    /// Any specifiers that were declared on the invokable
+    pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
+}
+
+impl ParsedQInvokable {
+    /// Parse a [syn::ImplItemMethod] and its #[qinvokable] attribute into a ParsedQInvokable
+    ///
+    /// Note that the #[qinvokable] attribute is expected to already be removed from the method
+    pub fn parse(method: ImplItemMethod, attr: &Attribute) -> Result<Self> {
+        let mut specifiers = HashSet::new();
+
+        // Read any specifiers from the attribute, eg #[qinvokable(cxx_override)]
+        if !attr.tokens.is_empty() {
+            for ident in attribute_tokens_to_list(attr)? {
+                let specifier = match ident.to_string().as_str() {
+                    "cxx_final" => ParsedQInvokableSpecifiers::Final,
+                    "cxx_override" => ParsedQInvokableSpecifiers::Override,
+                    "cxx_virtual" => ParsedQInvokableSpecifiers::Virtual,
+                    _others => {
+                        return Err(Error::new(
+                            ident.span(),
+                            "Unknown specifier, expected one of cxx_final, cxx_override, or cxx_virtual.",
+                        ))
+                    }
+                };
+
+                if !specifiers.insert(specifier) {
+                    return Err(Error::new(
+                        attr.span(),
+                        "Duplicate specifier in qinvokable.",
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { method, specifiers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::tokens_to_syn;
+    use quote::quote;
+
+    #[test]
+    fn test_parse_no_specifiers() {
+        let mut method: ImplItemMethod = tokens_to_syn(quote! {
+            #[qinvokable]
+            fn invokable(&self) {}
+        });
+        let attr = method.attrs.remove(0);
+        let invokable = ParsedQInvokable::parse(method, &attr).unwrap();
+        assert!(invokable.specifiers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_specifiers() {
+        let mut method: ImplItemMethod = tokens_to_syn(quote! {
+            #[qinvokable(cxx_final, cxx_override, cxx_virtual)]
+            fn invokable(&self) {}
+        });
+        let attr = method.attrs.remove(0);
+        let invokable = ParsedQInvokable::parse(method, &attr).unwrap();
+        assert_eq!(invokable.specifiers.len(), 3);
+        assert!(invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Final));
+        assert!(invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Override));
+        assert!(invokable
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Virtual));
+    }
+
+    #[test]
+    fn test_parse_unknown_specifier() {
+        let mut method: ImplItemMethod = tokens_to_syn(quote! {
+            #[qinvokable(cxx_unknown)]
+            fn invokable(&self) {}
+        });
+        let attr = method.attrs.remove(0);
+        assert!(ParsedQInvokable::parse(method, &attr).is_err());
+    }
+
+    #[test]
+    fn test_parse_duplicate_specifier() {
+        let mut method: ImplItemMethod = tokens_to_syn(quote! {
+            #[qinvokable(cxx_override, cxx_override)]
+            fn invokable(&self) {}
+        });
+        let attr = method.attrs.remove(0);
+        assert!(ParsedQInvokable::parse(method, &attr).is_err());
+    }
+}
@@ -3,9 +3,13 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod cpp_method;
 pub mod cxxqtdata;
+pub mod invokable;
 pub mod parameter;
 pub mod property;
+pub mod qenum;
+pub mod qgadget;
 pub mod qobject;
 pub mod signals;
 
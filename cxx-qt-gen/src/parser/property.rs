@@ -3,16 +3,234 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::{Ident, Type, Visibility};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Comma,
+    Attribute, Error, Ident, ImplItemMethod, Result, Token,
+};
 
 /// Describes a single Q_PROPERTY for a struct
 pub struct ParsedQProperty {
     /// The [syn::Ident] of the property
     pub ident: Ident,
-    /// The [syn::Type] of the property
-    pub ty: Type,
-    /// The [syn::Visiblity] of the property
-    pub vis: Visibility,
-    // TODO: later this will describe if the property has an attribute
-    // stating that the a conversion in C++ needs to occur (eg UniquePtr<T> to T)..
+    // TODO: later this will store the type and options once properties are
+    // generated from the QObject struct rather than the Data struct
+}
+
+/// Describes a Q_PROPERTY which is computed by methods in the impl block rather than stored
+pub struct ParsedComputedQProperty {
+    /// The getter method of the property, the name and return type of which define the property
+    pub method: ImplItemMethod,
+    /// The options that were declared in the #[qproperty] attribute, eg write = set_full_name
+    pub options: ParsedQPropertyOptions,
+}
+
+/// Describes the options of a #[qproperty] attribute,
+/// eg #[qproperty(read_only, notify = name_changed, reset = reset_name)]
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedQPropertyOptions {
+    /// The property cannot be written to from QML (no WRITE)
+    pub read_only: bool,
+    /// The property never changes after construction (CONSTANT)
+    pub constant: bool,
+    /// A custom notify signal for the property, which can be shared with other properties
+    pub notify: Option<Ident>,
+    /// A qinvokable which resets the property to its default value (RESET)
+    pub reset: Option<Ident>,
+    /// A method which sets the value of a computed property (WRITE)
+    pub write: Option<Ident>,
+}
+
+/// Representation of a single option in a #[qproperty] attribute, eg read_only or notify = name_changed
+struct QPropertyOption {
+    key: Ident,
+    value: Option<Ident>,
+}
+
+impl Parse for QPropertyOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.parse::<Ident>()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<Ident>()?)
+        } else {
+            None
+        };
+        Ok(QPropertyOption { key, value })
+    }
+}
+
+/// Representation of the list of options in a #[qproperty] attribute
+struct QPropertyOptionList {
+    items: Punctuated<QPropertyOption, Comma>,
+}
+
+impl Parse for QPropertyOptionList {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let items = content.parse_terminated(QPropertyOption::parse)?;
+        Ok(QPropertyOptionList { items })
+    }
+}
+
+impl ParsedQPropertyOptions {
+    /// Parse the options from a #[qproperty] attribute, a bare #[qproperty] has no options
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let mut options = Self::default();
+        if attr.tokens.is_empty() {
+            return Ok(options);
+        }
+
+        let list: QPropertyOptionList = syn::parse2(attr.tokens.clone())?;
+        for option in list.items {
+            let duplicate = match (option.key.to_string().as_str(), option.value) {
+                ("read_only", None) => std::mem::replace(&mut options.read_only, true),
+                ("constant", None) => std::mem::replace(&mut options.constant, true),
+                ("notify", Some(value)) => options.notify.replace(value).is_some(),
+                ("reset", Some(value)) => options.reset.replace(value).is_some(),
+                ("write", Some(value)) => options.write.replace(value).is_some(),
+                _others => {
+                    return Err(Error::new(
+                        option.key.span(),
+                        "Unknown option, expected one of read_only, constant, notify = signal, reset = method, or write = method.",
+                    ))
+                }
+            };
+
+            if duplicate {
+                return Err(Error::new(attr.span(), "Duplicate option in qproperty."));
+            }
+        }
+
+        // A constant property can never change, so it cannot have a notify signal
+        if options.constant && options.notify.is_some() {
+            return Err(Error::new(
+                attr.span(),
+                "A constant qproperty cannot have a notify signal.",
+            ));
+        }
+
+        // A read only or constant property cannot be written to
+        if (options.read_only || options.constant) && options.write.is_some() {
+            return Err(Error::new(
+                attr.span(),
+                "A read_only or constant qproperty cannot have a write method.",
+            ));
+        }
+
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::tokens_to_syn;
+    use quote::quote;
+    use syn::ItemStruct;
+
+    /// Helper which returns the first attribute of the first field of a struct
+    fn field_attr(s: ItemStruct) -> Attribute {
+        s.fields.into_iter().next().unwrap().attrs.remove(0)
+    }
+
+    #[test]
+    fn test_parse_no_options() {
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty]
+                number: i32,
+            }
+        }));
+        let options = ParsedQPropertyOptions::parse(&attr).unwrap();
+        assert_eq!(options, ParsedQPropertyOptions::default());
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(read_only, notify = name_changed, reset = reset_name)]
+                number: i32,
+            }
+        }));
+        let options = ParsedQPropertyOptions::parse(&attr).unwrap();
+        assert!(options.read_only);
+        assert!(!options.constant);
+        assert_eq!(options.notify.unwrap(), "name_changed");
+        assert_eq!(options.reset.unwrap(), "reset_name");
+        assert!(options.write.is_none());
+    }
+
+    #[test]
+    fn test_parse_options_write() {
+        let method: ImplItemMethod = tokens_to_syn(quote! {
+            #[qproperty(write = set_full_name, notify = name_changed)]
+            fn full_name(&self) {}
+        });
+        let options = ParsedQPropertyOptions::parse(&method.attrs[0]).unwrap();
+        assert_eq!(options.write.unwrap(), "set_full_name");
+        assert_eq!(options.notify.unwrap(), "name_changed");
+    }
+
+    #[test]
+    fn test_parse_options_constant() {
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(constant)]
+                number: i32,
+            }
+        }));
+        let options = ParsedQPropertyOptions::parse(&attr).unwrap();
+        assert!(options.constant);
+        assert!(options.notify.is_none());
+    }
+
+    #[test]
+    fn test_parse_options_invalid() {
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(unknown)]
+                number: i32,
+            }
+        }));
+        assert!(ParsedQPropertyOptions::parse(&attr).is_err());
+
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(read_only = signal)]
+                number: i32,
+            }
+        }));
+        assert!(ParsedQPropertyOptions::parse(&attr).is_err());
+
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(read_only, read_only)]
+                number: i32,
+            }
+        }));
+        assert!(ParsedQPropertyOptions::parse(&attr).is_err());
+
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(constant, notify = name_changed)]
+                number: i32,
+            }
+        }));
+        assert!(ParsedQPropertyOptions::parse(&attr).is_err());
+
+        let attr = field_attr(tokens_to_syn(quote! {
+            struct Data {
+                #[qproperty(read_only, write = set_number)]
+                number: i32,
+            }
+        }));
+        assert!(ParsedQPropertyOptions::parse(&attr).is_err());
+    }
 }
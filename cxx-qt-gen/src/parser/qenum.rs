@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_find_path;
+use std::convert::TryFrom;
+use syn::{
+    spanned::Spanned, Error, Expr, ExprLit, ExprUnary, Fields, Ident, ItemEnum, Lit, Result, UnOp,
+};
+
+/// Describes an individual value of a QEnum
+#[derive(Debug)]
+pub struct ParsedQEnumVariant {
+    /// The name of the value
+    pub ident: Ident,
+    /// The discriminant of the value
+    pub value: i32,
+}
+
+/// Describes a QEnum which is registered with Q_ENUM on a QObject, or Q_ENUM_NS on a namespace
+#[derive(Debug)]
+pub struct ParsedQEnum {
+    /// The name of the enum
+    pub ident: Ident,
+    /// The original enum with the CXX-Qt attribute removed
+    pub item: ItemEnum,
+    /// A list of the values defined in the enum
+    pub variants: Vec<ParsedQEnumVariant>,
+}
+
+/// Read the discriminant of a variant, this needs to be an integer literal
+/// as the same value needs to be written into the C++ enum
+fn parse_discriminant(expr: &Expr) -> Result<i32> {
+    // The literal is parsed as an i64 so that i32::MIN can be negated before the range check
+    let value = parse_discriminant_literal(expr)?;
+    i32::try_from(value).map_err(|_| {
+        Error::new(
+            expr.span(),
+            "The discriminant of a qenum value must fit in an i32.",
+        )
+    })
+}
+
+fn parse_discriminant_literal(expr: &Expr) -> Result<i64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse::<i64>(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant_literal(expr).map(|value| -value),
+        _others => Err(Error::new(
+            expr.span(),
+            "The discriminant of a qenum value must be an integer literal.",
+        )),
+    }
+}
+
+impl ParsedQEnum {
+    /// Constructs a ParsedQEnum object from a given [syn::ItemEnum] block
+    pub fn from(item: &ItemEnum, attr_index: usize) -> Result<Self> {
+        // Remove the attribute index as we have processed it
+        let mut item = item.clone();
+        item.attrs.remove(attr_index);
+
+        // The enum is always an i32 as this is what Qt and QML expect
+        if let Some(index) = attribute_find_path(&item.attrs, &["repr"]) {
+            return Err(Error::new(
+                item.attrs[index].span(),
+                "A qenum is always repr(i32), so repr should not be specified.",
+            ));
+        }
+
+        // Values without a discriminant follow on from the previous value like in Rust,
+        // this is None when the previous value was i32::MAX as there is no next value
+        let mut next_value = Some(0);
+        let variants = item
+            .variants
+            .iter()
+            .map(|variant| {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new(
+                        variant.span(),
+                        "Only unit variants are supported in a qenum.",
+                    ));
+                }
+
+                let value = if let Some((_, expr)) = &variant.discriminant {
+                    parse_discriminant(expr)?
+                } else {
+                    next_value.ok_or_else(|| {
+                        Error::new(
+                            variant.span(),
+                            "The discriminant of this qenum value overflows an i32.",
+                        )
+                    })?
+                };
+                next_value = value.checked_add(1);
+
+                Ok(ParsedQEnumVariant {
+                    ident: variant.ident.clone(),
+                    value,
+                })
+            })
+            .collect::<Result<Vec<ParsedQEnumVariant>>>()?;
+
+        Ok(Self {
+            ident: item.ident.clone(),
+            item,
+            variants,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::tokens_to_syn;
+    use quote::quote;
+
+    #[test]
+    fn test_parsed_qenum_from() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A,
+                B,
+            }
+        });
+        let qenum = ParsedQEnum::from(&e, 0).unwrap();
+        assert_eq!(qenum.ident, "MyEnum");
+        assert_eq!(qenum.item.attrs.len(), 0);
+        assert_eq!(qenum.variants.len(), 2);
+        assert_eq!(qenum.variants[0].ident, "A");
+        assert_eq!(qenum.variants[0].value, 0);
+        assert_eq!(qenum.variants[1].ident, "B");
+        assert_eq!(qenum.variants[1].value, 1);
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_discriminants() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A = -1,
+                B,
+                C = 5,
+                D,
+            }
+        });
+        let qenum = ParsedQEnum::from(&e, 0).unwrap();
+        assert_eq!(qenum.variants.len(), 4);
+        assert_eq!(qenum.variants[0].value, -1);
+        assert_eq!(qenum.variants[1].value, 0);
+        assert_eq!(qenum.variants[2].value, 5);
+        assert_eq!(qenum.variants[3].value, 6);
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_discriminants_i32_limits() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                Min = -2147483648,
+                Max = 2147483647,
+            }
+        });
+        let qenum = ParsedQEnum::from(&e, 0).unwrap();
+        assert_eq!(qenum.variants.len(), 2);
+        assert_eq!(qenum.variants[0].value, i32::MIN);
+        assert_eq!(qenum.variants[1].value, i32::MAX);
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_out_of_range_discriminant() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A = 2147483648,
+            }
+        });
+        assert!(ParsedQEnum::from(&e, 0).is_err());
+
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A = -2147483649,
+            }
+        });
+        assert!(ParsedQEnum::from(&e, 0).is_err());
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_overflowing_discriminant() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A = 2147483647,
+                B,
+            }
+        });
+        assert!(ParsedQEnum::from(&e, 0).is_err());
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_invalid_discriminant() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A = 1 + 1,
+            }
+        });
+        assert!(ParsedQEnum::from(&e, 0).is_err());
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_fields() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            enum MyEnum {
+                A { x: f64 },
+            }
+        });
+        assert!(ParsedQEnum::from(&e, 0).is_err());
+    }
+
+    #[test]
+    fn test_parsed_qenum_from_repr() {
+        let e: ItemEnum = tokens_to_syn(quote! {
+            #[cxx_qt::qenum(MyObject)]
+            #[repr(u8)]
+            enum MyEnum {
+                A,
+            }
+        });
+        assert!(ParsedQEnum::from(&e, 0).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::{spanned::Spanned, Error, Fields, Ident, ItemStruct, Result, Type};
+
+/// Describes a field of a QGadget which is exposed as a Q_PROPERTY
+#[derive(Debug)]
+pub struct ParsedQGadgetField {
+    /// The name of the field
+    pub ident: Ident,
+    /// The type of the field
+    pub ty: Type,
+}
+
+/// Describes a struct which is a Q_GADGET value type
+#[derive(Debug)]
+pub struct ParsedQGadget {
+    /// The name of the struct
+    pub ident: Ident,
+    /// The original struct with the CXX-Qt attribute removed
+    pub item: ItemStruct,
+    /// A list of the fields defined in the struct
+    pub fields: Vec<ParsedQGadgetField>,
+}
+
+impl ParsedQGadget {
+    /// Constructs a ParsedQGadget object from a given [syn::ItemStruct] block
+    pub fn from(item: &ItemStruct, attr_index: usize) -> Result<Self> {
+        // Remove the attribute index as we have processed it
+        let mut item = item.clone();
+        item.attrs.remove(attr_index);
+
+        // The struct becomes a shared struct in CXX, so generics are not supported
+        if !item.generics.params.is_empty() {
+            return Err(Error::new(
+                item.generics.span(),
+                "Generics are not supported in a qgadget.",
+            ));
+        }
+
+        // Only named fields can be exposed as properties
+        let fields = match &item.fields {
+            Fields::Named(fields_named) if !fields_named.named.is_empty() => fields_named
+                .named
+                .iter()
+                .map(|field| ParsedQGadgetField {
+                    // We can assume that named fields have an ident
+                    ident: field.ident.clone().unwrap(),
+                    ty: field.ty.clone(),
+                })
+                .collect(),
+            _others => {
+                return Err(Error::new(
+                    item.span(),
+                    "A qgadget must have at least one named field.",
+                ))
+            }
+        };
+
+        Ok(Self {
+            ident: item.ident.clone(),
+            item,
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::tokens_to_syn;
+    use quote::quote;
+
+    #[test]
+    fn test_parsed_qgadget_from() {
+        let s: ItemStruct = tokens_to_syn(quote! {
+            #[cxx_qt::qgadget]
+            #[derive(Default)]
+            struct MyGadget {
+                number: i32,
+                value: f64,
+            }
+        });
+        let qgadget = ParsedQGadget::from(&s, 0).unwrap();
+        assert_eq!(qgadget.ident, "MyGadget");
+        assert_eq!(qgadget.item.attrs.len(), 1);
+        assert_eq!(qgadget.fields.len(), 2);
+        assert_eq!(qgadget.fields[0].ident, "number");
+        assert_eq!(qgadget.fields[1].ident, "value");
+    }
+
+    #[test]
+    fn test_parsed_qgadget_from_unit() {
+        let s: ItemStruct = tokens_to_syn(quote! {
+            #[cxx_qt::qgadget]
+            struct MyGadget;
+        });
+        assert!(ParsedQGadget::from(&s, 0).is_err());
+    }
+
+    #[test]
+    fn test_parsed_qgadget_from_tuple() {
+        let s: ItemStruct = tokens_to_syn(quote! {
+            #[cxx_qt::qgadget]
+            struct MyGadget(i32);
+        });
+        assert!(ParsedQGadget::from(&s, 0).is_err());
+    }
+
+    #[test]
+    fn test_parsed_qgadget_from_generics() {
+        let s: ItemStruct = tokens_to_syn(quote! {
+            #[cxx_qt::qgadget]
+            struct MyGadget<T> {
+                value: T,
+            }
+        });
+        assert!(ParsedQGadget::from(&s, 0).is_err());
+    }
+}
@@ -3,12 +3,51 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::parser::{property::ParsedQProperty, signals::ParsedSignalsEnum};
+use crate::parser::{
+    cpp_method::ParsedCppMethod,
+    invokable::ParsedQInvokable,
+    property::{ParsedComputedQProperty, ParsedQProperty, ParsedQPropertyOptions},
+    qenum::ParsedQEnum,
+    signals::ParsedSignalsEnum,
+};
 use crate::syntax::{attribute::attribute_find_path, fields::fields_to_named_fields_mut};
 use syn::{
-    spanned::Spanned, Error, Fields, ImplItem, ImplItemMethod, Item, ItemImpl, ItemStruct, Result,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Comma,
+    Attribute, Error, Fields, Ident, ImplItem, ImplItemMethod, Item, ItemImpl, ItemStruct, LitStr,
+    Result, Token,
 };
 
+/// Describes how a QObject is registered as a QML element
+#[derive(Clone, Debug, PartialEq)]
+pub enum QmlElementKind {
+    /// The element can be created from QML (qmlRegisterType)
+    Creatable,
+    /// A single instance of the element is created by the engine (qmlRegisterSingletonType)
+    Singleton,
+    /// The element cannot be created from QML, with the given reason (qmlRegisterUncreatableType)
+    Uncreatable(String),
+}
+
+/// Describes the QML element that a QObject is registered as,
+/// eg #[cxx_qt::qobject(qml_uri = "com.kdab", qml_version = "1.0", qml_name = "Foo")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QmlMetadata {
+    /// The import uri of the QML module, eg com.kdab
+    pub uri: String,
+    /// The major version of the QML module
+    pub version_major: u32,
+    /// The minor version of the QML module
+    pub version_minor: u32,
+    /// The name of the element in QML, which defaults to the name of the QObject
+    pub name: String,
+    /// Whether the element is creatable, a singleton, or uncreatable
+    pub kind: QmlElementKind,
+}
+
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
 ///
 /// This has initial splitting of [syn::Item]'s into relevant blocks, other phases will
@@ -21,12 +60,22 @@ pub struct ParsedQObject {
     pub data_struct: Option<ItemStruct>,
     /// QObject struct that stores the invokables for the QObject
     pub qobject_struct: Option<ItemStruct>,
+    /// The C++ base class of the QObject, eg QAbstractListModel
+    ///
+    /// If this is None then QObject is used
+    pub base_class: Option<String>,
+    /// The QML element that the QObject is registered as
+    ///
+    /// If this is None then the QObject is not registered by the generated registration function
+    pub qml_metadata: Option<QmlMetadata>,
     /// Representation of the Signals enum that defines the Q_SIGNALS for the QObject
     pub signals: Option<ParsedSignalsEnum>,
+    /// List of enums that are registered with Q_ENUM on the QObject
+    pub qenums: Vec<ParsedQEnum>,
     /// List of invokables that need to be implemented on the C++ object in Rust
     ///
     /// These will also be exposed as Q_INVOKABLE on the C++ object
-    pub invokables: Vec<ImplItemMethod>,
+    pub invokables: Vec<ParsedQInvokable>,
     /// List of methods that need to be implemented on the C++ object in Rust
     ///
     /// Note that they will only be visible on the Rust side
@@ -35,6 +84,15 @@ pub struct ParsedQObject {
     ///
     /// These will be exposed as Q_PROPERTY on the C++ object
     pub properties: Vec<ParsedQProperty>,
+    /// List of properties that are computed by methods that are implemented on the C++ object in Rust
+    ///
+    /// These will be exposed as Q_PROPERTY on the C++ object
+    pub computed_properties: Vec<ParsedComputedQProperty>,
+    /// List of methods of the C++ class, or its base class, that are declared
+    /// in a cxx_qt::cpp_methods(T) extern block
+    ///
+    /// These can be called from Rust using the CppObj
+    pub cpp_methods: Vec<ParsedCppMethod>,
     /// Update request handler for the QObject
     ///
     /// In the future this may be removed
@@ -44,6 +102,183 @@ pub struct ParsedQObject {
     pub others: Vec<Item>,
 }
 
+/// Representation of a single option in a #[cxx_qt::qobject] attribute,
+/// eg base = "QAbstractListModel" or qml_singleton
+struct QObjectOption {
+    key: Ident,
+    value: Option<LitStr>,
+}
+
+impl Parse for QObjectOption {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.parse::<Ident>()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<LitStr>()?)
+        } else {
+            None
+        };
+        Ok(QObjectOption { key, value })
+    }
+}
+
+/// Representation of the list of options in a #[cxx_qt::qobject] attribute
+struct QObjectOptionList {
+    items: Punctuated<QObjectOption, Comma>,
+}
+
+impl Parse for QObjectOptionList {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let items = content.parse_terminated(QObjectOption::parse)?;
+        Ok(QObjectOptionList { items })
+    }
+}
+
+/// Describes the options of a #[cxx_qt::qobject] attribute,
+/// eg #[cxx_qt::qobject(base = "QAbstractListModel", qml_uri = "com.kdab", qml_singleton)]
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedQObjectOptions {
+    /// The C++ base class of the QObject
+    pub base_class: Option<String>,
+    /// The QML element that the QObject is registered as
+    pub qml_metadata: Option<QmlMetadata>,
+}
+
+impl ParsedQObjectOptions {
+    /// Parse the options from a #[cxx_qt::qobject] attribute, a bare #[cxx_qt::qobject] has no options
+    ///
+    /// The ident of the QObject is used as the QML name when there is no qml_name option.
+    pub fn parse(attr: &Attribute, qobject_ident: &Ident) -> Result<Self> {
+        let mut options = Self::default();
+        if attr.tokens.is_empty() {
+            return Ok(options);
+        }
+
+        let mut uri = None;
+        let mut version = None;
+        let mut name = None;
+        let mut singleton = false;
+        let mut uncreatable = None;
+
+        let list: QObjectOptionList = syn::parse2(attr.tokens.clone())?;
+        for option in list.items {
+            let duplicate = match (option.key.to_string().as_str(), option.value) {
+                ("base", Some(value)) => options.base_class.replace(value.value()).is_some(),
+                ("qml_uri", Some(value)) => uri.replace(value).is_some(),
+                ("qml_version", Some(value)) => version.replace(value).is_some(),
+                ("qml_name", Some(value)) => name.replace(value).is_some(),
+                ("qml_singleton", None) => std::mem::replace(&mut singleton, true),
+                ("qml_uncreatable", value) => uncreatable.replace(value).is_some(),
+                _others => {
+                    return Err(Error::new(
+                        option.key.span(),
+                        "Unknown option, expected one of base = \"class\", qml_uri = \"uri\", qml_version = \"major.minor\", qml_name = \"name\", qml_singleton, or qml_uncreatable.",
+                    ))
+                }
+            };
+
+            if duplicate {
+                return Err(Error::new(
+                    attr.span(),
+                    "Duplicate option in cxx_qt::qobject.",
+                ));
+            }
+        }
+
+        // Without a uri the QObject is not registered, so the other QML options are invalid
+        let uri = if let Some(uri) = uri {
+            uri
+        } else {
+            if version.is_some() || name.is_some() || singleton || uncreatable.is_some() {
+                return Err(Error::new(
+                    attr.span(),
+                    "A qml_uri is required to register the QObject as a QML element.",
+                ));
+            }
+            return Ok(options);
+        };
+
+        // The uri must be dotted identifiers as it is used for the import statement
+        if uri.value().is_empty()
+            || !uri.value().split('.').all(|part| {
+                part.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+        {
+            return Err(Error::new(
+                uri.span(),
+                "The qml_uri must be identifiers separated by dots, eg com.kdab.",
+            ));
+        }
+
+        // The version is in the form major.minor and defaults to 1.0
+        let (version_major, version_minor) = if let Some(version) = version {
+            let parts = version
+                .value()
+                .split('.')
+                .map(|part| part.parse::<u32>())
+                .collect::<std::result::Result<Vec<u32>, _>>();
+            match parts.as_deref() {
+                Ok([major, minor]) => (*major, *minor),
+                _others => {
+                    return Err(Error::new(
+                        version.span(),
+                        "The qml_version must be in the form major.minor, eg 1.0.",
+                    ))
+                }
+            }
+        } else {
+            (1, 0)
+        };
+
+        // QML element names must start with an upper case letter
+        let name = if let Some(name) = name {
+            if !name.value().starts_with(|c: char| c.is_ascii_uppercase())
+                || !name
+                    .value()
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                return Err(Error::new(
+                    name.span(),
+                    "The qml_name must be an identifier which starts with an upper case letter.",
+                ));
+            }
+            name.value()
+        } else {
+            qobject_ident.to_string()
+        };
+
+        let kind = match (singleton, uncreatable) {
+            (false, None) => QmlElementKind::Creatable,
+            (true, None) => QmlElementKind::Singleton,
+            (false, Some(reason)) => QmlElementKind::Uncreatable(
+                reason
+                    .map(|reason| reason.value())
+                    .unwrap_or_else(|| format!("{} cannot be created from QML", name)),
+            ),
+            (true, Some(_)) => {
+                return Err(Error::new(
+                    attr.span(),
+                    "A QML element cannot be both qml_singleton and qml_uncreatable.",
+                ))
+            }
+        };
+
+        options.qml_metadata = Some(QmlMetadata {
+            uri: uri.value(),
+            version_major,
+            version_minor,
+            name,
+            kind,
+        });
+
+        Ok(options)
+    }
+}
+
 impl ParsedQObject {
     /// Extract all methods (both invokable and non-invokable) from [syn::ImplItem]'s from each Impl block
     ///
@@ -54,10 +289,20 @@ impl ParsedQObject {
             if let ImplItem::Method(method) = item {
                 // Determine if this method is an invokable
                 if let Some(index) = attribute_find_path(&method.attrs, &["qinvokable"]) {
-                    // Remove the invokable attribute
-                    let mut invokable = method.clone();
-                    invokable.attrs.remove(index);
-                    self.invokables.push(invokable);
+                    // Remove the invokable attribute and parse any specifiers from it
+                    let mut method = method.clone();
+                    let attr = method.attrs.remove(index);
+                    self.invokables
+                        .push(ParsedQInvokable::parse(method, &attr)?);
+                // Determine if this method is the getter of a computed property
+                } else if let Some(index) = attribute_find_path(&method.attrs, &["qproperty"]) {
+                    // Remove the qproperty attribute and parse any options from it
+                    let mut method = method.clone();
+                    let attr = method.attrs.remove(index);
+                    self.computed_properties.push(ParsedComputedQProperty {
+                        method,
+                        options: ParsedQPropertyOptions::parse(&attr)?,
+                    });
                 } else {
                     self.methods.push(method.clone());
                 }
@@ -79,8 +324,6 @@ impl ParsedQObject {
 
                 self.properties.push(ParsedQProperty {
                     ident: field.ident.clone().unwrap(),
-                    ty: field.ty.clone(),
-                    vis: field.vis.clone(),
                 });
             }
         }
@@ -93,10 +336,92 @@ impl ParsedQObject {
 mod tests {
     use super::*;
 
-    use crate::parser::tests::f64_type;
     use crate::tests::tokens_to_syn;
     use quote::quote;
-    use syn::{ItemImpl, Visibility};
+    use syn::ItemImpl;
+
+    /// Helper which returns the #[cxx_qt::qobject] attribute of a struct and its ident
+    fn qobject_attr(s: ItemStruct) -> (Attribute, Ident) {
+        (s.attrs[0].clone(), s.ident)
+    }
+
+    #[test]
+    fn test_parse_options_no_options() {
+        let (attr, ident) = qobject_attr(tokens_to_syn(quote! {
+            #[cxx_qt::qobject]
+            struct MyObject;
+        }));
+        let options = ParsedQObjectOptions::parse(&attr, &ident).unwrap();
+        assert_eq!(options, ParsedQObjectOptions::default());
+    }
+
+    #[test]
+    fn test_parse_options_qml() {
+        let (attr, ident) = qobject_attr(tokens_to_syn(quote! {
+            #[cxx_qt::qobject(base = "QAbstractListModel", qml_uri = "com.kdab", qml_version = "2.3")]
+            struct MyObject;
+        }));
+        let options = ParsedQObjectOptions::parse(&attr, &ident).unwrap();
+        assert_eq!(options.base_class.as_deref(), Some("QAbstractListModel"));
+        assert_eq!(
+            options.qml_metadata.unwrap(),
+            QmlMetadata {
+                uri: "com.kdab".to_owned(),
+                version_major: 2,
+                version_minor: 3,
+                name: "MyObject".to_owned(),
+                kind: QmlElementKind::Creatable,
+            }
+        );
+
+        let (attr, ident) = qobject_attr(tokens_to_syn(quote! {
+            #[cxx_qt::qobject(qml_uri = "com.kdab", qml_name = "Foo", qml_singleton)]
+            struct MyObject;
+        }));
+        let metadata = ParsedQObjectOptions::parse(&attr, &ident)
+            .unwrap()
+            .qml_metadata
+            .unwrap();
+        assert_eq!(metadata.version_major, 1);
+        assert_eq!(metadata.version_minor, 0);
+        assert_eq!(metadata.name, "Foo");
+        assert_eq!(metadata.kind, QmlElementKind::Singleton);
+
+        let (attr, ident) = qobject_attr(tokens_to_syn(quote! {
+            #[cxx_qt::qobject(qml_uri = "com.kdab", qml_uncreatable)]
+            struct MyObject;
+        }));
+        let metadata = ParsedQObjectOptions::parse(&attr, &ident)
+            .unwrap()
+            .qml_metadata
+            .unwrap();
+        assert_eq!(
+            metadata.kind,
+            QmlElementKind::Uncreatable("MyObject cannot be created from QML".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_parse_options_invalid() {
+        let invalid = [
+            quote! { #[cxx_qt::qobject(unknown)] struct MyObject; },
+            quote! { #[cxx_qt::qobject(base)] struct MyObject; },
+            quote! { #[cxx_qt::qobject(qml_singleton = "true")] struct MyObject; },
+            quote! { #[cxx_qt::qobject(qml_uri = "a", qml_uri = "b")] struct MyObject; },
+            quote! { #[cxx_qt::qobject(qml_name = "Foo")] struct MyObject; },
+            quote! { #[cxx_qt::qobject(qml_uri = "com..kdab")] struct MyObject; },
+            quote! { #[cxx_qt::qobject(qml_uri = "com.kdab", qml_version = "1")] struct MyObject; },
+            quote! { #[cxx_qt::qobject(qml_uri = "com.kdab", qml_name = "foo")] struct MyObject; },
+            quote! {
+                #[cxx_qt::qobject(qml_uri = "com.kdab", qml_singleton, qml_uncreatable)]
+                struct MyObject;
+            },
+        ];
+        for tokens in invalid {
+            let (attr, ident) = qobject_attr(tokens_to_syn(tokens));
+            assert!(ParsedQObjectOptions::parse(&attr, &ident).is_err());
+        }
+    }
 
     #[test]
     fn test_parse_impl_items_valid() {
@@ -106,12 +431,27 @@ mod tests {
                 #[qinvokable]
                 fn invokable() {}
 
+                #[qproperty(write = set_computed)]
+                fn computed() -> i32 {}
+
+                fn set_computed(value: i32) {}
+
                 fn cpp_context() {}
             }
         });
         assert!(qobject.parse_impl_items(&item.items).is_ok());
         assert_eq!(qobject.invokables.len(), 1);
-        assert_eq!(qobject.methods.len(), 1);
+        assert_eq!(qobject.computed_properties.len(), 1);
+        assert!(qobject.computed_properties[0].method.attrs.is_empty());
+        assert_eq!(
+            qobject.computed_properties[0]
+                .options
+                .write
+                .as_ref()
+                .unwrap(),
+            "set_computed"
+        );
+        assert_eq!(qobject.methods.len(), 2);
     }
 
     #[test]
@@ -151,11 +491,7 @@ mod tests {
         assert!(qobject.parse_struct_fields(&mut item.fields).is_ok());
         assert_eq!(qobject.properties.len(), 2);
         assert_eq!(qobject.properties[0].ident, "f64_property");
-        assert_eq!(qobject.properties[0].ty, f64_type());
-        assert!(matches!(qobject.properties[0].vis, Visibility::Inherited));
         assert_eq!(qobject.properties[1].ident, "public_property");
-        assert_eq!(qobject.properties[1].ty, f64_type());
-        assert!(matches!(qobject.properties[1].vis, Visibility::Public(_)));
     }
 
     #[test]
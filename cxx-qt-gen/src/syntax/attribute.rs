@@ -91,7 +91,7 @@ pub fn attribute_tokens_to_ident(attr: &Attribute) -> Result<Ident> {
 /// and errors if there is a parser error
 pub fn attribute_tokens_to_list(attr: &Attribute) -> Result<Vec<Ident>> {
     let attrs: AttributeList = syn::parse2(attr.tokens.clone())?;
-    Ok(Vec::from_iter(attrs.items.into_iter()))
+    Ok(Vec::from_iter(attrs.items))
 }
 
 /// Returns a map of keys and values from an attribute, eg attribute(a = b, c = d)
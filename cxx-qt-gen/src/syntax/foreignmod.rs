@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Item, ItemForeignMod, Result, Token};
+
+/// An extern block which can be marked as unsafe, eg unsafe extern "C++" { ... }
+///
+/// syn 1 parses an unsafe extern block as an [syn::Item::Verbatim],
+/// so this is used to read the block from the tokens
+pub struct ForeignModMaybeUnsafe {
+    /// The unsafe token if the block is marked as unsafe
+    pub unsafety: Option<Token![unsafe]>,
+    /// The extern block including any attributes
+    pub foreign_mod: ItemForeignMod,
+}
+
+impl Parse for ForeignModMaybeUnsafe {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attrs = input.call(Attribute::parse_outer)?;
+        let unsafety = input.parse()?;
+        let mut foreign_mod: ItemForeignMod = input.parse()?;
+        attrs.append(&mut foreign_mod.attrs);
+        foreign_mod.attrs = attrs;
+
+        Ok(Self {
+            unsafety,
+            foreign_mod,
+        })
+    }
+}
+
+/// Try to read an extern block from an [syn::Item], returning None if the item is not an extern block
+pub fn item_to_foreign_mod(item: &Item) -> Option<ForeignModMaybeUnsafe> {
+    match item {
+        Item::ForeignMod(foreign_mod) => Some(ForeignModMaybeUnsafe {
+            unsafety: None,
+            foreign_mod: foreign_mod.clone(),
+        }),
+        Item::Verbatim(tokens) => syn::parse2(tokens.clone()).ok(),
+        _others => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::tokens_to_syn;
+    use quote::quote;
+
+    #[test]
+    fn test_item_to_foreign_mod() {
+        let item: Item = tokens_to_syn(quote! {
+            #[namespace = ""]
+            unsafe extern "C++" {
+                fn method(&self);
+            }
+        });
+        let parsed = item_to_foreign_mod(&item).unwrap();
+        assert!(parsed.unsafety.is_some());
+        assert_eq!(parsed.foreign_mod.attrs.len(), 1);
+        assert_eq!(parsed.foreign_mod.items.len(), 1);
+
+        let item: Item = tokens_to_syn(quote! {
+            extern "C++" {
+                unsafe fn method(&self);
+            }
+        });
+        let parsed = item_to_foreign_mod(&item).unwrap();
+        assert!(parsed.unsafety.is_none());
+        assert_eq!(parsed.foreign_mod.items.len(), 1);
+
+        let item: Item = tokens_to_syn(quote! {
+            struct MyStruct;
+        });
+        assert!(item_to_foreign_mod(&item).is_none());
+    }
+}
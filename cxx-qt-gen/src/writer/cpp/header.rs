@@ -3,7 +3,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::cpp::{fragment::CppFragmentPair, GeneratedCppBlocks};
+use crate::generator::cpp::{
+    fragment::CppFragmentPair, GeneratedCppBlocks, GeneratedCppQGadgetBlocks,
+    GeneratedCppQObjectBlocks,
+};
+use crate::writer::cpp::namespace_pair;
 use indoc::formatdoc;
 
 /// Extract the header from a given CppFragmentPair
@@ -33,23 +37,85 @@ fn create_block(block: &str, items: &[&str]) -> String {
     }
 }
 
-/// For a given GeneratedCppBlocks write this into a C++ header
-pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
+/// For a given GeneratedCppQObjectBlocks write the forward declaration of the class
+fn forward_declare(qobject: &GeneratedCppQObjectBlocks) -> String {
+    let (namespace_start, namespace_end) = namespace_pair(&qobject.namespace);
+
     formatdoc! {r#"
-        #pragma once
+        {namespace_start}
+        class {ident};
+        using {ident}CxxQtThread = rust::cxxqtlib1::CxxQtThread<{ident}>;
+        {forward_declares}{namespace_end}"#,
+    ident = qobject.ident,
+    forward_declares = qobject.forward_declares.iter().map(|item| format!("{}\n", item)).collect::<String>(),
+    namespace_start = namespace_start,
+    namespace_end = namespace_end,
+    }
+}
 
-        #include <memory>
-        #include <mutex>
+/// For a given GeneratedCppQGadgetBlocks write the struct definition
+///
+/// This is written before the CXX header so that CXX uses this definition for the shared struct
+fn qgadget_header(qgadget: &GeneratedCppQGadgetBlocks) -> String {
+    let (namespace_start, namespace_end) = namespace_pair(&qgadget.namespace);
 
+    formatdoc! {r#"
         {namespace_start}
-        class {ident};
+        struct {ident}
+        {{
+          Q_GADGET
+          {metaobjects}
+
+        public:
+          {members}
+
+          {methods}
+        }};
         {namespace_end}
 
-        #include "cxx-qt-gen/include/{cxx_stem}.cxx.h"
+        Q_DECLARE_METATYPE({metatype})
+        "#,
+    ident = qgadget.ident,
+    namespace_start = namespace_start,
+    namespace_end = namespace_end,
+    metaobjects = qgadget.metaobjects.join("\n  "),
+    members = qgadget.members.join("\n  "),
+    methods = qgadget.methods.join("\n  "),
+    metatype = if qgadget.namespace.is_empty() {
+        qgadget.ident.clone()
+    } else {
+        format!("{namespace}::{ident}", namespace = qgadget.namespace, ident = qgadget.ident)
+    },
+    }
+}
 
+/// For a given GeneratedCppBlocks write the qenums which are registered on the namespace
+///
+/// These are written before the CXX header so that CXX uses these definitions for the shared enums
+fn qenums_header(generated: &GeneratedCppBlocks) -> String {
+    let (namespace_start, namespace_end) = namespace_pair(&generated.namespace);
+
+    formatdoc! {r#"
         {namespace_start}
+        Q_NAMESPACE
 
-        class {ident} : public QObject
+        {qenums}
+        {namespace_end}
+        "#,
+    namespace_start = namespace_start,
+    namespace_end = namespace_end,
+    qenums = generated.qenums.join("\n\n"),
+    }
+}
+
+/// For a given GeneratedCppQObjectBlocks write the class definition and internal declarations
+fn qobject_header(qobject: &GeneratedCppQObjectBlocks) -> String {
+    let (namespace_start, namespace_end) = namespace_pair(&qobject.namespace);
+
+    formatdoc! {r#"
+        {namespace_start}
+
+        class {ident} : public {base_class}
         {{
           Q_OBJECT
           {metaobjects}
@@ -59,14 +125,16 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
           ~{ident}();
           const {rust_ident}& unsafeRust() const;
           {rust_ident}& unsafeRustMut();
+          std::unique_ptr<{ident}CxxQtThread> qtThread() const;
 
         {methods}
         {slots}
         {signals}
         private:
           rust::Box<{rust_ident}> m_rustObj;
-          std::mutex m_rustObjMutex;
+          mutable std::recursive_mutex m_rustObjMutex;
           bool m_initialised = false;
+          std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<{ident}>> m_cxxQtThreadObj;
 
           {members}
         }};
@@ -78,32 +146,61 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
         newCppObject();
         }} // namespace {namespace_internals}
 
-        Q_DECLARE_METATYPE({metatype}*)
-    "#,
-    cxx_stem = generated.cxx_stem,
-    ident = generated.ident,
-    namespace_start = if generated.namespace.is_empty() {
-        "".to_owned()
+        Q_DECLARE_METATYPE({metatype}*)"#,
+    ident = qobject.ident,
+    base_class = qobject.base_class,
+    namespace_start = namespace_start,
+    namespace_end = namespace_end,
+    namespace_internals = qobject.namespace_internals,
+    rust_ident = qobject.rust_ident,
+    metaobjects = qobject.metaobjects.join("\n  "),
+    methods = create_block("public", &qobject.methods.iter().map(pair_as_header).collect::<Vec<&str>>()),
+    slots = create_block("public Q_SLOTS", &qobject.slots.iter().map(pair_as_header).collect::<Vec<&str>>()),
+    signals = create_block("Q_SIGNALS", &qobject.signals.iter().map(AsRef::as_ref).collect::<Vec<&str>>()),
+    members = qobject.members.join("\n  "),
+    metatype = if qobject.namespace.is_empty() {
+        qobject.ident.clone()
     } else {
-        format!("namespace {namespace} {{", namespace = generated.namespace)
+        format!("{namespace}::{ident}", namespace = qobject.namespace, ident = qobject.ident)
     },
-    namespace_end = if generated.namespace.is_empty() {
+    }
+}
+
+/// For a given GeneratedCppBlocks write this into a C++ header
+pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
+    formatdoc! {r#"
+        #pragma once
+
+        #include <cstdint>
+        #include <memory>
+        #include <mutex>
+
+        #include "cxx-qt-lib/include/cxxqt_thread.h"
+
+        {forward_declares}
+
+        {qgadgets}#include "cxx-qt-gen/include/{cxx_stem}.cxx.h"
+
+        {qobjects}
+    "#,
+    cxx_stem = generated.cxx_stem,
+    forward_declares = generated.qobjects.iter().map(forward_declare).collect::<Vec<String>>().join("\n"),
+    // Q_GADGET and Q_NAMESPACE require the Qt meta object system to be available before the CXX header,
+    // the qenums are first as they can be used as the fields of a Q_GADGET
+    qgadgets = if generated.qgadgets.is_empty() && generated.qenums.is_empty() {
         "".to_owned()
     } else {
-        format!("}} // namespace {namespace}", namespace = generated.namespace)
-    },
-    namespace_internals = generated.namespace_internals,
-    rust_ident = generated.rust_ident,
-    metaobjects = generated.metaobjects.join("\n  "),
-    methods = create_block("public", &generated.methods.iter().map(pair_as_header).collect::<Vec<&str>>()),
-    slots = create_block("public Q_SLOTS", &generated.slots.iter().map(pair_as_header).collect::<Vec<&str>>()),
-    signals = create_block("Q_SIGNALS", &generated.signals.iter().map(AsRef::as_ref).collect::<Vec<&str>>()),
-    members = generated.members.join("\n  "),
-    metatype = if generated.namespace.is_empty() {
-        generated.ident.clone()
-    } else {
-        format!("{namespace}::{ident}", namespace = generated.namespace, ident = generated.ident)
+        format!(
+            "#include <QtCore/QObject>\n\n{}\n",
+            (!generated.qenums.is_empty())
+                .then(|| qenums_header(generated))
+                .into_iter()
+                .chain(generated.qgadgets.iter().map(qgadget_header))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
     },
+    qobjects = generated.qobjects.iter().map(qobject_header).collect::<Vec<String>>().join("\n\n"),
     }
 }
 
@@ -112,7 +209,8 @@ mod tests {
     use super::*;
 
     use crate::writer::cpp::tests::{
-        create_generated_cpp, create_generated_cpp_no_namespace, expected_header,
+        create_generated_cpp, create_generated_cpp_multi_qobjects,
+        create_generated_cpp_no_namespace, expected_header, expected_header_multi_qobjects,
         expected_header_no_namespace,
     };
     use indoc::indoc;
@@ -140,6 +238,54 @@ mod tests {
         assert_str_eq!(block, expected);
     }
 
+    #[test]
+    fn test_qgadget_header() {
+        let qgadget = GeneratedCppQGadgetBlocks {
+            ident: "MyGadget".to_owned(),
+            namespace: "cxx_qt".to_owned(),
+            metaobjects: vec!["Q_PROPERTY(qint32 number MEMBER number)".to_owned()],
+            members: vec!["qint32 number;".to_owned()],
+            methods: vec!["bool isValid() const;".to_owned()],
+        };
+        let expected = indoc! {"
+        namespace cxx_qt {
+        struct MyGadget
+        {
+          Q_GADGET
+          Q_PROPERTY(qint32 number MEMBER number)
+
+        public:
+          qint32 number;
+
+          bool isValid() const;
+        };
+        } // namespace cxx_qt
+
+        Q_DECLARE_METATYPE(cxx_qt::MyGadget)
+        "};
+        assert_str_eq!(qgadget_header(&qgadget), expected);
+    }
+
+    #[test]
+    fn test_qenums_header() {
+        let mut generated = create_generated_cpp();
+        generated.qenums = vec![
+            "enum class State : ::std::int32_t\n{\n  Idle = 0,\n};\nQ_ENUM_NS(State)".to_owned(),
+        ];
+        let expected = indoc! {"
+        namespace cxx_qt::my_object {
+        Q_NAMESPACE
+
+        enum class State : ::std::int32_t
+        {
+          Idle = 0,
+        };
+        Q_ENUM_NS(State)
+        } // namespace cxx_qt::my_object
+        "};
+        assert_str_eq!(qenums_header(&generated), expected);
+    }
+
     #[test]
     fn test_write_cpp_header() {
         let generated = create_generated_cpp();
@@ -153,4 +299,11 @@ mod tests {
         let output = write_cpp_header(&generated);
         assert_str_eq!(output, expected_header_no_namespace());
     }
+
+    #[test]
+    fn test_write_cpp_header_multi_qobjects() {
+        let generated = create_generated_cpp_multi_qobjects();
+        let output = write_cpp_header(&generated);
+        assert_str_eq!(output, expected_header_multi_qobjects());
+    }
 }
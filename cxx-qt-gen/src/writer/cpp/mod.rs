@@ -11,6 +11,18 @@ use clang_format::clang_format;
 use header::write_cpp_header;
 use source::write_cpp_source;
 
+/// Build the namespace start and end strings for a given namespace
+fn namespace_pair(namespace: &str) -> (String, String) {
+    if namespace.is_empty() {
+        ("".to_owned(), "".to_owned())
+    } else {
+        (
+            format!("namespace {namespace} {{", namespace = namespace),
+            format!("}} // namespace {namespace}", namespace = namespace),
+        )
+    }
+}
+
 /// For a given GeneratedCppBlocks write this into a C++ header and source pair
 pub fn write_cpp(generated: &GeneratedCppBlocks) -> CppFragmentPair {
     let header = write_cpp_header(generated);
@@ -26,6 +38,7 @@ pub fn write_cpp(generated: &GeneratedCppBlocks) -> CppFragmentPair {
 mod tests {
     use super::*;
 
+    use crate::generator::cpp::GeneratedCppQObjectBlocks;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
 
@@ -33,10 +46,22 @@ mod tests {
     pub fn create_generated_cpp() -> GeneratedCppBlocks {
         GeneratedCppBlocks {
             cxx_stem: "cxx_stem".to_owned(),
+            namespace: "cxx_qt::my_object".to_owned(),
+            qenums: vec![],
+            qgadgets: vec![],
+            qobjects: vec![create_generated_cpp_qobject()],
+        }
+    }
+
+    /// Helper to create a GeneratedCppQObjectBlocks for testing
+    fn create_generated_cpp_qobject() -> GeneratedCppQObjectBlocks {
+        GeneratedCppQObjectBlocks {
             ident: "MyObject".to_owned(),
+            base_class: "QObject".to_owned(),
             rust_ident: "MyObjectRust".to_owned(),
             namespace: "cxx_qt::my_object".to_owned(),
             namespace_internals: "cxx_qt::my_object::cxx_qt_my_object".to_owned(),
+            forward_declares: vec![],
             metaobjects: vec![
                 "Q_PROPERTY(int count READ count WRITE setCount NOTIFY countChanged)".to_owned(),
                 "Q_PROPERTY(bool longPropertyNameThatWrapsInClangFormat READ getToggle WRITE setToggle NOTIFY toggleChanged)"
@@ -132,20 +157,302 @@ mod tests {
     pub fn create_generated_cpp_no_namespace() -> GeneratedCppBlocks {
         let mut generated = create_generated_cpp();
         generated.namespace = "".to_owned();
-        generated.namespace_internals = "cxx_qt_my_object".to_owned();
+        generated.qobjects[0].namespace = "".to_owned();
+        generated.qobjects[0].namespace_internals = "cxx_qt_my_object".to_owned();
         generated
     }
 
+    /// Helper to create a GeneratedCppBlocks with multiple QObjects for testing
+    pub fn create_generated_cpp_multi_qobjects() -> GeneratedCppBlocks {
+        GeneratedCppBlocks {
+            cxx_stem: "cxx_stem".to_owned(),
+            namespace: "cxx_qt".to_owned(),
+            qenums: vec![],
+            qgadgets: vec![],
+            qobjects: vec![
+                GeneratedCppQObjectBlocks {
+                    ident: "FirstObject".to_owned(),
+                    base_class: "QObject".to_owned(),
+                    rust_ident: "FirstObjectRust".to_owned(),
+                    namespace: "cxx_qt".to_owned(),
+                    namespace_internals: "cxx_qt::cxx_qt_first_object".to_owned(),
+                    forward_declares: vec![],
+                    metaobjects: vec![
+                        "Q_PROPERTY(int count READ count WRITE setCount NOTIFY countChanged)"
+                            .to_owned(),
+                    ],
+                    methods: vec![CppFragmentPair {
+                        header: "int count() const;".to_owned(),
+                        source: indoc! {r#"
+                            int
+                            FirstObject::count() const
+                            {
+                              return m_count;
+                            }
+                        "#}
+                        .to_owned(),
+                    }],
+                    slots: vec![],
+                    signals: vec!["void countChanged();".to_owned()],
+                    members: vec!["int m_count;".to_owned()],
+                },
+                GeneratedCppQObjectBlocks {
+                    ident: "SecondObject".to_owned(),
+                    base_class: "QObject".to_owned(),
+                    rust_ident: "SecondObjectRust".to_owned(),
+                    namespace: "cxx_qt".to_owned(),
+                    namespace_internals: "cxx_qt::cxx_qt_second_object".to_owned(),
+                    forward_declares: vec![],
+                    metaobjects: vec![
+                        "Q_PROPERTY(bool toggle READ toggle WRITE setToggle NOTIFY toggleChanged)"
+                            .to_owned(),
+                    ],
+                    methods: vec![CppFragmentPair {
+                        header: "bool toggle() const;".to_owned(),
+                        source: indoc! {r#"
+                            bool
+                            SecondObject::toggle() const
+                            {
+                              return m_toggle;
+                            }
+                        "#}
+                        .to_owned(),
+                    }],
+                    slots: vec![],
+                    signals: vec!["void toggleChanged();".to_owned()],
+                    members: vec!["bool m_toggle;".to_owned()],
+                },
+            ],
+        }
+    }
+
+    /// Helper for the expected header with multiple QObjects
+    pub fn expected_header_multi_qobjects() -> &'static str {
+        indoc! {r#"
+        #pragma once
+
+        #include <cstdint>
+        #include <memory>
+        #include <mutex>
+
+        #include "cxx-qt-lib/include/cxxqt_thread.h"
+
+        namespace cxx_qt {
+        class FirstObject;
+        using FirstObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<FirstObject>;
+        } // namespace cxx_qt
+        namespace cxx_qt {
+        class SecondObject;
+        using SecondObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<SecondObject>;
+        } // namespace cxx_qt
+
+        #include "cxx-qt-gen/include/cxx_stem.cxx.h"
+
+        namespace cxx_qt {
+
+        class FirstObject : public QObject
+        {
+          Q_OBJECT
+          Q_PROPERTY(int count READ count WRITE setCount NOTIFY countChanged)
+
+        public:
+          explicit FirstObject(QObject* parent = nullptr);
+          ~FirstObject();
+          const FirstObjectRust& unsafeRust() const;
+          FirstObjectRust& unsafeRustMut();
+          std::unique_ptr<FirstObjectCxxQtThread> qtThread() const;
+
+        public:
+          int count() const;
+
+
+        Q_SIGNALS:
+          void countChanged();
+
+        private:
+          rust::Box<FirstObjectRust> m_rustObj;
+          mutable std::recursive_mutex m_rustObjMutex;
+          bool m_initialised = false;
+          std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<FirstObject>> m_cxxQtThreadObj;
+
+          int m_count;
+        };
+
+        } // namespace cxx_qt
+
+        namespace cxx_qt::cxx_qt_first_object {
+        std::unique_ptr<FirstObject>
+        newCppObject();
+        } // namespace cxx_qt::cxx_qt_first_object
+
+        Q_DECLARE_METATYPE(cxx_qt::FirstObject*)
+
+        namespace cxx_qt {
+
+        class SecondObject : public QObject
+        {
+          Q_OBJECT
+          Q_PROPERTY(bool toggle READ toggle WRITE setToggle NOTIFY toggleChanged)
+
+        public:
+          explicit SecondObject(QObject* parent = nullptr);
+          ~SecondObject();
+          const SecondObjectRust& unsafeRust() const;
+          SecondObjectRust& unsafeRustMut();
+          std::unique_ptr<SecondObjectCxxQtThread> qtThread() const;
+
+        public:
+          bool toggle() const;
+
+
+        Q_SIGNALS:
+          void toggleChanged();
+
+        private:
+          rust::Box<SecondObjectRust> m_rustObj;
+          mutable std::recursive_mutex m_rustObjMutex;
+          bool m_initialised = false;
+          std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<SecondObject>> m_cxxQtThreadObj;
+
+          bool m_toggle;
+        };
+
+        } // namespace cxx_qt
+
+        namespace cxx_qt::cxx_qt_second_object {
+        std::unique_ptr<SecondObject>
+        newCppObject();
+        } // namespace cxx_qt::cxx_qt_second_object
+
+        Q_DECLARE_METATYPE(cxx_qt::SecondObject*)
+        "#}
+    }
+
+    /// Helper for the expected source with multiple QObjects
+    pub fn expected_source_multi_qobjects() -> &'static str {
+        indoc! {r#"
+        #include "cxx-qt-gen/include/cxx_stem.cxxqt.h"
+
+        namespace cxx_qt {
+
+        FirstObject::FirstObject(QObject* parent)
+          : QObject(parent)
+          , m_rustObj(cxx_qt::cxx_qt_first_object::createRs())
+          , m_cxxQtThreadObj(std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<FirstObject>>(this))
+        {
+          cxx_qt::cxx_qt_first_object::initialiseCpp(*this);
+          m_initialised = true;
+        }
+
+        FirstObject::~FirstObject()
+        {
+          const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+          m_cxxQtThreadObj->ptr = nullptr;
+        }
+
+        const FirstObjectRust&
+        FirstObject::unsafeRust() const
+        {
+          return *m_rustObj;
+        }
+
+        FirstObjectRust&
+        FirstObject::unsafeRustMut()
+        {
+          return *m_rustObj;
+        }
+
+        std::unique_ptr<FirstObjectCxxQtThread>
+        FirstObject::qtThread() const
+        {
+          return std::make_unique<FirstObjectCxxQtThread>(m_cxxQtThreadObj, &m_rustObjMutex);
+        }
+
+        int
+        FirstObject::count() const
+        {
+          return m_count;
+        }
+
+
+        } // namespace cxx_qt
+
+        namespace cxx_qt::cxx_qt_first_object {
+        std::unique_ptr<FirstObject>
+        newCppObject()
+        {
+          return std::make_unique<FirstObject>();
+        }
+        } // namespace cxx_qt::cxx_qt_first_object
+
+        namespace cxx_qt {
+
+        SecondObject::SecondObject(QObject* parent)
+          : QObject(parent)
+          , m_rustObj(cxx_qt::cxx_qt_second_object::createRs())
+          , m_cxxQtThreadObj(std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<SecondObject>>(this))
+        {
+          cxx_qt::cxx_qt_second_object::initialiseCpp(*this);
+          m_initialised = true;
+        }
+
+        SecondObject::~SecondObject()
+        {
+          const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+          m_cxxQtThreadObj->ptr = nullptr;
+        }
+
+        const SecondObjectRust&
+        SecondObject::unsafeRust() const
+        {
+          return *m_rustObj;
+        }
+
+        SecondObjectRust&
+        SecondObject::unsafeRustMut()
+        {
+          return *m_rustObj;
+        }
+
+        std::unique_ptr<SecondObjectCxxQtThread>
+        SecondObject::qtThread() const
+        {
+          return std::make_unique<SecondObjectCxxQtThread>(m_cxxQtThreadObj, &m_rustObjMutex);
+        }
+
+        bool
+        SecondObject::toggle() const
+        {
+          return m_toggle;
+        }
+
+
+        } // namespace cxx_qt
+
+        namespace cxx_qt::cxx_qt_second_object {
+        std::unique_ptr<SecondObject>
+        newCppObject()
+        {
+          return std::make_unique<SecondObject>();
+        }
+        } // namespace cxx_qt::cxx_qt_second_object
+        "#}
+    }
+
     /// Helper for the expected header
     pub fn expected_header() -> &'static str {
         indoc! {r#"
         #pragma once
 
+        #include <cstdint>
         #include <memory>
         #include <mutex>
 
+        #include "cxx-qt-lib/include/cxxqt_thread.h"
+
         namespace cxx_qt::my_object {
         class MyObject;
+        using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
         } // namespace cxx_qt::my_object
 
         #include "cxx-qt-gen/include/cxx_stem.cxx.h"
@@ -163,6 +470,7 @@ mod tests {
           ~MyObject();
           const MyObjectRust& unsafeRust() const;
           MyObjectRust& unsafeRustMut();
+          std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
 
         public:
           int count() const;
@@ -180,8 +488,9 @@ mod tests {
 
         private:
           rust::Box<MyObjectRust> m_rustObj;
-          std::mutex m_rustObjMutex;
+          mutable std::recursive_mutex m_rustObjMutex;
           bool m_initialised = false;
+          std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>> m_cxxQtThreadObj;
 
           int m_count;
           bool m_toggle;
@@ -203,11 +512,15 @@ mod tests {
         indoc! {r#"
         #pragma once
 
+        #include <cstdint>
         #include <memory>
         #include <mutex>
 
+        #include "cxx-qt-lib/include/cxxqt_thread.h"
+
 
         class MyObject;
+        using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
 
 
         #include "cxx-qt-gen/include/cxx_stem.cxx.h"
@@ -225,6 +538,7 @@ mod tests {
           ~MyObject();
           const MyObjectRust& unsafeRust() const;
           MyObjectRust& unsafeRustMut();
+          std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
 
         public:
           int count() const;
@@ -242,8 +556,9 @@ mod tests {
 
         private:
           rust::Box<MyObjectRust> m_rustObj;
-          std::mutex m_rustObjMutex;
+          mutable std::recursive_mutex m_rustObjMutex;
           bool m_initialised = false;
+          std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>> m_cxxQtThreadObj;
 
           int m_count;
           bool m_toggle;
@@ -270,12 +585,17 @@ mod tests {
         MyObject::MyObject(QObject* parent)
           : QObject(parent)
           , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+          , m_cxxQtThreadObj(std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
         {
           cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
           m_initialised = true;
         }
 
-        MyObject::~MyObject() = default;
+        MyObject::~MyObject()
+        {
+          const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+          m_cxxQtThreadObj->ptr = nullptr;
+        }
 
         const MyObjectRust&
         MyObject::unsafeRust() const
@@ -289,6 +609,12 @@ mod tests {
           return *m_rustObj;
         }
 
+        std::unique_ptr<MyObjectCxxQtThread>
+        MyObject::qtThread() const
+        {
+          return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj, &m_rustObjMutex);
+        }
+
         int
         MyObject::count() const
         {
@@ -355,12 +681,17 @@ mod tests {
         MyObject::MyObject(QObject* parent)
           : QObject(parent)
           , m_rustObj(cxx_qt_my_object::createRs())
+          , m_cxxQtThreadObj(std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
         {
           cxx_qt_my_object::initialiseCpp(*this);
           m_initialised = true;
         }
 
-        MyObject::~MyObject() = default;
+        MyObject::~MyObject()
+        {
+          const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+          m_cxxQtThreadObj->ptr = nullptr;
+        }
 
         const MyObjectRust&
         MyObject::unsafeRust() const
@@ -374,6 +705,12 @@ mod tests {
           return *m_rustObj;
         }
 
+        std::unique_ptr<MyObjectCxxQtThread>
+        MyObject::qtThread() const
+        {
+          return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj, &m_rustObjMutex);
+        }
+
         int
         MyObject::count() const
         {
@@ -3,7 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::cpp::{fragment::CppFragmentPair, GeneratedCppBlocks};
+use crate::generator::cpp::{
+    fragment::CppFragmentPair, GeneratedCppBlocks, GeneratedCppQObjectBlocks,
+};
+use crate::writer::cpp::namespace_pair;
 use indoc::formatdoc;
 
 /// Extract the source from a given CppFragmentPair
@@ -11,22 +14,27 @@ fn pair_as_source(pair: &CppFragmentPair) -> String {
     pair.source.clone()
 }
 
-/// For a given GeneratedCppBlocks write this into a C++ source
-pub fn write_cpp_source(generated: &GeneratedCppBlocks) -> String {
-    formatdoc! {r#"
-        #include "cxx-qt-gen/include/{cxx_stem}.cxxqt.h"
+/// For a given GeneratedCppQObjectBlocks write the class implementation and internal definitions
+fn qobject_source(qobject: &GeneratedCppQObjectBlocks) -> String {
+    let (namespace_start, namespace_end) = namespace_pair(&qobject.namespace);
 
+    formatdoc! {r#"
         {namespace_start}
 
         {ident}::{ident}(QObject* parent)
-          : QObject(parent)
+          : {base_class}(parent)
           , m_rustObj({namespace_internals}::createRs())
+          , m_cxxQtThreadObj(std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<{ident}>>(this))
         {{
           {namespace_internals}::initialiseCpp(*this);
           m_initialised = true;
         }}
 
-        {ident}::~{ident}() = default;
+        {ident}::~{ident}()
+        {{
+          const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+          m_cxxQtThreadObj->ptr = nullptr;
+        }}
 
         const {rust_ident}&
         {ident}::unsafeRust() const
@@ -40,6 +48,12 @@ pub fn write_cpp_source(generated: &GeneratedCppBlocks) -> String {
           return *m_rustObj;
         }}
 
+        std::unique_ptr<{ident}CxxQtThread>
+        {ident}::qtThread() const
+        {{
+          return std::make_unique<{ident}CxxQtThread>(m_cxxQtThreadObj, &m_rustObjMutex);
+        }}
+
         {methods}
         {slots}
         {namespace_end}
@@ -50,24 +64,27 @@ pub fn write_cpp_source(generated: &GeneratedCppBlocks) -> String {
         {{
           return std::make_unique<{ident}>();
         }}
-        }} // namespace {namespace_internals}
+        }} // namespace {namespace_internals}"#,
+    ident = qobject.ident,
+    base_class = qobject.base_class,
+    namespace_start = namespace_start,
+    namespace_end = namespace_end,
+    namespace_internals = qobject.namespace_internals,
+    rust_ident = qobject.rust_ident,
+    methods = qobject.methods.iter().map(pair_as_source).collect::<Vec<String>>().join("\n"),
+    slots = qobject.slots.iter().map(pair_as_source).collect::<Vec<String>>().join("\n"),
+    }
+}
+
+/// For a given GeneratedCppBlocks write this into a C++ source
+pub fn write_cpp_source(generated: &GeneratedCppBlocks) -> String {
+    formatdoc! {r#"
+        #include "cxx-qt-gen/include/{cxx_stem}.cxxqt.h"
+
+        {qobjects}
     "#,
     cxx_stem = generated.cxx_stem,
-    ident = generated.ident,
-    namespace_start = if generated.namespace.is_empty() {
-      "".to_owned()
-    } else {
-      format!("namespace {namespace} {{", namespace = generated.namespace)
-    },
-    namespace_end = if generated.namespace.is_empty() {
-      "".to_owned()
-    } else {
-      format!("}} // namespace {namespace}", namespace = generated.namespace)
-    },
-    namespace_internals = generated.namespace_internals,
-    rust_ident = generated.rust_ident,
-    methods = generated.methods.iter().map(pair_as_source).collect::<Vec<String>>().join("\n"),
-    slots = generated.slots.iter().map(pair_as_source).collect::<Vec<String>>().join("\n"),
+    qobjects = generated.qobjects.iter().map(qobject_source).collect::<Vec<String>>().join("\n\n"),
     }
 }
 
@@ -76,7 +93,8 @@ mod tests {
     use super::*;
 
     use crate::writer::cpp::tests::{
-        create_generated_cpp, create_generated_cpp_no_namespace, expected_source,
+        create_generated_cpp, create_generated_cpp_multi_qobjects,
+        create_generated_cpp_no_namespace, expected_source, expected_source_multi_qobjects,
         expected_source_no_namespace,
     };
     use pretty_assertions::assert_str_eq;
@@ -94,4 +112,11 @@ mod tests {
         let output = write_cpp_source(&generated);
         assert_str_eq!(output, expected_source_no_namespace());
     }
+
+    #[test]
+    fn test_write_cpp_source_multi_qobjects() {
+        let generated = create_generated_cpp_multi_qobjects();
+        let output = write_cpp_source(&generated);
+        assert_str_eq!(output, expected_source_multi_qobjects());
+    }
 }
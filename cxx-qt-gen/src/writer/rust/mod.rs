@@ -3,31 +3,62 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::rust::GeneratedRustBlocks;
+use crate::generator::rust::{GeneratedRustBlocks, GeneratedRustQObjectBlocks};
+use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
+use syn::{Ident, Item, Visibility};
+
+/// Idents of the public items in the CXX-Qt module of a QObject that can be re-exported
+///
+/// `CppObj` and `FFICppObj` are generated for every QObject, so they are skipped to avoid
+/// ambiguous names when a bridge has multiple QObjects. Instead they are reached with the
+/// path of the CXX-Qt module, eg `cxx_qt_my_object::CppObj`.
+fn reexported_idents(items: &[Item]) -> Vec<&Ident> {
+    items
+        .iter()
+        .filter_map(|item| {
+            let (vis, ident) = match item {
+                Item::Const(item) => (&item.vis, &item.ident),
+                Item::Enum(item) => (&item.vis, &item.ident),
+                Item::Fn(item) => (&item.vis, &item.sig.ident),
+                Item::Mod(item) => (&item.vis, &item.ident),
+                Item::Static(item) => (&item.vis, &item.ident),
+                Item::Struct(item) => (&item.vis, &item.ident),
+                Item::Trait(item) => (&item.vis, &item.ident),
+                Item::Type(item) => (&item.vis, &item.ident),
+                Item::Union(item) => (&item.vis, &item.ident),
+                _others => return None,
+            };
+
+            if matches!(vis, Visibility::Public(_)) && ident != "CppObj" && ident != "FFICppObj" {
+                Some(ident)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
 
-/// For a given GeneratedRustBlocks write this into a Rust TokenStream
-pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
+/// For a given GeneratedRustQObjectBlocks write the CXX-Qt module of the QObject
+fn write_rust_qobject(qobject: &GeneratedRustQObjectBlocks, cxx_mod_ident: &Ident) -> TokenStream {
     // Retrieve the struct idents
-    let cpp_struct_ident = &generated.cpp_struct_ident;
-    let rust_struct_ident = &generated.rust_struct_ident;
+    let cpp_struct_ident = &qobject.cpp_struct_ident;
+    let rust_struct_ident = &qobject.rust_struct_ident;
 
-    // Build the module idents
-    let cxx_mod_ident = &generated.cxx_mod.ident;
-    let cxx_qt_mod_ident = format_ident!("cxx_qt_{}", cxx_mod_ident);
+    // Build the module and function idents, these are unique for each QObject
+    let ident_snake = rust_struct_ident.to_string().to_case(Case::Snake);
+    let cxx_qt_mod_ident = format_ident!("cxx_qt_{}", ident_snake);
+    let create_rs_ident = format_ident!("create_rs_{}", ident_snake);
+    let initialise_cpp_ident = format_ident!("initialise_cpp_{}", ident_snake);
 
-    // Retrieve the module contents and namespace
-    let cxx_mod = &generated.cxx_mod;
-    let cxx_qt_mod_contents = &generated.cxx_qt_mod_contents;
-    let namespace = &generated.namespace;
+    // Retrieve the module contents
+    let cxx_qt_mod_contents = &qobject.cxx_qt_mod_contents;
+    let reexported_idents = reexported_idents(cxx_qt_mod_contents);
 
     quote! {
-        #[cxx::bridge(namespace = #namespace)]
-        #cxx_mod
-
-        pub use self::#cxx_qt_mod_ident::*;
-        mod #cxx_qt_mod_ident {
+        pub use self::#cxx_qt_mod_ident::{#(#reexported_idents,)* #create_rs_ident, #initialise_cpp_ident};
+        pub mod #cxx_qt_mod_ident {
             use super::#cxx_mod_ident::*;
 
             pub type FFICppObj = super::#cxx_mod_ident::#cpp_struct_ident;
@@ -35,16 +66,39 @@ pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
 
             #(#cxx_qt_mod_contents)*
 
-            pub fn create_rs() -> std::boxed::Box<#rust_struct_ident> {
+            pub fn #create_rs_ident() -> std::boxed::Box<#rust_struct_ident> {
                 std::default::Default::default()
             }
 
-            pub fn initialise_cpp(cpp: std::pin::Pin<&mut FFICppObj>) {
+            pub fn #initialise_cpp_ident(cpp: std::pin::Pin<&mut FFICppObj>) {
                 let mut wrapper = CppObj::new(cpp);
-                wrapper.grab_values_from_data(Data::default());
+                wrapper.grab_values_from_data(std::default::Default::default());
             }
         }
     }
+}
+
+/// For a given GeneratedRustBlocks write this into a Rust TokenStream
+pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
+    // Build the module idents
+    let cxx_mod_ident = &generated.cxx_mod.ident;
+
+    // Retrieve the module contents and namespace
+    let cxx_mod = &generated.cxx_mod;
+    let namespace = &generated.namespace;
+
+    let qobjects = generated
+        .qobjects
+        .iter()
+        .map(|qobject| write_rust_qobject(qobject, cxx_mod_ident))
+        .collect::<Vec<TokenStream>>();
+
+    quote! {
+        #[cxx::bridge(namespace = #namespace)]
+        #cxx_mod
+
+        #(#qobjects)*
+    }
     .into_token_stream()
 }
 
@@ -72,22 +126,24 @@ mod tests {
                     }
                 }
             }),
-            cxx_qt_mod_contents: vec![
-                tokens_to_syn(quote! {
-                    #[derive(Default)]
-                    pub struct MyObject;
-                }),
-                tokens_to_syn(quote! {
-                    impl MyObject {
-                        fn rust_method(&self) {
-
-                        }
-                    }
-                }),
-            ],
-            cpp_struct_ident: format_ident!("MyObjectQt"),
             namespace: "cxx_qt::my_object".to_owned(),
-            rust_struct_ident: format_ident!("MyObject"),
+            qobjects: vec![GeneratedRustQObjectBlocks {
+                cxx_qt_mod_contents: vec![
+                    tokens_to_syn(quote! {
+                        #[derive(Default)]
+                        pub struct MyObject;
+                    }),
+                    tokens_to_syn(quote! {
+                        impl MyObject {
+                            fn rust_method(&self) {
+
+                            }
+                        }
+                    }),
+                ],
+                cpp_struct_ident: format_ident!("MyObjectQt"),
+                rust_struct_ident: format_ident!("MyObject"),
+            }],
         }
     }
 
@@ -107,8 +163,8 @@ mod tests {
                 }
             }
 
-            pub use self::cxx_qt_ffi::*;
-            mod cxx_qt_ffi {
+            pub use self::cxx_qt_my_object::{MyObject, create_rs_my_object, initialise_cpp_my_object};
+            pub mod cxx_qt_my_object {
                 use super::ffi::*;
 
                 pub type FFICppObj = super::ffi::MyObjectQt;
@@ -123,13 +179,13 @@ mod tests {
                     }
                 }
 
-                pub fn create_rs() -> std::boxed::Box<MyObject> {
+                pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
                     std::default::Default::default()
                 }
 
-                pub fn initialise_cpp(cpp: std::pin::Pin<&mut FFICppObj>) {
+                pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
                     let mut wrapper = CppObj::new(cpp);
-                    wrapper.grab_values_from_data(Data::default());
+                    wrapper.grab_values_from_data(std::default::Default::default());
                 }
             }
         }
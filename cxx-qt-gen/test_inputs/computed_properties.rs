@@ -0,0 +1,39 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[cxx_qt::signals(MyObject)]
+    enum MySignals {
+        ValuesChanged,
+    }
+
+    #[derive(Default)]
+    pub struct Data {
+        #[qproperty(notify = values_changed)]
+        number: i32,
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qproperty(write = set_doubled, notify = values_changed)]
+        pub fn doubled(&self, cpp: &mut CppObj) -> i32 {
+            cpp.number() * 2
+        }
+
+        pub fn set_doubled(&self, cpp: &mut CppObj, value: i32) {
+            cpp.set_number(value / 2);
+        }
+
+        #[qproperty(notify = values_changed)]
+        pub fn label(&self, cpp: &mut CppObj) -> UniquePtr<QString> {
+            QString::from_str(&format!("Number: {}", cpp.number()))
+        }
+    }
+}
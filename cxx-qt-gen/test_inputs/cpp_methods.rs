@@ -0,0 +1,25 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[cxx_qt::cpp_methods(MyObject)]
+    unsafe extern "C++" {
+        /// Set the objectName of the QObject
+        #[rust_name = "set_object_name"]
+        fn setObjectName(self: Pin<&mut Self>, name: &QString);
+
+        #[rust_name = "signals_blocked"]
+        fn signalsBlocked(&self) -> bool;
+
+        #[rust_name = "delete_later"]
+        unsafe fn deleteLater(self: Pin<&mut Self>);
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+}
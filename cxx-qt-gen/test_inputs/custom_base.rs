@@ -0,0 +1,59 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!(<QtCore/QAbstractListModel>);
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib/include/qhash.h");
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash_i32_QByteArray;
+    }
+
+    #[cxx_qt::qobject(base = "QAbstractListModel")]
+    #[derive(Default)]
+    pub struct MyObject {
+        vector: Vec<i32>,
+    }
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn add(&mut self, cpp: &mut CppObj, value: i32) {
+            let count = self.vector.len() as i32;
+            unsafe {
+                cpp.begin_insert_rows(&QModelIndex::default(), count, count);
+                self.vector.push(value);
+                cpp.end_insert_rows();
+            }
+        }
+
+        #[qinvokable(cxx_override)]
+        pub fn data(&self, index: &QModelIndex, _role: i32) -> UniquePtr<QVariant> {
+            if let Some(value) = self.vector.get(index.row() as usize) {
+                return QVariant::from(*value);
+            }
+
+            QVariant::null()
+        }
+
+        #[qinvokable(cxx_final, cxx_override)]
+        pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
+            self.vector.len() as i32
+        }
+
+        #[qinvokable(cxx_virtual)]
+        pub fn can_add(&self) -> bool {
+            true
+        }
+
+        #[qinvokable(cxx_override)]
+        pub fn role_names(&self) -> UniquePtr<QHash_i32_QByteArray> {
+            let mut roles = QHash_i32_QByteArray::new();
+            roles
+                .pin_mut()
+                .insert(&0, &QByteArray::from_slice(b"value"));
+            roles
+        }
+    }
+}
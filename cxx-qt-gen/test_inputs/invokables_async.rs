@@ -0,0 +1,33 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub async fn invokable_async() {
+            println!("invokable_async");
+        }
+
+        #[qinvokable]
+        pub async fn invokable_async_primitive(value: i32) -> i32 {
+            value * 2
+        }
+
+        #[qinvokable]
+        pub async fn invokable_async_opaque(known: bool) -> UniquePtr<QString> {
+            if known {
+                QString::from_str("known")
+            } else {
+                QString::from_str("unknown")
+            }
+        }
+    }
+}
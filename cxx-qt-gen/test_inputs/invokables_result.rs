@@ -0,0 +1,36 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn invokable_result(&self) -> Result<(), String> {
+            Err("invokable failed".to_owned())
+        }
+
+        #[qinvokable]
+        pub fn invokable_result_primitive(&mut self, value: i32) -> Result<i32, String> {
+            if value < 0 {
+                Err(format!("{} is negative", value))
+            } else {
+                Ok(value * 2)
+            }
+        }
+
+        #[qinvokable]
+        pub fn invokable_result_opaque(
+            &self,
+            string: &QString,
+        ) -> Result<UniquePtr<QString>, std::fmt::Error> {
+            Ok(QString::from_str(&string.to_string()))
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::multi_object")]
+mod ffi {
+    #[cxx_qt::data(FirstObject)]
+    #[derive(Default)]
+    pub struct FirstData {
+        property_name: i32,
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct FirstObject;
+
+    #[cxx_qt::signals(FirstObject)]
+    pub enum FirstSignals {
+        Accepted,
+    }
+
+    impl cxx_qt::QObject<FirstObject> {
+        #[qinvokable]
+        pub fn invokable(&self, cpp: &mut CppObj) {
+            cpp.emit_queued(FirstSignals::Accepted);
+        }
+    }
+
+    #[cxx_qt::data(SecondObject)]
+    #[derive(Default)]
+    pub struct SecondData {
+        property_name: i32,
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct SecondObject;
+
+    #[cxx_qt::signals(SecondObject)]
+    pub enum SecondSignals {
+        Rejected,
+    }
+
+    impl cxx_qt::QObject<SecondObject> {
+        #[qinvokable]
+        pub fn invokable(&self, cpp: &mut CppObj) {
+            cpp.emit_queued(SecondSignals::Rejected);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[derive(Default)]
+    pub struct Data {
+        #[qproperty(read_only)]
+        count: i32,
+        #[qproperty(constant)]
+        limit: i32,
+        #[qproperty(notify = values_changed, reset = reset_value)]
+        value: f64,
+        #[qproperty(notify = values_changed)]
+        offset: f64,
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn reset_value(&self, cpp: &mut CppObj) {
+            cpp.set_value(0.0);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[cxx_qt::qenum(MyObject)]
+    enum State {
+        Idle,
+        Running = 5,
+        Stopped,
+    }
+
+    #[cxx_qt::signals(MyObject)]
+    enum MySignals {
+        Transitioned { state: State },
+    }
+
+    pub struct Data {
+        state: State,
+    }
+
+    impl Default for Data {
+        fn default() -> Self {
+            Self { state: State::Idle }
+        }
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn next_state(&self, cpp: &mut CppObj, state: State) -> State {
+            let next = match state {
+                State::Idle => State::Running,
+                _others => State::Stopped,
+            };
+            cpp.emit_queued(MySignals::Transitioned { state: next });
+            next
+        }
+    }
+}
@@ -0,0 +1,34 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[cxx_qt::qenum]
+    enum Direction {
+        Up,
+        Down,
+    }
+
+    pub struct Data {
+        direction: Direction,
+    }
+
+    impl Default for Data {
+        fn default() -> Self {
+            Self {
+                direction: Direction::Up,
+            }
+        }
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn reverse(&self, direction: Direction) -> Direction {
+            match direction {
+                Direction::Up => Direction::Down,
+                _others => Direction::Up,
+            }
+        }
+    }
+}
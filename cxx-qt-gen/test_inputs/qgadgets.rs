@@ -0,0 +1,33 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[cxx_qt::qgadget]
+    #[derive(Clone, Copy, Default)]
+    struct Reading {
+        sensor_id: i32,
+        timestamp: f64,
+        value: f64,
+    }
+
+    #[cxx_qt::signals(MyObject)]
+    enum MySignals {
+        Recorded { reading: Reading },
+    }
+
+    #[derive(Default)]
+    pub struct Data {
+        reading: Reading,
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn record(&self, cpp: &mut CppObj, reading: Reading) -> f64 {
+            cpp.set_reading(reading);
+            cpp.emit_queued(MySignals::Recorded { reading });
+            reading.value
+        }
+    }
+}
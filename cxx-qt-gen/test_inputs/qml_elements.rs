@@ -0,0 +1,74 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[cxx_qt::qenum(MyObject)]
+    enum State {
+        Idle,
+        Running = 5,
+    }
+
+    #[cxx_qt::signals(MyObject)]
+    enum MySignals {
+        Ready,
+        Transitioned { state: State, count: i32 },
+    }
+
+    pub struct Data {
+        #[qproperty]
+        name: UniquePtr<QString>,
+        #[qproperty(read_only, notify = state_changed)]
+        state: State,
+    }
+
+    impl Default for Data {
+        fn default() -> Self {
+            Self {
+                name: QString::from_str(""),
+                state: State::Idle,
+            }
+        }
+    }
+
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo", qml_version = "1.0")]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn start(&self, cpp: &mut CppObj) {
+            cpp.emit_queued(MySignals::Ready);
+        }
+
+        #[qinvokable]
+        pub fn add(&self, a: i32, b: f64) -> f64 {
+            a as f64 + b
+        }
+
+        pub fn not_invokable(&self) {}
+    }
+
+    #[cxx_qt::qobject(
+        qml_uri = "com.kdab.cxx_qt.demo",
+        qml_version = "2.1",
+        qml_name = "Settings",
+        qml_singleton
+    )]
+    #[derive(Default)]
+    pub struct SettingsObject;
+
+    #[cxx_qt::qobject(
+        qml_uri = "com.kdab.cxx_qt.demo",
+        qml_uncreatable = "Readings are created by the \"sensor\""
+    )]
+    #[derive(Default)]
+    pub struct Reading;
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct Internal;
+}
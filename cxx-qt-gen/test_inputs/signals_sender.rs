@@ -0,0 +1,21 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[cxx_qt::signals(MyObject)]
+    enum MySignals {
+        ValueChanged { value: i32 },
+    }
+
+    #[cxx_qt::qobject]
+    #[derive(Default)]
+    pub struct MyObject {
+        value: i32,
+    }
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn set_value(&mut self, cpp: &mut CppObj, value: i32) {
+            self.value = value;
+            cpp.emit_queued(MySignals::ValueChanged { value });
+        }
+    }
+}
@@ -3,6 +3,7 @@ mod ffi {
     #[namespace = ""]
     unsafe extern "C++" {
         include!("cxx-qt-lib/include/qt_types.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
         type QColor = cxx_qt_lib::QColor;
         type QDate = cxx_qt_lib::QDate;
         type QDateTime = cxx_qt_lib::QDateTime;
@@ -16,6 +17,15 @@ mod ffi {
         type QTime = cxx_qt_lib::QTime;
         type QUrl = cxx_qt_lib::QUrl;
         type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib/include/qhash.h");
+        type QVariantHash = cxx_qt_lib::QVariantHash;
+
+        include!("cxx-qt-lib/include/qmap.h");
+        type QVariantMap = cxx_qt_lib::QVariantMap;
+
+        include!("cxx-qt-lib/include/qvector.h");
+        type QVector_QString = cxx_qt_lib::QVector_QString;
     }
 
     #[derive(Default)]
@@ -26,6 +36,15 @@ mod ffi {
     pub struct MyObject;
 
     impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn test_byte_array(
+            &self,
+            _cpp: &mut CppObj,
+            byte_array: &QByteArray,
+        ) -> UniquePtr<QByteArray> {
+            byte_array
+        }
+
         #[qinvokable]
         pub fn test_color(&self, _cpp: &mut CppObj, color: &QColor) -> UniquePtr<QColor> {
             color
@@ -94,5 +113,32 @@ mod ffi {
         pub fn test_variant(&self, _cpp: &mut CppObj, variant: &QVariant) -> UniquePtr<QVariant> {
             variant
         }
+
+        #[qinvokable]
+        pub fn test_variant_hash(
+            &self,
+            _cpp: &mut CppObj,
+            hash: &QVariantHash,
+        ) -> UniquePtr<QVariantHash> {
+            hash
+        }
+
+        #[qinvokable]
+        pub fn test_variant_map(
+            &self,
+            _cpp: &mut CppObj,
+            map: &QVariantMap,
+        ) -> UniquePtr<QVariantMap> {
+            map
+        }
+
+        #[qinvokable]
+        pub fn test_vector(
+            &self,
+            _cpp: &mut CppObj,
+            vector: &QVector_QString,
+        ) -> UniquePtr<QVector_QString> {
+            vector
+        }
     }
 }
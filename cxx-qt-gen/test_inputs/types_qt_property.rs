@@ -13,9 +13,13 @@ mod ffi {
         type QSize = cxx_qt_lib::QSize;
         type QSizeF = cxx_qt_lib::QSizeF;
         type QString = cxx_qt_lib::QString;
+        type QStringList = cxx_qt_lib::QStringList;
         type QTime = cxx_qt_lib::QTime;
         type QUrl = cxx_qt_lib::QUrl;
         type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib/include/qlist.h");
+        type QList_i32 = cxx_qt_lib::QList_i32;
     }
 
     #[derive(Default)]
@@ -33,6 +37,8 @@ mod ffi {
         time: QTime,
         url: UniquePtr<QUrl>,
         variant: UniquePtr<QVariant>,
+        list: UniquePtr<QList_i32>,
+        string_list: UniquePtr<QStringList>,
     }
 
     #[cxx_qt::qobject]
@@ -0,0 +1,129 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+qint32
+MyObject::getNumber() const
+{
+  return m_number;
+}
+
+void
+MyObject::setNumber(qint32 value)
+{
+  if (!m_initialised) {
+    m_number = value;
+    return;
+  }
+
+  if (value != m_number) {
+    m_number = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "valuesChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+qint32
+MyObject::doubled()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<qint32, qint32>{}(
+    m_rustObj->doubledWrapper(*this));
+}
+
+void
+MyObject::setDoubled(qint32 value)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->setDoubledWrapper(*this, value);
+}
+
+QString
+MyObject::label()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<QString, std::unique_ptr<QString>>{}(
+    m_rustObj->labelWrapper(*this));
+}
+
+void
+MyObject::emitValuesChanged()
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this, [this]() { Q_EMIT valuesChanged(); }, Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectValuesChanged(
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::ValuesChangedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectValuesChanged(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectValuesChanged(
+  MyObject& sender,
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::ValuesChangedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::valuesChanged,
+      this,
+      [this, handler = std::move(handler)]() mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::callValuesChangedHandler(
+          *handler, *this);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
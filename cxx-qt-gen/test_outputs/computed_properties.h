@@ -0,0 +1,69 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/include/my_object.cxx.h"
+
+namespace cxx_qt::my_object {
+
+class MyObject : public QObject
+{
+  Q_OBJECT
+  Q_PROPERTY(qint32 number READ getNumber WRITE setNumber NOTIFY valuesChanged)
+  Q_PROPERTY(qint32 doubled READ doubled WRITE setDoubled NOTIFY valuesChanged)
+  Q_PROPERTY(QString label READ label NOTIFY valuesChanged)
+
+public:
+  explicit MyObject(QObject* parent = nullptr);
+  ~MyObject();
+  const MyObjectRust& unsafeRust() const;
+  MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
+
+public:
+  qint32 getNumber() const;
+  qint32 doubled();
+  void setDoubled(qint32 value);
+  QString label();
+  void emitValuesChanged();
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectValuesChanged(
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::ValuesChangedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectValuesChanged(
+    MyObject& sender,
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::ValuesChangedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+
+public Q_SLOTS:
+  void setNumber(qint32 value);
+
+Q_SIGNALS:
+  void valuesChanged();
+
+private:
+  rust::Box<MyObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
+
+  qint32 m_number;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject();
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,401 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        #[rust_name = "number"]
+        fn getNumber(self: &MyObjectQt) -> i32;
+        #[rust_name = "set_number"]
+        fn setNumber(self: Pin<&mut MyObjectQt>, value: i32);
+
+        #[rust_name = "values_changed"]
+        fn valuesChanged(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "emit_values_changed"]
+        fn emitValuesChanged(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "connect_values_changed_handler"]
+        fn connectValuesChanged(
+            self: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectValuesChangedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_values_changed_from_handler"]
+        fn connectValuesChanged(
+            self: Pin<&mut MyObjectQt>,
+            sender: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectValuesChangedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "doubledWrapper"]
+        fn doubled_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>) -> i32;
+
+        #[cxx_name = "setDoubledWrapper"]
+        fn set_doubled_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>, value: i32);
+
+        #[cxx_name = "labelWrapper"]
+        fn label_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>) -> UniquePtr<QString>;
+
+        #[cxx_name = "ValuesChangedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectValuesChangedHandler;
+
+        #[cxx_name = "callValuesChangedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn call_my_object_values_changed_handler(
+            handler: &mut MyObjectValuesChangedHandler,
+            cpp: Pin<&mut MyObjectQt>,
+        );
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/connection.h");
+
+        #[namespace = "rust::cxxqtlib1"]
+        type ConnectionType = cxx_qt_lib::ConnectionType;
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnectionCpp;
+    }
+
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+}
+
+pub use self::cxx_qt_my_object::{
+    call_my_object_values_changed_handler, create_rs_my_object, initialise_cpp_my_object, Data,
+    MyObject, MyObjectCxxQtThreadQueuedFn, MyObjectValuesChangedHandler,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    enum MySignals {
+        ValuesChanged,
+    }
+
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl MyObject {
+        pub fn doubled_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>) -> i32 {
+            let mut cpp = CppObj::new(cpp);
+            return self.doubled(&mut cpp);
+        }
+
+        pub fn set_doubled_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>, value: i32) {
+            let mut cpp = CppObj::new(cpp);
+            self.set_doubled(&mut cpp, value);
+        }
+
+        pub fn label_wrapper(
+            &self,
+            cpp: std::pin::Pin<&mut FFICppObj>,
+        ) -> UniquePtr<cxx_qt_lib::QString> {
+            let mut cpp = CppObj::new(cpp);
+            return self.label(&mut cpp);
+        }
+
+        pub fn doubled(&self, cpp: &mut CppObj) -> i32 {
+            cpp.number() * 2
+        }
+
+        pub fn set_doubled(&self, cpp: &mut CppObj, value: i32) {
+            cpp.set_number(value / 2);
+        }
+
+        pub fn label(&self, cpp: &mut CppObj) -> UniquePtr<QString> {
+            QString::from_str(&format!("Number: {}", cpp.number()))
+        }
+    }
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        pub fn number(&self) -> i32 {
+            self.cpp.number()
+        }
+
+        pub fn set_number(&mut self, value: i32) {
+            self.cpp.as_mut().set_number(value);
+        }
+
+        pub fn emit_queued(&mut self, signal: MySignals) {
+            match signal {
+                MySignals::ValuesChanged {} => self.cpp.as_mut().emit_values_changed(),
+            }
+        }
+
+        pub unsafe fn emit_immediate(&mut self, signal: MySignals) {
+            match signal {
+                MySignals::ValuesChanged {} => self.cpp.as_mut().values_changed(),
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_values_changed_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_values_changed_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_values_changed<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_values_changed(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_values_changed_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_values_changed_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_values_changed_from<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_values_changed_from(sender, connection_type, closure)
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {
+            self.set_number(data.number);
+        }
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    pub struct MyObjectValuesChangedHandler(Box<dyn FnMut(&mut CppObj)>);
+
+    pub fn call_my_object_values_changed_handler(
+        handler: &mut MyObjectValuesChangedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp);
+    }
+
+    impl FFICppObj {
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_values_changed_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_values_changed(cxx_qt_lib::ConnectionType::QueuedConnection, closure)
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_values_changed<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_values_changed_handler(
+                Box::new(MyObjectValuesChangedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_values_changed_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_values_changed_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_values_changed_from<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_values_changed_from_handler(
+                    sender,
+                    Box::new(MyObjectValuesChangedHandler(Box::new(closure))),
+                    connection_type,
+                ),
+            )
+        }
+    }
+
+    #[derive(Default)]
+    pub struct Data {
+        number: i32,
+    }
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(value: &CppObj<'a>) -> Self {
+            Self {
+                number: value.number().into(),
+            }
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(value: &mut CppObj<'a>) -> Self {
+            Self::from(&*value)
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
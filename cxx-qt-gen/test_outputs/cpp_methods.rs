@@ -0,0 +1,159 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        #[doc = " Set the objectName of the QObject"]
+        #[rust_name = "set_object_name"]
+        fn setObjectName(self: Pin<&mut MyObjectQt>, name: &QString);
+        #[rust_name = "signals_blocked"]
+        fn signalsBlocked(self: &MyObjectQt) -> bool;
+        #[rust_name = "delete_later"]
+        unsafe fn deleteLater(self: Pin<&mut MyObjectQt>);
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+}
+
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl MyObject {}
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        #[doc = " Set the objectName of the QObject"]
+        pub fn set_object_name(&mut self, name: &QString) {
+            self.cpp.as_mut().set_object_name(name)
+        }
+
+        pub fn signals_blocked(&self) -> bool {
+            self.cpp.signals_blocked()
+        }
+
+        pub unsafe fn delete_later(&mut self) {
+            self.cpp.as_mut().delete_later()
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {}
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    pub struct Data;
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(_value: &CppObj<'a>) -> Self {
+            Self {}
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(_value: &mut CppObj<'a>) -> Self {
+            Self::from(&*_value)
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
@@ -0,0 +1,88 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QAbstractListModel(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+void
+MyObject::add(qint32 value)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->addWrapper(*this, value);
+}
+
+QVariant
+MyObject::data(const QModelIndex& index, qint32 _role) const
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<QVariant, std::unique_ptr<QVariant>>{}(
+    m_rustObj->dataWrapper(index, _role));
+}
+
+qint32
+MyObject::rowCount(const QModelIndex& _parent) const
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<qint32, qint32>{}(
+    m_rustObj->rowCount(_parent));
+}
+
+bool
+MyObject::canAdd() const
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<bool, bool>{}(m_rustObj->canAdd());
+}
+
+QHash<qint32, QByteArray>
+MyObject::roleNames() const
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<
+    QHash<qint32, QByteArray>,
+    std::unique_ptr<QHash<qint32, QByteArray>>>{}(
+    m_rustObj->roleNamesWrapper());
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
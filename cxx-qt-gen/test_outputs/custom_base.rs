@@ -0,0 +1,359 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        #[rust_name = "begin_insert_columns"]
+        fn beginInsertColumns(
+            self: Pin<&mut MyObjectQt>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+        #[rust_name = "end_insert_columns"]
+        fn endInsertColumns(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "begin_insert_rows"]
+        fn beginInsertRows(self: Pin<&mut MyObjectQt>, parent: &QModelIndex, first: i32, last: i32);
+        #[rust_name = "end_insert_rows"]
+        fn endInsertRows(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "begin_move_columns"]
+        fn beginMoveColumns(
+            self: Pin<&mut MyObjectQt>,
+            source_parent: &QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &QModelIndex,
+            destination_child: i32,
+        ) -> bool;
+        #[rust_name = "end_move_columns"]
+        fn endMoveColumns(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "begin_move_rows"]
+        fn beginMoveRows(
+            self: Pin<&mut MyObjectQt>,
+            source_parent: &QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &QModelIndex,
+            destination_child: i32,
+        ) -> bool;
+        #[rust_name = "end_move_rows"]
+        fn endMoveRows(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "begin_remove_columns"]
+        fn beginRemoveColumns(
+            self: Pin<&mut MyObjectQt>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+        #[rust_name = "end_remove_columns"]
+        fn endRemoveColumns(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "begin_remove_rows"]
+        fn beginRemoveRows(self: Pin<&mut MyObjectQt>, parent: &QModelIndex, first: i32, last: i32);
+        #[rust_name = "end_remove_rows"]
+        fn endRemoveRows(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "begin_reset_model"]
+        fn beginResetModel(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "end_reset_model"]
+        fn endResetModel(self: Pin<&mut MyObjectQt>);
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "addWrapper"]
+        fn add_wrapper(self: &mut MyObject, cpp: Pin<&mut MyObjectQt>, value: i32);
+        #[cxx_name = "dataWrapper"]
+        fn data_wrapper(self: &MyObject, index: &QModelIndex, _role: i32) -> UniquePtr<QVariant>;
+        #[cxx_name = "rowCount"]
+        fn row_count(self: &MyObject, _parent: &QModelIndex) -> i32;
+        #[cxx_name = "canAdd"]
+        fn can_add(self: &MyObject) -> bool;
+        #[cxx_name = "roleNamesWrapper"]
+        fn role_names_wrapper(self: &MyObject) -> UniquePtr<QHash_i32_QByteArray>;
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include ! (< QtCore / QAbstractListModel >);
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib/include/qhash.h");
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash_i32_QByteArray;
+    }
+}
+
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    #[derive(Default)]
+    pub struct MyObject {
+        vector: Vec<i32>,
+    }
+
+    impl MyObject {
+        pub fn add_wrapper(&mut self, cpp: std::pin::Pin<&mut FFICppObj>, value: i32) {
+            let mut cpp = CppObj::new(cpp);
+            self.add(&mut cpp, value);
+        }
+
+        pub fn data_wrapper(
+            &self,
+            index: &cxx_qt_lib::QModelIndex,
+            _role: i32,
+        ) -> UniquePtr<cxx_qt_lib::QVariant> {
+            return self.data(index, _role);
+        }
+
+        pub fn role_names_wrapper(&self) -> UniquePtr<cxx_qt_lib::QHash_i32_QByteArray> {
+            return self.role_names();
+        }
+
+        pub fn add(&mut self, cpp: &mut CppObj, value: i32) {
+            let count = self.vector.len() as i32;
+            unsafe {
+                cpp.begin_insert_rows(&QModelIndex::default(), count, count);
+                self.vector.push(value);
+                cpp.end_insert_rows();
+            }
+        }
+
+        pub fn data(&self, index: &QModelIndex, _role: i32) -> UniquePtr<QVariant> {
+            if let Some(value) = self.vector.get(index.row() as usize) {
+                return QVariant::from(*value);
+            }
+            QVariant::null()
+        }
+
+        pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
+            self.vector.len() as i32
+        }
+
+        pub fn can_add(&self) -> bool {
+            true
+        }
+
+        pub fn role_names(&self) -> UniquePtr<QHash_i32_QByteArray> {
+            let mut roles = QHash_i32_QByteArray::new();
+            roles
+                .pin_mut()
+                .insert(&0, &QByteArray::from_slice(b"value"));
+            roles
+        }
+    }
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        pub unsafe fn begin_insert_columns(
+            &mut self,
+            parent: &cxx_qt_lib::QModelIndex,
+            first: i32,
+            last: i32,
+        ) {
+            self.cpp.as_mut().begin_insert_columns(parent, first, last);
+        }
+
+        pub unsafe fn end_insert_columns(&mut self) {
+            self.cpp.as_mut().end_insert_columns();
+        }
+
+        pub unsafe fn begin_insert_rows(
+            &mut self,
+            parent: &cxx_qt_lib::QModelIndex,
+            first: i32,
+            last: i32,
+        ) {
+            self.cpp.as_mut().begin_insert_rows(parent, first, last);
+        }
+
+        pub unsafe fn end_insert_rows(&mut self) {
+            self.cpp.as_mut().end_insert_rows();
+        }
+
+        pub unsafe fn begin_move_columns(
+            &mut self,
+            source_parent: &cxx_qt_lib::QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &cxx_qt_lib::QModelIndex,
+            destination_child: i32,
+        ) -> bool {
+            self.cpp.as_mut().begin_move_columns(
+                source_parent,
+                source_first,
+                source_last,
+                destination_parent,
+                destination_child,
+            )
+        }
+
+        pub unsafe fn end_move_columns(&mut self) {
+            self.cpp.as_mut().end_move_columns();
+        }
+
+        pub unsafe fn begin_move_rows(
+            &mut self,
+            source_parent: &cxx_qt_lib::QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &cxx_qt_lib::QModelIndex,
+            destination_child: i32,
+        ) -> bool {
+            self.cpp.as_mut().begin_move_rows(
+                source_parent,
+                source_first,
+                source_last,
+                destination_parent,
+                destination_child,
+            )
+        }
+
+        pub unsafe fn end_move_rows(&mut self) {
+            self.cpp.as_mut().end_move_rows();
+        }
+
+        pub unsafe fn begin_remove_columns(
+            &mut self,
+            parent: &cxx_qt_lib::QModelIndex,
+            first: i32,
+            last: i32,
+        ) {
+            self.cpp.as_mut().begin_remove_columns(parent, first, last);
+        }
+
+        pub unsafe fn end_remove_columns(&mut self) {
+            self.cpp.as_mut().end_remove_columns();
+        }
+
+        pub unsafe fn begin_remove_rows(
+            &mut self,
+            parent: &cxx_qt_lib::QModelIndex,
+            first: i32,
+            last: i32,
+        ) {
+            self.cpp.as_mut().begin_remove_rows(parent, first, last);
+        }
+
+        pub unsafe fn end_remove_rows(&mut self) {
+            self.cpp.as_mut().end_remove_rows();
+        }
+
+        pub unsafe fn begin_reset_model(&mut self) {
+            self.cpp.as_mut().begin_reset_model();
+        }
+
+        pub unsafe fn end_reset_model(&mut self) {
+            self.cpp.as_mut().end_reset_model();
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {}
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    pub struct Data;
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(_value: &CppObj<'a>) -> Self {
+            Self {}
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(_value: &mut CppObj<'a>) -> Self {
+            Self::from(&*_value)
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
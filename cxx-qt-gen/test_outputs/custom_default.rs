@@ -15,9 +15,20 @@ mod ffi {
 
         #[cxx_name = "unsafeRust"]
         fn rust(self: &MyObjectQt) -> &MyObject;
-        #[rust_name = "new_cpp_object"]
+        #[rust_name = "new_cpp_object_my_object"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
         fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
     }
 
     extern "C++" {
@@ -31,16 +42,21 @@ mod ffi {
 
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn create_rs() -> Box<MyObject>;
+        fn create_rs_my_object() -> Box<MyObject>;
 
         #[cxx_name = "initialiseCpp"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn initialise_cpp(cpp: Pin<&mut MyObjectQt>);
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
     }
 }
 
-pub use self::cxx_qt_ffi::*;
-mod cxx_qt_ffi {
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
     use super::ffi::*;
 
     pub type FFICppObj = super::ffi::MyObjectQt;
@@ -69,11 +85,44 @@ mod cxx_qt_ffi {
             self.cpp.as_mut().set_public(value);
         }
 
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
         pub fn grab_values_from_data(&mut self, mut data: Data) {
             self.set_public(data.public);
         }
     }
 
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
     pub struct Data {
         public: i32,
     }
@@ -104,12 +153,12 @@ mod cxx_qt_ffi {
         }
     }
 
-    pub fn create_rs() -> std::boxed::Box<MyObject> {
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
         std::default::Default::default()
     }
 
-    pub fn initialise_cpp(cpp: std::pin::Pin<&mut FFICppObj>) {
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
         let mut wrapper = CppObj::new(cpp);
-        wrapper.grab_values_from_data(Data::default());
+        wrapper.grab_values_from_data(std::default::Default::default());
     }
 }
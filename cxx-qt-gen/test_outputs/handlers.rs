@@ -3,15 +3,11 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-gen/include/my_object.cxxqt.h");
         include!("cxx-qt-lib/include/convert.h");
-        include!("cxx-qt-lib/include/update_requester.h");
         include ! (< QtCore / QObject >);
 
         #[cxx_name = "MyObject"]
         type MyObjectQt;
 
-        #[namespace = "rust::cxxqtlib1"]
-        type UpdateRequester = cxx_qt_lib::UpdateRequesterCpp;
-
         #[rust_name = "number"]
         fn getNumber(self: &MyObjectQt) -> i32;
         #[rust_name = "set_number"]
@@ -24,10 +20,21 @@ mod ffi {
 
         #[cxx_name = "unsafeRust"]
         fn rust(self: &MyObjectQt) -> &MyObject;
-        #[rust_name = "new_cpp_object"]
+        #[rust_name = "new_cpp_object_my_object"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
         fn newCppObject() -> UniquePtr<MyObjectQt>;
 
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+
         #[rust_name = "update_requester"]
         fn updateRequester(self: Pin<&mut MyObjectQt>) -> UniquePtr<UpdateRequester>;
     }
@@ -43,16 +50,26 @@ mod ffi {
 
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn create_rs() -> Box<MyObject>;
+        fn create_rs_my_object() -> Box<MyObject>;
 
         #[cxx_name = "initialiseCpp"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn initialise_cpp(cpp: Pin<&mut MyObjectQt>);
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
 
         #[cxx_name = "handleUpdateRequest"]
         fn call_handle_update_request(self: &mut MyObject, cpp: Pin<&mut MyObjectQt>);
     }
 
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/update_requester.h");
+
+        #[namespace = "rust::cxxqtlib1"]
+        type UpdateRequester = cxx_qt_lib::UpdateRequesterCpp;
+    }
+
     #[namespace = ""]
     unsafe extern "C++" {
         include!("cxx-qt-lib/include/qt_types.h");
@@ -60,8 +77,10 @@ mod ffi {
     }
 }
 
-pub use self::cxx_qt_ffi::*;
-mod cxx_qt_ffi {
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
     use super::ffi::*;
 
     pub type FFICppObj = super::ffi::MyObjectQt;
@@ -108,12 +127,45 @@ mod cxx_qt_ffi {
             cxx_qt_lib::UpdateRequester::from_unique_ptr(self.cpp.as_mut().update_requester())
         }
 
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
         pub fn grab_values_from_data(&mut self, mut data: Data) {
             self.set_number(data.number);
             self.set_string(&data.string);
         }
     }
 
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
     #[derive(Default)]
     pub struct Data {
         number: i32,
@@ -141,12 +193,12 @@ mod cxx_qt_ffi {
         }
     }
 
-    pub fn create_rs() -> std::boxed::Box<MyObject> {
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
         std::default::Default::default()
     }
 
-    pub fn initialise_cpp(cpp: std::pin::Pin<&mut FFICppObj>) {
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
         let mut wrapper = CppObj::new(cpp);
-        wrapper.grab_values_from_data(Data::default());
+        wrapper.grab_values_from_data(std::default::Default::default());
     }
 }
@@ -5,12 +5,18 @@ namespace cxx_qt::my_object {
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
   , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
 {
   cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
   m_initialised = true;
 }
 
-MyObject::~MyObject() = default;
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
 
 const MyObjectRust&
 MyObject::unsafeRust() const
@@ -24,31 +30,38 @@ MyObject::unsafeRustMut()
   return *m_rustObj;
 }
 
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
 void
 MyObject::invokable()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokable();
 }
 
 void
 MyObject::invokableCppObj()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableCppObjWrapper(*this);
 }
 
 void
 MyObject::invokableMutable()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableMutable();
 }
 
 void
 MyObject::invokableMutableCppObj()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableMutableCppObjWrapper(*this);
 }
 
@@ -57,21 +70,21 @@ MyObject::invokableParameters(const QColor& opaque,
                               const QPoint& trivial,
                               qint32 primitive)
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableParameters(opaque, trivial, primitive);
 }
 
 void
 MyObject::invokableParametersCppObj(qint32 primitive)
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableParametersCppObjWrapper(primitive, *this);
 }
 
 QColor
 MyObject::invokableReturnOpaque()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   return rust::cxxqtlib1::cxx_qt_convert<QColor, std::unique_ptr<QColor>>{}(
     m_rustObj->invokableReturnOpaqueWrapper());
 }
@@ -79,7 +92,7 @@ MyObject::invokableReturnOpaque()
 qint32
 MyObject::invokableReturnPrimitive()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   return rust::cxxqtlib1::cxx_qt_convert<qint32, qint32>{}(
     m_rustObj->invokableReturnPrimitive());
 }
@@ -87,7 +100,7 @@ MyObject::invokableReturnPrimitive()
 QString
 MyObject::invokableReturnStatic()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   return rust::cxxqtlib1::cxx_qt_convert<QString, std::unique_ptr<QString>>{}(
     m_rustObj->invokableReturnStaticWrapper());
 }
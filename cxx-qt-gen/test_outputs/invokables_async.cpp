@@ -0,0 +1,195 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+void
+MyObject::invokableAsync()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->invokableAsyncWrapper(*this);
+}
+
+void
+MyObject::invokableAsyncPrimitive(qint32 value)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->invokableAsyncPrimitiveWrapper(*this, value);
+}
+
+void
+MyObject::invokableAsyncOpaque(bool known)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->invokableAsyncOpaqueWrapper(*this, known);
+}
+
+void
+MyObject::emitInvokableAsyncFinished()
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this, [this]() { Q_EMIT invokableAsyncFinished(); }, Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectInvokableAsyncFinished(
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncFinishedHandler>
+    handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectInvokableAsyncFinished(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectInvokableAsyncFinished(
+  MyObject& sender,
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncFinishedHandler>
+    handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::invokableAsyncFinished,
+      this,
+      [this, handler = std::move(handler)]() mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::callInvokableAsyncFinishedHandler(
+          *handler, *this);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+void
+MyObject::emitInvokableAsyncPrimitiveFinished(qint32 value)
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this,
+    [this, value = std::move(value)]() {
+      Q_EMIT invokableAsyncPrimitiveFinished(
+        rust::cxxqtlib1::cxx_qt_convert<qint32, qint32>{}(value));
+    },
+    Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectInvokableAsyncPrimitiveFinished(
+  rust::Box<
+    cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncPrimitiveFinishedHandler>
+    handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectInvokableAsyncPrimitiveFinished(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectInvokableAsyncPrimitiveFinished(
+  MyObject& sender,
+  rust::Box<
+    cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncPrimitiveFinishedHandler>
+    handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::invokableAsyncPrimitiveFinished,
+      this,
+      [this, handler = std::move(handler)](qint32 value) mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::
+          callInvokableAsyncPrimitiveFinishedHandler(*handler, *this, value);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+void
+MyObject::emitInvokableAsyncOpaqueFinished(std::unique_ptr<QString> value)
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this,
+    [this, value = std::move(value)]() {
+      Q_EMIT invokableAsyncOpaqueFinished(
+        rust::cxxqtlib1::cxx_qt_convert<const QString&,
+                                        std::unique_ptr<QString>>{}(value));
+    },
+    Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectInvokableAsyncOpaqueFinished(
+  rust::Box<
+    cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncOpaqueFinishedHandler>
+    handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectInvokableAsyncOpaqueFinished(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectInvokableAsyncOpaqueFinished(
+  MyObject& sender,
+  rust::Box<
+    cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncOpaqueFinishedHandler>
+    handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::invokableAsyncOpaqueFinished,
+      this,
+      [this, handler = std::move(handler)](const QString& value) mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::
+          callInvokableAsyncOpaqueFinishedHandler(*handler, *this, value);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
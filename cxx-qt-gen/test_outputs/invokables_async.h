@@ -0,0 +1,94 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/include/my_object.cxx.h"
+
+namespace cxx_qt::my_object {
+
+class MyObject : public QObject
+{
+  Q_OBJECT
+
+public:
+  explicit MyObject(QObject* parent = nullptr);
+  ~MyObject();
+  const MyObjectRust& unsafeRust() const;
+  MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
+
+public:
+  Q_INVOKABLE void invokableAsync();
+  Q_INVOKABLE void invokableAsyncPrimitive(qint32 value);
+  Q_INVOKABLE void invokableAsyncOpaque(bool known);
+  void emitInvokableAsyncFinished();
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+  connectInvokableAsyncFinished(
+    rust::Box<
+      cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncFinishedHandler>
+      handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+  connectInvokableAsyncFinished(
+    MyObject& sender,
+    rust::Box<
+      cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncFinishedHandler>
+      handler,
+    rust::cxxqtlib1::ConnectionType type);
+  void emitInvokableAsyncPrimitiveFinished(qint32 value);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+  connectInvokableAsyncPrimitiveFinished(
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::
+                InvokableAsyncPrimitiveFinishedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+  connectInvokableAsyncPrimitiveFinished(
+    MyObject& sender,
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::
+                InvokableAsyncPrimitiveFinishedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  void emitInvokableAsyncOpaqueFinished(std::unique_ptr<QString> value);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+  connectInvokableAsyncOpaqueFinished(
+    rust::Box<
+      cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncOpaqueFinishedHandler>
+      handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+  connectInvokableAsyncOpaqueFinished(
+    MyObject& sender,
+    rust::Box<
+      cxx_qt::my_object::cxx_qt_my_object::InvokableAsyncOpaqueFinishedHandler>
+      handler,
+    rust::cxxqtlib1::ConnectionType type);
+
+Q_SIGNALS:
+  void invokableAsyncFinished();
+  void invokableAsyncPrimitiveFinished(qint32 value);
+  void invokableAsyncOpaqueFinished(const QString& value);
+
+private:
+  rust::Box<MyObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject();
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,832 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        #[rust_name = "invokable_async_finished"]
+        fn invokableAsyncFinished(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "emit_invokable_async_finished"]
+        fn emitInvokableAsyncFinished(self: Pin<&mut MyObjectQt>);
+        #[rust_name = "connect_invokable_async_finished_handler"]
+        fn connectInvokableAsyncFinished(
+            self: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectInvokableAsyncFinishedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_invokable_async_finished_from_handler"]
+        fn connectInvokableAsyncFinished(
+            self: Pin<&mut MyObjectQt>,
+            sender: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectInvokableAsyncFinishedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+
+        #[rust_name = "invokable_async_primitive_finished"]
+        fn invokableAsyncPrimitiveFinished(self: Pin<&mut MyObjectQt>, value: i32);
+        #[rust_name = "emit_invokable_async_primitive_finished"]
+        fn emitInvokableAsyncPrimitiveFinished(self: Pin<&mut MyObjectQt>, value: i32);
+        #[rust_name = "connect_invokable_async_primitive_finished_handler"]
+        fn connectInvokableAsyncPrimitiveFinished(
+            self: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectInvokableAsyncPrimitiveFinishedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_invokable_async_primitive_finished_from_handler"]
+        fn connectInvokableAsyncPrimitiveFinished(
+            self: Pin<&mut MyObjectQt>,
+            sender: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectInvokableAsyncPrimitiveFinishedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+
+        #[rust_name = "invokable_async_opaque_finished"]
+        fn invokableAsyncOpaqueFinished(self: Pin<&mut MyObjectQt>, value: &QString);
+        #[rust_name = "emit_invokable_async_opaque_finished"]
+        fn emitInvokableAsyncOpaqueFinished(self: Pin<&mut MyObjectQt>, value: UniquePtr<QString>);
+        #[rust_name = "connect_invokable_async_opaque_finished_handler"]
+        fn connectInvokableAsyncOpaqueFinished(
+            self: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectInvokableAsyncOpaqueFinishedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_invokable_async_opaque_finished_from_handler"]
+        fn connectInvokableAsyncOpaqueFinished(
+            self: Pin<&mut MyObjectQt>,
+            sender: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectInvokableAsyncOpaqueFinishedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "invokableAsyncWrapper"]
+        fn invokable_async_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>);
+        #[cxx_name = "invokableAsyncPrimitiveWrapper"]
+        fn invokable_async_primitive_wrapper(
+            self: &MyObject,
+            cpp: Pin<&mut MyObjectQt>,
+            value: i32,
+        );
+        #[cxx_name = "invokableAsyncOpaqueWrapper"]
+        fn invokable_async_opaque_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>, known: bool);
+
+        #[cxx_name = "InvokableAsyncFinishedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectInvokableAsyncFinishedHandler;
+        #[cxx_name = "callInvokableAsyncFinishedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn call_my_object_invokable_async_finished_handler(
+            handler: &mut MyObjectInvokableAsyncFinishedHandler,
+            cpp: Pin<&mut MyObjectQt>,
+        );
+
+        #[cxx_name = "InvokableAsyncPrimitiveFinishedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectInvokableAsyncPrimitiveFinishedHandler;
+        #[cxx_name = "callInvokableAsyncPrimitiveFinishedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn call_my_object_invokable_async_primitive_finished_handler(
+            handler: &mut MyObjectInvokableAsyncPrimitiveFinishedHandler,
+            cpp: Pin<&mut MyObjectQt>,
+            value: i32,
+        );
+
+        #[cxx_name = "InvokableAsyncOpaqueFinishedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectInvokableAsyncOpaqueFinishedHandler;
+        #[cxx_name = "callInvokableAsyncOpaqueFinishedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn call_my_object_invokable_async_opaque_finished_handler(
+            handler: &mut MyObjectInvokableAsyncOpaqueFinishedHandler,
+            cpp: Pin<&mut MyObjectQt>,
+            value: &QString,
+        );
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/connection.h");
+
+        #[namespace = "rust::cxxqtlib1"]
+        type ConnectionType = cxx_qt_lib::ConnectionType;
+
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnectionCpp;
+    }
+
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+
+        type QString = cxx_qt_lib::QString;
+    }
+}
+pub use self::cxx_qt_my_object::{
+    call_my_object_invokable_async_finished_handler,
+    call_my_object_invokable_async_opaque_finished_handler,
+    call_my_object_invokable_async_primitive_finished_handler, create_rs_my_object,
+    initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+    MyObjectInvokableAsyncFinishedHandler, MyObjectInvokableAsyncOpaqueFinishedHandler,
+    MyObjectInvokableAsyncPrimitiveFinishedHandler,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl MyObject {
+        pub fn invokable_async_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>) {
+            let mut cpp = CppObj::new(cpp);
+            let qt_thread = std::sync::Mutex::new(cpp.qt_thread());
+            let task = cxx_qt_lib::Task::new(Self::invokable_async(), move |task| {
+                let _ = qt_thread.lock().unwrap().queue(move |mut cpp| {
+                    if task.poll().is_some() {
+                        cpp.cpp.as_mut().emit_invokable_async_finished();
+                    }
+                });
+            });
+            if task.poll().is_some() {
+                cpp.cpp.as_mut().emit_invokable_async_finished();
+            }
+        }
+
+        pub fn invokable_async_primitive_wrapper(
+            &self,
+            cpp: std::pin::Pin<&mut FFICppObj>,
+            value: i32,
+        ) {
+            let mut cpp = CppObj::new(cpp);
+            let qt_thread = std::sync::Mutex::new(cpp.qt_thread());
+            let task = cxx_qt_lib::Task::new(Self::invokable_async_primitive(value), move |task| {
+                let _ = qt_thread.lock().unwrap().queue(move |mut cpp| {
+                    if let Some(value) = task.poll() {
+                        cpp.cpp
+                            .as_mut()
+                            .emit_invokable_async_primitive_finished(value);
+                    }
+                });
+            });
+            if let Some(value) = task.poll() {
+                cpp.cpp
+                    .as_mut()
+                    .emit_invokable_async_primitive_finished(value);
+            }
+        }
+
+        pub fn invokable_async_opaque_wrapper(
+            &self,
+            cpp: std::pin::Pin<&mut FFICppObj>,
+            known: bool,
+        ) {
+            let mut cpp = CppObj::new(cpp);
+            let qt_thread = std::sync::Mutex::new(cpp.qt_thread());
+            let task = cxx_qt_lib::Task::new(Self::invokable_async_opaque(known), move |task| {
+                let _ = qt_thread.lock().unwrap().queue(move |mut cpp| {
+                    if let Some(value) = task.poll() {
+                        cpp.cpp.as_mut().emit_invokable_async_opaque_finished(value);
+                    }
+                });
+            });
+            if let Some(value) = task.poll() {
+                cpp.cpp.as_mut().emit_invokable_async_opaque_finished(value);
+            }
+        }
+
+        pub async fn invokable_async() {
+            println!("invokable_async");
+        }
+
+        pub async fn invokable_async_primitive(value: i32) -> i32 {
+            value * 2
+        }
+
+        pub async fn invokable_async_opaque(known: bool) -> UniquePtr<QString> {
+            if known {
+                QString::from_str("known")
+            } else {
+                QString::from_str("unknown")
+            }
+        }
+    }
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_invokable_async_finished_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_finished_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_invokable_async_finished<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_finished(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_invokable_async_finished_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_finished_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_invokable_async_finished_from<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_invokable_async_finished_from(
+                sender,
+                connection_type,
+                closure,
+            )
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_invokable_async_primitive_finished_queued<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_primitive_finished_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_invokable_async_primitive_finished<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_primitive_finished(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_invokable_async_primitive_finished_from_queued<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_primitive_finished_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_invokable_async_primitive_finished_from<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_primitive_finished_from(sender, connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_invokable_async_opaque_finished_queued<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_opaque_finished_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_invokable_async_opaque_finished<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_opaque_finished(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_invokable_async_opaque_finished_from_queued<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_opaque_finished_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_invokable_async_opaque_finished_from<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_invokable_async_opaque_finished_from(sender, connection_type, closure)
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {}
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    pub struct MyObjectInvokableAsyncFinishedHandler(Box<dyn FnMut(&mut CppObj)>);
+
+    pub fn call_my_object_invokable_async_finished_handler(
+        handler: &mut MyObjectInvokableAsyncFinishedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp);
+    }
+
+    pub struct MyObjectInvokableAsyncPrimitiveFinishedHandler(Box<dyn FnMut(&mut CppObj, i32)>);
+
+    pub fn call_my_object_invokable_async_primitive_finished_handler(
+        handler: &mut MyObjectInvokableAsyncPrimitiveFinishedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+        value: i32,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp, value);
+    }
+
+    pub struct MyObjectInvokableAsyncOpaqueFinishedHandler(Box<dyn FnMut(&mut CppObj, &QString)>);
+
+    pub fn call_my_object_invokable_async_opaque_finished_handler(
+        handler: &mut MyObjectInvokableAsyncOpaqueFinishedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+        value: &QString,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp, value);
+    }
+
+    impl FFICppObj {
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_invokable_async_finished_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_invokable_async_finished(
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_invokable_async_finished<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_invokable_async_finished_handler(
+                    Box::new(MyObjectInvokableAsyncFinishedHandler(Box::new(closure))),
+                    connection_type,
+                ),
+            )
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_invokable_async_finished_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_invokable_async_finished_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_invokable_async_finished_from<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_invokable_async_finished_from_handler(
+                    sender,
+                    Box::new(MyObjectInvokableAsyncFinishedHandler(Box::new(closure))),
+                    connection_type,
+                ),
+            )
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_invokable_async_primitive_finished_queued<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_invokable_async_primitive_finished(
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_invokable_async_primitive_finished<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_invokable_async_primitive_finished_handler(
+                    Box::new(MyObjectInvokableAsyncPrimitiveFinishedHandler(Box::new(
+                        closure,
+                    ))),
+                    connection_type,
+                ),
+            )
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_invokable_async_primitive_finished_from_queued<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_invokable_async_primitive_finished_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_invokable_async_primitive_finished_from<
+            F: FnMut(&mut CppObj, i32) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_invokable_async_primitive_finished_from_handler(
+                    sender,
+                    Box::new(MyObjectInvokableAsyncPrimitiveFinishedHandler(Box::new(
+                        closure,
+                    ))),
+                    connection_type,
+                ),
+            )
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_invokable_async_opaque_finished_queued<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_invokable_async_opaque_finished(
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_invokable_async_opaque_finished<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_invokable_async_opaque_finished_handler(
+                    Box::new(MyObjectInvokableAsyncOpaqueFinishedHandler(Box::new(
+                        closure,
+                    ))),
+                    connection_type,
+                ),
+            )
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_invokable_async_opaque_finished_from_queued<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_invokable_async_opaque_finished_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_invokable_async_opaque_finished_from<
+            F: FnMut(&mut CppObj, &QString) + 'static,
+        >(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_invokable_async_opaque_finished_from_handler(
+                    sender,
+                    Box::new(MyObjectInvokableAsyncOpaqueFinishedHandler(Box::new(
+                        closure,
+                    ))),
+                    connection_type,
+                ),
+            )
+        }
+    }
+
+    pub struct Data;
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(_value: &CppObj<'a>) -> Self {
+            Self {}
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(_value: &mut CppObj<'a>) -> Self {
+            Self::from(&*_value)
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
@@ -0,0 +1,86 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+void
+MyObject::invokableResult()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  try {
+    m_rustObj->invokableResult();
+  } catch (const rust::Error& error) {
+    rust::cxxqtlib1::throwError(this, QString::fromUtf8(error.what()));
+  }
+}
+
+qint32
+MyObject::invokableResultPrimitive(qint32 value)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  try {
+    return rust::cxxqtlib1::cxx_qt_convert<qint32, qint32>{}(
+      m_rustObj->invokableResultPrimitive(value));
+  } catch (const rust::Error& error) {
+    rust::cxxqtlib1::throwError(this, QString::fromUtf8(error.what()));
+    return {};
+  }
+}
+
+QString
+MyObject::invokableResultOpaque(const QString& string)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  try {
+    return rust::cxxqtlib1::cxx_qt_convert<QString,
+                                           std::unique_ptr<QString>>{}(
+      m_rustObj->invokableResultOpaqueWrapper(string));
+  } catch (const rust::Error& error) {
+    rust::cxxqtlib1::throwError(this, QString::fromUtf8(error.what()));
+    return {};
+  }
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
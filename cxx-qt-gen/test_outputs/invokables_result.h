@@ -0,0 +1,49 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/include/my_object.cxx.h"
+
+namespace cxx_qt::my_object {
+
+class MyObject : public QObject
+{
+  Q_OBJECT
+
+public:
+  explicit MyObject(QObject* parent = nullptr);
+  ~MyObject();
+  const MyObjectRust& unsafeRust() const;
+  MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
+
+public:
+  Q_INVOKABLE void invokableResult();
+  Q_INVOKABLE qint32 invokableResultPrimitive(qint32 value);
+  Q_INVOKABLE QString invokableResultOpaque(const QString& string);
+
+private:
+  rust::Box<MyObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject();
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
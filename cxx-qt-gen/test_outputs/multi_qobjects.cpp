@@ -0,0 +1,226 @@
+#include "cxx-qt-gen/include/first_object.cxxqt.h"
+
+namespace cxx_qt::multi_object {
+
+FirstObject::FirstObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::multi_object::cxx_qt_first_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<FirstObject>>(this))
+{
+  cxx_qt::multi_object::cxx_qt_first_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+FirstObject::~FirstObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const FirstObjectRust&
+FirstObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+FirstObjectRust&
+FirstObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<FirstObjectCxxQtThread>
+FirstObject::qtThread() const
+{
+  return std::make_unique<FirstObjectCxxQtThread>(m_cxxQtThreadObj,
+                                                  &m_rustObjMutex);
+}
+
+qint32
+FirstObject::getPropertyName() const
+{
+  return m_propertyName;
+}
+
+void
+FirstObject::setPropertyName(qint32 value)
+{
+  if (!m_initialised) {
+    m_propertyName = value;
+    return;
+  }
+
+  if (value != m_propertyName) {
+    m_propertyName = value;
+
+    const auto signalSuccess = QMetaObject::invokeMethod(
+      this, "propertyNameChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+void
+FirstObject::invokable()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->invokableWrapper(*this);
+}
+
+void
+FirstObject::emitAccepted()
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this, [this]() { Q_EMIT accepted(); }, Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+FirstObject::connectAccepted(
+  rust::Box<cxx_qt::multi_object::cxx_qt_first_object::AcceptedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectAccepted(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+FirstObject::connectAccepted(
+  FirstObject& sender,
+  rust::Box<cxx_qt::multi_object::cxx_qt_first_object::AcceptedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &FirstObject::accepted,
+      this,
+      [this, handler = std::move(handler)]() mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::multi_object::cxx_qt_first_object::callAcceptedHandler(
+          *handler, *this);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+} // namespace cxx_qt::multi_object
+
+namespace cxx_qt::multi_object::cxx_qt_first_object {
+std::unique_ptr<FirstObject>
+newCppObject()
+{
+  return std::make_unique<FirstObject>();
+}
+} // namespace cxx_qt::multi_object::cxx_qt_first_object
+
+namespace cxx_qt::multi_object {
+
+SecondObject::SecondObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::multi_object::cxx_qt_second_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<SecondObject>>(
+        this))
+{
+  cxx_qt::multi_object::cxx_qt_second_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+SecondObject::~SecondObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const SecondObjectRust&
+SecondObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+SecondObjectRust&
+SecondObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<SecondObjectCxxQtThread>
+SecondObject::qtThread() const
+{
+  return std::make_unique<SecondObjectCxxQtThread>(m_cxxQtThreadObj,
+                                                   &m_rustObjMutex);
+}
+
+qint32
+SecondObject::getPropertyName() const
+{
+  return m_propertyName;
+}
+
+void
+SecondObject::setPropertyName(qint32 value)
+{
+  if (!m_initialised) {
+    m_propertyName = value;
+    return;
+  }
+
+  if (value != m_propertyName) {
+    m_propertyName = value;
+
+    const auto signalSuccess = QMetaObject::invokeMethod(
+      this, "propertyNameChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+void
+SecondObject::invokable()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->invokableWrapper(*this);
+}
+
+void
+SecondObject::emitRejected()
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this, [this]() { Q_EMIT rejected(); }, Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+SecondObject::connectRejected(
+  rust::Box<cxx_qt::multi_object::cxx_qt_second_object::RejectedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectRejected(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+SecondObject::connectRejected(
+  SecondObject& sender,
+  rust::Box<cxx_qt::multi_object::cxx_qt_second_object::RejectedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &SecondObject::rejected,
+      this,
+      [this, handler = std::move(handler)]() mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::multi_object::cxx_qt_second_object::callRejectedHandler(
+          *handler, *this);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+} // namespace cxx_qt::multi_object
+
+namespace cxx_qt::multi_object::cxx_qt_second_object {
+std::unique_ptr<SecondObject>
+newCppObject()
+{
+  return std::make_unique<SecondObject>();
+}
+} // namespace cxx_qt::multi_object::cxx_qt_second_object
@@ -0,0 +1,124 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::multi_object {
+class FirstObject;
+using FirstObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<FirstObject>;
+} // namespace cxx_qt::multi_object
+namespace cxx_qt::multi_object {
+class SecondObject;
+using SecondObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<SecondObject>;
+} // namespace cxx_qt::multi_object
+
+#include "cxx-qt-gen/include/first_object.cxx.h"
+
+namespace cxx_qt::multi_object {
+
+class FirstObject : public QObject
+{
+  Q_OBJECT
+  Q_PROPERTY(qint32 propertyName READ getPropertyName WRITE setPropertyName
+               NOTIFY propertyNameChanged)
+
+public:
+  explicit FirstObject(QObject* parent = nullptr);
+  ~FirstObject();
+  const FirstObjectRust& unsafeRust() const;
+  FirstObjectRust& unsafeRustMut();
+  std::unique_ptr<FirstObjectCxxQtThread> qtThread() const;
+
+public:
+  qint32 getPropertyName() const;
+  Q_INVOKABLE void invokable();
+  void emitAccepted();
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectAccepted(
+    rust::Box<cxx_qt::multi_object::cxx_qt_first_object::AcceptedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectAccepted(
+    FirstObject& sender,
+    rust::Box<cxx_qt::multi_object::cxx_qt_first_object::AcceptedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+
+public Q_SLOTS:
+  void setPropertyName(qint32 value);
+
+Q_SIGNALS:
+  void propertyNameChanged();
+  void accepted();
+
+private:
+  rust::Box<FirstObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<FirstObject>>
+    m_cxxQtThreadObj;
+
+  qint32 m_propertyName;
+};
+
+} // namespace cxx_qt::multi_object
+
+namespace cxx_qt::multi_object::cxx_qt_first_object {
+std::unique_ptr<FirstObject>
+newCppObject();
+} // namespace cxx_qt::multi_object::cxx_qt_first_object
+
+Q_DECLARE_METATYPE(cxx_qt::multi_object::FirstObject*)
+
+namespace cxx_qt::multi_object {
+
+class SecondObject : public QObject
+{
+  Q_OBJECT
+  Q_PROPERTY(qint32 propertyName READ getPropertyName WRITE setPropertyName
+               NOTIFY propertyNameChanged)
+
+public:
+  explicit SecondObject(QObject* parent = nullptr);
+  ~SecondObject();
+  const SecondObjectRust& unsafeRust() const;
+  SecondObjectRust& unsafeRustMut();
+  std::unique_ptr<SecondObjectCxxQtThread> qtThread() const;
+
+public:
+  qint32 getPropertyName() const;
+  Q_INVOKABLE void invokable();
+  void emitRejected();
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectRejected(
+    rust::Box<cxx_qt::multi_object::cxx_qt_second_object::RejectedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectRejected(
+    SecondObject& sender,
+    rust::Box<cxx_qt::multi_object::cxx_qt_second_object::RejectedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+
+public Q_SLOTS:
+  void setPropertyName(qint32 value);
+
+Q_SIGNALS:
+  void propertyNameChanged();
+  void rejected();
+
+private:
+  rust::Box<SecondObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<SecondObject>>
+    m_cxxQtThreadObj;
+
+  qint32 m_propertyName;
+};
+
+} // namespace cxx_qt::multi_object
+
+namespace cxx_qt::multi_object::cxx_qt_second_object {
+std::unique_ptr<SecondObject>
+newCppObject();
+} // namespace cxx_qt::multi_object::cxx_qt_second_object
+
+Q_DECLARE_METATYPE(cxx_qt::multi_object::SecondObject*)
@@ -0,0 +1,636 @@
+#[cxx::bridge(namespace = "cxx_qt::multi_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/first_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+        #[cxx_name = "FirstObject"]
+        type FirstObjectQt;
+        #[rust_name = "property_name"]
+        fn getPropertyName(self: &FirstObjectQt) -> i32;
+        #[rust_name = "set_property_name"]
+        fn setPropertyName(self: Pin<&mut FirstObjectQt>, value: i32);
+        #[rust_name = "accepted"]
+        fn accepted(self: Pin<&mut FirstObjectQt>);
+        #[rust_name = "emit_accepted"]
+        fn emitAccepted(self: Pin<&mut FirstObjectQt>);
+        #[rust_name = "connect_accepted_handler"]
+        fn connectAccepted(
+            self: Pin<&mut FirstObjectQt>,
+            handler: Box<FirstObjectAcceptedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_accepted_from_handler"]
+        fn connectAccepted(
+            self: Pin<&mut FirstObjectQt>,
+            sender: Pin<&mut FirstObjectQt>,
+            handler: Box<FirstObjectAcceptedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &FirstObjectQt) -> &FirstObject;
+        #[rust_name = "new_cpp_object_first_object"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_first_object"]
+        fn newCppObject() -> UniquePtr<FirstObjectQt>;
+        type FirstObjectCxxQtThread;
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &FirstObjectQt) -> UniquePtr<FirstObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &FirstObjectCxxQtThread,
+            func: fn(Pin<&mut FirstObjectQt>, Box<FirstObjectCxxQtThreadQueuedFn>),
+            arg: Box<FirstObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut FirstObjectQt>) -> Pin<&mut FirstObject>;
+    }
+    extern "Rust" {
+        #[cxx_name = "FirstObjectRust"]
+        type FirstObject;
+        #[cxx_name = "invokableWrapper"]
+        fn invokable_wrapper(self: &FirstObject, cpp: Pin<&mut FirstObjectQt>);
+        #[cxx_name = "AcceptedHandler"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_first_object"]
+        type FirstObjectAcceptedHandler;
+        #[cxx_name = "callAcceptedHandler"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_first_object"]
+        fn call_first_object_accepted_handler(
+            handler: &mut FirstObjectAcceptedHandler,
+            cpp: Pin<&mut FirstObjectQt>,
+        );
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_first_object"]
+        fn create_rs_first_object() -> Box<FirstObject>;
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_first_object"]
+        fn initialise_cpp_first_object(cpp: Pin<&mut FirstObjectQt>);
+        #[namespace = "cxx_qt::multi_object::cxx_qt_first_object"]
+        type FirstObjectCxxQtThreadQueuedFn;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/first_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+        #[cxx_name = "SecondObject"]
+        type SecondObjectQt;
+        #[rust_name = "property_name"]
+        fn getPropertyName(self: &SecondObjectQt) -> i32;
+        #[rust_name = "set_property_name"]
+        fn setPropertyName(self: Pin<&mut SecondObjectQt>, value: i32);
+        #[rust_name = "rejected"]
+        fn rejected(self: Pin<&mut SecondObjectQt>);
+        #[rust_name = "emit_rejected"]
+        fn emitRejected(self: Pin<&mut SecondObjectQt>);
+        #[rust_name = "connect_rejected_handler"]
+        fn connectRejected(
+            self: Pin<&mut SecondObjectQt>,
+            handler: Box<SecondObjectRejectedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_rejected_from_handler"]
+        fn connectRejected(
+            self: Pin<&mut SecondObjectQt>,
+            sender: Pin<&mut SecondObjectQt>,
+            handler: Box<SecondObjectRejectedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &SecondObjectQt) -> &SecondObject;
+        #[rust_name = "new_cpp_object_second_object"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
+        fn newCppObject() -> UniquePtr<SecondObjectQt>;
+        type SecondObjectCxxQtThread;
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &SecondObjectQt) -> UniquePtr<SecondObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &SecondObjectCxxQtThread,
+            func: fn(Pin<&mut SecondObjectQt>, Box<SecondObjectCxxQtThreadQueuedFn>),
+            arg: Box<SecondObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut SecondObjectQt>) -> Pin<&mut SecondObject>;
+    }
+    extern "Rust" {
+        #[cxx_name = "SecondObjectRust"]
+        type SecondObject;
+        #[cxx_name = "invokableWrapper"]
+        fn invokable_wrapper(self: &SecondObject, cpp: Pin<&mut SecondObjectQt>);
+        #[cxx_name = "RejectedHandler"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
+        type SecondObjectRejectedHandler;
+        #[cxx_name = "callRejectedHandler"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
+        fn call_second_object_rejected_handler(
+            handler: &mut SecondObjectRejectedHandler,
+            cpp: Pin<&mut SecondObjectQt>,
+        );
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
+        fn create_rs_second_object() -> Box<SecondObject>;
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
+        fn initialise_cpp_second_object(cpp: Pin<&mut SecondObjectQt>);
+        #[namespace = "cxx_qt::multi_object::cxx_qt_second_object"]
+        type SecondObjectCxxQtThreadQueuedFn;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/connection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type ConnectionType = cxx_qt_lib::ConnectionType;
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnectionCpp;
+    }
+}
+pub use self::cxx_qt_first_object::{
+    call_first_object_accepted_handler, create_rs_first_object, initialise_cpp_first_object,
+    FirstData, FirstObject, FirstObjectAcceptedHandler, FirstObjectCxxQtThreadQueuedFn,
+    FirstSignals,
+};
+pub mod cxx_qt_first_object {
+    use super::ffi::*;
+    pub type FFICppObj = super::ffi::FirstObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+    pub enum FirstSignals {
+        Accepted,
+    }
+    #[derive(Default)]
+    pub struct FirstObject;
+    impl FirstObject {
+        pub fn invokable_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>) {
+            let mut cpp = CppObj::new(cpp);
+            self.invokable(&mut cpp);
+        }
+        pub fn invokable(&self, cpp: &mut CppObj) {
+            cpp.emit_queued(FirstSignals::Accepted);
+        }
+    }
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+        pub fn property_name(&self) -> i32 {
+            self.cpp.property_name()
+        }
+        pub fn set_property_name(&mut self, value: i32) {
+            self.cpp.as_mut().set_property_name(value);
+        }
+        pub fn emit_queued(&mut self, signal: FirstSignals) {
+            match signal {
+                FirstSignals::Accepted {} => self.cpp.as_mut().emit_accepted(),
+            }
+        }
+        pub unsafe fn emit_immediate(&mut self, signal: FirstSignals) {
+            match signal {
+                FirstSignals::Accepted {} => self.cpp.as_mut().accepted(),
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_accepted_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_accepted_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_accepted<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_accepted(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_accepted_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_accepted_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_accepted_from<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_accepted_from(sender, connection_type, closure)
+        }
+        pub fn qt_thread(&self) -> UniquePtr<FirstObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+        pub fn grab_values_from_data(&mut self, mut data: FirstData) {
+            self.set_property_name(data.property_name);
+        }
+    }
+    pub struct FirstObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+    impl FirstObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<FirstObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = FirstObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+    unsafe impl Send for FirstObjectCxxQtThread {}
+    pub struct FirstObjectAcceptedHandler(Box<dyn FnMut(&mut CppObj)>);
+    pub fn call_first_object_accepted_handler(
+        handler: &mut FirstObjectAcceptedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp);
+    }
+
+    impl FFICppObj {
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_accepted_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe { self.connect_accepted(cxx_qt_lib::ConnectionType::QueuedConnection, closure) }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_accepted<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_accepted_handler(
+                Box::new(FirstObjectAcceptedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_accepted_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_accepted_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_accepted_from<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_accepted_from_handler(
+                sender,
+                Box::new(FirstObjectAcceptedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+    }
+    #[derive(Default)]
+    pub struct FirstData {
+        property_name: i32,
+    }
+    impl<'a> From<&CppObj<'a>> for FirstData {
+        fn from(value: &CppObj<'a>) -> Self {
+            Self {
+                property_name: value.property_name().into(),
+            }
+        }
+    }
+    impl<'a> From<&mut CppObj<'a>> for FirstData {
+        fn from(value: &mut CppObj<'a>) -> Self {
+            Self::from(&*value)
+        }
+    }
+    pub fn create_rs_first_object() -> std::boxed::Box<FirstObject> {
+        std::default::Default::default()
+    }
+    pub fn initialise_cpp_first_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
+pub use self::cxx_qt_second_object::{
+    call_second_object_rejected_handler, create_rs_second_object, initialise_cpp_second_object,
+    SecondData, SecondObject, SecondObjectCxxQtThreadQueuedFn, SecondObjectRejectedHandler,
+    SecondSignals,
+};
+pub mod cxx_qt_second_object {
+    use super::ffi::*;
+    pub type FFICppObj = super::ffi::SecondObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+    pub enum SecondSignals {
+        Rejected,
+    }
+    #[derive(Default)]
+    pub struct SecondObject;
+    impl SecondObject {
+        pub fn invokable_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>) {
+            let mut cpp = CppObj::new(cpp);
+            self.invokable(&mut cpp);
+        }
+        pub fn invokable(&self, cpp: &mut CppObj) {
+            cpp.emit_queued(SecondSignals::Rejected);
+        }
+    }
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+        pub fn property_name(&self) -> i32 {
+            self.cpp.property_name()
+        }
+        pub fn set_property_name(&mut self, value: i32) {
+            self.cpp.as_mut().set_property_name(value);
+        }
+        pub fn emit_queued(&mut self, signal: SecondSignals) {
+            match signal {
+                SecondSignals::Rejected {} => self.cpp.as_mut().emit_rejected(),
+            }
+        }
+        pub unsafe fn emit_immediate(&mut self, signal: SecondSignals) {
+            match signal {
+                SecondSignals::Rejected {} => self.cpp.as_mut().rejected(),
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_rejected_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_rejected_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_rejected<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_rejected(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_rejected_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_rejected_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_rejected_from<F: FnMut(&mut CppObj) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_rejected_from(sender, connection_type, closure)
+        }
+        pub fn qt_thread(&self) -> UniquePtr<SecondObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+        pub fn grab_values_from_data(&mut self, mut data: SecondData) {
+            self.set_property_name(data.property_name);
+        }
+    }
+    pub struct SecondObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+    impl SecondObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<SecondObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = SecondObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+    unsafe impl Send for SecondObjectCxxQtThread {}
+    pub struct SecondObjectRejectedHandler(Box<dyn FnMut(&mut CppObj)>);
+    pub fn call_second_object_rejected_handler(
+        handler: &mut SecondObjectRejectedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp);
+    }
+
+    impl FFICppObj {
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_rejected_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe { self.connect_rejected(cxx_qt_lib::ConnectionType::QueuedConnection, closure) }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_rejected<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_rejected_handler(
+                Box::new(SecondObjectRejectedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_rejected_from_queued<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_rejected_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_rejected_from<F: FnMut(&mut CppObj) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_rejected_from_handler(
+                sender,
+                Box::new(SecondObjectRejectedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+    }
+    #[derive(Default)]
+    pub struct SecondData {
+        property_name: i32,
+    }
+    impl<'a> From<&CppObj<'a>> for SecondData {
+        fn from(value: &CppObj<'a>) -> Self {
+            Self {
+                property_name: value.property_name().into(),
+            }
+        }
+    }
+    impl<'a> From<&mut CppObj<'a>> for SecondData {
+        fn from(value: &mut CppObj<'a>) -> Self {
+            Self::from(&*value)
+        }
+    }
+    pub fn create_rs_second_object() -> std::boxed::Box<SecondObject> {
+        std::default::Default::default()
+    }
+    pub fn initialise_cpp_second_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
@@ -3,12 +3,18 @@
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
   , m_rustObj(cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
 {
   cxx_qt_my_object::initialiseCpp(*this);
   m_initialised = true;
 }
 
-MyObject::~MyObject() = default;
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
 
 const MyObjectRust&
 MyObject::unsafeRust() const
@@ -22,6 +28,13 @@ MyObject::unsafeRustMut()
   return *m_rustObj;
 }
 
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
 qint32
 MyObject::getPropertyName() const
 {
@@ -48,7 +61,7 @@ MyObject::setPropertyName(qint32 value)
 void
 MyObject::invokableName()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableName();
 }
 
@@ -1,10 +1,14 @@
 #pragma once
 
+#include <cstdint>
 #include <memory>
 #include <mutex>
 
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
 namespace cxx_qt::my_object {
 class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
 } // namespace cxx_qt::my_object
 
 #include "cxx-qt-gen/include/my_object.cxx.h"
@@ -23,6 +27,7 @@ public:
   ~MyObject();
   const MyObjectRust& unsafeRust() const;
   MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
 
 public:
   qint32 getPrimitive() const;
@@ -38,8 +43,10 @@ Q_SIGNALS:
 
 private:
   rust::Box<MyObjectRust> m_rustObj;
-  std::mutex m_rustObjMutex;
+  mutable std::recursive_mutex m_rustObjMutex;
   bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
 
   qint32 m_primitive;
   QColor m_opaque;
@@ -0,0 +1,138 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+qint32
+MyObject::getCount() const
+{
+  return m_count;
+}
+
+void
+MyObject::setCount(qint32 value)
+{
+  if (!m_initialised) {
+    m_count = value;
+    return;
+  }
+
+  if (value != m_count) {
+    m_count = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "countChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+qint32
+MyObject::getLimit() const
+{
+  return m_limit;
+}
+
+void
+MyObject::initialiseLimit(qint32 value)
+{
+  if (!m_initialised) {
+    m_limit = value;
+  }
+}
+
+double
+MyObject::getValue() const
+{
+  return m_value;
+}
+
+void
+MyObject::setValue(double value)
+{
+  if (!m_initialised) {
+    m_value = value;
+    return;
+  }
+
+  if (value != m_value) {
+    m_value = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "valuesChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+double
+MyObject::getOffset() const
+{
+  return m_offset;
+}
+
+void
+MyObject::setOffset(double value)
+{
+  if (!m_initialised) {
+    m_offset = value;
+    return;
+  }
+
+  if (value != m_offset) {
+    m_offset = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "valuesChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+void
+MyObject::resetValue()
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  m_rustObj->resetValueWrapper(*this);
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
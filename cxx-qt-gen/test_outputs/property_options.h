@@ -0,0 +1,71 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/include/my_object.cxx.h"
+
+namespace cxx_qt::my_object {
+
+class MyObject : public QObject
+{
+  Q_OBJECT
+  Q_PROPERTY(qint32 count READ getCount NOTIFY countChanged)
+  Q_PROPERTY(qint32 limit READ getLimit CONSTANT)
+  Q_PROPERTY(double value READ getValue WRITE setValue RESET resetValue NOTIFY
+               valuesChanged)
+  Q_PROPERTY(double offset READ getOffset WRITE setOffset NOTIFY valuesChanged)
+
+public:
+  explicit MyObject(QObject* parent = nullptr);
+  ~MyObject();
+  const MyObjectRust& unsafeRust() const;
+  MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
+
+public:
+  qint32 getCount() const;
+  void setCount(qint32 value);
+  qint32 getLimit() const;
+  void initialiseLimit(qint32 value);
+  double getValue() const;
+  double getOffset() const;
+  Q_INVOKABLE void resetValue();
+
+public Q_SLOTS:
+  void setValue(double value);
+  void setOffset(double value);
+
+Q_SIGNALS:
+  void countChanged();
+  void valuesChanged();
+
+private:
+  rust::Box<MyObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
+
+  qint32 m_count;
+  qint32 m_limit;
+  double m_value;
+  double m_offset;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject();
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,208 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        #[rust_name = "count"]
+        fn getCount(self: &MyObjectQt) -> i32;
+        #[rust_name = "set_count"]
+        fn setCount(self: Pin<&mut MyObjectQt>, value: i32);
+
+        #[rust_name = "limit"]
+        fn getLimit(self: &MyObjectQt) -> i32;
+        #[rust_name = "initialise_limit"]
+        fn initialiseLimit(self: Pin<&mut MyObjectQt>, value: i32);
+
+        #[rust_name = "value"]
+        fn getValue(self: &MyObjectQt) -> f64;
+        #[rust_name = "set_value"]
+        fn setValue(self: Pin<&mut MyObjectQt>, value: f64);
+
+        #[rust_name = "offset"]
+        fn getOffset(self: &MyObjectQt) -> f64;
+        #[rust_name = "set_offset"]
+        fn setOffset(self: Pin<&mut MyObjectQt>, value: f64);
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "resetValueWrapper"]
+        fn reset_value_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>);
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+}
+
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl MyObject {
+        pub fn reset_value_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>) {
+            let mut cpp = CppObj::new(cpp);
+            self.reset_value(&mut cpp);
+        }
+
+        pub fn reset_value(&self, cpp: &mut CppObj) {
+            cpp.set_value(0.0);
+        }
+    }
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        pub fn count(&self) -> i32 {
+            self.cpp.count()
+        }
+
+        pub fn set_count(&mut self, value: i32) {
+            self.cpp.as_mut().set_count(value);
+        }
+
+        pub fn limit(&self) -> i32 {
+            self.cpp.limit()
+        }
+
+        pub fn value(&self) -> f64 {
+            self.cpp.value()
+        }
+
+        pub fn set_value(&mut self, value: f64) {
+            self.cpp.as_mut().set_value(value);
+        }
+
+        pub fn offset(&self) -> f64 {
+            self.cpp.offset()
+        }
+
+        pub fn set_offset(&mut self, value: f64) {
+            self.cpp.as_mut().set_offset(value);
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {
+            self.set_count(data.count);
+            self.cpp.as_mut().initialise_limit(data.limit);
+            self.set_value(data.value);
+            self.set_offset(data.offset);
+        }
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    #[derive(Default)]
+    pub struct Data {
+        count: i32,
+        limit: i32,
+        value: f64,
+        offset: f64,
+    }
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(value: &CppObj<'a>) -> Self {
+            Self {
+                count: value.count().into(),
+                limit: value.limit().into(),
+                value: value.value().into(),
+                offset: value.offset().into(),
+            }
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(value: &mut CppObj<'a>) -> Self {
+            Self::from(&*value)
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
@@ -0,0 +1,119 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+State
+MyObject::getState() const
+{
+  return m_state;
+}
+
+void
+MyObject::setState(State value)
+{
+  if (!m_initialised) {
+    m_state = value;
+    return;
+  }
+
+  if (value != m_state) {
+    m_state = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "stateChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+State
+MyObject::nextState(State state)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<State, State>{}(
+    m_rustObj->nextStateWrapper(*this, state));
+}
+
+void
+MyObject::emitTransitioned(State state)
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this,
+    [this, state = std::move(state)]() {
+      Q_EMIT transitioned(
+        rust::cxxqtlib1::cxx_qt_convert<State, State>{}(state));
+    },
+    Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectTransitioned(
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::TransitionedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectTransitioned(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectTransitioned(
+  MyObject& sender,
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::TransitionedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::transitioned,
+      this,
+      [this, handler = std::move(handler)](State state) mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::callTransitionedHandler(
+          *handler, *this, state);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
@@ -0,0 +1,83 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
+enum class State : ::std::int32_t
+{
+  Idle = 0,
+  Running = 5,
+  Stopped = 6,
+};
+} // namespace cxx_qt::my_object
+
+#include "cxx-qt-gen/include/my_object.cxx.h"
+
+namespace cxx_qt::my_object {
+
+class MyObject : public QObject
+{
+  Q_OBJECT
+  Q_PROPERTY(State state READ getState WRITE setState NOTIFY stateChanged)
+
+public:
+  explicit MyObject(QObject* parent = nullptr);
+  ~MyObject();
+  const MyObjectRust& unsafeRust() const;
+  MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
+
+public:
+#ifdef Q_MOC_RUN
+  enum class State : ::std::int32_t
+  {
+    Idle = 0,
+    Running = 5,
+    Stopped = 6,
+  };
+#else
+  using State = ::cxx_qt::my_object::State;
+#endif
+  Q_ENUM(State)
+  State getState() const;
+  Q_INVOKABLE State nextState(State state);
+  void emitTransitioned(State state);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectTransitioned(
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::TransitionedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectTransitioned(
+    MyObject& sender,
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::TransitionedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+
+public Q_SLOTS:
+  void setState(State value);
+
+Q_SIGNALS:
+  void stateChanged();
+  void transitioned(State state);
+
+private:
+  rust::Box<MyObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
+
+  State m_state;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject();
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,395 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[repr(i32)]
+    enum State {
+        Idle,
+        Running = 5,
+        Stopped,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        type State;
+
+        #[rust_name = "state"]
+        fn getState(self: &MyObjectQt) -> State;
+        #[rust_name = "set_state"]
+        fn setState(self: Pin<&mut MyObjectQt>, value: State);
+
+        #[rust_name = "transitioned"]
+        fn transitioned(self: Pin<&mut MyObjectQt>, state: State);
+        #[rust_name = "emit_transitioned"]
+        fn emitTransitioned(self: Pin<&mut MyObjectQt>, state: State);
+        #[rust_name = "connect_transitioned_handler"]
+        fn connectTransitioned(
+            self: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectTransitionedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_transitioned_from_handler"]
+        fn connectTransitioned(
+            self: Pin<&mut MyObjectQt>,
+            sender: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectTransitionedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "nextStateWrapper"]
+        fn next_state_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>, state: State) -> State;
+
+        #[cxx_name = "TransitionedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectTransitionedHandler;
+
+        #[cxx_name = "callTransitionedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn call_my_object_transitioned_handler(
+            handler: &mut MyObjectTransitionedHandler,
+            cpp: Pin<&mut MyObjectQt>,
+            state: State,
+        );
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/connection.h");
+
+        #[namespace = "rust::cxxqtlib1"]
+        type ConnectionType = cxx_qt_lib::ConnectionType;
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnectionCpp;
+    }
+}
+
+pub use self::cxx_qt_my_object::{
+    call_my_object_transitioned_handler, create_rs_my_object, initialise_cpp_my_object, Data,
+    MyObject, MyObjectCxxQtThreadQueuedFn, MyObjectTransitionedHandler,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    enum MySignals {
+        Transitioned { state: State },
+    }
+
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl MyObject {
+        pub fn next_state_wrapper(
+            &self,
+            cpp: std::pin::Pin<&mut FFICppObj>,
+            state: State,
+        ) -> State {
+            let mut cpp = CppObj::new(cpp);
+            return self.next_state(&mut cpp, state);
+        }
+
+        pub fn next_state(&self, cpp: &mut CppObj, state: State) -> State {
+            let next = match state {
+                State::Idle => State::Running,
+                _others => State::Stopped,
+            };
+
+            cpp.emit_queued(MySignals::Transitioned { state: next });
+
+            next
+        }
+    }
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        pub fn state(&self) -> State {
+            self.cpp.state()
+        }
+
+        pub fn set_state(&mut self, value: State) {
+            self.cpp.as_mut().set_state(value);
+        }
+
+        pub fn emit_queued(&mut self, signal: MySignals) {
+            match signal {
+                MySignals::Transitioned { state } => self.cpp.as_mut().emit_transitioned(state),
+            }
+        }
+
+        pub unsafe fn emit_immediate(&mut self, signal: MySignals) {
+            match signal {
+                MySignals::Transitioned { state } => self.cpp.as_mut().transitioned(state),
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_transitioned_queued<F: FnMut(&mut CppObj, State) + 'static>(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_transitioned_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_transitioned<F: FnMut(&mut CppObj, State) + 'static>(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_transitioned(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_transitioned_from_queued<F: FnMut(&mut CppObj, State) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_transitioned_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_transitioned_from<F: FnMut(&mut CppObj, State) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_transitioned_from(sender, connection_type, closure)
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {
+            self.set_state(data.state);
+        }
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    pub struct MyObjectTransitionedHandler(Box<dyn FnMut(&mut CppObj, State)>);
+
+    pub fn call_my_object_transitioned_handler(
+        handler: &mut MyObjectTransitionedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+        state: State,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp, state);
+    }
+
+    impl FFICppObj {
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_transitioned_queued<F: FnMut(&mut CppObj, State) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_transitioned(cxx_qt_lib::ConnectionType::QueuedConnection, closure)
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_transitioned<F: FnMut(&mut CppObj, State) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_transitioned_handler(
+                Box::new(MyObjectTransitionedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_transitioned_from_queued<F: FnMut(&mut CppObj, State) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_transitioned_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_transitioned_from<F: FnMut(&mut CppObj, State) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(
+                self.connect_transitioned_from_handler(
+                    sender,
+                    Box::new(MyObjectTransitionedHandler(Box::new(closure))),
+                    connection_type,
+                ),
+            )
+        }
+    }
+
+    pub struct Data {
+        state: State,
+    }
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(value: &CppObj<'a>) -> Self {
+            Self {
+                state: value.state().into(),
+            }
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(value: &mut CppObj<'a>) -> Self {
+            Self::from(&*value)
+        }
+    }
+
+    impl Default for Data {
+        fn default() -> Self {
+            Self { state: State::Idle }
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
@@ -0,0 +1,79 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+Direction
+MyObject::getDirection() const
+{
+  return m_direction;
+}
+
+void
+MyObject::setDirection(Direction value)
+{
+  if (!m_initialised) {
+    m_direction = value;
+    return;
+  }
+
+  if (value != m_direction) {
+    m_direction = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "directionChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+Direction
+MyObject::reverse(Direction direction)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<Direction, Direction>{}(
+    m_rustObj->reverse(direction));
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
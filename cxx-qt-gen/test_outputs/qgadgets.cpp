@@ -0,0 +1,119 @@
+#include "cxx-qt-gen/include/my_object.cxxqt.h"
+
+namespace cxx_qt::my_object {
+
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
+{
+  cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
+  m_initialised = true;
+}
+
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
+
+const MyObjectRust&
+MyObject::unsafeRust() const
+{
+  return *m_rustObj;
+}
+
+MyObjectRust&
+MyObject::unsafeRustMut()
+{
+  return *m_rustObj;
+}
+
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
+Reading
+MyObject::getReading() const
+{
+  return m_reading;
+}
+
+void
+MyObject::setReading(Reading value)
+{
+  if (!m_initialised) {
+    m_reading = value;
+    return;
+  }
+
+  if (value != m_reading) {
+    m_reading = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "readingChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+double
+MyObject::record(Reading reading)
+{
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+  return rust::cxxqtlib1::cxx_qt_convert<double, double>{}(
+    m_rustObj->recordWrapper(*this, reading));
+}
+
+void
+MyObject::emitRecorded(Reading reading)
+{
+  const auto signalSuccess = QMetaObject::invokeMethod(
+    this,
+    [this, reading = std::move(reading)]() {
+      Q_EMIT recorded(
+        rust::cxxqtlib1::cxx_qt_convert<Reading, Reading>{}(reading));
+    },
+    Qt::QueuedConnection);
+  Q_ASSERT(signalSuccess);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectRecorded(
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::RecordedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectRecorded(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectRecorded(
+  MyObject& sender,
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::RecordedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::recorded,
+      this,
+      [this, handler = std::move(handler)](Reading reading) mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::callRecordedHandler(
+          *handler, *this, reading);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject()
+{
+  return std::make_unique<MyObject>();
+}
+} // namespace cxx_qt::my_object::cxx_qt_my_object
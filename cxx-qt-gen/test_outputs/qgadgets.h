@@ -0,0 +1,93 @@
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
+namespace cxx_qt::my_object {
+class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
+} // namespace cxx_qt::my_object
+
+#include <QtCore/QObject>
+
+namespace cxx_qt::my_object {
+struct Reading
+{
+  Q_GADGET
+  Q_PROPERTY(qint32 sensorId MEMBER sensor_id)
+  Q_PROPERTY(double timestamp MEMBER timestamp)
+  Q_PROPERTY(double value MEMBER value)
+
+public:
+  qint32 sensor_id;
+  double timestamp;
+  double value;
+
+  bool operator==(const Reading& other) const
+  {
+    return sensor_id == other.sensor_id && timestamp == other.timestamp &&
+           value == other.value;
+  }
+  bool operator!=(const Reading& other) const { return !(*this == other); }
+};
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::Reading)
+
+#include "cxx-qt-gen/include/my_object.cxx.h"
+
+namespace cxx_qt::my_object {
+
+class MyObject : public QObject
+{
+  Q_OBJECT
+  Q_PROPERTY(
+    Reading reading READ getReading WRITE setReading NOTIFY readingChanged)
+
+public:
+  explicit MyObject(QObject* parent = nullptr);
+  ~MyObject();
+  const MyObjectRust& unsafeRust() const;
+  MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
+
+public:
+  Reading getReading() const;
+  Q_INVOKABLE double record(Reading reading);
+  void emitRecorded(Reading reading);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectRecorded(
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::RecordedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectRecorded(
+    MyObject& sender,
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::RecordedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+
+public Q_SLOTS:
+  void setReading(Reading value);
+
+Q_SIGNALS:
+  void readingChanged();
+  void recorded(Reading reading);
+
+private:
+  rust::Box<MyObjectRust> m_rustObj;
+  mutable std::recursive_mutex m_rustObjMutex;
+  bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
+
+  Reading m_reading;
+};
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+std::unique_ptr<MyObject>
+newCppObject();
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
@@ -0,0 +1,379 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    #[derive(Clone, Copy, Default)]
+    struct Reading {
+        sensor_id: i32,
+        timestamp: f64,
+        value: f64,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+
+        type Reading;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-gen/include/my_object.cxxqt.h");
+        include!("cxx-qt-lib/include/convert.h");
+        include ! (< QtCore / QObject >);
+
+        #[cxx_name = "MyObject"]
+        type MyObjectQt;
+
+        #[rust_name = "reading"]
+        fn getReading(self: &MyObjectQt) -> Reading;
+        #[rust_name = "set_reading"]
+        fn setReading(self: Pin<&mut MyObjectQt>, value: Reading);
+
+        #[rust_name = "recorded"]
+        fn recorded(self: Pin<&mut MyObjectQt>, reading: Reading);
+        #[rust_name = "emit_recorded"]
+        fn emitRecorded(self: Pin<&mut MyObjectQt>, reading: Reading);
+        #[rust_name = "connect_recorded_handler"]
+        fn connectRecorded(
+            self: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectRecordedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+        #[rust_name = "connect_recorded_from_handler"]
+        fn connectRecorded(
+            self: Pin<&mut MyObjectQt>,
+            sender: Pin<&mut MyObjectQt>,
+            handler: Box<MyObjectRecordedHandler>,
+            connection_type: ConnectionType,
+        ) -> UniquePtr<QMetaObjectConnection>;
+
+        #[cxx_name = "unsafeRust"]
+        fn rust(self: &MyObjectQt) -> &MyObject;
+        #[rust_name = "new_cpp_object_my_object"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
+    }
+
+    extern "C++" {
+        #[cxx_name = "unsafeRustMut"]
+        unsafe fn rust_mut(self: Pin<&mut MyObjectQt>) -> Pin<&mut MyObject>;
+    }
+
+    extern "Rust" {
+        #[cxx_name = "MyObjectRust"]
+        type MyObject;
+
+        #[cxx_name = "recordWrapper"]
+        fn record_wrapper(self: &MyObject, cpp: Pin<&mut MyObjectQt>, reading: Reading) -> f64;
+
+        #[cxx_name = "RecordedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectRecordedHandler;
+
+        #[cxx_name = "callRecordedHandler"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn call_my_object_recorded_handler(
+            handler: &mut MyObjectRecordedHandler,
+            cpp: Pin<&mut MyObjectQt>,
+            reading: Reading,
+        );
+
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn create_rs_my_object() -> Box<MyObject>;
+
+        #[cxx_name = "initialiseCpp"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/connection.h");
+
+        #[namespace = "rust::cxxqtlib1"]
+        type ConnectionType = cxx_qt_lib::ConnectionType;
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnectionCpp;
+    }
+}
+
+pub use self::cxx_qt_my_object::{
+    call_my_object_recorded_handler, create_rs_my_object, initialise_cpp_my_object, Data, MyObject,
+    MyObjectCxxQtThreadQueuedFn, MyObjectRecordedHandler,
+};
+pub mod cxx_qt_my_object {
+    use super::ffi::*;
+
+    pub type FFICppObj = super::ffi::MyObjectQt;
+    type UniquePtr<T> = cxx::UniquePtr<T>;
+
+    enum MySignals {
+        Recorded { reading: Reading },
+    }
+
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl MyObject {
+        pub fn record_wrapper(&self, cpp: std::pin::Pin<&mut FFICppObj>, reading: Reading) -> f64 {
+            let mut cpp = CppObj::new(cpp);
+            return self.record(&mut cpp, reading);
+        }
+
+        pub fn record(&self, cpp: &mut CppObj, reading: Reading) -> f64 {
+            cpp.set_reading(reading);
+            cpp.emit_queued(MySignals::Recorded { reading });
+            reading.value
+        }
+    }
+
+    pub struct CppObj<'a> {
+        cpp: std::pin::Pin<&'a mut FFICppObj>,
+    }
+
+    impl<'a> CppObj<'a> {
+        pub fn new(cpp: std::pin::Pin<&'a mut FFICppObj>) -> Self {
+            Self { cpp }
+        }
+
+        pub fn reading(&self) -> Reading {
+            self.cpp.reading()
+        }
+
+        pub fn set_reading(&mut self, value: Reading) {
+            self.cpp.as_mut().set_reading(value);
+        }
+
+        pub fn emit_queued(&mut self, signal: MySignals) {
+            match signal {
+                MySignals::Recorded { reading } => self.cpp.as_mut().emit_recorded(reading),
+            }
+        }
+
+        pub unsafe fn emit_immediate(&mut self, signal: MySignals) {
+            match signal {
+                MySignals::Recorded { reading } => self.cpp.as_mut().recorded(reading),
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_recorded_queued<F: FnMut(&mut CppObj, Reading) + 'static>(
+            &mut self,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_recorded_queued(closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_recorded<F: FnMut(&mut CppObj, Reading) + 'static>(
+            &mut self,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp.as_mut().connect_recorded(connection_type, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        pub fn connect_recorded_from_queued<F: FnMut(&mut CppObj, Reading) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_recorded_from_queued(sender, closure)
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" see the method of the same name on the C++ object."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" A direct or auto connection can call the closure while Rust is borrowing the QObject."]
+        pub unsafe fn connect_recorded_from<F: FnMut(&mut CppObj, Reading) + 'static>(
+            &mut self,
+            sender: std::pin::Pin<&mut FFICppObj>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            self.cpp
+                .as_mut()
+                .connect_recorded_from(sender, connection_type, closure)
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
+        pub fn grab_values_from_data(&mut self, mut data: Data) {
+            self.set_reading(data.reading);
+        }
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
+    pub struct MyObjectRecordedHandler(Box<dyn FnMut(&mut CppObj, Reading)>);
+
+    pub fn call_my_object_recorded_handler(
+        handler: &mut MyObjectRecordedHandler,
+        cpp: std::pin::Pin<&mut FFICppObj>,
+        reading: Reading,
+    ) {
+        let mut cpp = CppObj::new(cpp);
+        (handler.0)(&mut cpp, reading);
+    }
+
+    impl FFICppObj {
+        #[doc = r" Connect the given closure to the signal with a queued connection, the closure"]
+        #[doc = r" is called with the parameters of the signal from the event loop of the thread"]
+        #[doc = r" of the QObject each time that the signal is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        pub fn connect_recorded_queued<F: FnMut(&mut CppObj, Reading) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe { self.connect_recorded(cxx_qt_lib::ConnectionType::QueuedConnection, closure) }
+        }
+
+        #[doc = r" Connect the given closure to the signal with the given connection type, the closure"]
+        #[doc = r" is called with the parameters of the signal each time that it is emitted."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called while the signal is emitted,"]
+        #[doc = r" so as with emit_immediate the object may already be borrowed by Rust,"]
+        #[doc = r" eg when the signal is emitted by a setter that is called from Rust."]
+        #[doc = r" An auto connection is direct when the signal is emitted from the thread of the QObject,"]
+        #[doc = r" and a blocking queued connection deadlocks when it is."]
+        pub unsafe fn connect_recorded<F: FnMut(&mut CppObj, Reading) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_recorded_handler(
+                Box::new(MyObjectRecordedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with a queued connection,"]
+        #[doc = r" the closure is called with this QObject rather than the sender, from the event"]
+        #[doc = r" loop of the thread of this QObject each time that the sender emits the signal."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        pub fn connect_recorded_from_queued<F: FnMut(&mut CppObj, Reading) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            unsafe {
+                self.connect_recorded_from(
+                    sender,
+                    cxx_qt_lib::ConnectionType::QueuedConnection,
+                    closure,
+                )
+            }
+        }
+
+        #[doc = r" Connect the given closure to the signal of the sender with the given connection type,"]
+        #[doc = r" the closure is called with this QObject rather than the sender."]
+        #[doc = r""]
+        #[doc = r" The connection is disconnected when the returned QMetaObjectConnection is dropped,"]
+        #[doc = r" or when either QObject is destroyed."]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" With a direct connection the closure is called on the thread that emits the signal,"]
+        #[doc = r" which may not be the thread of this QObject, and this QObject may already be borrowed by Rust."]
+        #[doc = r" An auto connection is direct when the sender and this QObject are on the same thread."]
+        pub unsafe fn connect_recorded_from<F: FnMut(&mut CppObj, Reading) + 'static>(
+            self: std::pin::Pin<&mut Self>,
+            sender: std::pin::Pin<&mut Self>,
+            connection_type: cxx_qt_lib::ConnectionType,
+            closure: F,
+        ) -> cxx_qt_lib::QMetaObjectConnection {
+            cxx_qt_lib::QMetaObjectConnection::from_unique_ptr(self.connect_recorded_from_handler(
+                sender,
+                Box::new(MyObjectRecordedHandler(Box::new(closure))),
+                connection_type,
+            ))
+        }
+    }
+
+    #[derive(Default)]
+    pub struct Data {
+        reading: Reading,
+    }
+
+    impl<'a> From<&CppObj<'a>> for Data {
+        fn from(value: &CppObj<'a>) -> Self {
+            Self {
+                reading: value.reading().into(),
+            }
+        }
+    }
+
+    impl<'a> From<&mut CppObj<'a>> for Data {
+        fn from(value: &mut CppObj<'a>) -> Self {
+            Self::from(&*value)
+        }
+    }
+
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
+        std::default::Default::default()
+    }
+
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
+        let mut wrapper = CppObj::new(cpp);
+        wrapper.grab_values_from_data(std::default::Default::default());
+    }
+}
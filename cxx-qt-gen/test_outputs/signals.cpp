@@ -5,12 +5,18 @@ namespace cxx_qt::my_object {
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
   , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
 {
   cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
   m_initialised = true;
 }
 
-MyObject::~MyObject() = default;
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
 
 const MyObjectRust&
 MyObject::unsafeRust() const
@@ -24,10 +30,17 @@ MyObject::unsafeRustMut()
   return *m_rustObj;
 }
 
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
 void
 MyObject::invokable()
 {
-  const std::lock_guard<std::mutex> guard(m_rustObjMutex);
+  const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
   m_rustObj->invokableWrapper(*this);
 }
 
@@ -39,6 +52,32 @@ MyObject::emitReady()
   Q_ASSERT(signalSuccess);
 }
 
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectReady(
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::ReadyHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectReady(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectReady(
+  MyObject& sender,
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::ReadyHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::ready,
+      this,
+      [this, handler = std::move(handler)]() mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::callReadyHandler(*handler, *this);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
 void
 MyObject::emitDataChanged(qint32 first,
                           std::unique_ptr<QVariant> second,
@@ -60,6 +99,34 @@ MyObject::emitDataChanged(qint32 first,
   Q_ASSERT(signalSuccess);
 }
 
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectDataChanged(
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::DataChangedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return connectDataChanged(*this, std::move(handler), type);
+}
+
+std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection>
+MyObject::connectDataChanged(
+  MyObject& sender,
+  rust::Box<cxx_qt::my_object::cxx_qt_my_object::DataChangedHandler> handler,
+  rust::cxxqtlib1::ConnectionType type)
+{
+  return std::make_unique<rust::cxxqtlib1::QMetaObjectConnection>(
+    QObject::connect(
+      &sender,
+      &MyObject::dataChanged,
+      this,
+      [this, handler = std::move(handler)](
+        qint32 first, const QVariant& second, const QPoint& third) mutable {
+        const std::lock_guard<std::recursive_mutex> guard(m_rustObjMutex);
+        cxx_qt::my_object::cxx_qt_my_object::callDataChangedHandler(
+          *handler, *this, first, second, third);
+      },
+      static_cast<Qt::ConnectionType>(type)));
+}
+
 } // namespace cxx_qt::my_object
 
 namespace cxx_qt::my_object::cxx_qt_my_object {
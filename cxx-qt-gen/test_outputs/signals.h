@@ -1,10 +1,14 @@
 #pragma once
 
+#include <cstdint>
 #include <memory>
 #include <mutex>
 
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
 namespace cxx_qt::my_object {
 class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
 } // namespace cxx_qt::my_object
 
 #include "cxx-qt-gen/include/my_object.cxx.h"
@@ -20,13 +24,28 @@ public:
   ~MyObject();
   const MyObjectRust& unsafeRust() const;
   MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
 
 public:
   Q_INVOKABLE void invokable();
   void emitReady();
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectReady(
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::ReadyHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectReady(
+    MyObject& sender,
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::ReadyHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
   void emitDataChanged(qint32 first,
                        std::unique_ptr<QVariant> second,
                        QPoint third);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectDataChanged(
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::DataChangedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
+  std::unique_ptr<rust::cxxqtlib1::QMetaObjectConnection> connectDataChanged(
+    MyObject& sender,
+    rust::Box<cxx_qt::my_object::cxx_qt_my_object::DataChangedHandler> handler,
+    rust::cxxqtlib1::ConnectionType type);
 
 Q_SIGNALS:
   void ready();
@@ -34,8 +53,10 @@ Q_SIGNALS:
 
 private:
   rust::Box<MyObjectRust> m_rustObj;
-  std::mutex m_rustObjMutex;
+  mutable std::recursive_mutex m_rustObjMutex;
   bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
 };
 
 } // namespace cxx_qt::my_object
@@ -1,10 +1,14 @@
 #pragma once
 
+#include <cstdint>
 #include <memory>
 #include <mutex>
 
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
 namespace cxx_qt::my_object {
 class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
 } // namespace cxx_qt::my_object
 
 #include "cxx-qt-gen/include/my_object.cxx.h"
@@ -20,8 +24,10 @@ public:
   ~MyObject();
   const MyObjectRust& unsafeRust() const;
   MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
 
 public:
+  Q_INVOKABLE QByteArray testByteArray(const QByteArray& byte_array);
   Q_INVOKABLE QColor testColor(const QColor& color);
   Q_INVOKABLE QDate testDate(const QDate& date);
   Q_INVOKABLE QDateTime testDateTime(const QDateTime& dateTime);
@@ -35,11 +41,16 @@ public:
   Q_INVOKABLE QTime testTime(const QTime& time);
   Q_INVOKABLE QUrl testUrl(const QUrl& url);
   Q_INVOKABLE QVariant testVariant(const QVariant& variant);
+  Q_INVOKABLE QVariantHash testVariantHash(const QVariantHash& hash);
+  Q_INVOKABLE QVariantMap testVariantMap(const QVariantMap& map);
+  Q_INVOKABLE QVector<QString> testVector(const QVector<QString>& vector);
 
 private:
   rust::Box<MyObjectRust> m_rustObj;
-  std::mutex m_rustObjMutex;
+  mutable std::recursive_mutex m_rustObjMutex;
   bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
 };
 
 } // namespace cxx_qt::my_object
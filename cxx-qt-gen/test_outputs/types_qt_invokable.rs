@@ -10,9 +10,20 @@ mod ffi {
 
         #[cxx_name = "unsafeRust"]
         fn rust(self: &MyObjectQt) -> &MyObject;
-        #[rust_name = "new_cpp_object"]
+        #[rust_name = "new_cpp_object_my_object"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
         fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
     }
 
     extern "C++" {
@@ -24,6 +35,13 @@ mod ffi {
         #[cxx_name = "MyObjectRust"]
         type MyObject;
 
+        #[cxx_name = "testByteArrayWrapper"]
+        fn test_byte_array_wrapper(
+            self: &MyObject,
+            _cpp: Pin<&mut MyObjectQt>,
+            byte_array: &QByteArray,
+        ) -> UniquePtr<QByteArray>;
+
         #[cxx_name = "testColorWrapper"]
         fn test_color_wrapper(
             self: &MyObject,
@@ -99,18 +117,43 @@ mod ffi {
             variant: &QVariant,
         ) -> UniquePtr<QVariant>;
 
+        #[cxx_name = "testVariantHashWrapper"]
+        fn test_variant_hash_wrapper(
+            self: &MyObject,
+            _cpp: Pin<&mut MyObjectQt>,
+            hash: &QVariantHash,
+        ) -> UniquePtr<QVariantHash>;
+
+        #[cxx_name = "testVariantMapWrapper"]
+        fn test_variant_map_wrapper(
+            self: &MyObject,
+            _cpp: Pin<&mut MyObjectQt>,
+            map: &QVariantMap,
+        ) -> UniquePtr<QVariantMap>;
+
+        #[cxx_name = "testVectorWrapper"]
+        fn test_vector_wrapper(
+            self: &MyObject,
+            _cpp: Pin<&mut MyObjectQt>,
+            vector: &QVector_QString,
+        ) -> UniquePtr<QVector_QString>;
+
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn create_rs() -> Box<MyObject>;
+        fn create_rs_my_object() -> Box<MyObject>;
 
         #[cxx_name = "initialiseCpp"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn initialise_cpp(cpp: Pin<&mut MyObjectQt>);
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
     }
 
     #[namespace = ""]
     unsafe extern "C++" {
         include!("cxx-qt-lib/include/qt_types.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
         type QColor = cxx_qt_lib::QColor;
         type QDate = cxx_qt_lib::QDate;
         type QDateTime = cxx_qt_lib::QDateTime;
@@ -124,11 +167,22 @@ mod ffi {
         type QTime = cxx_qt_lib::QTime;
         type QUrl = cxx_qt_lib::QUrl;
         type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib/include/qhash.h");
+        type QVariantHash = cxx_qt_lib::QVariantHash;
+
+        include!("cxx-qt-lib/include/qmap.h");
+        type QVariantMap = cxx_qt_lib::QVariantMap;
+
+        include!("cxx-qt-lib/include/qvector.h");
+        type QVector_QString = cxx_qt_lib::QVector_QString;
     }
 }
 
-pub use self::cxx_qt_ffi::*;
-mod cxx_qt_ffi {
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
     use super::ffi::*;
 
     pub type FFICppObj = super::ffi::MyObjectQt;
@@ -138,6 +192,15 @@ mod cxx_qt_ffi {
     pub struct MyObject;
 
     impl MyObject {
+        pub fn test_byte_array_wrapper(
+            &self,
+            _cpp: std::pin::Pin<&mut FFICppObj>,
+            byte_array: &cxx_qt_lib::QByteArray,
+        ) -> UniquePtr<cxx_qt_lib::QByteArray> {
+            let mut _cpp = CppObj::new(_cpp);
+            return self.test_byte_array(&mut _cpp, byte_array);
+        }
+
         pub fn test_color_wrapper(
             &self,
             _cpp: std::pin::Pin<&mut FFICppObj>,
@@ -255,6 +318,41 @@ mod cxx_qt_ffi {
             return self.test_variant(&mut _cpp, variant);
         }
 
+        pub fn test_variant_hash_wrapper(
+            &self,
+            _cpp: std::pin::Pin<&mut FFICppObj>,
+            hash: &cxx_qt_lib::QVariantHash,
+        ) -> UniquePtr<cxx_qt_lib::QVariantHash> {
+            let mut _cpp = CppObj::new(_cpp);
+            return self.test_variant_hash(&mut _cpp, hash);
+        }
+
+        pub fn test_variant_map_wrapper(
+            &self,
+            _cpp: std::pin::Pin<&mut FFICppObj>,
+            map: &cxx_qt_lib::QVariantMap,
+        ) -> UniquePtr<cxx_qt_lib::QVariantMap> {
+            let mut _cpp = CppObj::new(_cpp);
+            return self.test_variant_map(&mut _cpp, map);
+        }
+
+        pub fn test_vector_wrapper(
+            &self,
+            _cpp: std::pin::Pin<&mut FFICppObj>,
+            vector: &cxx_qt_lib::QVector_QString,
+        ) -> UniquePtr<cxx_qt_lib::QVector_QString> {
+            let mut _cpp = CppObj::new(_cpp);
+            return self.test_vector(&mut _cpp, vector);
+        }
+
+        pub fn test_byte_array(
+            &self,
+            _cpp: &mut CppObj,
+            byte_array: &QByteArray,
+        ) -> UniquePtr<QByteArray> {
+            byte_array
+        }
+
         pub fn test_color(&self, _cpp: &mut CppObj, color: &QColor) -> UniquePtr<QColor> {
             color
         }
@@ -310,6 +408,30 @@ mod cxx_qt_ffi {
         pub fn test_variant(&self, _cpp: &mut CppObj, variant: &QVariant) -> UniquePtr<QVariant> {
             variant
         }
+
+        pub fn test_variant_hash(
+            &self,
+            _cpp: &mut CppObj,
+            hash: &QVariantHash,
+        ) -> UniquePtr<QVariantHash> {
+            hash
+        }
+
+        pub fn test_variant_map(
+            &self,
+            _cpp: &mut CppObj,
+            map: &QVariantMap,
+        ) -> UniquePtr<QVariantMap> {
+            map
+        }
+
+        pub fn test_vector(
+            &self,
+            _cpp: &mut CppObj,
+            vector: &QVector_QString,
+        ) -> UniquePtr<QVector_QString> {
+            vector
+        }
     }
 
     pub struct CppObj<'a> {
@@ -321,9 +443,42 @@ mod cxx_qt_ffi {
             Self { cpp }
         }
 
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
         pub fn grab_values_from_data(&mut self, mut data: Data) {}
     }
 
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
+        }
+    }
+
+    unsafe impl Send for MyObjectCxxQtThread {}
+
     #[derive(Default)]
     pub struct Data;
 
@@ -339,12 +494,12 @@ mod cxx_qt_ffi {
         }
     }
 
-    pub fn create_rs() -> std::boxed::Box<MyObject> {
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
         std::default::Default::default()
     }
 
-    pub fn initialise_cpp(cpp: std::pin::Pin<&mut FFICppObj>) {
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
         let mut wrapper = CppObj::new(cpp);
-        wrapper.grab_values_from_data(Data::default());
+        wrapper.grab_values_from_data(std::default::Default::default());
     }
 }
@@ -5,12 +5,18 @@ namespace cxx_qt::my_object {
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
   , m_rustObj(cxx_qt::my_object::cxx_qt_my_object::createRs())
+  , m_cxxQtThreadObj(
+      std::make_shared<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>(this))
 {
   cxx_qt::my_object::cxx_qt_my_object::initialiseCpp(*this);
   m_initialised = true;
 }
 
-MyObject::~MyObject() = default;
+MyObject::~MyObject()
+{
+  const std::lock_guard<std::shared_mutex> guard(m_cxxQtThreadObj->mutex);
+  m_cxxQtThreadObj->ptr = nullptr;
+}
 
 const MyObjectRust&
 MyObject::unsafeRust() const
@@ -24,6 +30,13 @@ MyObject::unsafeRustMut()
   return *m_rustObj;
 }
 
+std::unique_ptr<MyObjectCxxQtThread>
+MyObject::qtThread() const
+{
+  return std::make_unique<MyObjectCxxQtThread>(m_cxxQtThreadObj,
+                                               &m_rustObjMutex);
+}
+
 const QColor&
 MyObject::getColor() const
 {
@@ -323,6 +336,52 @@ MyObject::setVariant(const QVariant& value)
   }
 }
 
+const QList<qint32>&
+MyObject::getList() const
+{
+  return m_list;
+}
+
+void
+MyObject::setList(const QList<qint32>& value)
+{
+  if (!m_initialised) {
+    m_list = value;
+    return;
+  }
+
+  if (value != m_list) {
+    m_list = value;
+
+    const auto signalSuccess =
+      QMetaObject::invokeMethod(this, "listChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
+const QStringList&
+MyObject::getStringList() const
+{
+  return m_stringList;
+}
+
+void
+MyObject::setStringList(const QStringList& value)
+{
+  if (!m_initialised) {
+    m_stringList = value;
+    return;
+  }
+
+  if (value != m_stringList) {
+    m_stringList = value;
+
+    const auto signalSuccess = QMetaObject::invokeMethod(
+      this, "stringListChanged", Qt::QueuedConnection);
+    Q_ASSERT(signalSuccess);
+  }
+}
+
 } // namespace cxx_qt::my_object
 
 namespace cxx_qt::my_object::cxx_qt_my_object {
@@ -1,10 +1,14 @@
 #pragma once
 
+#include <cstdint>
 #include <memory>
 #include <mutex>
 
+#include "cxx-qt-lib/include/cxxqt_thread.h"
+
 namespace cxx_qt::my_object {
 class MyObject;
+using MyObjectCxxQtThread = rust::cxxqtlib1::CxxQtThread<MyObject>;
 } // namespace cxx_qt::my_object
 
 #include "cxx-qt-gen/include/my_object.cxx.h"
@@ -29,12 +33,16 @@ class MyObject : public QObject
   Q_PROPERTY(QUrl url READ getUrl WRITE setUrl NOTIFY urlChanged)
   Q_PROPERTY(
     QVariant variant READ getVariant WRITE setVariant NOTIFY variantChanged)
+  Q_PROPERTY(QList<qint32> list READ getList WRITE setList NOTIFY listChanged)
+  Q_PROPERTY(QStringList stringList READ getStringList WRITE setStringList
+               NOTIFY stringListChanged)
 
 public:
   explicit MyObject(QObject* parent = nullptr);
   ~MyObject();
   const MyObjectRust& unsafeRust() const;
   MyObjectRust& unsafeRustMut();
+  std::unique_ptr<MyObjectCxxQtThread> qtThread() const;
 
 public:
   const QColor& getColor() const;
@@ -50,6 +58,8 @@ public:
   const QTime& getTime() const;
   const QUrl& getUrl() const;
   const QVariant& getVariant() const;
+  const QList<qint32>& getList() const;
+  const QStringList& getStringList() const;
 
 public Q_SLOTS:
   void setColor(const QColor& value);
@@ -65,6 +75,8 @@ public Q_SLOTS:
   void setTime(const QTime& value);
   void setUrl(const QUrl& value);
   void setVariant(const QVariant& value);
+  void setList(const QList<qint32>& value);
+  void setStringList(const QStringList& value);
 
 Q_SIGNALS:
   void colorChanged();
@@ -80,11 +92,15 @@ Q_SIGNALS:
   void timeChanged();
   void urlChanged();
   void variantChanged();
+  void listChanged();
+  void stringListChanged();
 
 private:
   rust::Box<MyObjectRust> m_rustObj;
-  std::mutex m_rustObjMutex;
+  mutable std::recursive_mutex m_rustObjMutex;
   bool m_initialised = false;
+  std::shared_ptr<rust::cxxqtlib1::CxxQtGuardedPointer<MyObject>>
+    m_cxxQtThreadObj;
 
   QColor m_color;
   QDate m_date;
@@ -99,6 +115,8 @@ private:
   QTime m_time;
   QUrl m_url;
   QVariant m_variant;
+  QList<qint32> m_list;
+  QStringList m_stringList;
 };
 
 } // namespace cxx_qt::my_object
@@ -73,11 +73,32 @@ mod ffi {
         #[rust_name = "set_variant"]
         fn setVariant(self: Pin<&mut MyObjectQt>, value: &QVariant);
 
+        #[rust_name = "list"]
+        fn getList(self: &MyObjectQt) -> &QList_i32;
+        #[rust_name = "set_list"]
+        fn setList(self: Pin<&mut MyObjectQt>, value: &QList_i32);
+
+        #[rust_name = "string_list"]
+        fn getStringList(self: &MyObjectQt) -> &QStringList;
+        #[rust_name = "set_string_list"]
+        fn setStringList(self: Pin<&mut MyObjectQt>, value: &QStringList);
+
         #[cxx_name = "unsafeRust"]
         fn rust(self: &MyObjectQt) -> &MyObject;
-        #[rust_name = "new_cpp_object"]
+        #[rust_name = "new_cpp_object_my_object"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
         fn newCppObject() -> UniquePtr<MyObjectQt>;
+
+        type MyObjectCxxQtThread;
+
+        #[cxx_name = "qtThread"]
+        fn qt_thread(self: &MyObjectQt) -> UniquePtr<MyObjectCxxQtThread>;
+        #[cxx_name = "queue"]
+        fn queue_boxed_fn(
+            self: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObjectQt>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+        ) -> Result<()>;
     }
 
     extern "C++" {
@@ -91,11 +112,14 @@ mod ffi {
 
         #[cxx_name = "createRs"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn create_rs() -> Box<MyObject>;
+        fn create_rs_my_object() -> Box<MyObject>;
 
         #[cxx_name = "initialiseCpp"]
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
-        fn initialise_cpp(cpp: Pin<&mut MyObjectQt>);
+        fn initialise_cpp_my_object(cpp: Pin<&mut MyObjectQt>);
+
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        type MyObjectCxxQtThreadQueuedFn;
     }
 
     #[namespace = ""]
@@ -111,14 +135,20 @@ mod ffi {
         type QSize = cxx_qt_lib::QSize;
         type QSizeF = cxx_qt_lib::QSizeF;
         type QString = cxx_qt_lib::QString;
+        type QStringList = cxx_qt_lib::QStringList;
         type QTime = cxx_qt_lib::QTime;
         type QUrl = cxx_qt_lib::QUrl;
         type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib/include/qlist.h");
+        type QList_i32 = cxx_qt_lib::QList_i32;
     }
 }
 
-pub use self::cxx_qt_ffi::*;
-mod cxx_qt_ffi {
+pub use self::cxx_qt_my_object::{
+    create_rs_my_object, initialise_cpp_my_object, Data, MyObject, MyObjectCxxQtThreadQueuedFn,
+};
+pub mod cxx_qt_my_object {
     use super::ffi::*;
 
     pub type FFICppObj = super::ffi::MyObjectQt;
@@ -242,6 +272,26 @@ mod cxx_qt_ffi {
             self.cpp.as_mut().set_variant(value);
         }
 
+        pub fn list(&self) -> &cxx_qt_lib::QList_i32 {
+            self.cpp.list()
+        }
+
+        pub fn set_list(&mut self, value: &cxx_qt_lib::QList_i32) {
+            self.cpp.as_mut().set_list(value);
+        }
+
+        pub fn string_list(&self) -> &cxx_qt_lib::QStringList {
+            self.cpp.string_list()
+        }
+
+        pub fn set_string_list(&mut self, value: &cxx_qt_lib::QStringList) {
+            self.cpp.as_mut().set_string_list(value);
+        }
+
+        pub fn qt_thread(&self) -> UniquePtr<MyObjectCxxQtThread> {
+            self.cpp.qt_thread()
+        }
+
         pub fn grab_values_from_data(&mut self, mut data: Data) {
             self.set_color(data.color.as_ref().unwrap());
             self.set_date(&data.date);
@@ -256,9 +306,40 @@ mod cxx_qt_ffi {
             self.set_time(&data.time);
             self.set_url(data.url.as_ref().unwrap());
             self.set_variant(data.variant.as_ref().unwrap());
+            self.set_list(data.list.as_ref().unwrap());
+            self.set_string_list(data.string_list.as_ref().unwrap());
+        }
+    }
+
+    pub struct MyObjectCxxQtThreadQueuedFn {
+        inner: std::boxed::Box<dyn FnOnce(std::pin::Pin<&mut CppObj>) + Send>,
+    }
+
+    impl MyObjectCxxQtThread {
+        #[doc = r" Queue the given closure onto the event loop of the thread of the QObject,"]
+        #[doc = r" an error is returned if the QObject has been destroyed."]
+        pub fn queue<F>(&self, f: F) -> std::result::Result<(), cxx::Exception>
+        where
+            F: FnOnce(std::pin::Pin<&mut CppObj>),
+            F: Send + 'static,
+        {
+            #[allow(clippy::boxed_local)]
+            fn func(
+                obj: std::pin::Pin<&mut FFICppObj>,
+                arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+            ) {
+                let mut cpp = CppObj::new(obj);
+                (arg.inner)(std::pin::Pin::new(&mut cpp));
+            }
+            let arg = MyObjectCxxQtThreadQueuedFn {
+                inner: std::boxed::Box::new(f),
+            };
+            self.queue_boxed_fn(func, std::boxed::Box::new(arg))
         }
     }
 
+    unsafe impl Send for MyObjectCxxQtThread {}
+
     #[derive(Default)]
     pub struct Data {
         color: UniquePtr<QColor>,
@@ -274,6 +355,8 @@ mod cxx_qt_ffi {
         time: QTime,
         url: UniquePtr<QUrl>,
         variant: UniquePtr<QVariant>,
+        list: UniquePtr<QList_i32>,
+        string_list: UniquePtr<QStringList>,
     }
 
     impl<'a> From<&CppObj<'a>> for Data {
@@ -292,6 +375,8 @@ mod cxx_qt_ffi {
                 time: value.time().into(),
                 url: value.url().into(),
                 variant: value.variant().into(),
+                list: value.list().into(),
+                string_list: value.string_list().into(),
             }
         }
     }
@@ -302,12 +387,12 @@ mod cxx_qt_ffi {
         }
     }
 
-    pub fn create_rs() -> std::boxed::Box<MyObject> {
+    pub fn create_rs_my_object() -> std::boxed::Box<MyObject> {
         std::default::Default::default()
     }
 
-    pub fn initialise_cpp(cpp: std::pin::Pin<&mut FFICppObj>) {
+    pub fn initialise_cpp_my_object(cpp: std::pin::Pin<&mut FFICppObj>) {
         let mut wrapper = CppObj::new(cpp);
-        wrapper.grab_values_from_data(Data::default());
+        wrapper.grab_values_from_data(std::default::Default::default());
     }
 }
@@ -18,8 +18,16 @@ fn main() {
     // Copying the header is only needed for making the header available to a C++
     // build system, in which case CARGO_TARGET_DIR will be set by
     // the C++ build system.
+    println!("cargo:rerun-if-changed=include/connection.h");
     println!("cargo:rerun-if-changed=include/convert.h");
+    println!("cargo:rerun-if-changed=include/cxxqt_thread.h");
+    println!("cargo:rerun-if-changed=include/executor.h");
+    println!("cargo:rerun-if-changed=include/qhash.h");
+    println!("cargo:rerun-if-changed=include/qlist.h");
+    println!("cargo:rerun-if-changed=include/qmap.h");
     println!("cargo:rerun-if-changed=include/qt_types.h");
+    println!("cargo:rerun-if-changed=include/qvector.h");
+    println!("cargo:rerun-if-changed=include/throw_error.h");
     println!("cargo:rerun-if-changed=include/update_requester.h");
     println!("cargo:rerun-if-env-changed=CARGO_TARGET_DIR");
     if let Ok(target_dir) = env::var("CARGO_TARGET_DIR") {
@@ -27,8 +35,16 @@ fn main() {
         std::fs::create_dir_all(&format!("{}/cxxbridge/cxx-qt-lib/include", target_dir)).unwrap();
 
         for cpp_file in [
+            "include/connection.h",
             "include/convert.h",
+            "include/cxxqt_thread.h",
+            "include/executor.h",
+            "include/qhash.h",
+            "include/qlist.h",
+            "include/qmap.h",
             "include/qt_types.h",
+            "include/qvector.h",
+            "include/throw_error.h",
             "include/update_requester.h",
         ] {
             // FIXME: Horrible hack around this sometimes failing because
@@ -51,9 +67,38 @@ fn main() {
     }
 
     let bridge_files = [
+        "src/executor.rs",
+        "src/types/connection.rs",
+        "src/types/qbytearray.rs",
         "src/types/qcolor.rs",
         "src/types/qdate.rs",
         "src/types/qdatetime.rs",
+        "src/types/qhash/qhash_i32_qbytearray.rs",
+        "src/types/qhash/qhash_qstring_qvariant.rs",
+        "src/types/qlist/qlist_bool.rs",
+        "src/types/qlist/qlist_f32.rs",
+        "src/types/qlist/qlist_f64.rs",
+        "src/types/qlist/qlist_i8.rs",
+        "src/types/qlist/qlist_i16.rs",
+        "src/types/qlist/qlist_i32.rs",
+        "src/types/qlist/qlist_qcolor.rs",
+        "src/types/qlist/qlist_qdate.rs",
+        "src/types/qlist/qlist_qdatetime.rs",
+        "src/types/qlist/qlist_qpoint.rs",
+        "src/types/qlist/qlist_qpointf.rs",
+        "src/types/qlist/qlist_qrect.rs",
+        "src/types/qlist/qlist_qrectf.rs",
+        "src/types/qlist/qlist_qsize.rs",
+        "src/types/qlist/qlist_qsizef.rs",
+        "src/types/qlist/qlist_qstring.rs",
+        "src/types/qlist/qlist_qtime.rs",
+        "src/types/qlist/qlist_qurl.rs",
+        "src/types/qlist/qlist_qvariant.rs",
+        "src/types/qlist/qlist_u8.rs",
+        "src/types/qlist/qlist_u16.rs",
+        "src/types/qlist/qlist_u32.rs",
+        "src/types/qmap/qmap_qstring_qvariant.rs",
+        "src/types/qmodelindex.rs",
         "src/types/qpoint.rs",
         "src/types/qpointf.rs",
         "src/types/qrect.rs",
@@ -61,8 +106,31 @@ fn main() {
         "src/types/qsize.rs",
         "src/types/qsizef.rs",
         "src/types/qstring.rs",
+        "src/types/qstringlist.rs",
         "src/types/qtime.rs",
         "src/types/qurl.rs",
+        "src/types/qvector/qvector_bool.rs",
+        "src/types/qvector/qvector_f32.rs",
+        "src/types/qvector/qvector_f64.rs",
+        "src/types/qvector/qvector_i8.rs",
+        "src/types/qvector/qvector_i16.rs",
+        "src/types/qvector/qvector_i32.rs",
+        "src/types/qvector/qvector_qcolor.rs",
+        "src/types/qvector/qvector_qdate.rs",
+        "src/types/qvector/qvector_qdatetime.rs",
+        "src/types/qvector/qvector_qpoint.rs",
+        "src/types/qvector/qvector_qpointf.rs",
+        "src/types/qvector/qvector_qrect.rs",
+        "src/types/qvector/qvector_qrectf.rs",
+        "src/types/qvector/qvector_qsize.rs",
+        "src/types/qvector/qvector_qsizef.rs",
+        "src/types/qvector/qvector_qstring.rs",
+        "src/types/qvector/qvector_qtime.rs",
+        "src/types/qvector/qvector_qurl.rs",
+        "src/types/qvector/qvector_qvariant.rs",
+        "src/types/qvector/qvector_u8.rs",
+        "src/types/qvector/qvector_u16.rs",
+        "src/types/qvector/qvector_u32.rs",
         "src/types/qvariant.rs",
         "src/types/update_requester.rs",
     ];
@@ -77,7 +145,12 @@ fn main() {
     }
 
     let mut builder = cxx_build::bridges(&bridge_files);
-    for cpp_file in ["src/qt_types.cpp", "src/update_requester.cpp"] {
+    for cpp_file in [
+        "src/connection.cpp",
+        "src/executor.cpp",
+        "src/qt_types.cpp",
+        "src/update_requester.cpp",
+    ] {
         builder.file(cpp_file);
         println!("cargo:rerun-if-changed={}", cpp_file);
     }
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// The connection types which can be used when connecting to a signal from Rust,
+// the values match Qt::ConnectionType so that they can be cast
+enum class ConnectionType : ::std::int32_t
+{
+  AutoConnection = Qt::AutoConnection,
+  DirectConnection = Qt::DirectConnection,
+  QueuedConnection = Qt::QueuedConnection,
+  BlockingQueuedConnection = Qt::BlockingQueuedConnection,
+};
+
+using QMetaObjectConnection = QMetaObject::Connection;
+
+bool
+qmetaobjectconnectionDisconnect(const QMetaObjectConnection& connection);
+bool
+qmetaobjectconnectionIsConnected(const QMetaObjectConnection& connection);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,86 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <mutex>
+#include <shared_mutex>
+#include <stdexcept>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A pointer to a QObject which is shared with any CxxQtThread of the object,
+// the QObject sets the pointer to nullptr when it is destroyed
+template<typename T>
+struct CxxQtGuardedPointer
+{
+  explicit CxxQtGuardedPointer(T* ptr)
+    : ptr(ptr)
+  {
+  }
+
+  T* ptr;
+  ::std::shared_mutex mutex;
+};
+
+// A handle to the thread of a QObject which can be sent to other threads
+// to queue functions onto the event loop of the QObject
+template<typename T>
+class CxxQtThread
+{
+public:
+  CxxQtThread(::std::shared_ptr<CxxQtGuardedPointer<T>> obj,
+              ::std::recursive_mutex* rustObjMutex)
+    : m_obj(obj)
+    , m_rustObjMutex(rustObjMutex)
+  {
+  }
+
+  // Queue the function to be called with the QObject and the argument on the
+  // thread of the QObject, this throws if the QObject has been destroyed
+  template<typename A>
+  void queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
+             ::rust::Box<A> arg) const
+  {
+    // Prevent the QObject from being destroyed while the function is queued
+    const ::std::shared_lock<::std::shared_mutex> guard(m_obj->mutex);
+    if (m_obj->ptr == nullptr) {
+      throw ::std::runtime_error(
+        "Cannot queue function as the QObject has been destroyed");
+    }
+
+    // The QObject is the context of the lambda, so if the QObject is destroyed
+    // before the lambda is called the lambda is destroyed without being called
+    const auto invoked = QMetaObject::invokeMethod(
+      m_obj->ptr,
+      [obj = m_obj->ptr,
+       rustObjMutex = m_rustObjMutex,
+       func = ::std::move(func),
+       arg = ::std::move(arg)]() mutable {
+        const ::std::lock_guard<::std::recursive_mutex> guard(*rustObjMutex);
+        func(*obj, ::std::move(arg));
+      },
+      Qt::QueuedConnection);
+    if (!invoked) {
+      throw ::std::runtime_error(
+        "Cannot queue function as invoking the QObject failed");
+    }
+  }
+
+private:
+  ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
+  ::std::recursive_mutex* m_rustObjMutex;
+};
+
+} // namespace cxxqtlib1
+} // namespace rust
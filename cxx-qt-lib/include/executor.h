@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Whether there is a QCoreApplication and this is called from its thread
+bool
+executorIsMainThread();
+
+// Queue the given function to be called with the id of a task from the
+// event loop of the QCoreApplication, returns false if this failed
+bool
+executorQueueOnMainThread(rust::Fn<void(::std::uint64_t)> func,
+                          ::std::uint64_t id);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,130 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by src/types/generate_qmap_qhash.sh, do not edit it directly
+
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+// The supported QHash<K, V> instantiations are bridged using typedefs, Qt already
+// provides some of these, eg QVariantHash is QHash<QString, QVariant>
+//
+// The iterator of each instantiation is also bridged, so that iterating from Rust
+// uses a const_iterator rather than looking up each position from the start
+using QHash_i32_QByteArray = QHash<::qint32, ::QByteArray>;
+using QHashConstIterator_i32_QByteArray =
+  QHash<::qint32, ::QByteArray>::const_iterator;
+using QHashConstIterator_QString_QVariant =
+  QHash<::QString, ::QVariant>::const_iterator;
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qhash {
+
+template<typename K, typename V>
+using ConstIterator = typename QHash<K, V>::const_iterator;
+
+template<typename K, typename V>
+void
+qhashClear(QHash<K, V>& hash)
+{
+  hash.clear();
+}
+
+template<typename K, typename V>
+std::unique_ptr<QHash<K, V>>
+qhashClone(const QHash<K, V>& hash)
+{
+  return std::make_unique<QHash<K, V>>(hash);
+}
+
+template<typename K, typename V>
+bool
+qhashContains(const QHash<K, V>& hash, const K& key)
+{
+  return hash.contains(key);
+}
+
+template<typename K, typename V>
+std::unique_ptr<QHash<K, V>>
+qhashDefault()
+{
+  return std::make_unique<QHash<K, V>>();
+}
+
+template<typename K, typename V>
+const V&
+qhashGetUnchecked(const QHash<K, V>& hash, const K& key)
+{
+  Q_ASSERT(hash.contains(key));
+  return hash.find(key).value();
+}
+
+template<typename K, typename V>
+void
+qhashInsert(QHash<K, V>& hash, const K& key, const V& value)
+{
+  hash.insert(key, value);
+}
+
+template<typename K, typename V>
+std::unique_ptr<ConstIterator<K, V>>
+qhashIterBegin(const QHash<K, V>& hash)
+{
+  return std::make_unique<ConstIterator<K, V>>(hash.cbegin());
+}
+
+template<typename K, typename V>
+const K&
+qhashIterKey(const QHash<K, V>& hash, const ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != hash.cend());
+  return iter.key();
+}
+
+template<typename K, typename V>
+void
+qhashIterNext(const QHash<K, V>& hash, ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != hash.cend());
+  ++iter;
+}
+
+template<typename K, typename V>
+const V&
+qhashIterValue(const QHash<K, V>& hash, const ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != hash.cend());
+  return iter.value();
+}
+
+template<typename K, typename V>
+::std::size_t
+qhashLen(const QHash<K, V>& hash)
+{
+  return static_cast<::std::size_t>(hash.size());
+}
+
+template<typename K, typename V>
+bool
+qhashRemove(QHash<K, V>& hash, const K& key)
+{
+  return hash.remove(key) >= 1;
+}
+
+} // namespace qhash
+} // namespace cxxqtlib1
+} // namespace rust
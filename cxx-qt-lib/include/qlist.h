@@ -0,0 +1,136 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by src/types/generate_qlist_qvector.sh, do not edit it directly
+
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QDate>
+#include <QtCore/QDateTime>
+#include <QtCore/QList>
+#include <QtCore/QPoint>
+#include <QtCore/QPointF>
+#include <QtCore/QRect>
+#include <QtCore/QRectF>
+#include <QtCore/QSize>
+#include <QtCore/QSizeF>
+#include <QtCore/QString>
+#include <QtCore/QTime>
+#include <QtCore/QUrl>
+#include <QtCore/QVariant>
+#include <QtGui/QColor>
+
+#include "rust/cxx.h"
+
+// CXX requires a single word for the name of a type, so give each of the
+// supported QList<T> instantiations an alias which can be used in a bridge
+using QList_bool = QList<bool>;
+using QList_f32 = QList<float>;
+using QList_f64 = QList<double>;
+using QList_i8 = QList<::qint8>;
+using QList_i16 = QList<::qint16>;
+using QList_i32 = QList<::qint32>;
+using QList_QColor = QList<::QColor>;
+using QList_QDate = QList<::QDate>;
+using QList_QDateTime = QList<::QDateTime>;
+using QList_QPoint = QList<::QPoint>;
+using QList_QPointF = QList<::QPointF>;
+using QList_QRect = QList<::QRect>;
+using QList_QRectF = QList<::QRectF>;
+using QList_QSize = QList<::QSize>;
+using QList_QSizeF = QList<::QSizeF>;
+using QList_QString = QList<::QString>;
+using QList_QTime = QList<::QTime>;
+using QList_QUrl = QList<::QUrl>;
+using QList_QVariant = QList<::QVariant>;
+using QList_u8 = QList<::quint8>;
+using QList_u16 = QList<::quint16>;
+using QList_u32 = QList<::quint32>;
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qlist {
+
+template<typename T>
+void
+qlistAppend(QList<T>& list, const T& value)
+{
+  list.append(value);
+}
+
+template<typename T>
+void
+qlistClear(QList<T>& list)
+{
+  list.clear();
+}
+
+template<typename T>
+std::unique_ptr<QList<T>>
+qlistClone(const QList<T>& list)
+{
+  return std::make_unique<QList<T>>(list);
+}
+
+template<typename T>
+bool
+qlistContains(const QList<T>& list, const T& value)
+{
+  return list.contains(value);
+}
+
+template<typename T>
+std::unique_ptr<QList<T>>
+qlistDefault()
+{
+  return std::make_unique<QList<T>>();
+}
+
+template<typename T>
+const T&
+qlistGetUnchecked(const QList<T>& list, ::std::size_t pos)
+{
+  Q_ASSERT(pos < static_cast<::std::size_t>(list.size()));
+  return list.at(static_cast<qsizetype>(pos));
+}
+
+template<typename T>
+::rust::isize
+qlistIndexOf(const QList<T>& list, const T& value)
+{
+  return static_cast<::rust::isize>(list.indexOf(value));
+}
+
+template<typename T>
+void
+qlistInsert(QList<T>& list, ::std::size_t pos, const T& value)
+{
+  Q_ASSERT(pos <= static_cast<::std::size_t>(list.size()));
+  list.insert(static_cast<qsizetype>(pos), value);
+}
+
+template<typename T>
+::std::size_t
+qlistLen(const QList<T>& list)
+{
+  return static_cast<::std::size_t>(list.size());
+}
+
+template<typename T>
+void
+qlistRemove(QList<T>& list, ::std::size_t pos)
+{
+  Q_ASSERT(pos < static_cast<::std::size_t>(list.size()));
+  list.removeAt(static_cast<qsizetype>(pos));
+}
+
+} // namespace qlist
+} // namespace cxxqtlib1
+} // namespace rust
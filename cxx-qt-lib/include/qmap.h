@@ -0,0 +1,126 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by src/types/generate_qmap_qhash.sh, do not edit it directly
+
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QMap>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+// The supported QMap<K, V> instantiations are bridged using typedefs, Qt already
+// provides some of these, eg QVariantMap is QMap<QString, QVariant>
+//
+// The iterator of each instantiation is also bridged, so that iterating from Rust
+// uses a const_iterator rather than looking up each position from the start
+using QMapConstIterator_QString_QVariant =
+  QMap<::QString, ::QVariant>::const_iterator;
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qmap {
+
+template<typename K, typename V>
+using ConstIterator = typename QMap<K, V>::const_iterator;
+
+template<typename K, typename V>
+void
+qmapClear(QMap<K, V>& map)
+{
+  map.clear();
+}
+
+template<typename K, typename V>
+std::unique_ptr<QMap<K, V>>
+qmapClone(const QMap<K, V>& map)
+{
+  return std::make_unique<QMap<K, V>>(map);
+}
+
+template<typename K, typename V>
+bool
+qmapContains(const QMap<K, V>& map, const K& key)
+{
+  return map.contains(key);
+}
+
+template<typename K, typename V>
+std::unique_ptr<QMap<K, V>>
+qmapDefault()
+{
+  return std::make_unique<QMap<K, V>>();
+}
+
+template<typename K, typename V>
+const V&
+qmapGetUnchecked(const QMap<K, V>& map, const K& key)
+{
+  Q_ASSERT(map.contains(key));
+  return map.find(key).value();
+}
+
+template<typename K, typename V>
+void
+qmapInsert(QMap<K, V>& map, const K& key, const V& value)
+{
+  map.insert(key, value);
+}
+
+template<typename K, typename V>
+std::unique_ptr<ConstIterator<K, V>>
+qmapIterBegin(const QMap<K, V>& map)
+{
+  return std::make_unique<ConstIterator<K, V>>(map.cbegin());
+}
+
+template<typename K, typename V>
+const K&
+qmapIterKey(const QMap<K, V>& map, const ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != map.cend());
+  return iter.key();
+}
+
+template<typename K, typename V>
+void
+qmapIterNext(const QMap<K, V>& map, ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != map.cend());
+  ++iter;
+}
+
+template<typename K, typename V>
+const V&
+qmapIterValue(const QMap<K, V>& map, const ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != map.cend());
+  return iter.value();
+}
+
+template<typename K, typename V>
+::std::size_t
+qmapLen(const QMap<K, V>& map)
+{
+  return static_cast<::std::size_t>(map.size());
+}
+
+template<typename K, typename V>
+bool
+qmapRemove(QMap<K, V>& map, const K& key)
+{
+  return map.remove(key) >= 1;
+}
+
+} // namespace qmap
+} // namespace cxxqtlib1
+} // namespace rust
@@ -8,9 +8,14 @@
 #pragma once
 #include <memory>
 
+#include <QByteArray>
 #include <QColor>
 #include <QDate>
 #include <QDateTime>
+#include <QHash>
+#include <QList>
+#include <QMap>
+#include <QModelIndex>
 #include <QPoint>
 #include <QPointF>
 #include <QRect>
@@ -18,6 +23,7 @@
 #include <QSize>
 #include <QSizeF>
 #include <QString>
+#include <QStringList>
 #include <QTime>
 #include <QUrl>
 #include <QVariant>
@@ -32,31 +38,56 @@ namespace types {
 enum class QVariantType : uint8_t
 {
   Unsupported = 0,
-  Bool = 1,
-  F32 = 2,
-  F64 = 3,
-  I8 = 4,
-  I16 = 5,
-  I32 = 6,
-  QColor = 7,
-  QDate = 8,
-  QDateTime = 9,
-  QPoint = 10,
-  QPointF = 11,
-  QRect = 12,
-  QRectF = 13,
-  QSize = 14,
-  QSizeF = 15,
-  QString = 16,
-  QTime = 17,
-  QUrl = 18,
-  U8 = 19,
-  U16 = 20,
-  U32 = 21,
+  Null = 1,
+  Bool = 2,
+  F32 = 3,
+  F64 = 4,
+  I8 = 5,
+  I16 = 6,
+  I32 = 7,
+  QByteArray = 8,
+  QColor = 9,
+  QDate = 10,
+  QDateTime = 11,
+  QPoint = 12,
+  QPointF = 13,
+  QRect = 14,
+  QRectF = 15,
+  QSize = 16,
+  QSizeF = 17,
+  QString = 18,
+  QStringList = 19,
+  QTime = 20,
+  QUrl = 21,
+  QVariantHash = 22,
+  QVariantList = 23,
+  QVariantMap = 24,
+  U8 = 25,
+  U16 = 26,
+  U32 = 27,
 };
 
 } // namespace types
 
+rust::Slice<const std::uint8_t>
+qbytearrayAsSlice(const QByteArray& byteArray);
+rust::Slice<std::uint8_t>
+qbytearrayAsMutSlice(QByteArray& byteArray);
+std::unique_ptr<QByteArray>
+qbytearrayFromBase64(rust::Slice<const std::uint8_t> base64);
+std::unique_ptr<QByteArray>
+qbytearrayFromHex(rust::Slice<const std::uint8_t> hex);
+std::unique_ptr<QByteArray>
+qbytearrayInit();
+std::unique_ptr<QByteArray>
+qbytearrayInitFromQByteArray(const QByteArray& byteArray);
+std::unique_ptr<QByteArray>
+qbytearrayInitFromSlice(rust::Slice<const std::uint8_t> bytes);
+std::unique_ptr<QByteArray>
+qbytearrayToBase64(const QByteArray& byteArray);
+std::unique_ptr<QByteArray>
+qbytearrayToHex(const QByteArray& byteArray);
+
 std::unique_ptr<QColor>
 qcolorInit();
 std::unique_ptr<QColor>
@@ -83,6 +114,9 @@ qdatetimeSetDate(QDateTime& datetime, QDate date);
 void
 qdatetimeSetTime(QDateTime& datetime, QTime time);
 
+QModelIndex
+qmodelindexInitDefault();
+
 QPoint
 qpointInitDefault();
 QPoint
@@ -121,6 +155,21 @@ std::unique_ptr<QString>
 qstringInitFromRustString(rust::Str string);
 std::unique_ptr<QString>
 qstringInitFromQString(const QString& string);
+std::unique_ptr<QStringList>
+qstringSplit(const QString& string, rust::Str separator);
+
+const QList<QString>&
+qstringlistAsQListQString(const QStringList& list);
+QList<QString>&
+qstringlistAsQListQStringMut(QStringList& list);
+std::unique_ptr<QStringList>
+qstringlistInit();
+std::unique_ptr<QStringList>
+qstringlistInitFromQStringList(const QStringList& list);
+std::unique_ptr<QString>
+qstringlistJoin(const QStringList& list, rust::Str separator);
+void
+qstringlistSort(QStringList& list);
 
 QTime
 qtimeInitDefault();
@@ -153,6 +202,8 @@ qvariantInitFromI16(qint16 i16);
 std::unique_ptr<QVariant>
 qvariantInitFromI32(qint32 i32);
 std::unique_ptr<QVariant>
+qvariantInitFromQByteArray(const QByteArray& byteArray);
+std::unique_ptr<QVariant>
 qvariantInitFromQColor(const QColor& color);
 std::unique_ptr<QVariant>
 qvariantInitFromQDate(const QDate& date);
@@ -177,6 +228,14 @@ qvariantInitFromQUrl(const QUrl& url);
 std::unique_ptr<QVariant>
 qvariantInitFromQString(const QString& string);
 std::unique_ptr<QVariant>
+qvariantInitFromQStringList(const QStringList& list);
+std::unique_ptr<QVariant>
+qvariantInitFromQVariantHash(const QVariantHash& hash);
+std::unique_ptr<QVariant>
+qvariantInitFromQVariantList(const QVariantList& list);
+std::unique_ptr<QVariant>
+qvariantInitFromQVariantMap(const QVariantMap& map);
+std::unique_ptr<QVariant>
 qvariantInitFromU8(quint8 u8);
 std::unique_ptr<QVariant>
 qvariantInitFromU16(quint16 u16);
@@ -196,6 +255,8 @@ qint16
 qvariantToI16(const QVariant& variant);
 qint32
 qvariantToI32(const QVariant& variant);
+std::unique_ptr<QByteArray>
+qvariantToQByteArray(const QVariant& variant);
 std::unique_ptr<QColor>
 qvariantToQColor(const QVariant& variant);
 QDate
@@ -220,6 +281,14 @@ std::unique_ptr<QUrl>
 qvariantToQUrl(const QVariant& variant);
 std::unique_ptr<QString>
 qvariantToQString(const QVariant& variant);
+std::unique_ptr<QStringList>
+qvariantToQStringList(const QVariant& variant);
+std::unique_ptr<QVariantHash>
+qvariantToQVariantHash(const QVariant& variant);
+std::unique_ptr<QVariantList>
+qvariantToQVariantList(const QVariant& variant);
+std::unique_ptr<QVariantMap>
+qvariantToQVariantMap(const QVariant& variant);
 quint8
 qvariantToU8(const QVariant& variant);
 quint16
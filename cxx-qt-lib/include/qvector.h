@@ -0,0 +1,145 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by src/types/generate_qlist_qvector.sh, do not edit it directly
+
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QDate>
+#include <QtCore/QDateTime>
+#include <QtCore/QPoint>
+#include <QtCore/QPointF>
+#include <QtCore/QRect>
+#include <QtCore/QRectF>
+#include <QtCore/QSize>
+#include <QtCore/QSizeF>
+#include <QtCore/QString>
+#include <QtCore/QTime>
+#include <QtCore/QUrl>
+#include <QtCore/QVariant>
+#include <QtCore/QVector>
+#include <QtGui/QColor>
+
+#include "rust/cxx.h"
+
+// CXX requires a single word for the name of a type, so give each of the
+// supported QVector<T> instantiations an alias which can be used in a bridge
+using QVector_bool = QVector<bool>;
+using QVector_f32 = QVector<float>;
+using QVector_f64 = QVector<double>;
+using QVector_i8 = QVector<::qint8>;
+using QVector_i16 = QVector<::qint16>;
+using QVector_i32 = QVector<::qint32>;
+using QVector_QColor = QVector<::QColor>;
+using QVector_QDate = QVector<::QDate>;
+using QVector_QDateTime = QVector<::QDateTime>;
+using QVector_QPoint = QVector<::QPoint>;
+using QVector_QPointF = QVector<::QPointF>;
+using QVector_QRect = QVector<::QRect>;
+using QVector_QRectF = QVector<::QRectF>;
+using QVector_QSize = QVector<::QSize>;
+using QVector_QSizeF = QVector<::QSizeF>;
+using QVector_QString = QVector<::QString>;
+using QVector_QTime = QVector<::QTime>;
+using QVector_QUrl = QVector<::QUrl>;
+using QVector_QVariant = QVector<::QVariant>;
+using QVector_u8 = QVector<::quint8>;
+using QVector_u16 = QVector<::quint16>;
+using QVector_u32 = QVector<::quint32>;
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qvector {
+
+template<typename T>
+void
+qvectorAppend(QVector<T>& vector, const T& value)
+{
+  vector.append(value);
+}
+
+template<typename T>
+::rust::Slice<const T>
+qvectorAsSlice(const QVector<T>& vector)
+{
+  // Note that this borrows the data of the QVector without copying
+  return ::rust::Slice<const T>(vector.constData(),
+                                static_cast<::std::size_t>(vector.size()));
+}
+
+template<typename T>
+void
+qvectorClear(QVector<T>& vector)
+{
+  vector.clear();
+}
+
+template<typename T>
+std::unique_ptr<QVector<T>>
+qvectorClone(const QVector<T>& vector)
+{
+  return std::make_unique<QVector<T>>(vector);
+}
+
+template<typename T>
+bool
+qvectorContains(const QVector<T>& vector, const T& value)
+{
+  return vector.contains(value);
+}
+
+template<typename T>
+std::unique_ptr<QVector<T>>
+qvectorDefault()
+{
+  return std::make_unique<QVector<T>>();
+}
+
+template<typename T>
+const T&
+qvectorGetUnchecked(const QVector<T>& vector, ::std::size_t pos)
+{
+  Q_ASSERT(pos < static_cast<::std::size_t>(vector.size()));
+  return vector.at(static_cast<qsizetype>(pos));
+}
+
+template<typename T>
+::rust::isize
+qvectorIndexOf(const QVector<T>& vector, const T& value)
+{
+  return static_cast<::rust::isize>(vector.indexOf(value));
+}
+
+template<typename T>
+void
+qvectorInsert(QVector<T>& vector, ::std::size_t pos, const T& value)
+{
+  Q_ASSERT(pos <= static_cast<::std::size_t>(vector.size()));
+  vector.insert(static_cast<qsizetype>(pos), value);
+}
+
+template<typename T>
+::std::size_t
+qvectorLen(const QVector<T>& vector)
+{
+  return static_cast<::std::size_t>(vector.size());
+}
+
+template<typename T>
+void
+qvectorRemove(QVector<T>& vector, ::std::size_t pos)
+{
+  Q_ASSERT(pos < static_cast<::std::size_t>(vector.size()));
+  vector.removeAt(static_cast<qsizetype>(pos));
+}
+
+} // namespace qvector
+} // namespace cxxqtlib1
+} // namespace rust
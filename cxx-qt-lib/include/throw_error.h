@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDebug>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtQml/QJSEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Called with the object and error message when an invokable returns an error
+// but the object is not associated with a QJSEngine, eg when called from C++
+using ThrowErrorFallback = void (*)(const QObject* object,
+                                    const QString& message);
+
+namespace detail {
+
+inline void
+throwErrorWarning(const QObject* object, const QString& message)
+{
+  qWarning() << object << "invokable returned an error:" << message;
+}
+
+inline ThrowErrorFallback throwErrorFallback = throwErrorWarning;
+
+} // namespace detail
+
+// Replace the fallback which is used when there is no QJSEngine to throw to,
+// by default a warning is logged
+inline void
+setThrowErrorFallback(ThrowErrorFallback fallback)
+{
+  detail::throwErrorFallback =
+    fallback != nullptr ? fallback : detail::throwErrorWarning;
+}
+
+// Throw the error message as a JavaScript Error to the QJSEngine of the object
+//
+// Note that this does not throw a C++ exception, instead the engine raises the
+// error once the invokable has returned to JavaScript
+inline void
+throwError(const QObject* object, const QString& message)
+{
+  if (QJSEngine* engine = qjsEngine(object)) {
+    engine->throwError(message);
+  } else {
+    detail::throwErrorFallback(object, message);
+  }
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/include/connection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qmetaobjectconnectionDisconnect(const QMetaObjectConnection& connection)
+{
+  return QObject::disconnect(connection);
+}
+
+bool
+qmetaobjectconnectionIsConnected(const QMetaObjectConnection& connection)
+{
+  return static_cast<bool>(connection);
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/include/executor.h"
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QMetaObject>
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+executorIsMainThread()
+{
+  const auto* app = QCoreApplication::instance();
+  return app != nullptr && QThread::currentThread() == app->thread();
+}
+
+bool
+executorQueueOnMainThread(rust::Fn<void(::std::uint64_t)> func,
+                          ::std::uint64_t id)
+{
+  auto* app = QCoreApplication::instance();
+  if (app == nullptr) {
+    return false;
+  }
+
+  return QMetaObject::invokeMethod(
+    app, [func, id]() { func(id); }, Qt::QueuedConnection);
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
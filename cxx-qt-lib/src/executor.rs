@@ -0,0 +1,304 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QMetaObjectConnection;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, TryLockError,
+    },
+    task::{Context, Poll, Wake, Waker},
+};
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/executor.h");
+
+        #[rust_name = "executor_is_main_thread"]
+        fn executorIsMainThread() -> bool;
+        #[rust_name = "executor_queue_on_main_thread"]
+        fn executorQueueOnMainThread(func: fn(u64), id: u64) -> bool;
+    }
+}
+
+type BoxedFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type ScheduleFn<T> = Box<dyn Fn(Arc<Task<T>>) + Send + Sync>;
+
+/// A future which is polled on the Qt thread that it has been scheduled onto
+///
+/// When the future is woken the task is given to the schedule function, which
+/// should queue a call to [Task::poll] onto the Qt thread, eg by using the
+/// `queue` method of the `CxxQtThread` of a QObject.
+pub struct Task<T> {
+    future: Mutex<Option<BoxedFuture<T>>>,
+    schedule: ScheduleFn<T>,
+}
+
+impl<T: 'static> Task<T> {
+    /// Create a new task for the given future and schedule function
+    ///
+    /// Note that the future is not polled until [Task::poll] is called.
+    ///
+    /// The future must be `Send` even though it is only polled on the Qt thread. The task is
+    /// shared with its wakers, which can be held and dropped by any thread, so when the QObject
+    /// is destroyed before the future completes the future can be dropped by another thread.
+    /// Use [spawn] for a future which is not `Send`, as it is only ever held by the Qt main thread.
+    pub fn new<F, S>(future: F, schedule: S) -> Arc<Self>
+    where
+        F: Future<Output = T> + Send + 'static,
+        S: Fn(Arc<Self>) + Send + Sync + 'static,
+    {
+        Arc::new(Self {
+            future: Mutex::new(Some(Box::pin(future))),
+            schedule: Box::new(schedule),
+        })
+    }
+
+    /// Poll the future of the task, this should only be called from the Qt thread
+    ///
+    /// The output of the future is returned once it has completed, after which
+    /// the future is dropped and any further polls return None.
+    pub fn poll(self: &Arc<Self>) -> Option<T> {
+        let mut future = match self.future.try_lock() {
+            Ok(future) => future,
+            // The task is already being polled, eg from a nested event loop,
+            // so schedule the task to be polled again later
+            Err(TryLockError::WouldBlock) => {
+                (self.schedule)(self.clone());
+                return None;
+            }
+            Err(TryLockError::Poisoned(_)) => return None,
+        };
+
+        let output = if let Some(inner) = future.as_mut() {
+            let waker = Waker::from(self.clone());
+            let mut context = Context::from_waker(&waker);
+            match inner.as_mut().poll(&mut context) {
+                Poll::Ready(output) => Some(output),
+                Poll::Pending => None,
+            }
+        } else {
+            None
+        };
+
+        if output.is_some() {
+            *future = None;
+        }
+
+        output
+    }
+}
+
+impl<T: 'static> Wake for Task<T> {
+    fn wake(self: Arc<Self>) {
+        (self.schedule)(self.clone());
+    }
+}
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// The state of a future which has been spawned onto the Qt main thread
+enum LocalTask {
+    /// The future is waiting to be polled
+    Idle(LocalFuture),
+    /// The future is currently being polled, and whether it was woken meanwhile
+    Polling { woken: bool },
+}
+
+thread_local! {
+    // The futures are only stored, polled, and dropped on the Qt main thread
+    static LOCAL_TASKS: RefCell<HashMap<u64, LocalTask>> = RefCell::new(HashMap::new());
+}
+
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The error returned when a future cannot be spawned onto the Qt main thread
+#[derive(Debug, PartialEq)]
+pub enum SpawnError {
+    /// The future was not spawned from the thread of the QCoreApplication,
+    /// or there is no QCoreApplication
+    NotMainThread,
+    /// The first poll of the future could not be queued onto the event loop
+    QueueFailed,
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotMainThread => write!(f, "futures can only be spawned from the Qt main thread"),
+            Self::QueueFailed => write!(f, "failed to queue the future onto the Qt event loop"),
+        }
+    }
+}
+
+impl std::error::Error for SpawnError {}
+
+/// Spawn a future onto the event loop of the Qt main thread
+///
+/// This must be called from the thread of the QCoreApplication, the future is then
+/// polled from the event loop of that thread and is woken by posting an event to it.
+/// As the future never leaves the Qt main thread it does not need to be `Send`,
+/// so it can hold values such as a `QMetaObjectConnection` or a `SignalFuture`.
+pub fn spawn<F>(future: F) -> Result<(), SpawnError>
+where
+    F: Future<Output = ()> + 'static,
+{
+    if !ffi::executor_is_main_thread() {
+        return Err(SpawnError::NotMainThread);
+    }
+
+    let id = NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed);
+    LOCAL_TASKS.with(|tasks| {
+        tasks
+            .borrow_mut()
+            .insert(id, LocalTask::Idle(Box::pin(future)))
+    });
+
+    if ffi::executor_queue_on_main_thread(poll_local_task, id) {
+        Ok(())
+    } else {
+        LOCAL_TASKS.with(|tasks| tasks.borrow_mut().remove(&id));
+        Err(SpawnError::QueueFailed)
+    }
+}
+
+/// Poll the spawned future with the given id, this is called from the Qt main thread
+fn poll_local_task(id: u64) {
+    // Take the future out of the map so that it can spawn further futures while being polled
+    let future = LOCAL_TASKS.with(|tasks| {
+        // The future has already completed if it is not in the map
+        let mut tasks = tasks.borrow_mut();
+        let task = tasks.get_mut(&id)?;
+        match std::mem::replace(task, LocalTask::Polling { woken: false }) {
+            LocalTask::Idle(future) => Some(future),
+            // The future is being polled from a nested event loop, so poll it again later
+            LocalTask::Polling { .. } => {
+                *task = LocalTask::Polling { woken: true };
+                None
+            }
+        }
+    });
+
+    if let Some(mut future) = future {
+        let waker = Waker::from(Arc::new(LocalWaker { id }));
+        let mut context = Context::from_waker(&waker);
+        let poll = future.as_mut().poll(&mut context);
+
+        let woken = LOCAL_TASKS.with(|tasks| {
+            let mut tasks = tasks.borrow_mut();
+            match poll {
+                Poll::Ready(()) => {
+                    tasks.remove(&id);
+                    false
+                }
+                Poll::Pending => matches!(
+                    tasks.insert(id, LocalTask::Idle(future)),
+                    Some(LocalTask::Polling { woken: true })
+                ),
+            }
+        });
+
+        if woken {
+            ffi::executor_queue_on_main_thread(poll_local_task, id);
+        }
+    }
+}
+
+/// Wakes a spawned future by queuing a poll onto the Qt main thread,
+/// only the id is stored so the waker can be sent to any thread
+struct LocalWaker {
+    id: u64,
+}
+
+impl Wake for LocalWaker {
+    fn wake(self: Arc<Self>) {
+        ffi::executor_queue_on_main_thread(poll_local_task, self.id);
+    }
+}
+
+/// The shared state between a SignalFuture and the closure connected to the signal
+struct SignalState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// A future which resolves with the parameters of the next emission of a signal
+///
+/// The closure is disconnected from the signal once the future has resolved or is dropped.
+/// Note that the future must be polled from the thread of the QObject which emits the signal,
+/// eg by using [spawn] for a QObject which lives on the Qt main thread.
+pub struct SignalFuture<T> {
+    connection: Option<QMetaObjectConnection>,
+    state: Rc<RefCell<SignalState<T>>>,
+}
+
+impl<T: 'static> SignalFuture<T> {
+    /// Create a future for the next emission of a signal
+    ///
+    /// The given function should connect the closure that it is given to the signal
+    /// and return the connection, the closure is then called with the parameters of the signal.
+    ///
+    /// ```ignore
+    /// let ready = SignalFuture::new(|mut emitted| {
+    ///     cpp.connect_ready_queued(move |_cpp| emitted(()))
+    /// });
+    /// ready.await;
+    /// ```
+    pub fn new<C>(connect: C) -> Self
+    where
+        C: FnOnce(Box<dyn FnMut(T)>) -> QMetaObjectConnection,
+    {
+        let state = Rc::new(RefCell::new(SignalState {
+            value: None,
+            waker: None,
+        }));
+        let emitted_state = state.clone();
+        let connection = connect(Box::new(move |value| {
+            let mut state = emitted_state.borrow_mut();
+            // Only the first emission is used
+            if state.value.is_none() {
+                state.value = Some(value);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            }
+        }));
+
+        Self {
+            connection: Some(connection),
+            state,
+        }
+    }
+}
+
+impl<T> Future for SignalFuture<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<T> {
+        let value = {
+            let mut state = self.state.borrow_mut();
+            if state.value.is_none() {
+                state.waker = Some(context.waker().clone());
+            }
+            state.value.take()
+        };
+
+        if let Some(value) = value {
+            // Dropping the connection disconnects the closure from the signal
+            self.connection = None;
+            Poll::Ready(value)
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -4,7 +4,12 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod executor;
+#[cfg(feature = "tokio")]
+pub mod tokio;
 mod types;
+
+pub use executor::{spawn, SignalFuture, SpawnError, Task};
 pub use types::*;
 
 pub trait UpdateRequestHandler<C> {
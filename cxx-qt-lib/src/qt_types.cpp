@@ -12,6 +12,76 @@
 namespace rust {
 namespace cxxqtlib1 {
 
+rust::Slice<const std::uint8_t>
+qbytearrayAsSlice(const QByteArray& byteArray)
+{
+  // Note that this borrows the data of the QByteArray without copying
+  return rust::Slice<const std::uint8_t>(
+    reinterpret_cast<const std::uint8_t*>(byteArray.constData()),
+    static_cast<std::size_t>(byteArray.size()));
+}
+
+rust::Slice<std::uint8_t>
+qbytearrayAsMutSlice(QByteArray& byteArray)
+{
+  // Note that data() detaches the QByteArray if it is shared, so that
+  // writing through the slice does not affect other copies
+  return rust::Slice<std::uint8_t>(
+    reinterpret_cast<std::uint8_t*>(byteArray.data()),
+    static_cast<std::size_t>(byteArray.size()));
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayFromBase64(rust::Slice<const std::uint8_t> base64)
+{
+  // fromRawData does not copy the borrowed rust::Slice
+  return std::make_unique<QByteArray>(
+    QByteArray::fromBase64(QByteArray::fromRawData(
+      reinterpret_cast<const char*>(base64.data()),
+      static_cast<int>(base64.size()))));
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayFromHex(rust::Slice<const std::uint8_t> hex)
+{
+  // fromRawData does not copy the borrowed rust::Slice
+  return std::make_unique<QByteArray>(QByteArray::fromHex(
+    QByteArray::fromRawData(reinterpret_cast<const char*>(hex.data()),
+                            static_cast<int>(hex.size()))));
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayInit()
+{
+  return std::make_unique<QByteArray>();
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayInitFromQByteArray(const QByteArray& byteArray)
+{
+  return std::make_unique<QByteArray>(byteArray);
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayInitFromSlice(rust::Slice<const std::uint8_t> bytes)
+{
+  return std::make_unique<QByteArray>(
+    reinterpret_cast<const char*>(bytes.data()),
+    static_cast<int>(bytes.size()));
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayToBase64(const QByteArray& byteArray)
+{
+  return std::make_unique<QByteArray>(byteArray.toBase64());
+}
+
+std::unique_ptr<QByteArray>
+qbytearrayToHex(const QByteArray& byteArray)
+{
+  return std::make_unique<QByteArray>(byteArray.toHex());
+}
+
 std::unique_ptr<QColor>
 qcolorInit()
 {
@@ -75,6 +145,12 @@ qdatetimeSetTime(QDateTime& datetime, QTime time)
   datetime.setTime(time);
 }
 
+QModelIndex
+qmodelindexInitDefault()
+{
+  return QModelIndex();
+}
+
 QPoint
 qpointInitDefault()
 {
@@ -177,6 +253,52 @@ qstringInitFromQString(const QString& string)
   return std::make_unique<QString>(string);
 }
 
+std::unique_ptr<QStringList>
+qstringSplit(const QString& string, rust::Str separator)
+{
+  return std::make_unique<QStringList>(
+    string.split(qstringFromRustString(separator)));
+}
+
+const QList<QString>&
+qstringlistAsQListQString(const QStringList& list)
+{
+  // In Qt 6 QStringList is an alias of QList<QString> and in Qt 5 it inherits
+  // from QList<QString>, so the list can always be used as a QList<QString>
+  return list;
+}
+
+QList<QString>&
+qstringlistAsQListQStringMut(QStringList& list)
+{
+  return list;
+}
+
+std::unique_ptr<QStringList>
+qstringlistInit()
+{
+  return std::make_unique<QStringList>();
+}
+
+std::unique_ptr<QStringList>
+qstringlistInitFromQStringList(const QStringList& list)
+{
+  return std::make_unique<QStringList>(list);
+}
+
+std::unique_ptr<QString>
+qstringlistJoin(const QStringList& list, rust::Str separator)
+{
+  return std::make_unique<QString>(
+    list.join(qstringFromRustString(separator)));
+}
+
+void
+qstringlistSort(QStringList& list)
+{
+  list.sort();
+}
+
 QTime
 qtimeInitDefault()
 {
@@ -241,6 +363,7 @@ CXX_QT_VARIANT_INIT(double, F64)
 CXX_QT_VARIANT_INIT(qint8, I8)
 CXX_QT_VARIANT_INIT(qint16, I16)
 CXX_QT_VARIANT_INIT(qint32, I32)
+CXX_QT_VARIANT_INIT_REF(QByteArray, QByteArray)
 CXX_QT_VARIANT_INIT_REF(QColor, QColor)
 CXX_QT_VARIANT_INIT_REF(QDate, QDate)
 CXX_QT_VARIANT_INIT_REF(QDateTime, QDateTime)
@@ -251,8 +374,12 @@ CXX_QT_VARIANT_INIT_REF(QRectF, QRectF)
 CXX_QT_VARIANT_INIT_REF(QSize, QSize)
 CXX_QT_VARIANT_INIT_REF(QSizeF, QSizeF)
 CXX_QT_VARIANT_INIT_REF(QString, QString)
+CXX_QT_VARIANT_INIT_REF(QStringList, QStringList)
 CXX_QT_VARIANT_INIT_REF(QTime, QTime)
 CXX_QT_VARIANT_INIT_REF(QUrl, QUrl)
+CXX_QT_VARIANT_INIT_REF(QVariantHash, QVariantHash)
+CXX_QT_VARIANT_INIT_REF(QVariantList, QVariantList)
+CXX_QT_VARIANT_INIT_REF(QVariantMap, QVariantMap)
 CXX_QT_VARIANT_INIT(quint8, U8)
 CXX_QT_VARIANT_INIT(quint16, U16)
 CXX_QT_VARIANT_INIT(quint32, U32)
@@ -267,6 +394,11 @@ qvariantType(const QVariant& variant)
   // warnings
   switch (static_cast<QMetaType::Type>(variant.type())) {
 #endif
+    // An invalid QVariant has an unknown type, and a null from QML has a
+    // nullptr type, both of these are considered to be null
+    case QMetaType::UnknownType:
+    case QMetaType::Nullptr:
+      return types::QVariantType::Null;
     case QMetaType::Bool:
       return types::QVariantType::Bool;
     case QMetaType::Float:
@@ -279,6 +411,8 @@ qvariantType(const QVariant& variant)
       return types::QVariantType::I16;
     case QMetaType::Int:
       return types::QVariantType::I32;
+    case QMetaType::QByteArray:
+      return types::QVariantType::QByteArray;
     case QMetaType::QColor:
       return types::QVariantType::QColor;
     case QMetaType::QDate:
@@ -299,10 +433,18 @@ qvariantType(const QVariant& variant)
       return types::QVariantType::QSizeF;
     case QMetaType::QString:
       return types::QVariantType::QString;
+    case QMetaType::QStringList:
+      return types::QVariantType::QStringList;
     case QMetaType::QTime:
       return types::QVariantType::QTime;
     case QMetaType::QUrl:
       return types::QVariantType::QUrl;
+    case QMetaType::QVariantHash:
+      return types::QVariantType::QVariantHash;
+    case QMetaType::QVariantList:
+      return types::QVariantType::QVariantList;
+    case QMetaType::QVariantMap:
+      return types::QVariantType::QVariantMap;
     case QMetaType::UChar:
       return types::QVariantType::U8;
     case QMetaType::UShort:
@@ -335,6 +477,7 @@ CXX_QT_VARIANT_TRIVIAL_VALUE(double, F64)
 CXX_QT_VARIANT_TRIVIAL_VALUE(qint8, I8)
 CXX_QT_VARIANT_TRIVIAL_VALUE(qint16, I16)
 CXX_QT_VARIANT_TRIVIAL_VALUE(qint32, I32)
+CXX_QT_VARIANT_OPAQUE_VALUE(QByteArray, QByteArray)
 CXX_QT_VARIANT_OPAQUE_VALUE(QColor, QColor)
 CXX_QT_VARIANT_TRIVIAL_VALUE(QDate, QDate)
 CXX_QT_VARIANT_OPAQUE_VALUE(QDateTime, QDateTime)
@@ -345,8 +488,12 @@ CXX_QT_VARIANT_TRIVIAL_VALUE(QRectF, QRectF)
 CXX_QT_VARIANT_TRIVIAL_VALUE(QSize, QSize)
 CXX_QT_VARIANT_TRIVIAL_VALUE(QSizeF, QSizeF)
 CXX_QT_VARIANT_OPAQUE_VALUE(QString, QString)
+CXX_QT_VARIANT_OPAQUE_VALUE(QStringList, QStringList)
 CXX_QT_VARIANT_TRIVIAL_VALUE(QTime, QTime)
 CXX_QT_VARIANT_OPAQUE_VALUE(QUrl, QUrl)
+CXX_QT_VARIANT_OPAQUE_VALUE(QVariantHash, QVariantHash)
+CXX_QT_VARIANT_OPAQUE_VALUE(QVariantList, QVariantList)
+CXX_QT_VARIANT_OPAQUE_VALUE(QVariantMap, QVariantMap)
 CXX_QT_VARIANT_TRIVIAL_VALUE(quint8, U8)
 CXX_QT_VARIANT_TRIVIAL_VALUE(quint16, U16)
 CXX_QT_VARIANT_TRIVIAL_VALUE(quint32, U32)
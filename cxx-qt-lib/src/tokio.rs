@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Helpers for using a tokio runtime with QObjects, enabled by the `tokio` feature.
+//!
+//! Invokables are called from the Qt thread, which is not within a tokio runtime,
+//! so futures are spawned via a [Handle](::tokio::runtime::Handle) to the runtime.
+//! The output can then be delivered back to the Qt thread of the QObject by queuing
+//! a closure with the `CxxQtThread` of the QObject, or by awaiting the [JoinHandle]
+//! from a future which has been spawned onto the Qt main thread with [crate::spawn].
+
+use std::future::Future;
+
+pub use ::tokio::{runtime::Handle, task::JoinHandle};
+
+/// Spawn a future onto the tokio runtime of the given handle
+///
+/// This can be called from the Qt thread, eg from an invokable, as it does not
+/// require the current thread to be within the tokio runtime.
+pub fn spawn<F>(handle: &Handle, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    handle.spawn(future)
+}
+
+/// Spawn a future onto the tokio runtime of the given handle and call the given
+/// closure with its output from the runtime once the future has completed
+///
+/// The closure is intended to queue the output onto the Qt thread of the QObject,
+/// so that it can update properties or emit signals without polling for the result.
+///
+/// ```ignore
+/// let qt_thread = cpp.qt_thread();
+/// cxx_qt_lib::tokio::spawn_then(&self.runtime, fetch_title(), move |title| {
+///     qt_thread.queue(move |mut cpp| cpp.as_mut().set_title(&title))
+/// });
+/// ```
+pub fn spawn_then<F, C, R>(handle: &Handle, future: F, then: C) -> JoinHandle<R>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+    C: FnOnce(F::Output) -> R + Send + 'static,
+    R: Send + 'static,
+{
+    handle.spawn(async move { then(future.await) })
+}
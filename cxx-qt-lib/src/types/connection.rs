@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// The type of connection to use when connecting to a signal,
+    /// this matches Qt::ConnectionType
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    enum ConnectionType {
+        /// Direct if the signal is emitted from the thread of the receiver, otherwise queued
+        AutoConnection = 0,
+        /// The closure is called immediately when the signal is emitted
+        DirectConnection = 1,
+        /// The closure is called when control returns to the event loop of the receiver's thread
+        QueuedConnection = 2,
+        /// As QueuedConnection, but the emitting thread blocks until the closure returns
+        BlockingQueuedConnection = 3,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/connection.h");
+
+        type ConnectionType;
+        type QMetaObjectConnection;
+
+        #[rust_name = "qmetaobjectconnection_disconnect"]
+        fn qmetaobjectconnectionDisconnect(connection: &QMetaObjectConnection) -> bool;
+        #[rust_name = "qmetaobjectconnection_is_connected"]
+        fn qmetaobjectconnectionIsConnected(connection: &QMetaObjectConnection) -> bool;
+    }
+
+    impl UniquePtr<QMetaObjectConnection> {}
+}
+
+pub use ffi::ConnectionType;
+pub type QMetaObjectConnectionCpp = ffi::QMetaObjectConnection;
+
+impl Default for ConnectionType {
+    fn default() -> Self {
+        Self::AutoConnection
+    }
+}
+
+/// A handle to a connection between a signal and a closure,
+/// the connection is disconnected when the handle is dropped
+pub struct QMetaObjectConnection {
+    inner: cxx::UniquePtr<QMetaObjectConnectionCpp>,
+}
+
+impl QMetaObjectConnection {
+    pub fn from_unique_ptr(ptr: cxx::UniquePtr<QMetaObjectConnectionCpp>) -> Self {
+        Self { inner: ptr }
+    }
+
+    /// Disconnect the signal from the closure, returns true if the connection was disconnected
+    pub fn disconnect(&mut self) -> bool {
+        if let Some(inner) = self.inner.as_ref() {
+            ffi::qmetaobjectconnection_disconnect(inner)
+        } else {
+            false
+        }
+    }
+
+    /// Returns true if the connection is valid, eg the signal has not been disconnected
+    /// and the sender has not been destroyed
+    pub fn is_connected(&self) -> bool {
+        if let Some(inner) = self.inner.as_ref() {
+            ffi::qmetaobjectconnection_is_connected(inner)
+        } else {
+            false
+        }
+    }
+}
+
+impl Drop for QMetaObjectConnection {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
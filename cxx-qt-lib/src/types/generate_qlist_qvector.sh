@@ -0,0 +1,366 @@
+#!/usr/bin/env bash
+
+# SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+# SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+# Generates QList<T> and QVector<T> from one template, this is the C++ header
+# and the CXX bridge for each supported element type
+#
+# CXX does not support generic functions, so each element type needs its own bridge
+# with uniquely named functions. Re-run this script after changing the template.
+
+set -e
+
+SCRIPT=$(realpath "$0")
+SCRIPTPATH=$(dirname "$SCRIPT")
+INCLUDEPATH=$(realpath "$SCRIPTPATH/../../include")
+
+# The supported element types, Qt types are declared in the bridges with a type alias
+ELEMENTS=(
+    "bool"
+    "f32"
+    "f64"
+    "i8"
+    "i16"
+    "i32"
+    "QColor"
+    "QDate"
+    "QDateTime"
+    "QPoint"
+    "QPointF"
+    "QRect"
+    "QRectF"
+    "QSize"
+    "QSizeF"
+    "QString"
+    "QTime"
+    "QUrl"
+    "QVariant"
+    "u8"
+    "u16"
+    "u32"
+)
+
+# The C++ type of an element type
+function cpp_type() {
+    case "$1" in
+        bool) echo "bool" ;;
+        f32) echo "float" ;;
+        f64) echo "double" ;;
+        i8) echo "::qint8" ;;
+        i16) echo "::qint16" ;;
+        i32) echo "::qint32" ;;
+        u8) echo "::quint8" ;;
+        u16) echo "::quint16" ;;
+        u32) echo "::quint32" ;;
+        *) echo "::$1" ;;
+    esac
+}
+
+# The Qt module which provides a Qt element type
+function qt_module() {
+    case "$1" in
+        QColor) echo "QtGui" ;;
+        *) echo "QtCore" ;;
+    esac
+}
+
+# Qt types are declared in the bridges with a type alias, primitives are built into CXX
+function is_qt_type() {
+    [[ "$1" == Q* ]]
+}
+
+# Primitives and Qt types which are trivial in the bridges can be borrowed as a Rust slice
+function is_trivial_type() {
+    case "$1" in
+        QColor | QDateTime | QString | QUrl | QVariant) return 1 ;;
+        *) return 0 ;;
+    esac
+}
+
+function generate_header() {
+    local CONTAINER=$1
+    local VAR=$2
+    local CONTAINER_LOWER
+    CONTAINER_LOWER=$(echo "$CONTAINER" | tr '[:upper:]' '[:lower:]')
+
+    local INCLUDES="#include <QtCore/$CONTAINER>"
+    for ELEMENT in "${ELEMENTS[@]}"; do
+        if is_qt_type "$ELEMENT"; then
+            INCLUDES="$INCLUDES
+#include <$(qt_module "$ELEMENT")/$ELEMENT>"
+        fi
+    done
+    INCLUDES=$(echo "$INCLUDES" | LC_ALL=C sort)
+
+    # A QList on Qt 5 stores most element types as an array of pointers,
+    # so only a QVector is guaranteed to be contiguous and can be borrowed as a slice
+    local AS_SLICE=""
+    if [ "$CONTAINER" == "QVector" ]; then
+        AS_SLICE="
+template<typename T>
+::rust::Slice<const T>
+${CONTAINER_LOWER}AsSlice(const $CONTAINER<T>& $VAR)
+{
+  // Note that this borrows the data of the $CONTAINER without copying
+  return ::rust::Slice<const T>($VAR.constData(),
+                                static_cast<::std::size_t>($VAR.size()));
+}
+"
+    fi
+
+    local ALIASES=""
+    for ELEMENT in "${ELEMENTS[@]}"; do
+        ALIASES="$ALIASES
+using ${CONTAINER}_$ELEMENT = $CONTAINER<$(cpp_type "$ELEMENT")>;"
+    done
+
+    tee "$INCLUDEPATH/$CONTAINER_LOWER.h" <<EOF > /dev/null
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by src/types/generate_qlist_qvector.sh, do not edit it directly
+
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+$INCLUDES
+
+#include "rust/cxx.h"
+
+// CXX requires a single word for the name of a type, so give each of the
+// supported $CONTAINER<T> instantiations an alias which can be used in a bridge$ALIASES
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace $CONTAINER_LOWER {
+
+template<typename T>
+void
+${CONTAINER_LOWER}Append($CONTAINER<T>& $VAR, const T& value)
+{
+  $VAR.append(value);
+}
+${AS_SLICE}
+template<typename T>
+void
+${CONTAINER_LOWER}Clear($CONTAINER<T>& $VAR)
+{
+  $VAR.clear();
+}
+
+template<typename T>
+std::unique_ptr<$CONTAINER<T>>
+${CONTAINER_LOWER}Clone(const $CONTAINER<T>& $VAR)
+{
+  return std::make_unique<$CONTAINER<T>>($VAR);
+}
+
+template<typename T>
+bool
+${CONTAINER_LOWER}Contains(const $CONTAINER<T>& $VAR, const T& value)
+{
+  return $VAR.contains(value);
+}
+
+template<typename T>
+std::unique_ptr<$CONTAINER<T>>
+${CONTAINER_LOWER}Default()
+{
+  return std::make_unique<$CONTAINER<T>>();
+}
+
+template<typename T>
+const T&
+${CONTAINER_LOWER}GetUnchecked(const $CONTAINER<T>& $VAR, ::std::size_t pos)
+{
+  Q_ASSERT(pos < static_cast<::std::size_t>($VAR.size()));
+  return $VAR.at(static_cast<qsizetype>(pos));
+}
+
+template<typename T>
+::rust::isize
+${CONTAINER_LOWER}IndexOf(const $CONTAINER<T>& $VAR, const T& value)
+{
+  return static_cast<::rust::isize>($VAR.indexOf(value));
+}
+
+template<typename T>
+void
+${CONTAINER_LOWER}Insert($CONTAINER<T>& $VAR, ::std::size_t pos, const T& value)
+{
+  Q_ASSERT(pos <= static_cast<::std::size_t>($VAR.size()));
+  $VAR.insert(static_cast<qsizetype>(pos), value);
+}
+
+template<typename T>
+::std::size_t
+${CONTAINER_LOWER}Len(const $CONTAINER<T>& $VAR)
+{
+  return static_cast<::std::size_t>($VAR.size());
+}
+
+template<typename T>
+void
+${CONTAINER_LOWER}Remove($CONTAINER<T>& $VAR, ::std::size_t pos)
+{
+  Q_ASSERT(pos < static_cast<::std::size_t>($VAR.size()));
+  $VAR.removeAt(static_cast<qsizetype>(pos));
+}
+
+} // namespace $CONTAINER_LOWER
+} // namespace cxxqtlib1
+} // namespace rust
+EOF
+}
+
+function generate_bridge() {
+    local CONTAINER=$1
+    local VAR=$2
+    local ELEMENT=$3
+    local CONTAINER_LOWER
+    CONTAINER_LOWER=$(echo "$CONTAINER" | tr '[:upper:]' '[:lower:]')
+    local LOWER
+    LOWER=$(echo "$ELEMENT" | tr '[:upper:]' '[:lower:]')
+    local NAME="${CONTAINER}_$ELEMENT"
+
+    local TYPE_ALIAS=""
+    local USE_ELEMENT=""
+    if is_qt_type "$ELEMENT"; then
+        USE_ELEMENT="use crate::$ELEMENT;"
+        TYPE_ALIAS="
+        include!(\"cxx-qt-lib/include/qt_types.h\");
+        type $ELEMENT = crate::$ELEMENT;
+"
+    fi
+
+    local AS_SLICE_FN=""
+    local AS_SLICE_IMPL=""
+    if [ "$CONTAINER" == "QVector" ] && is_trivial_type "$ELEMENT"; then
+        AS_SLICE_FN="
+        #[rust_name = \"${CONTAINER_LOWER}_as_slice_$LOWER\"]
+        fn ${CONTAINER_LOWER}AsSlice($VAR: &$NAME) -> &[$ELEMENT];"
+        AS_SLICE_IMPL="
+
+impl crate::${CONTAINER}SliceElement for $ELEMENT {
+    fn as_slice($VAR: &ffi::$NAME) -> &[Self] {
+        ffi::${CONTAINER_LOWER}_as_slice_$LOWER($VAR)
+    }
+}"
+    fi
+
+    tee "$SCRIPTPATH/$CONTAINER_LOWER/${CONTAINER_LOWER}_$LOWER.rs" <<EOF > /dev/null
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+$USE_ELEMENT
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/$CONTAINER_LOWER.h");
+$TYPE_ALIAS
+        type $NAME = crate::$CONTAINER<$ELEMENT>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::$CONTAINER_LOWER"]
+    unsafe extern "C++" {
+        #[rust_name = "${CONTAINER_LOWER}_append_$LOWER"]
+        fn ${CONTAINER_LOWER}Append($VAR: Pin<&mut $NAME>, value: &$ELEMENT);$AS_SLICE_FN
+        #[rust_name = "${CONTAINER_LOWER}_clear_$LOWER"]
+        fn ${CONTAINER_LOWER}Clear($VAR: Pin<&mut $NAME>);
+        #[rust_name = "${CONTAINER_LOWER}_clone_$LOWER"]
+        fn ${CONTAINER_LOWER}Clone($VAR: &$NAME) -> UniquePtr<$NAME>;
+        #[rust_name = "${CONTAINER_LOWER}_contains_$LOWER"]
+        fn ${CONTAINER_LOWER}Contains($VAR: &$NAME, value: &$ELEMENT) -> bool;
+        #[rust_name = "${CONTAINER_LOWER}_default_$LOWER"]
+        fn ${CONTAINER_LOWER}Default() -> UniquePtr<$NAME>;
+        #[rust_name = "${CONTAINER_LOWER}_get_unchecked_$LOWER"]
+        unsafe fn ${CONTAINER_LOWER}GetUnchecked($VAR: &$NAME, pos: usize) -> &$ELEMENT;
+        #[rust_name = "${CONTAINER_LOWER}_index_of_$LOWER"]
+        fn ${CONTAINER_LOWER}IndexOf($VAR: &$NAME, value: &$ELEMENT) -> isize;
+        #[rust_name = "${CONTAINER_LOWER}_insert_$LOWER"]
+        fn ${CONTAINER_LOWER}Insert($VAR: Pin<&mut $NAME>, pos: usize, value: &$ELEMENT);
+        #[rust_name = "${CONTAINER_LOWER}_len_$LOWER"]
+        fn ${CONTAINER_LOWER}Len($VAR: &$NAME) -> usize;
+        #[rust_name = "${CONTAINER_LOWER}_remove_$LOWER"]
+        fn ${CONTAINER_LOWER}Remove($VAR: Pin<&mut $NAME>, pos: usize);
+    }
+
+    impl UniquePtr<$NAME> {}
+}
+
+impl crate::${CONTAINER}Element for $ELEMENT {
+    type TypeId = type_id!("$NAME");
+
+    fn append($VAR: Pin<&mut ffi::$NAME>, value: &Self) {
+        ffi::${CONTAINER_LOWER}_append_$LOWER($VAR, value);
+    }
+
+    fn clear($VAR: Pin<&mut ffi::$NAME>) {
+        ffi::${CONTAINER_LOWER}_clear_$LOWER($VAR);
+    }
+
+    fn clone($VAR: &ffi::$NAME) -> UniquePtr<ffi::$NAME> {
+        ffi::${CONTAINER_LOWER}_clone_$LOWER($VAR)
+    }
+
+    fn contains($VAR: &ffi::$NAME, value: &Self) -> bool {
+        ffi::${CONTAINER_LOWER}_contains_$LOWER($VAR, value)
+    }
+
+    fn default() -> UniquePtr<ffi::$NAME> {
+        ffi::${CONTAINER_LOWER}_default_$LOWER()
+    }
+
+    unsafe fn get_unchecked($VAR: &ffi::$NAME, pos: usize) -> &Self {
+        ffi::${CONTAINER_LOWER}_get_unchecked_$LOWER($VAR, pos)
+    }
+
+    fn index_of($VAR: &ffi::$NAME, value: &Self) -> isize {
+        ffi::${CONTAINER_LOWER}_index_of_$LOWER($VAR, value)
+    }
+
+    fn insert($VAR: Pin<&mut ffi::$NAME>, pos: usize, value: &Self) {
+        ffi::${CONTAINER_LOWER}_insert_$LOWER($VAR, pos, value);
+    }
+
+    fn len($VAR: &ffi::$NAME) -> usize {
+        ffi::${CONTAINER_LOWER}_len_$LOWER($VAR)
+    }
+
+    fn remove($VAR: Pin<&mut ffi::$NAME>, pos: usize) {
+        ffi::${CONTAINER_LOWER}_remove_$LOWER($VAR, pos);
+    }
+}$AS_SLICE_IMPL
+EOF
+    rustfmt --edition 2018 "$SCRIPTPATH/$CONTAINER_LOWER/${CONTAINER_LOWER}_$LOWER.rs"
+}
+
+# Generate the C++ header and the bridges of a container
+function generate_container() {
+    local CONTAINER=$1
+    local VAR=$2
+
+    generate_header "$CONTAINER" "$VAR"
+    for ELEMENT in "${ELEMENTS[@]}"; do
+        generate_bridge "$CONTAINER" "$VAR" "$ELEMENT"
+    done
+}
+
+generate_container "QList" "list"
+generate_container "QVector" "vector"
@@ -0,0 +1,646 @@
+#!/usr/bin/env bash
+
+# SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+# SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+# Generates QMap<K, V> and QHash<K, V> from one template, this is the Rust module,
+# the C++ header, and the CXX bridge for each supported key value pair
+#
+# CXX does not support generic functions, so each pair needs its own bridge
+# with uniquely named functions. Re-run this script after changing the template.
+
+set -e
+
+SCRIPT=$(realpath "$0")
+SCRIPTPATH=$(dirname "$SCRIPT")
+INCLUDEPATH=$(realpath "$SCRIPTPATH/../../include")
+
+# The C++ type of a key or value type
+function cpp_type() {
+    case "$1" in
+        i32) echo "::qint32" ;;
+        *) echo "::$1" ;;
+    esac
+}
+
+# Qt types are declared in the bridges with a type alias, primitives are built into CXX
+function is_qt_type() {
+    [[ "$1" == Q* ]]
+}
+
+function generate_bridge() {
+    local CONTAINER=$1
+    local VAR=$2
+    local KEY=$3
+    local VALUE=$4
+    local NAME=$5
+    local CONTAINER_LOWER
+    CONTAINER_LOWER=$(echo "$CONTAINER" | tr '[:upper:]' '[:lower:]')
+    local LOWER
+    LOWER=$(echo "${KEY}_${VALUE}" | tr '[:upper:]' '[:lower:]')
+    local ITERATOR="${CONTAINER}ConstIterator_${KEY}_${VALUE}"
+
+    local QT_TYPES=()
+    local TYPE_ALIASES=""
+    for TYPE in "$KEY" "$VALUE"; do
+        if is_qt_type "$TYPE"; then
+            QT_TYPES+=("$TYPE")
+            TYPE_ALIASES="$TYPE_ALIASES
+        type $TYPE = crate::$TYPE;"
+        fi
+    done
+
+    local USE_TYPES=""
+    if [ ${#QT_TYPES[@]} -eq 1 ]; then
+        USE_TYPES="use crate::${QT_TYPES[0]};
+"
+    elif [ ${#QT_TYPES[@]} -gt 1 ]; then
+        USE_TYPES="use crate::{${QT_TYPES[0]}, ${QT_TYPES[1]}};
+"
+    fi
+
+    tee "$SCRIPTPATH/$CONTAINER_LOWER/${CONTAINER_LOWER}_$LOWER.rs" <<EOF > /dev/null
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+
+${USE_TYPES}use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        include!("cxx-qt-lib/include/$CONTAINER_LOWER.h");
+$TYPE_ALIASES
+        type $NAME = crate::$CONTAINER<$KEY, $VALUE>;
+        type $ITERATOR;
+    }
+
+    #[namespace = "rust::cxxqtlib1::$CONTAINER_LOWER"]
+    unsafe extern "C++" {
+        #[rust_name = "${CONTAINER_LOWER}_clear_$LOWER"]
+        fn ${CONTAINER_LOWER}Clear($VAR: Pin<&mut $NAME>);
+        #[rust_name = "${CONTAINER_LOWER}_clone_$LOWER"]
+        fn ${CONTAINER_LOWER}Clone($VAR: &$NAME) -> UniquePtr<$NAME>;
+        #[rust_name = "${CONTAINER_LOWER}_contains_$LOWER"]
+        fn ${CONTAINER_LOWER}Contains($VAR: &$NAME, key: &$KEY) -> bool;
+        #[rust_name = "${CONTAINER_LOWER}_default_$LOWER"]
+        fn ${CONTAINER_LOWER}Default() -> UniquePtr<$NAME>;
+        #[rust_name = "${CONTAINER_LOWER}_get_unchecked_$LOWER"]
+        unsafe fn ${CONTAINER_LOWER}GetUnchecked<'a>($VAR: &'a $NAME, key: &$KEY) -> &'a $VALUE;
+        #[rust_name = "${CONTAINER_LOWER}_insert_$LOWER"]
+        fn ${CONTAINER_LOWER}Insert($VAR: Pin<&mut $NAME>, key: &$KEY, value: &$VALUE);
+        #[rust_name = "${CONTAINER_LOWER}_iter_begin_$LOWER"]
+        fn ${CONTAINER_LOWER}IterBegin($VAR: &$NAME) -> UniquePtr<$ITERATOR>;
+        #[rust_name = "${CONTAINER_LOWER}_iter_key_$LOWER"]
+        unsafe fn ${CONTAINER_LOWER}IterKey<'a>($VAR: &'a $NAME, iter: &$ITERATOR) -> &'a $KEY;
+        #[rust_name = "${CONTAINER_LOWER}_iter_next_$LOWER"]
+        unsafe fn ${CONTAINER_LOWER}IterNext($VAR: &$NAME, iter: Pin<&mut $ITERATOR>);
+        #[rust_name = "${CONTAINER_LOWER}_iter_value_$LOWER"]
+        unsafe fn ${CONTAINER_LOWER}IterValue<'a>($VAR: &'a $NAME, iter: &$ITERATOR) -> &'a $VALUE;
+        #[rust_name = "${CONTAINER_LOWER}_len_$LOWER"]
+        fn ${CONTAINER_LOWER}Len($VAR: &$NAME) -> usize;
+        #[rust_name = "${CONTAINER_LOWER}_remove_$LOWER"]
+        fn ${CONTAINER_LOWER}Remove($VAR: Pin<&mut $NAME>, key: &$KEY) -> bool;
+    }
+
+    impl UniquePtr<$NAME> {}
+}
+
+impl crate::${CONTAINER}Pair for ($KEY, $VALUE) {
+    type Key = $KEY;
+    type Value = $VALUE;
+    type TypeId = type_id!("$NAME");
+    type ConstIterator = ffi::$ITERATOR;
+
+    fn clear($VAR: Pin<&mut ffi::$NAME>) {
+        ffi::${CONTAINER_LOWER}_clear_$LOWER($VAR);
+    }
+
+    fn clone($VAR: &ffi::$NAME) -> UniquePtr<ffi::$NAME> {
+        ffi::${CONTAINER_LOWER}_clone_$LOWER($VAR)
+    }
+
+    fn contains($VAR: &ffi::$NAME, key: &$KEY) -> bool {
+        ffi::${CONTAINER_LOWER}_contains_$LOWER($VAR, key)
+    }
+
+    fn default() -> UniquePtr<ffi::$NAME> {
+        ffi::${CONTAINER_LOWER}_default_$LOWER()
+    }
+
+    unsafe fn get_unchecked<'a>($VAR: &'a ffi::$NAME, key: &$KEY) -> &'a $VALUE {
+        ffi::${CONTAINER_LOWER}_get_unchecked_$LOWER($VAR, key)
+    }
+
+    fn insert($VAR: Pin<&mut ffi::$NAME>, key: &$KEY, value: &$VALUE) {
+        ffi::${CONTAINER_LOWER}_insert_$LOWER($VAR, key, value);
+    }
+
+    fn iter_begin($VAR: &ffi::$NAME) -> UniquePtr<ffi::$ITERATOR> {
+        ffi::${CONTAINER_LOWER}_iter_begin_$LOWER($VAR)
+    }
+
+    unsafe fn iter_key<'a>($VAR: &'a ffi::$NAME, iter: &ffi::$ITERATOR) -> &'a $KEY {
+        ffi::${CONTAINER_LOWER}_iter_key_$LOWER($VAR, iter)
+    }
+
+    unsafe fn iter_next($VAR: &ffi::$NAME, iter: Pin<&mut ffi::$ITERATOR>) {
+        ffi::${CONTAINER_LOWER}_iter_next_$LOWER($VAR, iter);
+    }
+
+    unsafe fn iter_value<'a>($VAR: &'a ffi::$NAME, iter: &ffi::$ITERATOR) -> &'a $VALUE {
+        ffi::${CONTAINER_LOWER}_iter_value_$LOWER($VAR, iter)
+    }
+
+    fn len($VAR: &ffi::$NAME) -> usize {
+        ffi::${CONTAINER_LOWER}_len_$LOWER($VAR)
+    }
+
+    fn remove($VAR: Pin<&mut ffi::$NAME>, key: &$KEY) -> bool {
+        ffi::${CONTAINER_LOWER}_remove_$LOWER($VAR, key)
+    }
+}
+EOF
+    rustfmt --edition 2018 "$SCRIPTPATH/$CONTAINER_LOWER/${CONTAINER_LOWER}_$LOWER.rs"
+}
+
+# Generate the Rust module, the C++ header, and the bridges of a container
+#
+# Each pair is given as KEY:VALUE:NAME, where NAME is the C++ typedef of the container.
+# The typedef is declared in the header unless Qt already provides it, eg QVariantMap.
+function generate_container() {
+    local CONTAINER=$1
+    local VAR=$2
+    local DESCRIPTION=$3
+    local ORDER=$4
+    local ALIAS=$5
+    shift 5
+    local CONTAINER_LOWER
+    CONTAINER_LOWER=$(echo "$CONTAINER" | tr '[:upper:]' '[:lower:]')
+
+    local MODS=""
+    local TYPEDEFS=""
+    local RUST_TYPEDEFS=""
+    local INCLUDES="#include <QtCore/$CONTAINER>"
+    local USE_TYPES=("IntoQVariant" "QString" "QVariant")
+    for PAIR in "$@"; do
+        local KEY VALUE NAME
+        IFS=: read -r KEY VALUE NAME <<< "$PAIR"
+        local LOWER
+        LOWER=$(echo "${KEY}_${VALUE}" | tr '[:upper:]' '[:lower:]')
+        MODS="${MODS}mod ${CONTAINER_LOWER}_$LOWER;
+"
+        local CONTAINER_CPP
+        CONTAINER_CPP="$CONTAINER<$(cpp_type "$KEY"), $(cpp_type "$VALUE")>"
+        if [[ "$NAME" == "${CONTAINER}_"* ]]; then
+            TYPEDEFS="${TYPEDEFS}using $NAME = $CONTAINER_CPP;
+"
+            RUST_TYPEDEFS="${RUST_TYPEDEFS}#[allow(non_camel_case_types)]
+pub type $NAME = $CONTAINER<$KEY, $VALUE>;
+"
+        fi
+        TYPEDEFS="${TYPEDEFS}using ${CONTAINER}ConstIterator_${KEY}_${VALUE} =
+  $CONTAINER_CPP::const_iterator;
+"
+        for TYPE in "$KEY" "$VALUE"; do
+            if is_qt_type "$TYPE" && [[ "$INCLUDES" != *"<QtCore/$TYPE>"* ]]; then
+                INCLUDES="$INCLUDES
+#include <QtCore/$TYPE>"
+            fi
+            if is_qt_type "$TYPE" && [[ " ${USE_TYPES[*]} " != *" $TYPE "* ]]; then
+                USE_TYPES+=("$TYPE")
+            fi
+        done
+
+        generate_bridge "$CONTAINER" "$VAR" "$KEY" "$VALUE" "$NAME"
+    done
+
+    INCLUDES=$(echo "$INCLUDES" | LC_ALL=C sort)
+    local USE_LIST
+    USE_LIST=$(printf '%s\n' "${USE_TYPES[@]}" | LC_ALL=C sort | paste -sd ',' - | sed 's/,/, /g')
+    if [ -n "$RUST_TYPEDEFS" ]; then
+        RUST_TYPEDEFS="
+// CXX requires a single word for the name of a type, these are the names used in a bridge
+$RUST_TYPEDEFS"
+    fi
+
+    tee "$SCRIPTPATH/$CONTAINER_LOWER/mod.rs" <<EOF > /dev/null
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+${MODS}
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    pin::Pin,
+};
+use cxx::{memory::UniquePtrTarget, ExternType, UniquePtr};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{$USE_LIST};
+
+/// The $CONTAINER class is a template class that provides $DESCRIPTION.
+///
+/// Note that this is the C++ representation, so it is only accessed by reference
+/// or through a \`UniquePtr<$CONTAINER<K, V>>\`.
+#[repr(C)]
+pub struct $CONTAINER<K, V> {
+    _private: [u8; 0],
+    _marker: PhantomData<(K, V, PhantomPinned)>,
+}
+
+/// A [\`$CONTAINER\`] of [\`QVariant\`] values with [\`QString\`] keys, a JavaScript object
+/// from QML is converted into a $ALIAS.
+///
+/// Use [\`$ALIAS::from\`] and \`HashMap::from\` or \`BTreeMap::from\` to convert
+/// from and to Rust collections.
+pub type $ALIAS = $CONTAINER<QString, QVariant>;
+$RUST_TYPEDEFS
+/// Trait implementation for a key value pair in a [\`$CONTAINER\`].
+///
+/// This is implemented for the \`(K, V)\` tuple of each of the supported pairs by the generated bridges.
+pub trait ${CONTAINER}Pair: Sized
+where
+    $CONTAINER<Self::Key, Self::Value>: UniquePtrTarget,
+{
+    type Key;
+    type Value;
+    type TypeId;
+    type ConstIterator: UniquePtrTarget;
+
+    fn clear($VAR: Pin<&mut $CONTAINER<Self::Key, Self::Value>>);
+    fn clone($VAR: &$CONTAINER<Self::Key, Self::Value>) -> UniquePtr<$CONTAINER<Self::Key, Self::Value>>;
+    fn contains($VAR: &$CONTAINER<Self::Key, Self::Value>, key: &Self::Key) -> bool;
+    fn default() -> UniquePtr<$CONTAINER<Self::Key, Self::Value>>;
+    /// # Safety
+    ///
+    /// Calling this method with a key that is not in the $VAR is undefined behavior
+    /// even if the resulting reference is not used.
+    unsafe fn get_unchecked<'a>(
+        $VAR: &'a $CONTAINER<Self::Key, Self::Value>,
+        key: &Self::Key,
+    ) -> &'a Self::Value;
+    fn insert($VAR: Pin<&mut $CONTAINER<Self::Key, Self::Value>>, key: &Self::Key, value: &Self::Value);
+    fn iter_begin($VAR: &$CONTAINER<Self::Key, Self::Value>) -> UniquePtr<Self::ConstIterator>;
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another $VAR, an iterator which is at the end,
+    /// or after the $VAR has been modified is undefined behavior even if the resulting reference is not used.
+    unsafe fn iter_key<'a>(
+        $VAR: &'a $CONTAINER<Self::Key, Self::Value>,
+        iter: &Self::ConstIterator,
+    ) -> &'a Self::Key;
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another $VAR, an iterator which is at the end,
+    /// or after the $VAR has been modified is undefined behavior.
+    unsafe fn iter_next(
+        $VAR: &$CONTAINER<Self::Key, Self::Value>,
+        iter: Pin<&mut Self::ConstIterator>,
+    );
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another $VAR, an iterator which is at the end,
+    /// or after the $VAR has been modified is undefined behavior even if the resulting reference is not used.
+    unsafe fn iter_value<'a>(
+        $VAR: &'a $CONTAINER<Self::Key, Self::Value>,
+        iter: &Self::ConstIterator,
+    ) -> &'a Self::Value;
+    fn len($VAR: &$CONTAINER<Self::Key, Self::Value>) -> usize;
+    fn remove($VAR: Pin<&mut $CONTAINER<Self::Key, Self::Value>>, key: &Self::Key) -> bool;
+}
+
+impl<K, V> $CONTAINER<K, V>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    /// Constructs an empty $VAR.
+    pub fn new() -> UniquePtr<Self> {
+        <(K, V) as ${CONTAINER}Pair>::default()
+    }
+
+    /// Constructs a copy of the given $VAR.
+    pub fn from_ref($VAR: &Self) -> UniquePtr<Self> {
+        <(K, V) as ${CONTAINER}Pair>::clone($VAR)
+    }
+
+    /// Removes all the items from the $VAR.
+    pub fn clear(self: Pin<&mut Self>) {
+        <(K, V) as ${CONTAINER}Pair>::clear(self);
+    }
+
+    /// Returns true if the $VAR contains an item with the key; otherwise returns false.
+    pub fn contains(&self, key: &K) -> bool {
+        <(K, V) as ${CONTAINER}Pair>::contains(self, key)
+    }
+
+    /// Returns the value associated with the key, or None if the $VAR does not contain the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        if self.contains(key) {
+            Some(unsafe { <(K, V) as ${CONTAINER}Pair>::get_unchecked(self, key) })
+        } else {
+            None
+        }
+    }
+
+    /// Inserts a new item with the key and a value of value.
+    ///
+    /// If there is already an item with the key, that item's value is replaced with value.
+    pub fn insert(self: Pin<&mut Self>, key: &K, value: &V) {
+        <(K, V) as ${CONTAINER}Pair>::insert(self, key, value);
+    }
+
+    /// Returns true if the $VAR contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all the key value pairs in the $VAR, $ORDER.
+    pub fn iter(&self) -> ${CONTAINER}Iter<K, V> {
+        ${CONTAINER}Iter {
+            $VAR: self,
+            iter: <(K, V) as ${CONTAINER}Pair>::iter_begin(self),
+            len: self.len(),
+        }
+    }
+
+    /// Returns the number of items in the $VAR.
+    pub fn len(&self) -> usize {
+        <(K, V) as ${CONTAINER}Pair>::len(self)
+    }
+
+    /// Removes the item that has the key from the $VAR.
+    ///
+    /// Returns true if an item was removed; otherwise returns false.
+    pub fn remove(self: Pin<&mut Self>, key: &K) -> bool {
+        <(K, V) as ${CONTAINER}Pair>::remove(self, key)
+    }
+}
+
+impl $CONTAINER<QString, QVariant> {
+    /// Constructs a $VAR from Rust key value pairs, such as a \`HashMap<String, i32>\`
+    /// or a \`BTreeMap<&str, UniquePtr<QVariant>>\`.
+    ///
+    /// This is used instead of a \`From\` implementation for \`HashMap\` and \`BTreeMap\`,
+    /// as the orphan rules do not allow implementing \`From\` for a \`UniquePtr\`.
+    pub fn from<I, S, T>(pairs: I) -> UniquePtr<Self>
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: AsRef<str>,
+        T: IntoQVariant,
+    {
+        let mut $VAR = Self::new();
+        for (key, value) in pairs {
+            $VAR.pin_mut()
+                .insert(&QString::from_str(key.as_ref()), &value.into_qvariant());
+        }
+        $VAR
+    }
+}
+
+unsafe impl<K, V> ExternType for $CONTAINER<K, V>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    type Id = <(K, V) as ${CONTAINER}Pair>::TypeId;
+    type Kind = cxx::kind::Opaque;
+}
+
+impl<K, V> From<&$CONTAINER<K, V>> for UniquePtr<$CONTAINER<K, V>>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    fn from($VAR: &$CONTAINER<K, V>) -> Self {
+        $CONTAINER::from_ref($VAR)
+    }
+}
+
+impl From<&$CONTAINER<QString, QVariant>> for BTreeMap<String, UniquePtr<QVariant>> {
+    /// Convert a reference to a $ALIAS into a BTreeMap by making a deep copy of the data.
+    fn from($VAR: &$CONTAINER<QString, QVariant>) -> Self {
+        $VAR.iter()
+            .map(|(key, value)| (key.to_string(), QVariant::from_ref(value)))
+            .collect()
+    }
+}
+
+impl From<&$CONTAINER<QString, QVariant>> for HashMap<String, UniquePtr<QVariant>> {
+    /// Convert a reference to a $ALIAS into a HashMap by making a deep copy of the data.
+    fn from($VAR: &$CONTAINER<QString, QVariant>) -> Self {
+        $VAR.iter()
+            .map(|(key, value)| (key.to_string(), QVariant::from_ref(value)))
+            .collect()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a $CONTAINER<K, V>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = ${CONTAINER}Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key value pairs of a [\`$CONTAINER\`].
+///
+/// This holds a C++ const_iterator of the $VAR, so that each step is constant time.
+pub struct ${CONTAINER}Iter<'a, K, V>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    $VAR: &'a $CONTAINER<K, V>,
+    iter: UniquePtr<<(K, V) as ${CONTAINER}Pair>::ConstIterator>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for ${CONTAINER}Iter<'a, K, V>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // The $VAR is borrowed for the lifetime of the iterator, so it cannot be modified
+        // and the iterator is not at the end while there are items remaining
+        let next = unsafe {
+            let next = (
+                <(K, V) as ${CONTAINER}Pair>::iter_key(self.$VAR, &self.iter),
+                <(K, V) as ${CONTAINER}Pair>::iter_value(self.$VAR, &self.iter),
+            );
+            <(K, V) as ${CONTAINER}Pair>::iter_next(self.$VAR, self.iter.pin_mut());
+            next
+        };
+        self.len -= 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ${CONTAINER}Iter<'a, K, V>
+where
+    (K, V): ${CONTAINER}Pair<Key = K, Value = V>,
+    $CONTAINER<K, V>: UniquePtrTarget,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+EOF
+    rustfmt --edition 2018 "$SCRIPTPATH/$CONTAINER_LOWER/mod.rs"
+
+    tee "$INCLUDEPATH/$CONTAINER_LOWER.h" <<EOF > /dev/null
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by src/types/generate_qmap_qhash.sh, do not edit it directly
+
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+${INCLUDES}
+
+#include "rust/cxx.h"
+
+// The supported $CONTAINER<K, V> instantiations are bridged using typedefs, Qt already
+// provides some of these, eg $ALIAS is $CONTAINER<QString, QVariant>
+//
+// The iterator of each instantiation is also bridged, so that iterating from Rust
+// uses a const_iterator rather than looking up each position from the start
+${TYPEDEFS}
+namespace rust {
+namespace cxxqtlib1 {
+namespace $CONTAINER_LOWER {
+
+template<typename K, typename V>
+using ConstIterator = typename $CONTAINER<K, V>::const_iterator;
+
+template<typename K, typename V>
+void
+${CONTAINER_LOWER}Clear($CONTAINER<K, V>& $VAR)
+{
+  $VAR.clear();
+}
+
+template<typename K, typename V>
+std::unique_ptr<$CONTAINER<K, V>>
+${CONTAINER_LOWER}Clone(const $CONTAINER<K, V>& $VAR)
+{
+  return std::make_unique<$CONTAINER<K, V>>($VAR);
+}
+
+template<typename K, typename V>
+bool
+${CONTAINER_LOWER}Contains(const $CONTAINER<K, V>& $VAR, const K& key)
+{
+  return $VAR.contains(key);
+}
+
+template<typename K, typename V>
+std::unique_ptr<$CONTAINER<K, V>>
+${CONTAINER_LOWER}Default()
+{
+  return std::make_unique<$CONTAINER<K, V>>();
+}
+
+template<typename K, typename V>
+const V&
+${CONTAINER_LOWER}GetUnchecked(const $CONTAINER<K, V>& $VAR, const K& key)
+{
+  Q_ASSERT($VAR.contains(key));
+  return $VAR.find(key).value();
+}
+
+template<typename K, typename V>
+void
+${CONTAINER_LOWER}Insert($CONTAINER<K, V>& $VAR, const K& key, const V& value)
+{
+  $VAR.insert(key, value);
+}
+
+template<typename K, typename V>
+std::unique_ptr<ConstIterator<K, V>>
+${CONTAINER_LOWER}IterBegin(const $CONTAINER<K, V>& $VAR)
+{
+  return std::make_unique<ConstIterator<K, V>>($VAR.cbegin());
+}
+
+template<typename K, typename V>
+const K&
+${CONTAINER_LOWER}IterKey(const $CONTAINER<K, V>& $VAR, const ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != $VAR.cend());
+  return iter.key();
+}
+
+template<typename K, typename V>
+void
+${CONTAINER_LOWER}IterNext(const $CONTAINER<K, V>& $VAR, ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != $VAR.cend());
+  ++iter;
+}
+
+template<typename K, typename V>
+const V&
+${CONTAINER_LOWER}IterValue(const $CONTAINER<K, V>& $VAR, const ConstIterator<K, V>& iter)
+{
+  Q_ASSERT(iter != $VAR.cend());
+  return iter.value();
+}
+
+template<typename K, typename V>
+::std::size_t
+${CONTAINER_LOWER}Len(const $CONTAINER<K, V>& $VAR)
+{
+  return static_cast<::std::size_t>($VAR.size());
+}
+
+template<typename K, typename V>
+bool
+${CONTAINER_LOWER}Remove($CONTAINER<K, V>& $VAR, const K& key)
+{
+  return $VAR.remove(key) >= 1;
+}
+
+} // namespace $CONTAINER_LOWER
+} // namespace cxxqtlib1
+} // namespace rust
+EOF
+}
+
+generate_container "QHash" "hash" "a hash-table-based dictionary" "in an arbitrary order" "QVariantHash" \
+    "i32:QByteArray:QHash_i32_QByteArray" "QString:QVariant:QVariantHash"
+generate_container "QMap" "map" "an associative array, which is sorted by key" "in ascending order by key" "QVariantMap" \
+    "QString:QVariant:QVariantMap"
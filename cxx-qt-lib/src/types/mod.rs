@@ -3,6 +3,12 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod connection;
+pub use connection::{ConnectionType, QMetaObjectConnection, QMetaObjectConnectionCpp};
+
+mod qbytearray;
+pub use qbytearray::QByteArray;
+
 mod qcolor;
 pub use qcolor::QColor;
 
@@ -12,6 +18,23 @@ pub use qdate::QDate;
 mod qdatetime;
 pub use qdatetime::QDateTime;
 
+mod qhash;
+pub use qhash::{QHash, QHashIter, QHashPair, QHash_i32_QByteArray, QVariantHash};
+
+mod qlist;
+pub use qlist::{
+    QList, QListElement, QListIter, QList_QColor, QList_QDate, QList_QDateTime, QList_QPoint,
+    QList_QPointF, QList_QRect, QList_QRectF, QList_QSize, QList_QSizeF, QList_QString,
+    QList_QTime, QList_QUrl, QList_QVariant, QList_bool, QList_f32, QList_f64, QList_i16,
+    QList_i32, QList_i8, QList_u16, QList_u32, QList_u8,
+};
+
+mod qmap;
+pub use qmap::{QMap, QMapIter, QMapPair, QVariantMap};
+
+mod qmodelindex;
+pub use qmodelindex::QModelIndex;
+
 mod qrect;
 pub use qrect::QRect;
 
@@ -27,6 +50,9 @@ pub use qsizef::QSizeF;
 mod qstring;
 pub use qstring::QString;
 
+mod qstringlist;
+pub use qstringlist::QStringList;
+
 mod qtime;
 pub use qtime::QTime;
 
@@ -39,8 +65,17 @@ pub use qpointf::QPointF;
 mod qurl;
 pub use qurl::QUrl;
 
+mod qvector;
+pub use qvector::{
+    QVector, QVectorElement, QVectorIter, QVectorSliceElement, QVector_QColor, QVector_QDate,
+    QVector_QDateTime, QVector_QPoint, QVector_QPointF, QVector_QRect, QVector_QRectF,
+    QVector_QSize, QVector_QSizeF, QVector_QString, QVector_QTime, QVector_QUrl, QVector_QVariant,
+    QVector_bool, QVector_f32, QVector_f64, QVector_i16, QVector_i32, QVector_i8, QVector_u16,
+    QVector_u32, QVector_u8,
+};
+
 mod qvariant;
-pub use qvariant::{QVariant, QVariantValue};
+pub use qvariant::{IntoQVariant, QVariant, QVariantValue};
 
 mod update_requester;
 pub use update_requester::{UpdateRequester, UpdateRequesterCpp};
@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+
+        type QByteArray;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_as_slice"]
+        fn qbytearrayAsSlice(byteArray: &QByteArray) -> &[u8];
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_as_mut_slice"]
+        fn qbytearrayAsMutSlice(byteArray: Pin<&mut QByteArray>) -> &mut [u8];
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_from_base64"]
+        fn qbytearrayFromBase64(base64: &[u8]) -> UniquePtr<QByteArray>;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_from_hex"]
+        fn qbytearrayFromHex(hex: &[u8]) -> UniquePtr<QByteArray>;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_init"]
+        fn qbytearrayInit() -> UniquePtr<QByteArray>;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_init_from_qbytearray"]
+        fn qbytearrayInitFromQByteArray(byteArray: &QByteArray) -> UniquePtr<QByteArray>;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_init_from_slice"]
+        fn qbytearrayInitFromSlice(bytes: &[u8]) -> UniquePtr<QByteArray>;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_to_base64"]
+        fn qbytearrayToBase64(byteArray: &QByteArray) -> UniquePtr<QByteArray>;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qbytearray_to_hex"]
+        fn qbytearrayToHex(byteArray: &QByteArray) -> UniquePtr<QByteArray>;
+    }
+
+    impl UniquePtr<QByteArray> {}
+}
+
+/// The QByteArray class provides an array of bytes.
+///
+/// Note that this is the C++ representation and `&[u8]` or `Vec<u8>` should be used in Rust.
+pub type QByteArray = ffi::QByteArray;
+
+impl QByteArray {
+    /// Constructs an empty byte array.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_init()
+    }
+
+    /// Construct a QByteArray from an existing QByteArray, this is a copy operation.
+    pub fn from_ref(byte_array: &QByteArray) -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_init_from_qbytearray(byte_array)
+    }
+
+    /// Constructs a byte array containing a copy of the bytes in the slice.
+    pub fn from_slice(bytes: &[u8]) -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_init_from_slice(bytes)
+    }
+
+    /// Constructs a byte array containing a copy of the bytes in the Vec.
+    pub fn from_vec(bytes: Vec<u8>) -> cxx::UniquePtr<Self> {
+        Self::from_slice(&bytes)
+    }
+
+    /// Returns a decoded copy of the Base64 array base64.
+    ///
+    /// Invalid characters in the input are skipped, as with `QByteArray::fromBase64` in Qt.
+    pub fn from_base64(base64: &[u8]) -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_from_base64(base64)
+    }
+
+    /// Returns a decoded copy of the hex encoded array hex.
+    ///
+    /// Invalid characters in the input are skipped, as with `QByteArray::fromHex` in Qt.
+    pub fn from_hex(hex: &[u8]) -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_from_hex(hex)
+    }
+
+    /// Returns a slice which borrows the bytes of the byte array without copying them.
+    pub fn as_slice(&self) -> &[u8] {
+        ffi::qbytearray_as_slice(self)
+    }
+
+    /// Returns a mutable slice which borrows the bytes of the byte array without copying them.
+    ///
+    /// If the data of the byte array is shared with another QByteArray it is detached first.
+    pub fn as_mut_slice(self: Pin<&mut Self>) -> &mut [u8] {
+        ffi::qbytearray_as_mut_slice(self)
+    }
+
+    /// Returns true if the byte array has size 0; otherwise returns false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes in this byte array.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns a copy of the byte array, encoded using Base64.
+    pub fn to_base64(&self) -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_to_base64(self)
+    }
+
+    /// Returns a hex encoded copy of the byte array.
+    pub fn to_hex(&self) -> cxx::UniquePtr<Self> {
+        ffi::qbytearray_to_hex(self)
+    }
+}
+
+impl From<&QByteArray> for cxx::UniquePtr<QByteArray> {
+    fn from(value: &QByteArray) -> cxx::UniquePtr<QByteArray> {
+        QByteArray::from_ref(value)
+    }
+}
+
+impl From<&QByteArray> for Vec<u8> {
+    /// Convert a reference to a QByteArray into a Vec of bytes, this is a copy operation.
+    fn from(byte_array: &QByteArray) -> Self {
+        byte_array.as_slice().to_vec()
+    }
+}
@@ -0,0 +1,282 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+mod qhash_i32_qbytearray;
+mod qhash_qstring_qvariant;
+
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    pin::Pin,
+};
+use cxx::{memory::UniquePtrTarget, ExternType, UniquePtr};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{IntoQVariant, QByteArray, QString, QVariant};
+
+/// The QHash class is a template class that provides a hash-table-based dictionary.
+///
+/// Note that this is the C++ representation, so it is only accessed by reference
+/// or through a `UniquePtr<QHash<K, V>>`.
+#[repr(C)]
+pub struct QHash<K, V> {
+    _private: [u8; 0],
+    _marker: PhantomData<(K, V, PhantomPinned)>,
+}
+
+/// A [`QHash`] of [`QVariant`] values with [`QString`] keys, a JavaScript object
+/// from QML is converted into a QVariantHash.
+///
+/// Use [`QVariantHash::from`] and `HashMap::from` or `BTreeMap::from` to convert
+/// from and to Rust collections.
+pub type QVariantHash = QHash<QString, QVariant>;
+
+// CXX requires a single word for the name of a type, these are the names used in a bridge
+#[allow(non_camel_case_types)]
+pub type QHash_i32_QByteArray = QHash<i32, QByteArray>;
+
+/// Trait implementation for a key value pair in a [`QHash`].
+///
+/// This is implemented for the `(K, V)` tuple of each of the supported pairs by the generated bridges.
+pub trait QHashPair: Sized
+where
+    QHash<Self::Key, Self::Value>: UniquePtrTarget,
+{
+    type Key;
+    type Value;
+    type TypeId;
+    type ConstIterator: UniquePtrTarget;
+
+    fn clear(hash: Pin<&mut QHash<Self::Key, Self::Value>>);
+    fn clone(hash: &QHash<Self::Key, Self::Value>) -> UniquePtr<QHash<Self::Key, Self::Value>>;
+    fn contains(hash: &QHash<Self::Key, Self::Value>, key: &Self::Key) -> bool;
+    fn default() -> UniquePtr<QHash<Self::Key, Self::Value>>;
+    /// # Safety
+    ///
+    /// Calling this method with a key that is not in the hash is undefined behavior
+    /// even if the resulting reference is not used.
+    unsafe fn get_unchecked<'a>(
+        hash: &'a QHash<Self::Key, Self::Value>,
+        key: &Self::Key,
+    ) -> &'a Self::Value;
+    fn insert(hash: Pin<&mut QHash<Self::Key, Self::Value>>, key: &Self::Key, value: &Self::Value);
+    fn iter_begin(hash: &QHash<Self::Key, Self::Value>) -> UniquePtr<Self::ConstIterator>;
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another hash, an iterator which is at the end,
+    /// or after the hash has been modified is undefined behavior even if the resulting reference is not used.
+    unsafe fn iter_key<'a>(
+        hash: &'a QHash<Self::Key, Self::Value>,
+        iter: &Self::ConstIterator,
+    ) -> &'a Self::Key;
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another hash, an iterator which is at the end,
+    /// or after the hash has been modified is undefined behavior.
+    unsafe fn iter_next(hash: &QHash<Self::Key, Self::Value>, iter: Pin<&mut Self::ConstIterator>);
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another hash, an iterator which is at the end,
+    /// or after the hash has been modified is undefined behavior even if the resulting reference is not used.
+    unsafe fn iter_value<'a>(
+        hash: &'a QHash<Self::Key, Self::Value>,
+        iter: &Self::ConstIterator,
+    ) -> &'a Self::Value;
+    fn len(hash: &QHash<Self::Key, Self::Value>) -> usize;
+    fn remove(hash: Pin<&mut QHash<Self::Key, Self::Value>>, key: &Self::Key) -> bool;
+}
+
+impl<K, V> QHash<K, V>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    /// Constructs an empty hash.
+    pub fn new() -> UniquePtr<Self> {
+        <(K, V) as QHashPair>::default()
+    }
+
+    /// Constructs a copy of the given hash.
+    pub fn from_ref(hash: &Self) -> UniquePtr<Self> {
+        <(K, V) as QHashPair>::clone(hash)
+    }
+
+    /// Removes all the items from the hash.
+    pub fn clear(self: Pin<&mut Self>) {
+        <(K, V) as QHashPair>::clear(self);
+    }
+
+    /// Returns true if the hash contains an item with the key; otherwise returns false.
+    pub fn contains(&self, key: &K) -> bool {
+        <(K, V) as QHashPair>::contains(self, key)
+    }
+
+    /// Returns the value associated with the key, or None if the hash does not contain the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        if self.contains(key) {
+            Some(unsafe { <(K, V) as QHashPair>::get_unchecked(self, key) })
+        } else {
+            None
+        }
+    }
+
+    /// Inserts a new item with the key and a value of value.
+    ///
+    /// If there is already an item with the key, that item's value is replaced with value.
+    pub fn insert(self: Pin<&mut Self>, key: &K, value: &V) {
+        <(K, V) as QHashPair>::insert(self, key, value);
+    }
+
+    /// Returns true if the hash contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all the key value pairs in the hash, in an arbitrary order.
+    pub fn iter(&self) -> QHashIter<K, V> {
+        QHashIter {
+            hash: self,
+            iter: <(K, V) as QHashPair>::iter_begin(self),
+            len: self.len(),
+        }
+    }
+
+    /// Returns the number of items in the hash.
+    pub fn len(&self) -> usize {
+        <(K, V) as QHashPair>::len(self)
+    }
+
+    /// Removes the item that has the key from the hash.
+    ///
+    /// Returns true if an item was removed; otherwise returns false.
+    pub fn remove(self: Pin<&mut Self>, key: &K) -> bool {
+        <(K, V) as QHashPair>::remove(self, key)
+    }
+}
+
+impl QHash<QString, QVariant> {
+    /// Constructs a hash from Rust key value pairs, such as a `HashMap<String, i32>`
+    /// or a `BTreeMap<&str, UniquePtr<QVariant>>`.
+    ///
+    /// This is used instead of a `From` implementation for `HashMap` and `BTreeMap`,
+    /// as the orphan rules do not allow implementing `From` for a `UniquePtr`.
+    pub fn from<I, S, T>(pairs: I) -> UniquePtr<Self>
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: AsRef<str>,
+        T: IntoQVariant,
+    {
+        let mut hash = Self::new();
+        for (key, value) in pairs {
+            hash.pin_mut()
+                .insert(&QString::from_str(key.as_ref()), &value.into_qvariant());
+        }
+        hash
+    }
+}
+
+unsafe impl<K, V> ExternType for QHash<K, V>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    type Id = <(K, V) as QHashPair>::TypeId;
+    type Kind = cxx::kind::Opaque;
+}
+
+impl<K, V> From<&QHash<K, V>> for UniquePtr<QHash<K, V>>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    fn from(hash: &QHash<K, V>) -> Self {
+        QHash::from_ref(hash)
+    }
+}
+
+impl From<&QHash<QString, QVariant>> for BTreeMap<String, UniquePtr<QVariant>> {
+    /// Convert a reference to a QVariantHash into a BTreeMap by making a deep copy of the data.
+    fn from(hash: &QHash<QString, QVariant>) -> Self {
+        hash.iter()
+            .map(|(key, value)| (key.to_string(), QVariant::from_ref(value)))
+            .collect()
+    }
+}
+
+impl From<&QHash<QString, QVariant>> for HashMap<String, UniquePtr<QVariant>> {
+    /// Convert a reference to a QVariantHash into a HashMap by making a deep copy of the data.
+    fn from(hash: &QHash<QString, QVariant>) -> Self {
+        hash.iter()
+            .map(|(key, value)| (key.to_string(), QVariant::from_ref(value)))
+            .collect()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a QHash<K, V>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = QHashIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key value pairs of a [`QHash`].
+///
+/// This holds a C++ const_iterator of the hash, so that each step is constant time.
+pub struct QHashIter<'a, K, V>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    hash: &'a QHash<K, V>,
+    iter: UniquePtr<<(K, V) as QHashPair>::ConstIterator>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for QHashIter<'a, K, V>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // The hash is borrowed for the lifetime of the iterator, so it cannot be modified
+        // and the iterator is not at the end while there are items remaining
+        let next = unsafe {
+            let next = (
+                <(K, V) as QHashPair>::iter_key(self.hash, &self.iter),
+                <(K, V) as QHashPair>::iter_value(self.hash, &self.iter),
+            );
+            <(K, V) as QHashPair>::iter_next(self.hash, self.iter.pin_mut());
+            next
+        };
+        self.len -= 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for QHashIter<'a, K, V>
+where
+    (K, V): QHashPair<Key = K, Value = V>,
+    QHash<K, V>: UniquePtrTarget,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
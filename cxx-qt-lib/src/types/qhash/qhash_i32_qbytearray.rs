@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+
+use crate::QByteArray;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        include!("cxx-qt-lib/include/qhash.h");
+
+        type QByteArray = crate::QByteArray;
+        type QHash_i32_QByteArray = crate::QHash<i32, QByteArray>;
+        type QHashConstIterator_i32_QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qhash"]
+    unsafe extern "C++" {
+        #[rust_name = "qhash_clear_i32_qbytearray"]
+        fn qhashClear(hash: Pin<&mut QHash_i32_QByteArray>);
+        #[rust_name = "qhash_clone_i32_qbytearray"]
+        fn qhashClone(hash: &QHash_i32_QByteArray) -> UniquePtr<QHash_i32_QByteArray>;
+        #[rust_name = "qhash_contains_i32_qbytearray"]
+        fn qhashContains(hash: &QHash_i32_QByteArray, key: &i32) -> bool;
+        #[rust_name = "qhash_default_i32_qbytearray"]
+        fn qhashDefault() -> UniquePtr<QHash_i32_QByteArray>;
+        #[rust_name = "qhash_get_unchecked_i32_qbytearray"]
+        unsafe fn qhashGetUnchecked<'a>(
+            hash: &'a QHash_i32_QByteArray,
+            key: &i32,
+        ) -> &'a QByteArray;
+        #[rust_name = "qhash_insert_i32_qbytearray"]
+        fn qhashInsert(hash: Pin<&mut QHash_i32_QByteArray>, key: &i32, value: &QByteArray);
+        #[rust_name = "qhash_iter_begin_i32_qbytearray"]
+        fn qhashIterBegin(
+            hash: &QHash_i32_QByteArray,
+        ) -> UniquePtr<QHashConstIterator_i32_QByteArray>;
+        #[rust_name = "qhash_iter_key_i32_qbytearray"]
+        unsafe fn qhashIterKey<'a>(
+            hash: &'a QHash_i32_QByteArray,
+            iter: &QHashConstIterator_i32_QByteArray,
+        ) -> &'a i32;
+        #[rust_name = "qhash_iter_next_i32_qbytearray"]
+        unsafe fn qhashIterNext(
+            hash: &QHash_i32_QByteArray,
+            iter: Pin<&mut QHashConstIterator_i32_QByteArray>,
+        );
+        #[rust_name = "qhash_iter_value_i32_qbytearray"]
+        unsafe fn qhashIterValue<'a>(
+            hash: &'a QHash_i32_QByteArray,
+            iter: &QHashConstIterator_i32_QByteArray,
+        ) -> &'a QByteArray;
+        #[rust_name = "qhash_len_i32_qbytearray"]
+        fn qhashLen(hash: &QHash_i32_QByteArray) -> usize;
+        #[rust_name = "qhash_remove_i32_qbytearray"]
+        fn qhashRemove(hash: Pin<&mut QHash_i32_QByteArray>, key: &i32) -> bool;
+    }
+
+    impl UniquePtr<QHash_i32_QByteArray> {}
+}
+
+impl crate::QHashPair for (i32, QByteArray) {
+    type Key = i32;
+    type Value = QByteArray;
+    type TypeId = type_id!("QHash_i32_QByteArray");
+    type ConstIterator = ffi::QHashConstIterator_i32_QByteArray;
+
+    fn clear(hash: Pin<&mut ffi::QHash_i32_QByteArray>) {
+        ffi::qhash_clear_i32_qbytearray(hash);
+    }
+
+    fn clone(hash: &ffi::QHash_i32_QByteArray) -> UniquePtr<ffi::QHash_i32_QByteArray> {
+        ffi::qhash_clone_i32_qbytearray(hash)
+    }
+
+    fn contains(hash: &ffi::QHash_i32_QByteArray, key: &i32) -> bool {
+        ffi::qhash_contains_i32_qbytearray(hash, key)
+    }
+
+    fn default() -> UniquePtr<ffi::QHash_i32_QByteArray> {
+        ffi::qhash_default_i32_qbytearray()
+    }
+
+    unsafe fn get_unchecked<'a>(hash: &'a ffi::QHash_i32_QByteArray, key: &i32) -> &'a QByteArray {
+        ffi::qhash_get_unchecked_i32_qbytearray(hash, key)
+    }
+
+    fn insert(hash: Pin<&mut ffi::QHash_i32_QByteArray>, key: &i32, value: &QByteArray) {
+        ffi::qhash_insert_i32_qbytearray(hash, key, value);
+    }
+
+    fn iter_begin(
+        hash: &ffi::QHash_i32_QByteArray,
+    ) -> UniquePtr<ffi::QHashConstIterator_i32_QByteArray> {
+        ffi::qhash_iter_begin_i32_qbytearray(hash)
+    }
+
+    unsafe fn iter_key<'a>(
+        hash: &'a ffi::QHash_i32_QByteArray,
+        iter: &ffi::QHashConstIterator_i32_QByteArray,
+    ) -> &'a i32 {
+        ffi::qhash_iter_key_i32_qbytearray(hash, iter)
+    }
+
+    unsafe fn iter_next(
+        hash: &ffi::QHash_i32_QByteArray,
+        iter: Pin<&mut ffi::QHashConstIterator_i32_QByteArray>,
+    ) {
+        ffi::qhash_iter_next_i32_qbytearray(hash, iter);
+    }
+
+    unsafe fn iter_value<'a>(
+        hash: &'a ffi::QHash_i32_QByteArray,
+        iter: &ffi::QHashConstIterator_i32_QByteArray,
+    ) -> &'a QByteArray {
+        ffi::qhash_iter_value_i32_qbytearray(hash, iter)
+    }
+
+    fn len(hash: &ffi::QHash_i32_QByteArray) -> usize {
+        ffi::qhash_len_i32_qbytearray(hash)
+    }
+
+    fn remove(hash: Pin<&mut ffi::QHash_i32_QByteArray>, key: &i32) -> bool {
+        ffi::qhash_remove_i32_qbytearray(hash, key)
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+
+use crate::{QString, QVariant};
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        include!("cxx-qt-lib/include/qhash.h");
+
+        type QString = crate::QString;
+        type QVariant = crate::QVariant;
+        type QVariantHash = crate::QHash<QString, QVariant>;
+        type QHashConstIterator_QString_QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qhash"]
+    unsafe extern "C++" {
+        #[rust_name = "qhash_clear_qstring_qvariant"]
+        fn qhashClear(hash: Pin<&mut QVariantHash>);
+        #[rust_name = "qhash_clone_qstring_qvariant"]
+        fn qhashClone(hash: &QVariantHash) -> UniquePtr<QVariantHash>;
+        #[rust_name = "qhash_contains_qstring_qvariant"]
+        fn qhashContains(hash: &QVariantHash, key: &QString) -> bool;
+        #[rust_name = "qhash_default_qstring_qvariant"]
+        fn qhashDefault() -> UniquePtr<QVariantHash>;
+        #[rust_name = "qhash_get_unchecked_qstring_qvariant"]
+        unsafe fn qhashGetUnchecked<'a>(hash: &'a QVariantHash, key: &QString) -> &'a QVariant;
+        #[rust_name = "qhash_insert_qstring_qvariant"]
+        fn qhashInsert(hash: Pin<&mut QVariantHash>, key: &QString, value: &QVariant);
+        #[rust_name = "qhash_iter_begin_qstring_qvariant"]
+        fn qhashIterBegin(hash: &QVariantHash) -> UniquePtr<QHashConstIterator_QString_QVariant>;
+        #[rust_name = "qhash_iter_key_qstring_qvariant"]
+        unsafe fn qhashIterKey<'a>(
+            hash: &'a QVariantHash,
+            iter: &QHashConstIterator_QString_QVariant,
+        ) -> &'a QString;
+        #[rust_name = "qhash_iter_next_qstring_qvariant"]
+        unsafe fn qhashIterNext(
+            hash: &QVariantHash,
+            iter: Pin<&mut QHashConstIterator_QString_QVariant>,
+        );
+        #[rust_name = "qhash_iter_value_qstring_qvariant"]
+        unsafe fn qhashIterValue<'a>(
+            hash: &'a QVariantHash,
+            iter: &QHashConstIterator_QString_QVariant,
+        ) -> &'a QVariant;
+        #[rust_name = "qhash_len_qstring_qvariant"]
+        fn qhashLen(hash: &QVariantHash) -> usize;
+        #[rust_name = "qhash_remove_qstring_qvariant"]
+        fn qhashRemove(hash: Pin<&mut QVariantHash>, key: &QString) -> bool;
+    }
+
+    impl UniquePtr<QVariantHash> {}
+}
+
+impl crate::QHashPair for (QString, QVariant) {
+    type Key = QString;
+    type Value = QVariant;
+    type TypeId = type_id!("QVariantHash");
+    type ConstIterator = ffi::QHashConstIterator_QString_QVariant;
+
+    fn clear(hash: Pin<&mut ffi::QVariantHash>) {
+        ffi::qhash_clear_qstring_qvariant(hash);
+    }
+
+    fn clone(hash: &ffi::QVariantHash) -> UniquePtr<ffi::QVariantHash> {
+        ffi::qhash_clone_qstring_qvariant(hash)
+    }
+
+    fn contains(hash: &ffi::QVariantHash, key: &QString) -> bool {
+        ffi::qhash_contains_qstring_qvariant(hash, key)
+    }
+
+    fn default() -> UniquePtr<ffi::QVariantHash> {
+        ffi::qhash_default_qstring_qvariant()
+    }
+
+    unsafe fn get_unchecked<'a>(hash: &'a ffi::QVariantHash, key: &QString) -> &'a QVariant {
+        ffi::qhash_get_unchecked_qstring_qvariant(hash, key)
+    }
+
+    fn insert(hash: Pin<&mut ffi::QVariantHash>, key: &QString, value: &QVariant) {
+        ffi::qhash_insert_qstring_qvariant(hash, key, value);
+    }
+
+    fn iter_begin(hash: &ffi::QVariantHash) -> UniquePtr<ffi::QHashConstIterator_QString_QVariant> {
+        ffi::qhash_iter_begin_qstring_qvariant(hash)
+    }
+
+    unsafe fn iter_key<'a>(
+        hash: &'a ffi::QVariantHash,
+        iter: &ffi::QHashConstIterator_QString_QVariant,
+    ) -> &'a QString {
+        ffi::qhash_iter_key_qstring_qvariant(hash, iter)
+    }
+
+    unsafe fn iter_next(
+        hash: &ffi::QVariantHash,
+        iter: Pin<&mut ffi::QHashConstIterator_QString_QVariant>,
+    ) {
+        ffi::qhash_iter_next_qstring_qvariant(hash, iter);
+    }
+
+    unsafe fn iter_value<'a>(
+        hash: &'a ffi::QVariantHash,
+        iter: &ffi::QHashConstIterator_QString_QVariant,
+    ) -> &'a QVariant {
+        ffi::qhash_iter_value_qstring_qvariant(hash, iter)
+    }
+
+    fn len(hash: &ffi::QVariantHash) -> usize {
+        ffi::qhash_len_qstring_qvariant(hash)
+    }
+
+    fn remove(hash: Pin<&mut ffi::QVariantHash>, key: &QString) -> bool {
+        ffi::qhash_remove_qstring_qvariant(hash, key)
+    }
+}
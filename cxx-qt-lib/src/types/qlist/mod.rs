@@ -0,0 +1,373 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// The bridges for each element type are generated by generate_qlist_qvector.sh
+mod qlist_bool;
+mod qlist_f32;
+mod qlist_f64;
+mod qlist_i16;
+mod qlist_i32;
+mod qlist_i8;
+mod qlist_qcolor;
+mod qlist_qdate;
+mod qlist_qdatetime;
+mod qlist_qpoint;
+mod qlist_qpointf;
+mod qlist_qrect;
+mod qlist_qrectf;
+mod qlist_qsize;
+mod qlist_qsizef;
+mod qlist_qstring;
+mod qlist_qtime;
+mod qlist_qurl;
+mod qlist_qvariant;
+mod qlist_u16;
+mod qlist_u32;
+mod qlist_u8;
+
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    pin::Pin,
+};
+use cxx::{memory::UniquePtrTarget, ExternType, UniquePtr};
+
+use crate::{
+    QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl,
+    QVariant,
+};
+
+/// The QList class is a template class that provides a dynamic array.
+///
+/// Note that this is the C++ representation, so it is only accessed by reference
+/// or through a `UniquePtr<QList<T>>`. Use [`QList::from_slice`] and `Vec::from` to convert
+/// from and to Rust collections, or [`QList::from_unique_ptrs`] and `Vec<UniquePtr<T>>` for
+/// opaque element types such as [`QVariant`]. A [`QList_QString`] can also be converted from and
+/// to Rust strings with [`QList::from_strs`] and `Vec<String>`.
+///
+/// Unlike [`QVector`](crate::QVector), a QList cannot be borrowed as a Rust slice. On Qt 5 a QList
+/// stores most element types as an array of pointers to the elements, so they are not contiguous.
+#[repr(C)]
+pub struct QList<T> {
+    _private: [u8; 0],
+    _marker: PhantomData<(T, PhantomPinned)>,
+}
+
+// CXX requires a single word for the name of a type, these are the names used in a bridge
+#[allow(non_camel_case_types)]
+pub type QList_bool = QList<bool>;
+#[allow(non_camel_case_types)]
+pub type QList_f32 = QList<f32>;
+#[allow(non_camel_case_types)]
+pub type QList_f64 = QList<f64>;
+#[allow(non_camel_case_types)]
+pub type QList_i8 = QList<i8>;
+#[allow(non_camel_case_types)]
+pub type QList_i16 = QList<i16>;
+#[allow(non_camel_case_types)]
+pub type QList_i32 = QList<i32>;
+#[allow(non_camel_case_types)]
+pub type QList_QColor = QList<QColor>;
+#[allow(non_camel_case_types)]
+pub type QList_QDate = QList<QDate>;
+#[allow(non_camel_case_types)]
+pub type QList_QDateTime = QList<QDateTime>;
+#[allow(non_camel_case_types)]
+pub type QList_QPoint = QList<QPoint>;
+#[allow(non_camel_case_types)]
+pub type QList_QPointF = QList<QPointF>;
+#[allow(non_camel_case_types)]
+pub type QList_QRect = QList<QRect>;
+#[allow(non_camel_case_types)]
+pub type QList_QRectF = QList<QRectF>;
+#[allow(non_camel_case_types)]
+pub type QList_QSize = QList<QSize>;
+#[allow(non_camel_case_types)]
+pub type QList_QSizeF = QList<QSizeF>;
+#[allow(non_camel_case_types)]
+pub type QList_QString = QList<QString>;
+#[allow(non_camel_case_types)]
+pub type QList_QTime = QList<QTime>;
+#[allow(non_camel_case_types)]
+pub type QList_QUrl = QList<QUrl>;
+#[allow(non_camel_case_types)]
+pub type QList_QVariant = QList<QVariant>;
+#[allow(non_camel_case_types)]
+pub type QList_u8 = QList<u8>;
+#[allow(non_camel_case_types)]
+pub type QList_u16 = QList<u16>;
+#[allow(non_camel_case_types)]
+pub type QList_u32 = QList<u32>;
+
+/// Trait implementation for an element in a [`QList`].
+///
+/// This is implemented for each of the supported element types by the generated bridges.
+pub trait QListElement: Sized
+where
+    QList<Self>: UniquePtrTarget,
+{
+    type TypeId;
+
+    fn append(list: Pin<&mut QList<Self>>, value: &Self);
+    fn clear(list: Pin<&mut QList<Self>>);
+    fn clone(list: &QList<Self>) -> UniquePtr<QList<Self>>;
+    fn contains(list: &QList<Self>, value: &Self) -> bool;
+    fn default() -> UniquePtr<QList<Self>>;
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is undefined behavior
+    /// even if the resulting reference is not used.
+    unsafe fn get_unchecked(list: &QList<Self>, pos: usize) -> &Self;
+    fn index_of(list: &QList<Self>, value: &Self) -> isize;
+    fn insert(list: Pin<&mut QList<Self>>, pos: usize, value: &Self);
+    fn len(list: &QList<Self>) -> usize;
+    fn remove(list: Pin<&mut QList<Self>>, pos: usize);
+}
+
+impl<T> QList<T>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    /// Constructs an empty list.
+    pub fn new() -> UniquePtr<Self> {
+        T::default()
+    }
+
+    /// Constructs a list containing a copy of each of the elements in the slice.
+    ///
+    /// Opaque element types cannot be stored in a Rust slice, use
+    /// [`QList::from_unique_ptrs`] for these instead.
+    pub fn from_slice(values: &[T]) -> UniquePtr<Self> {
+        let mut list = Self::new();
+        for value in values {
+            list.pin_mut().push(value);
+        }
+        list
+    }
+
+    /// Constructs a copy of the given list.
+    pub fn from_ref(list: &Self) -> UniquePtr<Self> {
+        T::clone(list)
+    }
+
+    /// Removes all the elements from the list.
+    pub fn clear(self: Pin<&mut Self>) {
+        T::clear(self);
+    }
+
+    /// Returns true if the list contains an occurrence of value; otherwise returns false.
+    pub fn contains(&self, value: &T) -> bool {
+        T::contains(self, value)
+    }
+
+    /// Returns the item at index position in the list, or None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            Some(unsafe { T::get_unchecked(self, index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index position of the first occurrence of value in the list,
+    /// or None if no item matched.
+    pub fn index_of(&self, value: &T) -> Option<usize> {
+        let index = T::index_of(self, value);
+        if index < 0 {
+            None
+        } else {
+            Some(index as usize)
+        }
+    }
+
+    /// Inserts value at index position in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(self: Pin<&mut Self>, index: usize, value: &T) {
+        let len = self.len();
+        if index > len {
+            panic!(
+                "insertion index (is {}) should be <= len (is {})",
+                index, len
+            );
+        }
+
+        T::insert(self, index, value);
+    }
+
+    /// Returns true if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all the elements in the list in order.
+    pub fn iter(&self) -> QListIter<T> {
+        QListIter {
+            list: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        T::len(self)
+    }
+
+    /// Appends value to the end of the list.
+    pub fn push(self: Pin<&mut Self>, value: &T) {
+        T::append(self, value);
+    }
+
+    /// Removes the element at index position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(self: Pin<&mut Self>, index: usize) {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {}) should be < len (is {})", index, len);
+        }
+
+        T::remove(self, index);
+    }
+}
+
+impl<T> QList<T>
+where
+    T: QListElement + UniquePtrTarget,
+    QList<T>: UniquePtrTarget,
+{
+    /// Constructs a list containing a copy of each of the elements in the slice,
+    /// this is used for opaque element types which can only be held by a `UniquePtr`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the elements are null.
+    pub fn from_unique_ptrs(values: &[UniquePtr<T>]) -> UniquePtr<Self> {
+        let mut list = Self::new();
+        for value in values {
+            list.pin_mut().push(value);
+        }
+        list
+    }
+}
+
+impl QList<QString> {
+    /// Constructs a list of strings from a slice of Rust strings, such as a `&[&str]`
+    /// or a `Vec<String>`.
+    pub fn from_strs<S: AsRef<str>>(values: &[S]) -> UniquePtr<Self> {
+        let mut list = Self::new();
+        for value in values {
+            list.pin_mut().push(&QString::from_str(value.as_ref()));
+        }
+        list
+    }
+}
+
+unsafe impl<T> ExternType for QList<T>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    type Id = T::TypeId;
+    type Kind = cxx::kind::Opaque;
+}
+
+impl<T> From<&QList<T>> for UniquePtr<QList<T>>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    fn from(list: &QList<T>) -> Self {
+        QList::from_ref(list)
+    }
+}
+
+impl<T> From<&QList<T>> for Vec<T>
+where
+    T: QListElement + Clone,
+    QList<T>: UniquePtrTarget,
+{
+    /// Convert a reference to a QList into a Vec by making a deep copy of the data.
+    fn from(list: &QList<T>) -> Self {
+        list.iter().cloned().collect()
+    }
+}
+
+impl<T> From<&QList<T>> for Vec<UniquePtr<T>>
+where
+    T: QListElement + UniquePtrTarget,
+    QList<T>: UniquePtrTarget,
+    for<'b> UniquePtr<T>: From<&'b T>,
+{
+    /// Convert a reference to a QList into a Vec of UniquePtr by making a deep copy of the data.
+    fn from(list: &QList<T>) -> Self {
+        list.iter().map(UniquePtr::from).collect()
+    }
+}
+
+impl From<&QList<QString>> for Vec<String> {
+    /// Convert a reference to a QList of strings into a Vec of Rust strings.
+    fn from(list: &QList<QString>) -> Self {
+        list.iter().map(|string| string.to_string()).collect()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a QList<T>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    type Item = &'a T;
+    type IntoIter = QListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`QList`].
+pub struct QListIter<'a, T>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    list: &'a QList<T>,
+    index: usize,
+}
+
+impl<'a, T> Iterator for QListIter<'a, T>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.list.get(self.index);
+        if next.is_some() {
+            self.index += 1;
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for QListIter<'a, T>
+where
+    T: QListElement,
+    QList<T>: UniquePtrTarget,
+{
+    fn len(&self) -> usize {
+        self.list.len() - self.index
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_bool = crate::QList<bool>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_bool"]
+        fn qlistAppend(list: Pin<&mut QList_bool>, value: &bool);
+        #[rust_name = "qlist_clear_bool"]
+        fn qlistClear(list: Pin<&mut QList_bool>);
+        #[rust_name = "qlist_clone_bool"]
+        fn qlistClone(list: &QList_bool) -> UniquePtr<QList_bool>;
+        #[rust_name = "qlist_contains_bool"]
+        fn qlistContains(list: &QList_bool, value: &bool) -> bool;
+        #[rust_name = "qlist_default_bool"]
+        fn qlistDefault() -> UniquePtr<QList_bool>;
+        #[rust_name = "qlist_get_unchecked_bool"]
+        unsafe fn qlistGetUnchecked(list: &QList_bool, pos: usize) -> &bool;
+        #[rust_name = "qlist_index_of_bool"]
+        fn qlistIndexOf(list: &QList_bool, value: &bool) -> isize;
+        #[rust_name = "qlist_insert_bool"]
+        fn qlistInsert(list: Pin<&mut QList_bool>, pos: usize, value: &bool);
+        #[rust_name = "qlist_len_bool"]
+        fn qlistLen(list: &QList_bool) -> usize;
+        #[rust_name = "qlist_remove_bool"]
+        fn qlistRemove(list: Pin<&mut QList_bool>, pos: usize);
+    }
+
+    impl UniquePtr<QList_bool> {}
+}
+
+impl crate::QListElement for bool {
+    type TypeId = type_id!("QList_bool");
+
+    fn append(list: Pin<&mut ffi::QList_bool>, value: &Self) {
+        ffi::qlist_append_bool(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_bool>) {
+        ffi::qlist_clear_bool(list);
+    }
+
+    fn clone(list: &ffi::QList_bool) -> UniquePtr<ffi::QList_bool> {
+        ffi::qlist_clone_bool(list)
+    }
+
+    fn contains(list: &ffi::QList_bool, value: &Self) -> bool {
+        ffi::qlist_contains_bool(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_bool> {
+        ffi::qlist_default_bool()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_bool, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_bool(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_bool, value: &Self) -> isize {
+        ffi::qlist_index_of_bool(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_bool>, pos: usize, value: &Self) {
+        ffi::qlist_insert_bool(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_bool) -> usize {
+        ffi::qlist_len_bool(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_bool>, pos: usize) {
+        ffi::qlist_remove_bool(list, pos);
+    }
+}
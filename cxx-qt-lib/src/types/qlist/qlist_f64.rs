@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_f64 = crate::QList<f64>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_f64"]
+        fn qlistAppend(list: Pin<&mut QList_f64>, value: &f64);
+        #[rust_name = "qlist_clear_f64"]
+        fn qlistClear(list: Pin<&mut QList_f64>);
+        #[rust_name = "qlist_clone_f64"]
+        fn qlistClone(list: &QList_f64) -> UniquePtr<QList_f64>;
+        #[rust_name = "qlist_contains_f64"]
+        fn qlistContains(list: &QList_f64, value: &f64) -> bool;
+        #[rust_name = "qlist_default_f64"]
+        fn qlistDefault() -> UniquePtr<QList_f64>;
+        #[rust_name = "qlist_get_unchecked_f64"]
+        unsafe fn qlistGetUnchecked(list: &QList_f64, pos: usize) -> &f64;
+        #[rust_name = "qlist_index_of_f64"]
+        fn qlistIndexOf(list: &QList_f64, value: &f64) -> isize;
+        #[rust_name = "qlist_insert_f64"]
+        fn qlistInsert(list: Pin<&mut QList_f64>, pos: usize, value: &f64);
+        #[rust_name = "qlist_len_f64"]
+        fn qlistLen(list: &QList_f64) -> usize;
+        #[rust_name = "qlist_remove_f64"]
+        fn qlistRemove(list: Pin<&mut QList_f64>, pos: usize);
+    }
+
+    impl UniquePtr<QList_f64> {}
+}
+
+impl crate::QListElement for f64 {
+    type TypeId = type_id!("QList_f64");
+
+    fn append(list: Pin<&mut ffi::QList_f64>, value: &Self) {
+        ffi::qlist_append_f64(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_f64>) {
+        ffi::qlist_clear_f64(list);
+    }
+
+    fn clone(list: &ffi::QList_f64) -> UniquePtr<ffi::QList_f64> {
+        ffi::qlist_clone_f64(list)
+    }
+
+    fn contains(list: &ffi::QList_f64, value: &Self) -> bool {
+        ffi::qlist_contains_f64(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_f64> {
+        ffi::qlist_default_f64()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_f64, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_f64(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_f64, value: &Self) -> isize {
+        ffi::qlist_index_of_f64(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_f64>, pos: usize, value: &Self) {
+        ffi::qlist_insert_f64(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_f64) -> usize {
+        ffi::qlist_len_f64(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_f64>, pos: usize) {
+        ffi::qlist_remove_f64(list, pos);
+    }
+}
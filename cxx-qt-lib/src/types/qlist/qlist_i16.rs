@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_i16 = crate::QList<i16>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_i16"]
+        fn qlistAppend(list: Pin<&mut QList_i16>, value: &i16);
+        #[rust_name = "qlist_clear_i16"]
+        fn qlistClear(list: Pin<&mut QList_i16>);
+        #[rust_name = "qlist_clone_i16"]
+        fn qlistClone(list: &QList_i16) -> UniquePtr<QList_i16>;
+        #[rust_name = "qlist_contains_i16"]
+        fn qlistContains(list: &QList_i16, value: &i16) -> bool;
+        #[rust_name = "qlist_default_i16"]
+        fn qlistDefault() -> UniquePtr<QList_i16>;
+        #[rust_name = "qlist_get_unchecked_i16"]
+        unsafe fn qlistGetUnchecked(list: &QList_i16, pos: usize) -> &i16;
+        #[rust_name = "qlist_index_of_i16"]
+        fn qlistIndexOf(list: &QList_i16, value: &i16) -> isize;
+        #[rust_name = "qlist_insert_i16"]
+        fn qlistInsert(list: Pin<&mut QList_i16>, pos: usize, value: &i16);
+        #[rust_name = "qlist_len_i16"]
+        fn qlistLen(list: &QList_i16) -> usize;
+        #[rust_name = "qlist_remove_i16"]
+        fn qlistRemove(list: Pin<&mut QList_i16>, pos: usize);
+    }
+
+    impl UniquePtr<QList_i16> {}
+}
+
+impl crate::QListElement for i16 {
+    type TypeId = type_id!("QList_i16");
+
+    fn append(list: Pin<&mut ffi::QList_i16>, value: &Self) {
+        ffi::qlist_append_i16(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_i16>) {
+        ffi::qlist_clear_i16(list);
+    }
+
+    fn clone(list: &ffi::QList_i16) -> UniquePtr<ffi::QList_i16> {
+        ffi::qlist_clone_i16(list)
+    }
+
+    fn contains(list: &ffi::QList_i16, value: &Self) -> bool {
+        ffi::qlist_contains_i16(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_i16> {
+        ffi::qlist_default_i16()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_i16, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_i16(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_i16, value: &Self) -> isize {
+        ffi::qlist_index_of_i16(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_i16>, pos: usize, value: &Self) {
+        ffi::qlist_insert_i16(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_i16) -> usize {
+        ffi::qlist_len_i16(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_i16>, pos: usize) {
+        ffi::qlist_remove_i16(list, pos);
+    }
+}
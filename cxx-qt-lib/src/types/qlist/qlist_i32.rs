@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_i32 = crate::QList<i32>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_i32"]
+        fn qlistAppend(list: Pin<&mut QList_i32>, value: &i32);
+        #[rust_name = "qlist_clear_i32"]
+        fn qlistClear(list: Pin<&mut QList_i32>);
+        #[rust_name = "qlist_clone_i32"]
+        fn qlistClone(list: &QList_i32) -> UniquePtr<QList_i32>;
+        #[rust_name = "qlist_contains_i32"]
+        fn qlistContains(list: &QList_i32, value: &i32) -> bool;
+        #[rust_name = "qlist_default_i32"]
+        fn qlistDefault() -> UniquePtr<QList_i32>;
+        #[rust_name = "qlist_get_unchecked_i32"]
+        unsafe fn qlistGetUnchecked(list: &QList_i32, pos: usize) -> &i32;
+        #[rust_name = "qlist_index_of_i32"]
+        fn qlistIndexOf(list: &QList_i32, value: &i32) -> isize;
+        #[rust_name = "qlist_insert_i32"]
+        fn qlistInsert(list: Pin<&mut QList_i32>, pos: usize, value: &i32);
+        #[rust_name = "qlist_len_i32"]
+        fn qlistLen(list: &QList_i32) -> usize;
+        #[rust_name = "qlist_remove_i32"]
+        fn qlistRemove(list: Pin<&mut QList_i32>, pos: usize);
+    }
+
+    impl UniquePtr<QList_i32> {}
+}
+
+impl crate::QListElement for i32 {
+    type TypeId = type_id!("QList_i32");
+
+    fn append(list: Pin<&mut ffi::QList_i32>, value: &Self) {
+        ffi::qlist_append_i32(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_i32>) {
+        ffi::qlist_clear_i32(list);
+    }
+
+    fn clone(list: &ffi::QList_i32) -> UniquePtr<ffi::QList_i32> {
+        ffi::qlist_clone_i32(list)
+    }
+
+    fn contains(list: &ffi::QList_i32, value: &Self) -> bool {
+        ffi::qlist_contains_i32(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_i32> {
+        ffi::qlist_default_i32()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_i32, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_i32(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_i32, value: &Self) -> isize {
+        ffi::qlist_index_of_i32(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_i32>, pos: usize, value: &Self) {
+        ffi::qlist_insert_i32(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_i32) -> usize {
+        ffi::qlist_len_i32(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_i32>, pos: usize) {
+        ffi::qlist_remove_i32(list, pos);
+    }
+}
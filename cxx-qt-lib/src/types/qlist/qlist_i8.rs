@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_i8 = crate::QList<i8>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_i8"]
+        fn qlistAppend(list: Pin<&mut QList_i8>, value: &i8);
+        #[rust_name = "qlist_clear_i8"]
+        fn qlistClear(list: Pin<&mut QList_i8>);
+        #[rust_name = "qlist_clone_i8"]
+        fn qlistClone(list: &QList_i8) -> UniquePtr<QList_i8>;
+        #[rust_name = "qlist_contains_i8"]
+        fn qlistContains(list: &QList_i8, value: &i8) -> bool;
+        #[rust_name = "qlist_default_i8"]
+        fn qlistDefault() -> UniquePtr<QList_i8>;
+        #[rust_name = "qlist_get_unchecked_i8"]
+        unsafe fn qlistGetUnchecked(list: &QList_i8, pos: usize) -> &i8;
+        #[rust_name = "qlist_index_of_i8"]
+        fn qlistIndexOf(list: &QList_i8, value: &i8) -> isize;
+        #[rust_name = "qlist_insert_i8"]
+        fn qlistInsert(list: Pin<&mut QList_i8>, pos: usize, value: &i8);
+        #[rust_name = "qlist_len_i8"]
+        fn qlistLen(list: &QList_i8) -> usize;
+        #[rust_name = "qlist_remove_i8"]
+        fn qlistRemove(list: Pin<&mut QList_i8>, pos: usize);
+    }
+
+    impl UniquePtr<QList_i8> {}
+}
+
+impl crate::QListElement for i8 {
+    type TypeId = type_id!("QList_i8");
+
+    fn append(list: Pin<&mut ffi::QList_i8>, value: &Self) {
+        ffi::qlist_append_i8(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_i8>) {
+        ffi::qlist_clear_i8(list);
+    }
+
+    fn clone(list: &ffi::QList_i8) -> UniquePtr<ffi::QList_i8> {
+        ffi::qlist_clone_i8(list)
+    }
+
+    fn contains(list: &ffi::QList_i8, value: &Self) -> bool {
+        ffi::qlist_contains_i8(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_i8> {
+        ffi::qlist_default_i8()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_i8, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_i8(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_i8, value: &Self) -> isize {
+        ffi::qlist_index_of_i8(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_i8>, pos: usize, value: &Self) {
+        ffi::qlist_insert_i8(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_i8) -> usize {
+        ffi::qlist_len_i8(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_i8>, pos: usize) {
+        ffi::qlist_remove_i8(list, pos);
+    }
+}
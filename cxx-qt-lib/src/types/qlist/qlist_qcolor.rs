@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QColor;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QColor = crate::QColor;
+
+        type QList_QColor = crate::QList<QColor>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qcolor"]
+        fn qlistAppend(list: Pin<&mut QList_QColor>, value: &QColor);
+        #[rust_name = "qlist_clear_qcolor"]
+        fn qlistClear(list: Pin<&mut QList_QColor>);
+        #[rust_name = "qlist_clone_qcolor"]
+        fn qlistClone(list: &QList_QColor) -> UniquePtr<QList_QColor>;
+        #[rust_name = "qlist_contains_qcolor"]
+        fn qlistContains(list: &QList_QColor, value: &QColor) -> bool;
+        #[rust_name = "qlist_default_qcolor"]
+        fn qlistDefault() -> UniquePtr<QList_QColor>;
+        #[rust_name = "qlist_get_unchecked_qcolor"]
+        unsafe fn qlistGetUnchecked(list: &QList_QColor, pos: usize) -> &QColor;
+        #[rust_name = "qlist_index_of_qcolor"]
+        fn qlistIndexOf(list: &QList_QColor, value: &QColor) -> isize;
+        #[rust_name = "qlist_insert_qcolor"]
+        fn qlistInsert(list: Pin<&mut QList_QColor>, pos: usize, value: &QColor);
+        #[rust_name = "qlist_len_qcolor"]
+        fn qlistLen(list: &QList_QColor) -> usize;
+        #[rust_name = "qlist_remove_qcolor"]
+        fn qlistRemove(list: Pin<&mut QList_QColor>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QColor> {}
+}
+
+impl crate::QListElement for QColor {
+    type TypeId = type_id!("QList_QColor");
+
+    fn append(list: Pin<&mut ffi::QList_QColor>, value: &Self) {
+        ffi::qlist_append_qcolor(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QColor>) {
+        ffi::qlist_clear_qcolor(list);
+    }
+
+    fn clone(list: &ffi::QList_QColor) -> UniquePtr<ffi::QList_QColor> {
+        ffi::qlist_clone_qcolor(list)
+    }
+
+    fn contains(list: &ffi::QList_QColor, value: &Self) -> bool {
+        ffi::qlist_contains_qcolor(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QColor> {
+        ffi::qlist_default_qcolor()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QColor, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qcolor(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QColor, value: &Self) -> isize {
+        ffi::qlist_index_of_qcolor(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QColor>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qcolor(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QColor) -> usize {
+        ffi::qlist_len_qcolor(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QColor>, pos: usize) {
+        ffi::qlist_remove_qcolor(list, pos);
+    }
+}
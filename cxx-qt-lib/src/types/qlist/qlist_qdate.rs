@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QDate;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QDate = crate::QDate;
+
+        type QList_QDate = crate::QList<QDate>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qdate"]
+        fn qlistAppend(list: Pin<&mut QList_QDate>, value: &QDate);
+        #[rust_name = "qlist_clear_qdate"]
+        fn qlistClear(list: Pin<&mut QList_QDate>);
+        #[rust_name = "qlist_clone_qdate"]
+        fn qlistClone(list: &QList_QDate) -> UniquePtr<QList_QDate>;
+        #[rust_name = "qlist_contains_qdate"]
+        fn qlistContains(list: &QList_QDate, value: &QDate) -> bool;
+        #[rust_name = "qlist_default_qdate"]
+        fn qlistDefault() -> UniquePtr<QList_QDate>;
+        #[rust_name = "qlist_get_unchecked_qdate"]
+        unsafe fn qlistGetUnchecked(list: &QList_QDate, pos: usize) -> &QDate;
+        #[rust_name = "qlist_index_of_qdate"]
+        fn qlistIndexOf(list: &QList_QDate, value: &QDate) -> isize;
+        #[rust_name = "qlist_insert_qdate"]
+        fn qlistInsert(list: Pin<&mut QList_QDate>, pos: usize, value: &QDate);
+        #[rust_name = "qlist_len_qdate"]
+        fn qlistLen(list: &QList_QDate) -> usize;
+        #[rust_name = "qlist_remove_qdate"]
+        fn qlistRemove(list: Pin<&mut QList_QDate>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QDate> {}
+}
+
+impl crate::QListElement for QDate {
+    type TypeId = type_id!("QList_QDate");
+
+    fn append(list: Pin<&mut ffi::QList_QDate>, value: &Self) {
+        ffi::qlist_append_qdate(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QDate>) {
+        ffi::qlist_clear_qdate(list);
+    }
+
+    fn clone(list: &ffi::QList_QDate) -> UniquePtr<ffi::QList_QDate> {
+        ffi::qlist_clone_qdate(list)
+    }
+
+    fn contains(list: &ffi::QList_QDate, value: &Self) -> bool {
+        ffi::qlist_contains_qdate(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QDate> {
+        ffi::qlist_default_qdate()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QDate, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qdate(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QDate, value: &Self) -> isize {
+        ffi::qlist_index_of_qdate(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QDate>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qdate(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QDate) -> usize {
+        ffi::qlist_len_qdate(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QDate>, pos: usize) {
+        ffi::qlist_remove_qdate(list, pos);
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QDateTime;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QDateTime = crate::QDateTime;
+
+        type QList_QDateTime = crate::QList<QDateTime>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qdatetime"]
+        fn qlistAppend(list: Pin<&mut QList_QDateTime>, value: &QDateTime);
+        #[rust_name = "qlist_clear_qdatetime"]
+        fn qlistClear(list: Pin<&mut QList_QDateTime>);
+        #[rust_name = "qlist_clone_qdatetime"]
+        fn qlistClone(list: &QList_QDateTime) -> UniquePtr<QList_QDateTime>;
+        #[rust_name = "qlist_contains_qdatetime"]
+        fn qlistContains(list: &QList_QDateTime, value: &QDateTime) -> bool;
+        #[rust_name = "qlist_default_qdatetime"]
+        fn qlistDefault() -> UniquePtr<QList_QDateTime>;
+        #[rust_name = "qlist_get_unchecked_qdatetime"]
+        unsafe fn qlistGetUnchecked(list: &QList_QDateTime, pos: usize) -> &QDateTime;
+        #[rust_name = "qlist_index_of_qdatetime"]
+        fn qlistIndexOf(list: &QList_QDateTime, value: &QDateTime) -> isize;
+        #[rust_name = "qlist_insert_qdatetime"]
+        fn qlistInsert(list: Pin<&mut QList_QDateTime>, pos: usize, value: &QDateTime);
+        #[rust_name = "qlist_len_qdatetime"]
+        fn qlistLen(list: &QList_QDateTime) -> usize;
+        #[rust_name = "qlist_remove_qdatetime"]
+        fn qlistRemove(list: Pin<&mut QList_QDateTime>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QDateTime> {}
+}
+
+impl crate::QListElement for QDateTime {
+    type TypeId = type_id!("QList_QDateTime");
+
+    fn append(list: Pin<&mut ffi::QList_QDateTime>, value: &Self) {
+        ffi::qlist_append_qdatetime(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QDateTime>) {
+        ffi::qlist_clear_qdatetime(list);
+    }
+
+    fn clone(list: &ffi::QList_QDateTime) -> UniquePtr<ffi::QList_QDateTime> {
+        ffi::qlist_clone_qdatetime(list)
+    }
+
+    fn contains(list: &ffi::QList_QDateTime, value: &Self) -> bool {
+        ffi::qlist_contains_qdatetime(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QDateTime> {
+        ffi::qlist_default_qdatetime()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QDateTime, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qdatetime(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QDateTime, value: &Self) -> isize {
+        ffi::qlist_index_of_qdatetime(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QDateTime>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qdatetime(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QDateTime) -> usize {
+        ffi::qlist_len_qdatetime(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QDateTime>, pos: usize) {
+        ffi::qlist_remove_qdatetime(list, pos);
+    }
+}
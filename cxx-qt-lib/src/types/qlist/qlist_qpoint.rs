@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QPoint;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QPoint = crate::QPoint;
+
+        type QList_QPoint = crate::QList<QPoint>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qpoint"]
+        fn qlistAppend(list: Pin<&mut QList_QPoint>, value: &QPoint);
+        #[rust_name = "qlist_clear_qpoint"]
+        fn qlistClear(list: Pin<&mut QList_QPoint>);
+        #[rust_name = "qlist_clone_qpoint"]
+        fn qlistClone(list: &QList_QPoint) -> UniquePtr<QList_QPoint>;
+        #[rust_name = "qlist_contains_qpoint"]
+        fn qlistContains(list: &QList_QPoint, value: &QPoint) -> bool;
+        #[rust_name = "qlist_default_qpoint"]
+        fn qlistDefault() -> UniquePtr<QList_QPoint>;
+        #[rust_name = "qlist_get_unchecked_qpoint"]
+        unsafe fn qlistGetUnchecked(list: &QList_QPoint, pos: usize) -> &QPoint;
+        #[rust_name = "qlist_index_of_qpoint"]
+        fn qlistIndexOf(list: &QList_QPoint, value: &QPoint) -> isize;
+        #[rust_name = "qlist_insert_qpoint"]
+        fn qlistInsert(list: Pin<&mut QList_QPoint>, pos: usize, value: &QPoint);
+        #[rust_name = "qlist_len_qpoint"]
+        fn qlistLen(list: &QList_QPoint) -> usize;
+        #[rust_name = "qlist_remove_qpoint"]
+        fn qlistRemove(list: Pin<&mut QList_QPoint>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QPoint> {}
+}
+
+impl crate::QListElement for QPoint {
+    type TypeId = type_id!("QList_QPoint");
+
+    fn append(list: Pin<&mut ffi::QList_QPoint>, value: &Self) {
+        ffi::qlist_append_qpoint(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QPoint>) {
+        ffi::qlist_clear_qpoint(list);
+    }
+
+    fn clone(list: &ffi::QList_QPoint) -> UniquePtr<ffi::QList_QPoint> {
+        ffi::qlist_clone_qpoint(list)
+    }
+
+    fn contains(list: &ffi::QList_QPoint, value: &Self) -> bool {
+        ffi::qlist_contains_qpoint(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QPoint> {
+        ffi::qlist_default_qpoint()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QPoint, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qpoint(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QPoint, value: &Self) -> isize {
+        ffi::qlist_index_of_qpoint(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QPoint>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qpoint(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QPoint) -> usize {
+        ffi::qlist_len_qpoint(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QPoint>, pos: usize) {
+        ffi::qlist_remove_qpoint(list, pos);
+    }
+}
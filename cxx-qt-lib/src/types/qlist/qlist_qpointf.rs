@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QPointF;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QPointF = crate::QPointF;
+
+        type QList_QPointF = crate::QList<QPointF>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qpointf"]
+        fn qlistAppend(list: Pin<&mut QList_QPointF>, value: &QPointF);
+        #[rust_name = "qlist_clear_qpointf"]
+        fn qlistClear(list: Pin<&mut QList_QPointF>);
+        #[rust_name = "qlist_clone_qpointf"]
+        fn qlistClone(list: &QList_QPointF) -> UniquePtr<QList_QPointF>;
+        #[rust_name = "qlist_contains_qpointf"]
+        fn qlistContains(list: &QList_QPointF, value: &QPointF) -> bool;
+        #[rust_name = "qlist_default_qpointf"]
+        fn qlistDefault() -> UniquePtr<QList_QPointF>;
+        #[rust_name = "qlist_get_unchecked_qpointf"]
+        unsafe fn qlistGetUnchecked(list: &QList_QPointF, pos: usize) -> &QPointF;
+        #[rust_name = "qlist_index_of_qpointf"]
+        fn qlistIndexOf(list: &QList_QPointF, value: &QPointF) -> isize;
+        #[rust_name = "qlist_insert_qpointf"]
+        fn qlistInsert(list: Pin<&mut QList_QPointF>, pos: usize, value: &QPointF);
+        #[rust_name = "qlist_len_qpointf"]
+        fn qlistLen(list: &QList_QPointF) -> usize;
+        #[rust_name = "qlist_remove_qpointf"]
+        fn qlistRemove(list: Pin<&mut QList_QPointF>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QPointF> {}
+}
+
+impl crate::QListElement for QPointF {
+    type TypeId = type_id!("QList_QPointF");
+
+    fn append(list: Pin<&mut ffi::QList_QPointF>, value: &Self) {
+        ffi::qlist_append_qpointf(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QPointF>) {
+        ffi::qlist_clear_qpointf(list);
+    }
+
+    fn clone(list: &ffi::QList_QPointF) -> UniquePtr<ffi::QList_QPointF> {
+        ffi::qlist_clone_qpointf(list)
+    }
+
+    fn contains(list: &ffi::QList_QPointF, value: &Self) -> bool {
+        ffi::qlist_contains_qpointf(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QPointF> {
+        ffi::qlist_default_qpointf()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QPointF, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qpointf(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QPointF, value: &Self) -> isize {
+        ffi::qlist_index_of_qpointf(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QPointF>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qpointf(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QPointF) -> usize {
+        ffi::qlist_len_qpointf(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QPointF>, pos: usize) {
+        ffi::qlist_remove_qpointf(list, pos);
+    }
+}
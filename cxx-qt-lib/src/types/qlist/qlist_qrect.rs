@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QRect;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QRect = crate::QRect;
+
+        type QList_QRect = crate::QList<QRect>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qrect"]
+        fn qlistAppend(list: Pin<&mut QList_QRect>, value: &QRect);
+        #[rust_name = "qlist_clear_qrect"]
+        fn qlistClear(list: Pin<&mut QList_QRect>);
+        #[rust_name = "qlist_clone_qrect"]
+        fn qlistClone(list: &QList_QRect) -> UniquePtr<QList_QRect>;
+        #[rust_name = "qlist_contains_qrect"]
+        fn qlistContains(list: &QList_QRect, value: &QRect) -> bool;
+        #[rust_name = "qlist_default_qrect"]
+        fn qlistDefault() -> UniquePtr<QList_QRect>;
+        #[rust_name = "qlist_get_unchecked_qrect"]
+        unsafe fn qlistGetUnchecked(list: &QList_QRect, pos: usize) -> &QRect;
+        #[rust_name = "qlist_index_of_qrect"]
+        fn qlistIndexOf(list: &QList_QRect, value: &QRect) -> isize;
+        #[rust_name = "qlist_insert_qrect"]
+        fn qlistInsert(list: Pin<&mut QList_QRect>, pos: usize, value: &QRect);
+        #[rust_name = "qlist_len_qrect"]
+        fn qlistLen(list: &QList_QRect) -> usize;
+        #[rust_name = "qlist_remove_qrect"]
+        fn qlistRemove(list: Pin<&mut QList_QRect>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QRect> {}
+}
+
+impl crate::QListElement for QRect {
+    type TypeId = type_id!("QList_QRect");
+
+    fn append(list: Pin<&mut ffi::QList_QRect>, value: &Self) {
+        ffi::qlist_append_qrect(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QRect>) {
+        ffi::qlist_clear_qrect(list);
+    }
+
+    fn clone(list: &ffi::QList_QRect) -> UniquePtr<ffi::QList_QRect> {
+        ffi::qlist_clone_qrect(list)
+    }
+
+    fn contains(list: &ffi::QList_QRect, value: &Self) -> bool {
+        ffi::qlist_contains_qrect(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QRect> {
+        ffi::qlist_default_qrect()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QRect, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qrect(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QRect, value: &Self) -> isize {
+        ffi::qlist_index_of_qrect(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QRect>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qrect(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QRect) -> usize {
+        ffi::qlist_len_qrect(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QRect>, pos: usize) {
+        ffi::qlist_remove_qrect(list, pos);
+    }
+}
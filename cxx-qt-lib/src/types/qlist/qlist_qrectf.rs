@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QRectF;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QRectF = crate::QRectF;
+
+        type QList_QRectF = crate::QList<QRectF>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qrectf"]
+        fn qlistAppend(list: Pin<&mut QList_QRectF>, value: &QRectF);
+        #[rust_name = "qlist_clear_qrectf"]
+        fn qlistClear(list: Pin<&mut QList_QRectF>);
+        #[rust_name = "qlist_clone_qrectf"]
+        fn qlistClone(list: &QList_QRectF) -> UniquePtr<QList_QRectF>;
+        #[rust_name = "qlist_contains_qrectf"]
+        fn qlistContains(list: &QList_QRectF, value: &QRectF) -> bool;
+        #[rust_name = "qlist_default_qrectf"]
+        fn qlistDefault() -> UniquePtr<QList_QRectF>;
+        #[rust_name = "qlist_get_unchecked_qrectf"]
+        unsafe fn qlistGetUnchecked(list: &QList_QRectF, pos: usize) -> &QRectF;
+        #[rust_name = "qlist_index_of_qrectf"]
+        fn qlistIndexOf(list: &QList_QRectF, value: &QRectF) -> isize;
+        #[rust_name = "qlist_insert_qrectf"]
+        fn qlistInsert(list: Pin<&mut QList_QRectF>, pos: usize, value: &QRectF);
+        #[rust_name = "qlist_len_qrectf"]
+        fn qlistLen(list: &QList_QRectF) -> usize;
+        #[rust_name = "qlist_remove_qrectf"]
+        fn qlistRemove(list: Pin<&mut QList_QRectF>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QRectF> {}
+}
+
+impl crate::QListElement for QRectF {
+    type TypeId = type_id!("QList_QRectF");
+
+    fn append(list: Pin<&mut ffi::QList_QRectF>, value: &Self) {
+        ffi::qlist_append_qrectf(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QRectF>) {
+        ffi::qlist_clear_qrectf(list);
+    }
+
+    fn clone(list: &ffi::QList_QRectF) -> UniquePtr<ffi::QList_QRectF> {
+        ffi::qlist_clone_qrectf(list)
+    }
+
+    fn contains(list: &ffi::QList_QRectF, value: &Self) -> bool {
+        ffi::qlist_contains_qrectf(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QRectF> {
+        ffi::qlist_default_qrectf()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QRectF, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qrectf(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QRectF, value: &Self) -> isize {
+        ffi::qlist_index_of_qrectf(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QRectF>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qrectf(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QRectF) -> usize {
+        ffi::qlist_len_qrectf(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QRectF>, pos: usize) {
+        ffi::qlist_remove_qrectf(list, pos);
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QSize;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QSize = crate::QSize;
+
+        type QList_QSize = crate::QList<QSize>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qsize"]
+        fn qlistAppend(list: Pin<&mut QList_QSize>, value: &QSize);
+        #[rust_name = "qlist_clear_qsize"]
+        fn qlistClear(list: Pin<&mut QList_QSize>);
+        #[rust_name = "qlist_clone_qsize"]
+        fn qlistClone(list: &QList_QSize) -> UniquePtr<QList_QSize>;
+        #[rust_name = "qlist_contains_qsize"]
+        fn qlistContains(list: &QList_QSize, value: &QSize) -> bool;
+        #[rust_name = "qlist_default_qsize"]
+        fn qlistDefault() -> UniquePtr<QList_QSize>;
+        #[rust_name = "qlist_get_unchecked_qsize"]
+        unsafe fn qlistGetUnchecked(list: &QList_QSize, pos: usize) -> &QSize;
+        #[rust_name = "qlist_index_of_qsize"]
+        fn qlistIndexOf(list: &QList_QSize, value: &QSize) -> isize;
+        #[rust_name = "qlist_insert_qsize"]
+        fn qlistInsert(list: Pin<&mut QList_QSize>, pos: usize, value: &QSize);
+        #[rust_name = "qlist_len_qsize"]
+        fn qlistLen(list: &QList_QSize) -> usize;
+        #[rust_name = "qlist_remove_qsize"]
+        fn qlistRemove(list: Pin<&mut QList_QSize>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QSize> {}
+}
+
+impl crate::QListElement for QSize {
+    type TypeId = type_id!("QList_QSize");
+
+    fn append(list: Pin<&mut ffi::QList_QSize>, value: &Self) {
+        ffi::qlist_append_qsize(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QSize>) {
+        ffi::qlist_clear_qsize(list);
+    }
+
+    fn clone(list: &ffi::QList_QSize) -> UniquePtr<ffi::QList_QSize> {
+        ffi::qlist_clone_qsize(list)
+    }
+
+    fn contains(list: &ffi::QList_QSize, value: &Self) -> bool {
+        ffi::qlist_contains_qsize(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QSize> {
+        ffi::qlist_default_qsize()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QSize, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qsize(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QSize, value: &Self) -> isize {
+        ffi::qlist_index_of_qsize(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QSize>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qsize(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QSize) -> usize {
+        ffi::qlist_len_qsize(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QSize>, pos: usize) {
+        ffi::qlist_remove_qsize(list, pos);
+    }
+}
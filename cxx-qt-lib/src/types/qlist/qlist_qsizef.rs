@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QSizeF;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QSizeF = crate::QSizeF;
+
+        type QList_QSizeF = crate::QList<QSizeF>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qsizef"]
+        fn qlistAppend(list: Pin<&mut QList_QSizeF>, value: &QSizeF);
+        #[rust_name = "qlist_clear_qsizef"]
+        fn qlistClear(list: Pin<&mut QList_QSizeF>);
+        #[rust_name = "qlist_clone_qsizef"]
+        fn qlistClone(list: &QList_QSizeF) -> UniquePtr<QList_QSizeF>;
+        #[rust_name = "qlist_contains_qsizef"]
+        fn qlistContains(list: &QList_QSizeF, value: &QSizeF) -> bool;
+        #[rust_name = "qlist_default_qsizef"]
+        fn qlistDefault() -> UniquePtr<QList_QSizeF>;
+        #[rust_name = "qlist_get_unchecked_qsizef"]
+        unsafe fn qlistGetUnchecked(list: &QList_QSizeF, pos: usize) -> &QSizeF;
+        #[rust_name = "qlist_index_of_qsizef"]
+        fn qlistIndexOf(list: &QList_QSizeF, value: &QSizeF) -> isize;
+        #[rust_name = "qlist_insert_qsizef"]
+        fn qlistInsert(list: Pin<&mut QList_QSizeF>, pos: usize, value: &QSizeF);
+        #[rust_name = "qlist_len_qsizef"]
+        fn qlistLen(list: &QList_QSizeF) -> usize;
+        #[rust_name = "qlist_remove_qsizef"]
+        fn qlistRemove(list: Pin<&mut QList_QSizeF>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QSizeF> {}
+}
+
+impl crate::QListElement for QSizeF {
+    type TypeId = type_id!("QList_QSizeF");
+
+    fn append(list: Pin<&mut ffi::QList_QSizeF>, value: &Self) {
+        ffi::qlist_append_qsizef(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QSizeF>) {
+        ffi::qlist_clear_qsizef(list);
+    }
+
+    fn clone(list: &ffi::QList_QSizeF) -> UniquePtr<ffi::QList_QSizeF> {
+        ffi::qlist_clone_qsizef(list)
+    }
+
+    fn contains(list: &ffi::QList_QSizeF, value: &Self) -> bool {
+        ffi::qlist_contains_qsizef(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QSizeF> {
+        ffi::qlist_default_qsizef()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QSizeF, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qsizef(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QSizeF, value: &Self) -> isize {
+        ffi::qlist_index_of_qsizef(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QSizeF>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qsizef(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QSizeF) -> usize {
+        ffi::qlist_len_qsizef(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QSizeF>, pos: usize) {
+        ffi::qlist_remove_qsizef(list, pos);
+    }
+}
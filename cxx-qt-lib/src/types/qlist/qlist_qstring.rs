@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QString;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = crate::QString;
+
+        type QList_QString = crate::QList<QString>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qstring"]
+        fn qlistAppend(list: Pin<&mut QList_QString>, value: &QString);
+        #[rust_name = "qlist_clear_qstring"]
+        fn qlistClear(list: Pin<&mut QList_QString>);
+        #[rust_name = "qlist_clone_qstring"]
+        fn qlistClone(list: &QList_QString) -> UniquePtr<QList_QString>;
+        #[rust_name = "qlist_contains_qstring"]
+        fn qlistContains(list: &QList_QString, value: &QString) -> bool;
+        #[rust_name = "qlist_default_qstring"]
+        fn qlistDefault() -> UniquePtr<QList_QString>;
+        #[rust_name = "qlist_get_unchecked_qstring"]
+        unsafe fn qlistGetUnchecked(list: &QList_QString, pos: usize) -> &QString;
+        #[rust_name = "qlist_index_of_qstring"]
+        fn qlistIndexOf(list: &QList_QString, value: &QString) -> isize;
+        #[rust_name = "qlist_insert_qstring"]
+        fn qlistInsert(list: Pin<&mut QList_QString>, pos: usize, value: &QString);
+        #[rust_name = "qlist_len_qstring"]
+        fn qlistLen(list: &QList_QString) -> usize;
+        #[rust_name = "qlist_remove_qstring"]
+        fn qlistRemove(list: Pin<&mut QList_QString>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QString> {}
+}
+
+impl crate::QListElement for QString {
+    type TypeId = type_id!("QList_QString");
+
+    fn append(list: Pin<&mut ffi::QList_QString>, value: &Self) {
+        ffi::qlist_append_qstring(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QString>) {
+        ffi::qlist_clear_qstring(list);
+    }
+
+    fn clone(list: &ffi::QList_QString) -> UniquePtr<ffi::QList_QString> {
+        ffi::qlist_clone_qstring(list)
+    }
+
+    fn contains(list: &ffi::QList_QString, value: &Self) -> bool {
+        ffi::qlist_contains_qstring(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QString> {
+        ffi::qlist_default_qstring()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QString, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qstring(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QString, value: &Self) -> isize {
+        ffi::qlist_index_of_qstring(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QString>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qstring(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QString) -> usize {
+        ffi::qlist_len_qstring(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QString>, pos: usize) {
+        ffi::qlist_remove_qstring(list, pos);
+    }
+}
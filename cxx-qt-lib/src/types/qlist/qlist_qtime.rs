@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QTime;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QTime = crate::QTime;
+
+        type QList_QTime = crate::QList<QTime>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qtime"]
+        fn qlistAppend(list: Pin<&mut QList_QTime>, value: &QTime);
+        #[rust_name = "qlist_clear_qtime"]
+        fn qlistClear(list: Pin<&mut QList_QTime>);
+        #[rust_name = "qlist_clone_qtime"]
+        fn qlistClone(list: &QList_QTime) -> UniquePtr<QList_QTime>;
+        #[rust_name = "qlist_contains_qtime"]
+        fn qlistContains(list: &QList_QTime, value: &QTime) -> bool;
+        #[rust_name = "qlist_default_qtime"]
+        fn qlistDefault() -> UniquePtr<QList_QTime>;
+        #[rust_name = "qlist_get_unchecked_qtime"]
+        unsafe fn qlistGetUnchecked(list: &QList_QTime, pos: usize) -> &QTime;
+        #[rust_name = "qlist_index_of_qtime"]
+        fn qlistIndexOf(list: &QList_QTime, value: &QTime) -> isize;
+        #[rust_name = "qlist_insert_qtime"]
+        fn qlistInsert(list: Pin<&mut QList_QTime>, pos: usize, value: &QTime);
+        #[rust_name = "qlist_len_qtime"]
+        fn qlistLen(list: &QList_QTime) -> usize;
+        #[rust_name = "qlist_remove_qtime"]
+        fn qlistRemove(list: Pin<&mut QList_QTime>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QTime> {}
+}
+
+impl crate::QListElement for QTime {
+    type TypeId = type_id!("QList_QTime");
+
+    fn append(list: Pin<&mut ffi::QList_QTime>, value: &Self) {
+        ffi::qlist_append_qtime(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QTime>) {
+        ffi::qlist_clear_qtime(list);
+    }
+
+    fn clone(list: &ffi::QList_QTime) -> UniquePtr<ffi::QList_QTime> {
+        ffi::qlist_clone_qtime(list)
+    }
+
+    fn contains(list: &ffi::QList_QTime, value: &Self) -> bool {
+        ffi::qlist_contains_qtime(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QTime> {
+        ffi::qlist_default_qtime()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QTime, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qtime(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QTime, value: &Self) -> isize {
+        ffi::qlist_index_of_qtime(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QTime>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qtime(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QTime) -> usize {
+        ffi::qlist_len_qtime(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QTime>, pos: usize) {
+        ffi::qlist_remove_qtime(list, pos);
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QUrl;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QUrl = crate::QUrl;
+
+        type QList_QUrl = crate::QList<QUrl>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qurl"]
+        fn qlistAppend(list: Pin<&mut QList_QUrl>, value: &QUrl);
+        #[rust_name = "qlist_clear_qurl"]
+        fn qlistClear(list: Pin<&mut QList_QUrl>);
+        #[rust_name = "qlist_clone_qurl"]
+        fn qlistClone(list: &QList_QUrl) -> UniquePtr<QList_QUrl>;
+        #[rust_name = "qlist_contains_qurl"]
+        fn qlistContains(list: &QList_QUrl, value: &QUrl) -> bool;
+        #[rust_name = "qlist_default_qurl"]
+        fn qlistDefault() -> UniquePtr<QList_QUrl>;
+        #[rust_name = "qlist_get_unchecked_qurl"]
+        unsafe fn qlistGetUnchecked(list: &QList_QUrl, pos: usize) -> &QUrl;
+        #[rust_name = "qlist_index_of_qurl"]
+        fn qlistIndexOf(list: &QList_QUrl, value: &QUrl) -> isize;
+        #[rust_name = "qlist_insert_qurl"]
+        fn qlistInsert(list: Pin<&mut QList_QUrl>, pos: usize, value: &QUrl);
+        #[rust_name = "qlist_len_qurl"]
+        fn qlistLen(list: &QList_QUrl) -> usize;
+        #[rust_name = "qlist_remove_qurl"]
+        fn qlistRemove(list: Pin<&mut QList_QUrl>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QUrl> {}
+}
+
+impl crate::QListElement for QUrl {
+    type TypeId = type_id!("QList_QUrl");
+
+    fn append(list: Pin<&mut ffi::QList_QUrl>, value: &Self) {
+        ffi::qlist_append_qurl(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QUrl>) {
+        ffi::qlist_clear_qurl(list);
+    }
+
+    fn clone(list: &ffi::QList_QUrl) -> UniquePtr<ffi::QList_QUrl> {
+        ffi::qlist_clone_qurl(list)
+    }
+
+    fn contains(list: &ffi::QList_QUrl, value: &Self) -> bool {
+        ffi::qlist_contains_qurl(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QUrl> {
+        ffi::qlist_default_qurl()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QUrl, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qurl(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QUrl, value: &Self) -> isize {
+        ffi::qlist_index_of_qurl(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QUrl>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qurl(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QUrl) -> usize {
+        ffi::qlist_len_qurl(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QUrl>, pos: usize) {
+        ffi::qlist_remove_qurl(list, pos);
+    }
+}
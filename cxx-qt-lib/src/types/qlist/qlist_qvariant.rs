@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QVariant;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QVariant = crate::QVariant;
+
+        type QList_QVariant = crate::QList<QVariant>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_qvariant"]
+        fn qlistAppend(list: Pin<&mut QList_QVariant>, value: &QVariant);
+        #[rust_name = "qlist_clear_qvariant"]
+        fn qlistClear(list: Pin<&mut QList_QVariant>);
+        #[rust_name = "qlist_clone_qvariant"]
+        fn qlistClone(list: &QList_QVariant) -> UniquePtr<QList_QVariant>;
+        #[rust_name = "qlist_contains_qvariant"]
+        fn qlistContains(list: &QList_QVariant, value: &QVariant) -> bool;
+        #[rust_name = "qlist_default_qvariant"]
+        fn qlistDefault() -> UniquePtr<QList_QVariant>;
+        #[rust_name = "qlist_get_unchecked_qvariant"]
+        unsafe fn qlistGetUnchecked(list: &QList_QVariant, pos: usize) -> &QVariant;
+        #[rust_name = "qlist_index_of_qvariant"]
+        fn qlistIndexOf(list: &QList_QVariant, value: &QVariant) -> isize;
+        #[rust_name = "qlist_insert_qvariant"]
+        fn qlistInsert(list: Pin<&mut QList_QVariant>, pos: usize, value: &QVariant);
+        #[rust_name = "qlist_len_qvariant"]
+        fn qlistLen(list: &QList_QVariant) -> usize;
+        #[rust_name = "qlist_remove_qvariant"]
+        fn qlistRemove(list: Pin<&mut QList_QVariant>, pos: usize);
+    }
+
+    impl UniquePtr<QList_QVariant> {}
+}
+
+impl crate::QListElement for QVariant {
+    type TypeId = type_id!("QList_QVariant");
+
+    fn append(list: Pin<&mut ffi::QList_QVariant>, value: &Self) {
+        ffi::qlist_append_qvariant(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_QVariant>) {
+        ffi::qlist_clear_qvariant(list);
+    }
+
+    fn clone(list: &ffi::QList_QVariant) -> UniquePtr<ffi::QList_QVariant> {
+        ffi::qlist_clone_qvariant(list)
+    }
+
+    fn contains(list: &ffi::QList_QVariant, value: &Self) -> bool {
+        ffi::qlist_contains_qvariant(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_QVariant> {
+        ffi::qlist_default_qvariant()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_QVariant, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_qvariant(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_QVariant, value: &Self) -> isize {
+        ffi::qlist_index_of_qvariant(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_QVariant>, pos: usize, value: &Self) {
+        ffi::qlist_insert_qvariant(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_QVariant) -> usize {
+        ffi::qlist_len_qvariant(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_QVariant>, pos: usize) {
+        ffi::qlist_remove_qvariant(list, pos);
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_u16 = crate::QList<u16>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_append_u16"]
+        fn qlistAppend(list: Pin<&mut QList_u16>, value: &u16);
+        #[rust_name = "qlist_clear_u16"]
+        fn qlistClear(list: Pin<&mut QList_u16>);
+        #[rust_name = "qlist_clone_u16"]
+        fn qlistClone(list: &QList_u16) -> UniquePtr<QList_u16>;
+        #[rust_name = "qlist_contains_u16"]
+        fn qlistContains(list: &QList_u16, value: &u16) -> bool;
+        #[rust_name = "qlist_default_u16"]
+        fn qlistDefault() -> UniquePtr<QList_u16>;
+        #[rust_name = "qlist_get_unchecked_u16"]
+        unsafe fn qlistGetUnchecked(list: &QList_u16, pos: usize) -> &u16;
+        #[rust_name = "qlist_index_of_u16"]
+        fn qlistIndexOf(list: &QList_u16, value: &u16) -> isize;
+        #[rust_name = "qlist_insert_u16"]
+        fn qlistInsert(list: Pin<&mut QList_u16>, pos: usize, value: &u16);
+        #[rust_name = "qlist_len_u16"]
+        fn qlistLen(list: &QList_u16) -> usize;
+        #[rust_name = "qlist_remove_u16"]
+        fn qlistRemove(list: Pin<&mut QList_u16>, pos: usize);
+    }
+
+    impl UniquePtr<QList_u16> {}
+}
+
+impl crate::QListElement for u16 {
+    type TypeId = type_id!("QList_u16");
+
+    fn append(list: Pin<&mut ffi::QList_u16>, value: &Self) {
+        ffi::qlist_append_u16(list, value);
+    }
+
+    fn clear(list: Pin<&mut ffi::QList_u16>) {
+        ffi::qlist_clear_u16(list);
+    }
+
+    fn clone(list: &ffi::QList_u16) -> UniquePtr<ffi::QList_u16> {
+        ffi::qlist_clone_u16(list)
+    }
+
+    fn contains(list: &ffi::QList_u16, value: &Self) -> bool {
+        ffi::qlist_contains_u16(list, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QList_u16> {
+        ffi::qlist_default_u16()
+    }
+
+    unsafe fn get_unchecked(list: &ffi::QList_u16, pos: usize) -> &Self {
+        ffi::qlist_get_unchecked_u16(list, pos)
+    }
+
+    fn index_of(list: &ffi::QList_u16, value: &Self) -> isize {
+        ffi::qlist_index_of_u16(list, value)
+    }
+
+    fn insert(list: Pin<&mut ffi::QList_u16>, pos: usize, value: &Self) {
+        ffi::qlist_insert_u16(list, pos, value);
+    }
+
+    fn len(list: &ffi::QList_u16) -> usize {
+        ffi::qlist_len_u16(list)
+    }
+
+    fn remove(list: Pin<&mut ffi::QList_u16>, pos: usize) {
+        ffi::qlist_remove_u16(list, pos);
+    }
+}
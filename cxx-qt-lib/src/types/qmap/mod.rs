@@ -0,0 +1,277 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+mod qmap_qstring_qvariant;
+
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    pin::Pin,
+};
+use cxx::{memory::UniquePtrTarget, ExternType, UniquePtr};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{IntoQVariant, QString, QVariant};
+
+/// The QMap class is a template class that provides an associative array, which is sorted by key.
+///
+/// Note that this is the C++ representation, so it is only accessed by reference
+/// or through a `UniquePtr<QMap<K, V>>`.
+#[repr(C)]
+pub struct QMap<K, V> {
+    _private: [u8; 0],
+    _marker: PhantomData<(K, V, PhantomPinned)>,
+}
+
+/// A [`QMap`] of [`QVariant`] values with [`QString`] keys, a JavaScript object
+/// from QML is converted into a QVariantMap.
+///
+/// Use [`QVariantMap::from`] and `HashMap::from` or `BTreeMap::from` to convert
+/// from and to Rust collections.
+pub type QVariantMap = QMap<QString, QVariant>;
+
+/// Trait implementation for a key value pair in a [`QMap`].
+///
+/// This is implemented for the `(K, V)` tuple of each of the supported pairs by the generated bridges.
+pub trait QMapPair: Sized
+where
+    QMap<Self::Key, Self::Value>: UniquePtrTarget,
+{
+    type Key;
+    type Value;
+    type TypeId;
+    type ConstIterator: UniquePtrTarget;
+
+    fn clear(map: Pin<&mut QMap<Self::Key, Self::Value>>);
+    fn clone(map: &QMap<Self::Key, Self::Value>) -> UniquePtr<QMap<Self::Key, Self::Value>>;
+    fn contains(map: &QMap<Self::Key, Self::Value>, key: &Self::Key) -> bool;
+    fn default() -> UniquePtr<QMap<Self::Key, Self::Value>>;
+    /// # Safety
+    ///
+    /// Calling this method with a key that is not in the map is undefined behavior
+    /// even if the resulting reference is not used.
+    unsafe fn get_unchecked<'a>(
+        map: &'a QMap<Self::Key, Self::Value>,
+        key: &Self::Key,
+    ) -> &'a Self::Value;
+    fn insert(map: Pin<&mut QMap<Self::Key, Self::Value>>, key: &Self::Key, value: &Self::Value);
+    fn iter_begin(map: &QMap<Self::Key, Self::Value>) -> UniquePtr<Self::ConstIterator>;
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another map, an iterator which is at the end,
+    /// or after the map has been modified is undefined behavior even if the resulting reference is not used.
+    unsafe fn iter_key<'a>(
+        map: &'a QMap<Self::Key, Self::Value>,
+        iter: &Self::ConstIterator,
+    ) -> &'a Self::Key;
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another map, an iterator which is at the end,
+    /// or after the map has been modified is undefined behavior.
+    unsafe fn iter_next(map: &QMap<Self::Key, Self::Value>, iter: Pin<&mut Self::ConstIterator>);
+    /// # Safety
+    ///
+    /// Calling this method with an iterator of another map, an iterator which is at the end,
+    /// or after the map has been modified is undefined behavior even if the resulting reference is not used.
+    unsafe fn iter_value<'a>(
+        map: &'a QMap<Self::Key, Self::Value>,
+        iter: &Self::ConstIterator,
+    ) -> &'a Self::Value;
+    fn len(map: &QMap<Self::Key, Self::Value>) -> usize;
+    fn remove(map: Pin<&mut QMap<Self::Key, Self::Value>>, key: &Self::Key) -> bool;
+}
+
+impl<K, V> QMap<K, V>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    /// Constructs an empty map.
+    pub fn new() -> UniquePtr<Self> {
+        <(K, V) as QMapPair>::default()
+    }
+
+    /// Constructs a copy of the given map.
+    pub fn from_ref(map: &Self) -> UniquePtr<Self> {
+        <(K, V) as QMapPair>::clone(map)
+    }
+
+    /// Removes all the items from the map.
+    pub fn clear(self: Pin<&mut Self>) {
+        <(K, V) as QMapPair>::clear(self);
+    }
+
+    /// Returns true if the map contains an item with the key; otherwise returns false.
+    pub fn contains(&self, key: &K) -> bool {
+        <(K, V) as QMapPair>::contains(self, key)
+    }
+
+    /// Returns the value associated with the key, or None if the map does not contain the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        if self.contains(key) {
+            Some(unsafe { <(K, V) as QMapPair>::get_unchecked(self, key) })
+        } else {
+            None
+        }
+    }
+
+    /// Inserts a new item with the key and a value of value.
+    ///
+    /// If there is already an item with the key, that item's value is replaced with value.
+    pub fn insert(self: Pin<&mut Self>, key: &K, value: &V) {
+        <(K, V) as QMapPair>::insert(self, key, value);
+    }
+
+    /// Returns true if the map contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all the key value pairs in the map, in ascending order by key.
+    pub fn iter(&self) -> QMapIter<K, V> {
+        QMapIter {
+            map: self,
+            iter: <(K, V) as QMapPair>::iter_begin(self),
+            len: self.len(),
+        }
+    }
+
+    /// Returns the number of items in the map.
+    pub fn len(&self) -> usize {
+        <(K, V) as QMapPair>::len(self)
+    }
+
+    /// Removes the item that has the key from the map.
+    ///
+    /// Returns true if an item was removed; otherwise returns false.
+    pub fn remove(self: Pin<&mut Self>, key: &K) -> bool {
+        <(K, V) as QMapPair>::remove(self, key)
+    }
+}
+
+impl QMap<QString, QVariant> {
+    /// Constructs a map from Rust key value pairs, such as a `HashMap<String, i32>`
+    /// or a `BTreeMap<&str, UniquePtr<QVariant>>`.
+    ///
+    /// This is used instead of a `From` implementation for `HashMap` and `BTreeMap`,
+    /// as the orphan rules do not allow implementing `From` for a `UniquePtr`.
+    pub fn from<I, S, T>(pairs: I) -> UniquePtr<Self>
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: AsRef<str>,
+        T: IntoQVariant,
+    {
+        let mut map = Self::new();
+        for (key, value) in pairs {
+            map.pin_mut()
+                .insert(&QString::from_str(key.as_ref()), &value.into_qvariant());
+        }
+        map
+    }
+}
+
+unsafe impl<K, V> ExternType for QMap<K, V>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    type Id = <(K, V) as QMapPair>::TypeId;
+    type Kind = cxx::kind::Opaque;
+}
+
+impl<K, V> From<&QMap<K, V>> for UniquePtr<QMap<K, V>>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    fn from(map: &QMap<K, V>) -> Self {
+        QMap::from_ref(map)
+    }
+}
+
+impl From<&QMap<QString, QVariant>> for BTreeMap<String, UniquePtr<QVariant>> {
+    /// Convert a reference to a QVariantMap into a BTreeMap by making a deep copy of the data.
+    fn from(map: &QMap<QString, QVariant>) -> Self {
+        map.iter()
+            .map(|(key, value)| (key.to_string(), QVariant::from_ref(value)))
+            .collect()
+    }
+}
+
+impl From<&QMap<QString, QVariant>> for HashMap<String, UniquePtr<QVariant>> {
+    /// Convert a reference to a QVariantMap into a HashMap by making a deep copy of the data.
+    fn from(map: &QMap<QString, QVariant>) -> Self {
+        map.iter()
+            .map(|(key, value)| (key.to_string(), QVariant::from_ref(value)))
+            .collect()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a QMap<K, V>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = QMapIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key value pairs of a [`QMap`].
+///
+/// This holds a C++ const_iterator of the map, so that each step is constant time.
+pub struct QMapIter<'a, K, V>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    map: &'a QMap<K, V>,
+    iter: UniquePtr<<(K, V) as QMapPair>::ConstIterator>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for QMapIter<'a, K, V>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // The map is borrowed for the lifetime of the iterator, so it cannot be modified
+        // and the iterator is not at the end while there are items remaining
+        let next = unsafe {
+            let next = (
+                <(K, V) as QMapPair>::iter_key(self.map, &self.iter),
+                <(K, V) as QMapPair>::iter_value(self.map, &self.iter),
+            );
+            <(K, V) as QMapPair>::iter_next(self.map, self.iter.pin_mut());
+            next
+        };
+        self.len -= 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for QMapIter<'a, K, V>
+where
+    (K, V): QMapPair<Key = K, Value = V>,
+    QMap<K, V>: UniquePtrTarget,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
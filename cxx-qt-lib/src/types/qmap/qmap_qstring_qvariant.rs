@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qmap_qhash.sh, do not edit it directly
+
+use crate::{QString, QVariant};
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        include!("cxx-qt-lib/include/qmap.h");
+
+        type QString = crate::QString;
+        type QVariant = crate::QVariant;
+        type QVariantMap = crate::QMap<QString, QVariant>;
+        type QMapConstIterator_QString_QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qmap"]
+    unsafe extern "C++" {
+        #[rust_name = "qmap_clear_qstring_qvariant"]
+        fn qmapClear(map: Pin<&mut QVariantMap>);
+        #[rust_name = "qmap_clone_qstring_qvariant"]
+        fn qmapClone(map: &QVariantMap) -> UniquePtr<QVariantMap>;
+        #[rust_name = "qmap_contains_qstring_qvariant"]
+        fn qmapContains(map: &QVariantMap, key: &QString) -> bool;
+        #[rust_name = "qmap_default_qstring_qvariant"]
+        fn qmapDefault() -> UniquePtr<QVariantMap>;
+        #[rust_name = "qmap_get_unchecked_qstring_qvariant"]
+        unsafe fn qmapGetUnchecked<'a>(map: &'a QVariantMap, key: &QString) -> &'a QVariant;
+        #[rust_name = "qmap_insert_qstring_qvariant"]
+        fn qmapInsert(map: Pin<&mut QVariantMap>, key: &QString, value: &QVariant);
+        #[rust_name = "qmap_iter_begin_qstring_qvariant"]
+        fn qmapIterBegin(map: &QVariantMap) -> UniquePtr<QMapConstIterator_QString_QVariant>;
+        #[rust_name = "qmap_iter_key_qstring_qvariant"]
+        unsafe fn qmapIterKey<'a>(
+            map: &'a QVariantMap,
+            iter: &QMapConstIterator_QString_QVariant,
+        ) -> &'a QString;
+        #[rust_name = "qmap_iter_next_qstring_qvariant"]
+        unsafe fn qmapIterNext(
+            map: &QVariantMap,
+            iter: Pin<&mut QMapConstIterator_QString_QVariant>,
+        );
+        #[rust_name = "qmap_iter_value_qstring_qvariant"]
+        unsafe fn qmapIterValue<'a>(
+            map: &'a QVariantMap,
+            iter: &QMapConstIterator_QString_QVariant,
+        ) -> &'a QVariant;
+        #[rust_name = "qmap_len_qstring_qvariant"]
+        fn qmapLen(map: &QVariantMap) -> usize;
+        #[rust_name = "qmap_remove_qstring_qvariant"]
+        fn qmapRemove(map: Pin<&mut QVariantMap>, key: &QString) -> bool;
+    }
+
+    impl UniquePtr<QVariantMap> {}
+}
+
+impl crate::QMapPair for (QString, QVariant) {
+    type Key = QString;
+    type Value = QVariant;
+    type TypeId = type_id!("QVariantMap");
+    type ConstIterator = ffi::QMapConstIterator_QString_QVariant;
+
+    fn clear(map: Pin<&mut ffi::QVariantMap>) {
+        ffi::qmap_clear_qstring_qvariant(map);
+    }
+
+    fn clone(map: &ffi::QVariantMap) -> UniquePtr<ffi::QVariantMap> {
+        ffi::qmap_clone_qstring_qvariant(map)
+    }
+
+    fn contains(map: &ffi::QVariantMap, key: &QString) -> bool {
+        ffi::qmap_contains_qstring_qvariant(map, key)
+    }
+
+    fn default() -> UniquePtr<ffi::QVariantMap> {
+        ffi::qmap_default_qstring_qvariant()
+    }
+
+    unsafe fn get_unchecked<'a>(map: &'a ffi::QVariantMap, key: &QString) -> &'a QVariant {
+        ffi::qmap_get_unchecked_qstring_qvariant(map, key)
+    }
+
+    fn insert(map: Pin<&mut ffi::QVariantMap>, key: &QString, value: &QVariant) {
+        ffi::qmap_insert_qstring_qvariant(map, key, value);
+    }
+
+    fn iter_begin(map: &ffi::QVariantMap) -> UniquePtr<ffi::QMapConstIterator_QString_QVariant> {
+        ffi::qmap_iter_begin_qstring_qvariant(map)
+    }
+
+    unsafe fn iter_key<'a>(
+        map: &'a ffi::QVariantMap,
+        iter: &ffi::QMapConstIterator_QString_QVariant,
+    ) -> &'a QString {
+        ffi::qmap_iter_key_qstring_qvariant(map, iter)
+    }
+
+    unsafe fn iter_next(
+        map: &ffi::QVariantMap,
+        iter: Pin<&mut ffi::QMapConstIterator_QString_QVariant>,
+    ) {
+        ffi::qmap_iter_next_qstring_qvariant(map, iter);
+    }
+
+    unsafe fn iter_value<'a>(
+        map: &'a ffi::QVariantMap,
+        iter: &ffi::QMapConstIterator_QString_QVariant,
+    ) -> &'a QVariant {
+        ffi::qmap_iter_value_qstring_qvariant(map, iter)
+    }
+
+    fn len(map: &ffi::QVariantMap) -> usize {
+        ffi::qmap_len_qstring_qvariant(map)
+    }
+
+    fn remove(map: Pin<&mut ffi::QVariantMap>, key: &QString) -> bool {
+        ffi::qmap_remove_qstring_qvariant(map, key)
+    }
+}
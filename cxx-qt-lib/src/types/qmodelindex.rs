@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+
+        type QModelIndex = super::QModelIndex;
+
+        /// Returns the column this model index refers to.
+        fn column(self: &QModelIndex) -> i32;
+        /// Returns true if this model index is valid; otherwise returns false.
+        ///
+        /// A valid index belongs to a model, and has non-negative row and column numbers.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QModelIndex) -> bool;
+        /// Returns the parent of the model index, or QModelIndex() if it has no parent.
+        fn parent(self: &QModelIndex) -> QModelIndex;
+        /// Returns the row this model index refers to.
+        fn row(self: &QModelIndex) -> i32;
+        /// Returns the sibling at row and column.
+        ///
+        /// If there is no sibling at this position, an invalid QModelIndex is returned.
+        fn sibling(self: &QModelIndex, row: i32, column: i32) -> QModelIndex;
+
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qmodelindex_init_default"]
+        fn qmodelindexInitDefault() -> QModelIndex;
+    }
+}
+
+/// The QModelIndex class is used to locate data in a data model.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct QModelIndex {
+    r: i32,
+    c: i32,
+    i: usize,
+    m: *const std::ffi::c_void,
+}
+
+impl Default for QModelIndex {
+    /// Creates a new empty model index, which is used to indicate an invalid position in the model.
+    fn default() -> Self {
+        ffi::qmodelindex_init_default()
+    }
+}
+
+impl std::fmt::Debug for QModelIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QModelIndex")
+            .field("row", &self.r)
+            .field("column", &self.c)
+            .finish()
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QModelIndex is trivial.
+unsafe impl ExternType for QModelIndex {
+    type Id = type_id!("QModelIndex");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[doc(hidden)]
+impl From<&QModelIndex> for QModelIndex {
+    // TODO: in the future remove at least the deref to a clone and potentially remove this ?
+    fn from(qmodelindex: &QModelIndex) -> Self {
+        *qmodelindex
+    }
+}
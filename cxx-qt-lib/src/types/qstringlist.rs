@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{ops::Deref, pin::Pin};
+
+use crate::{QList, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        include!("cxx-qt-lib/include/qlist.h");
+
+        type QList_QString = crate::QList_QString;
+        type QString = crate::QString;
+        type QStringList;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstring_split"]
+        fn qstringSplit(string: &QString, separator: &str) -> UniquePtr<QStringList>;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstringlist_as_qlist_qstring"]
+        fn qstringlistAsQListQString(list: &QStringList) -> &QList_QString;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstringlist_as_qlist_qstring_mut"]
+        fn qstringlistAsQListQStringMut(list: Pin<&mut QStringList>) -> Pin<&mut QList_QString>;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstringlist_init"]
+        fn qstringlistInit() -> UniquePtr<QStringList>;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstringlist_init_from_qstringlist"]
+        fn qstringlistInitFromQStringList(list: &QStringList) -> UniquePtr<QStringList>;
+
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstringlist_join"]
+        fn qstringlistJoin(list: &QStringList, separator: &str) -> UniquePtr<QString>;
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qstringlist_sort"]
+        fn qstringlistSort(list: Pin<&mut QStringList>);
+    }
+
+    impl UniquePtr<QStringList> {}
+}
+
+/// The QStringList class provides a list of strings.
+///
+/// Note that this is the C++ representation and `Vec<String>` should be used in Rust.
+///
+/// The read-only methods of [`QList<QString>`](QList), such as `len`, `get`, `contains`
+/// and `iter`, are available through `Deref`.
+pub type QStringList = ffi::QStringList;
+
+impl QStringList {
+    /// Constructs an empty string list.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qstringlist_init()
+    }
+
+    /// Construct a QStringList from an existing QStringList, this is a copy operation.
+    pub fn from_ref(list: &QStringList) -> cxx::UniquePtr<Self> {
+        ffi::qstringlist_init_from_qstringlist(list)
+    }
+
+    /// Constructs a string list containing a copy of each of the strings in the slice.
+    pub fn from_slice<S: AsRef<str>>(values: &[S]) -> cxx::UniquePtr<Self> {
+        let mut list = Self::new();
+        for value in values {
+            list.pin_mut().push(&QString::from_str(value.as_ref()));
+        }
+        list
+    }
+
+    /// Removes all the strings from the list.
+    pub fn clear(self: Pin<&mut Self>) {
+        ffi::qstringlist_as_qlist_qstring_mut(self).clear();
+    }
+
+    /// Inserts string at index position in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(self: Pin<&mut Self>, index: usize, string: &QString) {
+        ffi::qstringlist_as_qlist_qstring_mut(self).insert(index, string);
+    }
+
+    /// Joins all the string list's strings into a single string with each element
+    /// separated by the given separator.
+    pub fn join(&self, separator: &str) -> cxx::UniquePtr<QString> {
+        ffi::qstringlist_join(self, separator)
+    }
+
+    /// Appends string to the end of the list.
+    pub fn push(self: Pin<&mut Self>, string: &QString) {
+        ffi::qstringlist_as_qlist_qstring_mut(self).push(string);
+    }
+
+    /// Removes the string at index position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(self: Pin<&mut Self>, index: usize) {
+        ffi::qstringlist_as_qlist_qstring_mut(self).remove(index);
+    }
+
+    /// Sorts the list of strings in ascending order, this is case sensitive.
+    pub fn sort(self: Pin<&mut Self>) {
+        ffi::qstringlist_sort(self);
+    }
+}
+
+impl QString {
+    /// Splits the string into substrings wherever separator occurs, and returns the list of those strings.
+    pub fn split(&self, separator: &str) -> cxx::UniquePtr<QStringList> {
+        ffi::qstring_split(self, separator)
+    }
+}
+
+impl Deref for QStringList {
+    type Target = QList<QString>;
+
+    fn deref(&self) -> &Self::Target {
+        ffi::qstringlist_as_qlist_qstring(self)
+    }
+}
+
+impl From<&QStringList> for cxx::UniquePtr<QStringList> {
+    fn from(value: &QStringList) -> cxx::UniquePtr<QStringList> {
+        QStringList::from_ref(value)
+    }
+}
+
+impl From<&QStringList> for Vec<String> {
+    /// Convert a reference to a QStringList into a Vec of Rust strings, this is a copy operation.
+    fn from(list: &QStringList) -> Self {
+        list.iter().map(|string| string.to_string()).collect()
+    }
+}
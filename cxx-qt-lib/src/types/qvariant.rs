@@ -4,8 +4,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use std::collections::BTreeMap;
+
 use crate::{
-    QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl,
+    QByteArray, QColor, QDate, QDateTime, QList, QPoint, QPointF, QRect, QRectF, QSize, QSizeF,
+    QString, QStringList, QTime, QUrl, QVariantHash, QVariantMap,
 };
 
 #[cxx::bridge]
@@ -14,32 +17,42 @@ mod ffi {
     #[namespace = "rust::cxxqtlib1::types"]
     enum QVariantType {
         Unsupported = 0,
-        Bool = 1,
-        F32 = 2,
-        F64 = 3,
-        I8 = 4,
-        I16 = 5,
-        I32 = 6,
-        QColor = 7,
-        QDate = 8,
-        QDateTime = 9,
-        QPoint = 10,
-        QPointF = 11,
-        QRect = 12,
-        QRectF = 13,
-        QSize = 14,
-        QSizeF = 15,
-        QString = 16,
-        QTime = 17,
-        QUrl = 18,
-        U8 = 19,
-        U16 = 20,
-        U32 = 21,
+        Null = 1,
+        Bool = 2,
+        F32 = 3,
+        F64 = 4,
+        I8 = 5,
+        I16 = 6,
+        I32 = 7,
+        QByteArray = 8,
+        QColor = 9,
+        QDate = 10,
+        QDateTime = 11,
+        QPoint = 12,
+        QPointF = 13,
+        QRect = 14,
+        QRectF = 15,
+        QSize = 16,
+        QSizeF = 17,
+        QString = 18,
+        QStringList = 19,
+        QTime = 20,
+        QUrl = 21,
+        QVariantHash = 22,
+        QVariantList = 23,
+        QVariantMap = 24,
+        U8 = 25,
+        U16 = 26,
+        U32 = 27,
     }
 
     unsafe extern "C++" {
         include!("cxx-qt-lib/include/qt_types.h");
+        include!("cxx-qt-lib/include/qhash.h");
+        include!("cxx-qt-lib/include/qlist.h");
+        include!("cxx-qt-lib/include/qmap.h");
 
+        type QByteArray = crate::QByteArray;
         type QColor = crate::QColor;
         type QDate = crate::QDate;
         type QDateTime = crate::QDateTime;
@@ -49,10 +62,14 @@ mod ffi {
         type QRectF = crate::QRectF;
         type QSize = crate::QSize;
         type QSizeF = crate::QSizeF;
+        type QList_QVariant = crate::QList_QVariant;
         type QString = crate::QString;
+        type QStringList = crate::QStringList;
         type QTime = crate::QTime;
         type QUrl = crate::QUrl;
         type QVariant;
+        type QVariantHash = crate::QVariantHash;
+        type QVariantMap = crate::QVariantMap;
 
         #[namespace = "rust::cxxqtlib1::types"]
         type QVariantType;
@@ -81,6 +98,8 @@ mod ffi {
         fn qvariantInitFromI16(i: i16) -> UniquePtr<QVariant>;
         #[rust_name = "qvariant_init_from_i32"]
         fn qvariantInitFromI32(i: i32) -> UniquePtr<QVariant>;
+        #[rust_name = "qvariant_init_from_qbytearray"]
+        fn qvariantInitFromQByteArray(byteArray: &QByteArray) -> UniquePtr<QVariant>;
         #[rust_name = "qvariant_init_from_qcolor"]
         fn qvariantInitFromQColor(color: &QColor) -> UniquePtr<QVariant>;
         #[rust_name = "qvariant_init_from_qdate"]
@@ -105,6 +124,14 @@ mod ffi {
         fn qvariantInitFromQUrl(url: &QUrl) -> UniquePtr<QVariant>;
         #[rust_name = "qvariant_init_from_qstring"]
         fn qvariantInitFromQString(string: &QString) -> UniquePtr<QVariant>;
+        #[rust_name = "qvariant_init_from_qstringlist"]
+        fn qvariantInitFromQStringList(list: &QStringList) -> UniquePtr<QVariant>;
+        #[rust_name = "qvariant_init_from_qvarianthash"]
+        fn qvariantInitFromQVariantHash(hash: &QVariantHash) -> UniquePtr<QVariant>;
+        #[rust_name = "qvariant_init_from_qvariantlist"]
+        fn qvariantInitFromQVariantList(list: &QList_QVariant) -> UniquePtr<QVariant>;
+        #[rust_name = "qvariant_init_from_qvariantmap"]
+        fn qvariantInitFromQVariantMap(map: &QVariantMap) -> UniquePtr<QVariant>;
         #[rust_name = "qvariant_init_from_u8"]
         fn qvariantInitFromU8(u: u8) -> UniquePtr<QVariant>;
         #[rust_name = "qvariant_init_from_u16"]
@@ -124,6 +151,8 @@ mod ffi {
         fn qvariantToI16(qvariant: &QVariant) -> i16;
         #[rust_name = "qvariant_to_i32"]
         fn qvariantToI32(qvariant: &QVariant) -> i32;
+        #[rust_name = "qvariant_to_qbytearray"]
+        fn qvariantToQByteArray(qvariant: &QVariant) -> UniquePtr<QByteArray>;
         #[rust_name = "qvariant_to_qcolor"]
         fn qvariantToQColor(qvariant: &QVariant) -> UniquePtr<QColor>;
         #[rust_name = "qvariant_to_qdate"]
@@ -148,6 +177,14 @@ mod ffi {
         fn qvariantToQUrl(qvariant: &QVariant) -> UniquePtr<QUrl>;
         #[rust_name = "qvariant_to_qstring"]
         fn qvariantToQString(qvariant: &QVariant) -> UniquePtr<QString>;
+        #[rust_name = "qvariant_to_qstringlist"]
+        fn qvariantToQStringList(qvariant: &QVariant) -> UniquePtr<QStringList>;
+        #[rust_name = "qvariant_to_qvarianthash"]
+        fn qvariantToQVariantHash(qvariant: &QVariant) -> UniquePtr<QVariantHash>;
+        #[rust_name = "qvariant_to_qvariantlist"]
+        fn qvariantToQVariantList(qvariant: &QVariant) -> UniquePtr<QList_QVariant>;
+        #[rust_name = "qvariant_to_qvariantmap"]
+        fn qvariantToQVariantMap(qvariant: &QVariant) -> UniquePtr<QVariantMap>;
         #[rust_name = "qvariant_to_u8"]
         fn qvariantToU8(qvariant: &QVariant) -> u8;
         #[rust_name = "qvariant_to_u16"]
@@ -165,14 +202,24 @@ mod ffi {
 pub type QVariant = ffi::QVariant;
 
 /// The Rust inner value of a QVariant
+///
+/// Lists and maps, such as arrays and objects from QML, are converted recursively
+/// into `List` and `Map` values.
 pub enum QVariantValue {
     Unsupported,
+    /// An invalid QVariant, or a null value from QML
+    Null,
     Bool(bool),
     F32(f32),
     F64(f64),
     I8(i8),
     I16(i16),
     I32(i32),
+    /// A QVariantList, where each of the values has been converted
+    List(Vec<QVariantValue>),
+    /// A QVariantMap or QVariantHash, where each of the values has been converted
+    Map(BTreeMap<String, QVariantValue>),
+    QByteArray(cxx::UniquePtr<QByteArray>),
     QColor(cxx::UniquePtr<QColor>),
     QDate(QDate),
     QDateTime(cxx::UniquePtr<QDateTime>),
@@ -183,6 +230,7 @@ pub enum QVariantValue {
     QSize(QSize),
     QSizeF(QSizeF),
     QString(cxx::UniquePtr<QString>),
+    QStringList(cxx::UniquePtr<QStringList>),
     QTime(QTime),
     QUrl(cxx::UniquePtr<QUrl>),
     U8(u8),
@@ -232,6 +280,7 @@ into_qvariant!(f64, ffi::qvariant_init_from_f64);
 into_qvariant!(i8, ffi::qvariant_init_from_i8);
 into_qvariant!(i16, ffi::qvariant_init_from_i16);
 into_qvariant!(i32, ffi::qvariant_init_from_i32);
+into_qvariant_opaque_ref!(QByteArray, ffi::qvariant_init_from_qbytearray);
 into_qvariant_opaque_ref!(QColor, ffi::qvariant_init_from_qcolor);
 into_qvariant_ref!(QDate, ffi::qvariant_init_from_qdate);
 into_qvariant_opaque_ref!(QDateTime, ffi::qvariant_init_from_qdatetime);
@@ -244,10 +293,74 @@ into_qvariant_ref!(QSizeF, ffi::qvariant_init_from_qsizef);
 into_qvariant_ref!(QTime, ffi::qvariant_init_from_qtime);
 into_qvariant_opaque_ref!(QUrl, ffi::qvariant_init_from_qurl);
 into_qvariant_opaque_ref!(QString, ffi::qvariant_init_from_qstring);
+into_qvariant_opaque_ref!(QStringList, ffi::qvariant_init_from_qstringlist);
+into_qvariant_opaque_ref!(QVariant, ffi::qvariant_init_from_qvariant);
+into_qvariant_opaque_ref!(QVariantHash, ffi::qvariant_init_from_qvarianthash);
+into_qvariant_opaque_ref!(QList<QVariant>, ffi::qvariant_init_from_qvariantlist);
+into_qvariant_opaque_ref!(QVariantMap, ffi::qvariant_init_from_qvariantmap);
 into_qvariant!(u8, ffi::qvariant_init_from_u8);
 into_qvariant!(u16, ffi::qvariant_init_from_u16);
 into_qvariant!(u32, ffi::qvariant_init_from_u32);
 
+impl IntoQVariant for cxx::UniquePtr<QVariant> {
+    fn into_qvariant(self) -> cxx::UniquePtr<QVariant> {
+        self
+    }
+}
+
+impl IntoQVariant for QVariantValue {
+    /// Lists and maps are converted recursively, and an unsupported or null value
+    /// becomes an invalid QVariant.
+    fn into_qvariant(self) -> cxx::UniquePtr<QVariant> {
+        match self {
+            QVariantValue::Unsupported | QVariantValue::Null => QVariant::null(),
+            QVariantValue::Bool(b) => b.into_qvariant(),
+            QVariantValue::F32(f) => f.into_qvariant(),
+            QVariantValue::F64(f) => f.into_qvariant(),
+            QVariantValue::I8(i) => i.into_qvariant(),
+            QVariantValue::I16(i) => i.into_qvariant(),
+            QVariantValue::I32(i) => i.into_qvariant(),
+            QVariantValue::List(values) => {
+                let mut list = QList::<QVariant>::new();
+                for value in values {
+                    list.pin_mut().push(&value.into_qvariant());
+                }
+                ffi::qvariant_init_from_qvariantlist(&list)
+            }
+            QVariantValue::Map(values) => {
+                ffi::qvariant_init_from_qvariantmap(&QVariantMap::from(values))
+            }
+            QVariantValue::QByteArray(bytes) => {
+                bytes.as_ref().map_or_else(QVariant::null, QVariant::from)
+            }
+            QVariantValue::QColor(color) => {
+                color.as_ref().map_or_else(QVariant::null, QVariant::from)
+            }
+            QVariantValue::QDate(date) => date.into_qvariant(),
+            QVariantValue::QDateTime(date_time) => date_time
+                .as_ref()
+                .map_or_else(QVariant::null, QVariant::from),
+            QVariantValue::QPoint(point) => point.into_qvariant(),
+            QVariantValue::QPointF(pointf) => pointf.into_qvariant(),
+            QVariantValue::QRect(rect) => rect.into_qvariant(),
+            QVariantValue::QRectF(rectf) => rectf.into_qvariant(),
+            QVariantValue::QSize(size) => size.into_qvariant(),
+            QVariantValue::QSizeF(sizef) => sizef.into_qvariant(),
+            QVariantValue::QString(string) => {
+                string.as_ref().map_or_else(QVariant::null, QVariant::from)
+            }
+            QVariantValue::QStringList(list) => {
+                list.as_ref().map_or_else(QVariant::null, QVariant::from)
+            }
+            QVariantValue::QTime(time) => time.into_qvariant(),
+            QVariantValue::QUrl(url) => url.as_ref().map_or_else(QVariant::null, QVariant::from),
+            QVariantValue::U8(u) => u.into_qvariant(),
+            QVariantValue::U16(u) => u.into_qvariant(),
+            QVariantValue::U32(u) => u.into_qvariant(),
+        }
+    }
+}
+
 impl QVariant {
     pub fn from<T>(value: T) -> cxx::UniquePtr<Self>
     where
@@ -271,12 +384,16 @@ impl QVariant {
     pub fn value(&self) -> QVariantValue {
         match ffi::qvariant_get_type(self) {
             ffi::QVariantType::Unsupported => QVariantValue::Unsupported,
+            ffi::QVariantType::Null => QVariantValue::Null,
             ffi::QVariantType::Bool => QVariantValue::Bool(ffi::qvariant_to_bool(self)),
             ffi::QVariantType::F32 => QVariantValue::F32(ffi::qvariant_to_f32(self)),
             ffi::QVariantType::F64 => QVariantValue::F64(ffi::qvariant_to_f64(self)),
             ffi::QVariantType::I8 => QVariantValue::I8(ffi::qvariant_to_i8(self)),
             ffi::QVariantType::I16 => QVariantValue::I16(ffi::qvariant_to_i16(self)),
             ffi::QVariantType::I32 => QVariantValue::I32(ffi::qvariant_to_i32(self)),
+            ffi::QVariantType::QByteArray => {
+                QVariantValue::QByteArray(ffi::qvariant_to_qbytearray(self))
+            }
             ffi::QVariantType::QColor => QVariantValue::QColor(ffi::qvariant_to_qcolor(self)),
             ffi::QVariantType::QDate => QVariantValue::QDate(ffi::qvariant_to_qdate(self)),
             ffi::QVariantType::QDateTime => {
@@ -289,8 +406,29 @@ impl QVariant {
             ffi::QVariantType::QSize => QVariantValue::QSize(ffi::qvariant_to_qsize(self)),
             ffi::QVariantType::QSizeF => QVariantValue::QSizeF(ffi::qvariant_to_qsizef(self)),
             ffi::QVariantType::QString => QVariantValue::QString(ffi::qvariant_to_qstring(self)),
+            ffi::QVariantType::QStringList => {
+                QVariantValue::QStringList(ffi::qvariant_to_qstringlist(self))
+            }
             ffi::QVariantType::QTime => QVariantValue::QTime(ffi::qvariant_to_qtime(self)),
             ffi::QVariantType::QUrl => QVariantValue::QUrl(ffi::qvariant_to_qurl(self)),
+            ffi::QVariantType::QVariantHash => QVariantValue::Map(
+                ffi::qvariant_to_qvarianthash(self)
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.value()))
+                    .collect(),
+            ),
+            ffi::QVariantType::QVariantList => QVariantValue::List(
+                ffi::qvariant_to_qvariantlist(self)
+                    .iter()
+                    .map(QVariant::value)
+                    .collect(),
+            ),
+            ffi::QVariantType::QVariantMap => QVariantValue::Map(
+                ffi::qvariant_to_qvariantmap(self)
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.value()))
+                    .collect(),
+            ),
             ffi::QVariantType::U8 => QVariantValue::U8(ffi::qvariant_to_u8(self)),
             ffi::QVariantType::U16 => QVariantValue::U16(ffi::qvariant_to_u16(self)),
             ffi::QVariantType::U32 => QVariantValue::U32(ffi::qvariant_to_u32(self)),
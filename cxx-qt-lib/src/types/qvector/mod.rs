@@ -0,0 +1,392 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// The bridges for each element type are generated by generate_qlist_qvector.sh
+mod qvector_bool;
+mod qvector_f32;
+mod qvector_f64;
+mod qvector_i16;
+mod qvector_i32;
+mod qvector_i8;
+mod qvector_qcolor;
+mod qvector_qdate;
+mod qvector_qdatetime;
+mod qvector_qpoint;
+mod qvector_qpointf;
+mod qvector_qrect;
+mod qvector_qrectf;
+mod qvector_qsize;
+mod qvector_qsizef;
+mod qvector_qstring;
+mod qvector_qtime;
+mod qvector_qurl;
+mod qvector_qvariant;
+mod qvector_u16;
+mod qvector_u32;
+mod qvector_u8;
+
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    pin::Pin,
+};
+use cxx::{memory::UniquePtrTarget, ExternType, UniquePtr};
+
+use crate::{
+    QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl,
+    QVariant,
+};
+
+/// The QVector class is a template class that provides a dynamic array.
+///
+/// Note that this is the C++ representation, so it is only accessed by reference
+/// or through a `UniquePtr<QVector<T>>`. Use [`QVector::from_slice`] and `Vec::from` to convert
+/// from and to Rust collections, or [`QVector::from_unique_ptrs`] and `Vec<UniquePtr<T>>` for
+/// opaque element types such as [`QVariant`]. A [`QVector_QString`] can also be converted from and
+/// to Rust strings with [`QVector::from_strs`] and `Vec<String>`.
+#[repr(C)]
+pub struct QVector<T> {
+    _private: [u8; 0],
+    _marker: PhantomData<(T, PhantomPinned)>,
+}
+
+// CXX requires a single word for the name of a type, these are the names used in a bridge
+#[allow(non_camel_case_types)]
+pub type QVector_bool = QVector<bool>;
+#[allow(non_camel_case_types)]
+pub type QVector_f32 = QVector<f32>;
+#[allow(non_camel_case_types)]
+pub type QVector_f64 = QVector<f64>;
+#[allow(non_camel_case_types)]
+pub type QVector_i8 = QVector<i8>;
+#[allow(non_camel_case_types)]
+pub type QVector_i16 = QVector<i16>;
+#[allow(non_camel_case_types)]
+pub type QVector_i32 = QVector<i32>;
+#[allow(non_camel_case_types)]
+pub type QVector_QColor = QVector<QColor>;
+#[allow(non_camel_case_types)]
+pub type QVector_QDate = QVector<QDate>;
+#[allow(non_camel_case_types)]
+pub type QVector_QDateTime = QVector<QDateTime>;
+#[allow(non_camel_case_types)]
+pub type QVector_QPoint = QVector<QPoint>;
+#[allow(non_camel_case_types)]
+pub type QVector_QPointF = QVector<QPointF>;
+#[allow(non_camel_case_types)]
+pub type QVector_QRect = QVector<QRect>;
+#[allow(non_camel_case_types)]
+pub type QVector_QRectF = QVector<QRectF>;
+#[allow(non_camel_case_types)]
+pub type QVector_QSize = QVector<QSize>;
+#[allow(non_camel_case_types)]
+pub type QVector_QSizeF = QVector<QSizeF>;
+#[allow(non_camel_case_types)]
+pub type QVector_QString = QVector<QString>;
+#[allow(non_camel_case_types)]
+pub type QVector_QTime = QVector<QTime>;
+#[allow(non_camel_case_types)]
+pub type QVector_QUrl = QVector<QUrl>;
+#[allow(non_camel_case_types)]
+pub type QVector_QVariant = QVector<QVariant>;
+#[allow(non_camel_case_types)]
+pub type QVector_u8 = QVector<u8>;
+#[allow(non_camel_case_types)]
+pub type QVector_u16 = QVector<u16>;
+#[allow(non_camel_case_types)]
+pub type QVector_u32 = QVector<u32>;
+
+/// Trait implementation for an element in a [`QVector`].
+///
+/// This is implemented for each of the supported element types by the generated bridges.
+pub trait QVectorElement: Sized
+where
+    QVector<Self>: UniquePtrTarget,
+{
+    type TypeId;
+
+    fn append(vector: Pin<&mut QVector<Self>>, value: &Self);
+    fn clear(vector: Pin<&mut QVector<Self>>);
+    fn clone(vector: &QVector<Self>) -> UniquePtr<QVector<Self>>;
+    fn contains(vector: &QVector<Self>, value: &Self) -> bool;
+    fn default() -> UniquePtr<QVector<Self>>;
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is undefined behavior
+    /// even if the resulting reference is not used.
+    unsafe fn get_unchecked(vector: &QVector<Self>, pos: usize) -> &Self;
+    fn index_of(vector: &QVector<Self>, value: &Self) -> isize;
+    fn insert(vector: Pin<&mut QVector<Self>>, pos: usize, value: &Self);
+    fn len(vector: &QVector<Self>) -> usize;
+    fn remove(vector: Pin<&mut QVector<Self>>, pos: usize);
+}
+
+impl<T> QVector<T>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    /// Constructs an empty vector.
+    pub fn new() -> UniquePtr<Self> {
+        T::default()
+    }
+
+    /// Constructs a vector containing a copy of each of the elements in the slice.
+    ///
+    /// Opaque element types cannot be stored in a Rust slice, use
+    /// [`QVector::from_unique_ptrs`] for these instead.
+    pub fn from_slice(values: &[T]) -> UniquePtr<Self> {
+        let mut vector = Self::new();
+        for value in values {
+            vector.pin_mut().push(value);
+        }
+        vector
+    }
+
+    /// Constructs a copy of the given vector.
+    pub fn from_ref(vector: &Self) -> UniquePtr<Self> {
+        T::clone(vector)
+    }
+
+    /// Removes all the elements from the vector.
+    pub fn clear(self: Pin<&mut Self>) {
+        T::clear(self);
+    }
+
+    /// Returns true if the vector contains an occurrence of value; otherwise returns false.
+    pub fn contains(&self, value: &T) -> bool {
+        T::contains(self, value)
+    }
+
+    /// Returns the item at index position in the vector, or None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            Some(unsafe { T::get_unchecked(self, index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index position of the first occurrence of value in the vector,
+    /// or None if no item matched.
+    pub fn index_of(&self, value: &T) -> Option<usize> {
+        let index = T::index_of(self, value);
+        if index < 0 {
+            None
+        } else {
+            Some(index as usize)
+        }
+    }
+
+    /// Inserts value at index position in the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(self: Pin<&mut Self>, index: usize, value: &T) {
+        let len = self.len();
+        if index > len {
+            panic!(
+                "insertion index (is {}) should be <= len (is {})",
+                index, len
+            );
+        }
+
+        T::insert(self, index, value);
+    }
+
+    /// Returns true if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all the elements in the vector in order.
+    pub fn iter(&self) -> QVectorIter<T> {
+        QVectorIter {
+            vector: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        T::len(self)
+    }
+
+    /// Appends value to the end of the vector.
+    pub fn push(self: Pin<&mut Self>, value: &T) {
+        T::append(self, value);
+    }
+
+    /// Removes the element at index position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(self: Pin<&mut Self>, index: usize) {
+        let len = self.len();
+        if index >= len {
+            panic!("removal index (is {}) should be < len (is {})", index, len);
+        }
+
+        T::remove(self, index);
+    }
+}
+
+/// Trait implementation for an element in a [`QVector`] which can be borrowed as a Rust slice.
+///
+/// This is implemented for the primitive and trivial Qt element types by the generated bridges,
+/// opaque element types such as [`QString`] cannot be held in a Rust slice.
+pub trait QVectorSliceElement: QVectorElement
+where
+    QVector<Self>: UniquePtrTarget,
+{
+    fn as_slice(vector: &QVector<Self>) -> &[Self];
+}
+
+impl<T> QVector<T>
+where
+    T: QVectorSliceElement,
+    QVector<T>: UniquePtrTarget,
+{
+    /// Returns a slice which borrows the elements of the vector without copying them.
+    pub fn as_slice(&self) -> &[T] {
+        T::as_slice(self)
+    }
+}
+
+impl<T> QVector<T>
+where
+    T: QVectorElement + UniquePtrTarget,
+    QVector<T>: UniquePtrTarget,
+{
+    /// Constructs a vector containing a copy of each of the elements in the slice,
+    /// this is used for opaque element types which can only be held by a `UniquePtr`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the elements are null.
+    pub fn from_unique_ptrs(values: &[UniquePtr<T>]) -> UniquePtr<Self> {
+        let mut vector = Self::new();
+        for value in values {
+            vector.pin_mut().push(value);
+        }
+        vector
+    }
+}
+
+impl QVector<QString> {
+    /// Constructs a vector of strings from a slice of Rust strings, such as a `&[&str]`
+    /// or a `Vec<String>`.
+    pub fn from_strs<S: AsRef<str>>(values: &[S]) -> UniquePtr<Self> {
+        let mut vector = Self::new();
+        for value in values {
+            vector.pin_mut().push(&QString::from_str(value.as_ref()));
+        }
+        vector
+    }
+}
+
+unsafe impl<T> ExternType for QVector<T>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    type Id = T::TypeId;
+    type Kind = cxx::kind::Opaque;
+}
+
+impl<T> From<&QVector<T>> for UniquePtr<QVector<T>>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    fn from(vector: &QVector<T>) -> Self {
+        QVector::from_ref(vector)
+    }
+}
+
+impl<T> From<&QVector<T>> for Vec<T>
+where
+    T: QVectorElement + Clone,
+    QVector<T>: UniquePtrTarget,
+{
+    /// Convert a reference to a QVector into a Vec by making a deep copy of the data.
+    fn from(vector: &QVector<T>) -> Self {
+        vector.iter().cloned().collect()
+    }
+}
+
+impl<T> From<&QVector<T>> for Vec<UniquePtr<T>>
+where
+    T: QVectorElement + UniquePtrTarget,
+    QVector<T>: UniquePtrTarget,
+    for<'b> UniquePtr<T>: From<&'b T>,
+{
+    /// Convert a reference to a QVector into a Vec of UniquePtr by making a deep copy of the data.
+    fn from(vector: &QVector<T>) -> Self {
+        vector.iter().map(UniquePtr::from).collect()
+    }
+}
+
+impl From<&QVector<QString>> for Vec<String> {
+    /// Convert a reference to a QVector of strings into a Vec of Rust strings.
+    fn from(vector: &QVector<QString>) -> Self {
+        vector.iter().map(|string| string.to_string()).collect()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a QVector<T>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    type Item = &'a T;
+    type IntoIter = QVectorIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`QVector`].
+pub struct QVectorIter<'a, T>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    vector: &'a QVector<T>,
+    index: usize,
+}
+
+impl<'a, T> Iterator for QVectorIter<'a, T>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.vector.get(self.index);
+        if next.is_some() {
+            self.index += 1;
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for QVectorIter<'a, T>
+where
+    T: QVectorElement,
+    QVector<T>: UniquePtrTarget,
+{
+    fn len(&self) -> usize {
+        self.vector.len() - self.index
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        type QVector_bool = crate::QVector<bool>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_bool"]
+        fn qvectorAppend(vector: Pin<&mut QVector_bool>, value: &bool);
+        #[rust_name = "qvector_as_slice_bool"]
+        fn qvectorAsSlice(vector: &QVector_bool) -> &[bool];
+        #[rust_name = "qvector_clear_bool"]
+        fn qvectorClear(vector: Pin<&mut QVector_bool>);
+        #[rust_name = "qvector_clone_bool"]
+        fn qvectorClone(vector: &QVector_bool) -> UniquePtr<QVector_bool>;
+        #[rust_name = "qvector_contains_bool"]
+        fn qvectorContains(vector: &QVector_bool, value: &bool) -> bool;
+        #[rust_name = "qvector_default_bool"]
+        fn qvectorDefault() -> UniquePtr<QVector_bool>;
+        #[rust_name = "qvector_get_unchecked_bool"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_bool, pos: usize) -> &bool;
+        #[rust_name = "qvector_index_of_bool"]
+        fn qvectorIndexOf(vector: &QVector_bool, value: &bool) -> isize;
+        #[rust_name = "qvector_insert_bool"]
+        fn qvectorInsert(vector: Pin<&mut QVector_bool>, pos: usize, value: &bool);
+        #[rust_name = "qvector_len_bool"]
+        fn qvectorLen(vector: &QVector_bool) -> usize;
+        #[rust_name = "qvector_remove_bool"]
+        fn qvectorRemove(vector: Pin<&mut QVector_bool>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_bool> {}
+}
+
+impl crate::QVectorElement for bool {
+    type TypeId = type_id!("QVector_bool");
+
+    fn append(vector: Pin<&mut ffi::QVector_bool>, value: &Self) {
+        ffi::qvector_append_bool(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_bool>) {
+        ffi::qvector_clear_bool(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_bool) -> UniquePtr<ffi::QVector_bool> {
+        ffi::qvector_clone_bool(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_bool, value: &Self) -> bool {
+        ffi::qvector_contains_bool(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_bool> {
+        ffi::qvector_default_bool()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_bool, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_bool(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_bool, value: &Self) -> isize {
+        ffi::qvector_index_of_bool(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_bool>, pos: usize, value: &Self) {
+        ffi::qvector_insert_bool(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_bool) -> usize {
+        ffi::qvector_len_bool(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_bool>, pos: usize) {
+        ffi::qvector_remove_bool(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for bool {
+    fn as_slice(vector: &ffi::QVector_bool) -> &[Self] {
+        ffi::qvector_as_slice_bool(vector)
+    }
+}
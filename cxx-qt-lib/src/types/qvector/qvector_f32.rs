@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        type QVector_f32 = crate::QVector<f32>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_f32"]
+        fn qvectorAppend(vector: Pin<&mut QVector_f32>, value: &f32);
+        #[rust_name = "qvector_as_slice_f32"]
+        fn qvectorAsSlice(vector: &QVector_f32) -> &[f32];
+        #[rust_name = "qvector_clear_f32"]
+        fn qvectorClear(vector: Pin<&mut QVector_f32>);
+        #[rust_name = "qvector_clone_f32"]
+        fn qvectorClone(vector: &QVector_f32) -> UniquePtr<QVector_f32>;
+        #[rust_name = "qvector_contains_f32"]
+        fn qvectorContains(vector: &QVector_f32, value: &f32) -> bool;
+        #[rust_name = "qvector_default_f32"]
+        fn qvectorDefault() -> UniquePtr<QVector_f32>;
+        #[rust_name = "qvector_get_unchecked_f32"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_f32, pos: usize) -> &f32;
+        #[rust_name = "qvector_index_of_f32"]
+        fn qvectorIndexOf(vector: &QVector_f32, value: &f32) -> isize;
+        #[rust_name = "qvector_insert_f32"]
+        fn qvectorInsert(vector: Pin<&mut QVector_f32>, pos: usize, value: &f32);
+        #[rust_name = "qvector_len_f32"]
+        fn qvectorLen(vector: &QVector_f32) -> usize;
+        #[rust_name = "qvector_remove_f32"]
+        fn qvectorRemove(vector: Pin<&mut QVector_f32>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_f32> {}
+}
+
+impl crate::QVectorElement for f32 {
+    type TypeId = type_id!("QVector_f32");
+
+    fn append(vector: Pin<&mut ffi::QVector_f32>, value: &Self) {
+        ffi::qvector_append_f32(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_f32>) {
+        ffi::qvector_clear_f32(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_f32) -> UniquePtr<ffi::QVector_f32> {
+        ffi::qvector_clone_f32(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_f32, value: &Self) -> bool {
+        ffi::qvector_contains_f32(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_f32> {
+        ffi::qvector_default_f32()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_f32, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_f32(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_f32, value: &Self) -> isize {
+        ffi::qvector_index_of_f32(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_f32>, pos: usize, value: &Self) {
+        ffi::qvector_insert_f32(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_f32) -> usize {
+        ffi::qvector_len_f32(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_f32>, pos: usize) {
+        ffi::qvector_remove_f32(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for f32 {
+    fn as_slice(vector: &ffi::QVector_f32) -> &[Self] {
+        ffi::qvector_as_slice_f32(vector)
+    }
+}
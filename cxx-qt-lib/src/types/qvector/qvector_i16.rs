@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        type QVector_i16 = crate::QVector<i16>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_i16"]
+        fn qvectorAppend(vector: Pin<&mut QVector_i16>, value: &i16);
+        #[rust_name = "qvector_as_slice_i16"]
+        fn qvectorAsSlice(vector: &QVector_i16) -> &[i16];
+        #[rust_name = "qvector_clear_i16"]
+        fn qvectorClear(vector: Pin<&mut QVector_i16>);
+        #[rust_name = "qvector_clone_i16"]
+        fn qvectorClone(vector: &QVector_i16) -> UniquePtr<QVector_i16>;
+        #[rust_name = "qvector_contains_i16"]
+        fn qvectorContains(vector: &QVector_i16, value: &i16) -> bool;
+        #[rust_name = "qvector_default_i16"]
+        fn qvectorDefault() -> UniquePtr<QVector_i16>;
+        #[rust_name = "qvector_get_unchecked_i16"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_i16, pos: usize) -> &i16;
+        #[rust_name = "qvector_index_of_i16"]
+        fn qvectorIndexOf(vector: &QVector_i16, value: &i16) -> isize;
+        #[rust_name = "qvector_insert_i16"]
+        fn qvectorInsert(vector: Pin<&mut QVector_i16>, pos: usize, value: &i16);
+        #[rust_name = "qvector_len_i16"]
+        fn qvectorLen(vector: &QVector_i16) -> usize;
+        #[rust_name = "qvector_remove_i16"]
+        fn qvectorRemove(vector: Pin<&mut QVector_i16>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_i16> {}
+}
+
+impl crate::QVectorElement for i16 {
+    type TypeId = type_id!("QVector_i16");
+
+    fn append(vector: Pin<&mut ffi::QVector_i16>, value: &Self) {
+        ffi::qvector_append_i16(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_i16>) {
+        ffi::qvector_clear_i16(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_i16) -> UniquePtr<ffi::QVector_i16> {
+        ffi::qvector_clone_i16(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_i16, value: &Self) -> bool {
+        ffi::qvector_contains_i16(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_i16> {
+        ffi::qvector_default_i16()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_i16, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_i16(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_i16, value: &Self) -> isize {
+        ffi::qvector_index_of_i16(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_i16>, pos: usize, value: &Self) {
+        ffi::qvector_insert_i16(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_i16) -> usize {
+        ffi::qvector_len_i16(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_i16>, pos: usize) {
+        ffi::qvector_remove_i16(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for i16 {
+    fn as_slice(vector: &ffi::QVector_i16) -> &[Self] {
+        ffi::qvector_as_slice_i16(vector)
+    }
+}
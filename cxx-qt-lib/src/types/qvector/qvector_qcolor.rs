@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QColor;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QColor = crate::QColor;
+
+        type QVector_QColor = crate::QVector<QColor>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qcolor"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QColor>, value: &QColor);
+        #[rust_name = "qvector_clear_qcolor"]
+        fn qvectorClear(vector: Pin<&mut QVector_QColor>);
+        #[rust_name = "qvector_clone_qcolor"]
+        fn qvectorClone(vector: &QVector_QColor) -> UniquePtr<QVector_QColor>;
+        #[rust_name = "qvector_contains_qcolor"]
+        fn qvectorContains(vector: &QVector_QColor, value: &QColor) -> bool;
+        #[rust_name = "qvector_default_qcolor"]
+        fn qvectorDefault() -> UniquePtr<QVector_QColor>;
+        #[rust_name = "qvector_get_unchecked_qcolor"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QColor, pos: usize) -> &QColor;
+        #[rust_name = "qvector_index_of_qcolor"]
+        fn qvectorIndexOf(vector: &QVector_QColor, value: &QColor) -> isize;
+        #[rust_name = "qvector_insert_qcolor"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QColor>, pos: usize, value: &QColor);
+        #[rust_name = "qvector_len_qcolor"]
+        fn qvectorLen(vector: &QVector_QColor) -> usize;
+        #[rust_name = "qvector_remove_qcolor"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QColor>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QColor> {}
+}
+
+impl crate::QVectorElement for QColor {
+    type TypeId = type_id!("QVector_QColor");
+
+    fn append(vector: Pin<&mut ffi::QVector_QColor>, value: &Self) {
+        ffi::qvector_append_qcolor(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QColor>) {
+        ffi::qvector_clear_qcolor(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QColor) -> UniquePtr<ffi::QVector_QColor> {
+        ffi::qvector_clone_qcolor(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QColor, value: &Self) -> bool {
+        ffi::qvector_contains_qcolor(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QColor> {
+        ffi::qvector_default_qcolor()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QColor, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qcolor(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QColor, value: &Self) -> isize {
+        ffi::qvector_index_of_qcolor(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QColor>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qcolor(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QColor) -> usize {
+        ffi::qvector_len_qcolor(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QColor>, pos: usize) {
+        ffi::qvector_remove_qcolor(vector, pos);
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QDate;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QDate = crate::QDate;
+
+        type QVector_QDate = crate::QVector<QDate>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qdate"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QDate>, value: &QDate);
+        #[rust_name = "qvector_as_slice_qdate"]
+        fn qvectorAsSlice(vector: &QVector_QDate) -> &[QDate];
+        #[rust_name = "qvector_clear_qdate"]
+        fn qvectorClear(vector: Pin<&mut QVector_QDate>);
+        #[rust_name = "qvector_clone_qdate"]
+        fn qvectorClone(vector: &QVector_QDate) -> UniquePtr<QVector_QDate>;
+        #[rust_name = "qvector_contains_qdate"]
+        fn qvectorContains(vector: &QVector_QDate, value: &QDate) -> bool;
+        #[rust_name = "qvector_default_qdate"]
+        fn qvectorDefault() -> UniquePtr<QVector_QDate>;
+        #[rust_name = "qvector_get_unchecked_qdate"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QDate, pos: usize) -> &QDate;
+        #[rust_name = "qvector_index_of_qdate"]
+        fn qvectorIndexOf(vector: &QVector_QDate, value: &QDate) -> isize;
+        #[rust_name = "qvector_insert_qdate"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QDate>, pos: usize, value: &QDate);
+        #[rust_name = "qvector_len_qdate"]
+        fn qvectorLen(vector: &QVector_QDate) -> usize;
+        #[rust_name = "qvector_remove_qdate"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QDate>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QDate> {}
+}
+
+impl crate::QVectorElement for QDate {
+    type TypeId = type_id!("QVector_QDate");
+
+    fn append(vector: Pin<&mut ffi::QVector_QDate>, value: &Self) {
+        ffi::qvector_append_qdate(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QDate>) {
+        ffi::qvector_clear_qdate(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QDate) -> UniquePtr<ffi::QVector_QDate> {
+        ffi::qvector_clone_qdate(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QDate, value: &Self) -> bool {
+        ffi::qvector_contains_qdate(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QDate> {
+        ffi::qvector_default_qdate()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QDate, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qdate(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QDate, value: &Self) -> isize {
+        ffi::qvector_index_of_qdate(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QDate>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qdate(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QDate) -> usize {
+        ffi::qvector_len_qdate(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QDate>, pos: usize) {
+        ffi::qvector_remove_qdate(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QDate {
+    fn as_slice(vector: &ffi::QVector_QDate) -> &[Self] {
+        ffi::qvector_as_slice_qdate(vector)
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QDateTime;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QDateTime = crate::QDateTime;
+
+        type QVector_QDateTime = crate::QVector<QDateTime>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qdatetime"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QDateTime>, value: &QDateTime);
+        #[rust_name = "qvector_clear_qdatetime"]
+        fn qvectorClear(vector: Pin<&mut QVector_QDateTime>);
+        #[rust_name = "qvector_clone_qdatetime"]
+        fn qvectorClone(vector: &QVector_QDateTime) -> UniquePtr<QVector_QDateTime>;
+        #[rust_name = "qvector_contains_qdatetime"]
+        fn qvectorContains(vector: &QVector_QDateTime, value: &QDateTime) -> bool;
+        #[rust_name = "qvector_default_qdatetime"]
+        fn qvectorDefault() -> UniquePtr<QVector_QDateTime>;
+        #[rust_name = "qvector_get_unchecked_qdatetime"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QDateTime, pos: usize) -> &QDateTime;
+        #[rust_name = "qvector_index_of_qdatetime"]
+        fn qvectorIndexOf(vector: &QVector_QDateTime, value: &QDateTime) -> isize;
+        #[rust_name = "qvector_insert_qdatetime"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QDateTime>, pos: usize, value: &QDateTime);
+        #[rust_name = "qvector_len_qdatetime"]
+        fn qvectorLen(vector: &QVector_QDateTime) -> usize;
+        #[rust_name = "qvector_remove_qdatetime"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QDateTime>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QDateTime> {}
+}
+
+impl crate::QVectorElement for QDateTime {
+    type TypeId = type_id!("QVector_QDateTime");
+
+    fn append(vector: Pin<&mut ffi::QVector_QDateTime>, value: &Self) {
+        ffi::qvector_append_qdatetime(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QDateTime>) {
+        ffi::qvector_clear_qdatetime(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QDateTime) -> UniquePtr<ffi::QVector_QDateTime> {
+        ffi::qvector_clone_qdatetime(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QDateTime, value: &Self) -> bool {
+        ffi::qvector_contains_qdatetime(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QDateTime> {
+        ffi::qvector_default_qdatetime()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QDateTime, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qdatetime(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QDateTime, value: &Self) -> isize {
+        ffi::qvector_index_of_qdatetime(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QDateTime>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qdatetime(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QDateTime) -> usize {
+        ffi::qvector_len_qdatetime(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QDateTime>, pos: usize) {
+        ffi::qvector_remove_qdatetime(vector, pos);
+    }
+}
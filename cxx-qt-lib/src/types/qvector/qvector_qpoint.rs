@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QPoint;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QPoint = crate::QPoint;
+
+        type QVector_QPoint = crate::QVector<QPoint>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qpoint"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QPoint>, value: &QPoint);
+        #[rust_name = "qvector_as_slice_qpoint"]
+        fn qvectorAsSlice(vector: &QVector_QPoint) -> &[QPoint];
+        #[rust_name = "qvector_clear_qpoint"]
+        fn qvectorClear(vector: Pin<&mut QVector_QPoint>);
+        #[rust_name = "qvector_clone_qpoint"]
+        fn qvectorClone(vector: &QVector_QPoint) -> UniquePtr<QVector_QPoint>;
+        #[rust_name = "qvector_contains_qpoint"]
+        fn qvectorContains(vector: &QVector_QPoint, value: &QPoint) -> bool;
+        #[rust_name = "qvector_default_qpoint"]
+        fn qvectorDefault() -> UniquePtr<QVector_QPoint>;
+        #[rust_name = "qvector_get_unchecked_qpoint"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QPoint, pos: usize) -> &QPoint;
+        #[rust_name = "qvector_index_of_qpoint"]
+        fn qvectorIndexOf(vector: &QVector_QPoint, value: &QPoint) -> isize;
+        #[rust_name = "qvector_insert_qpoint"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QPoint>, pos: usize, value: &QPoint);
+        #[rust_name = "qvector_len_qpoint"]
+        fn qvectorLen(vector: &QVector_QPoint) -> usize;
+        #[rust_name = "qvector_remove_qpoint"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QPoint>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QPoint> {}
+}
+
+impl crate::QVectorElement for QPoint {
+    type TypeId = type_id!("QVector_QPoint");
+
+    fn append(vector: Pin<&mut ffi::QVector_QPoint>, value: &Self) {
+        ffi::qvector_append_qpoint(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QPoint>) {
+        ffi::qvector_clear_qpoint(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QPoint) -> UniquePtr<ffi::QVector_QPoint> {
+        ffi::qvector_clone_qpoint(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QPoint, value: &Self) -> bool {
+        ffi::qvector_contains_qpoint(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QPoint> {
+        ffi::qvector_default_qpoint()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QPoint, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qpoint(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QPoint, value: &Self) -> isize {
+        ffi::qvector_index_of_qpoint(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QPoint>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qpoint(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QPoint) -> usize {
+        ffi::qvector_len_qpoint(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QPoint>, pos: usize) {
+        ffi::qvector_remove_qpoint(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QPoint {
+    fn as_slice(vector: &ffi::QVector_QPoint) -> &[Self] {
+        ffi::qvector_as_slice_qpoint(vector)
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QPointF;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QPointF = crate::QPointF;
+
+        type QVector_QPointF = crate::QVector<QPointF>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qpointf"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QPointF>, value: &QPointF);
+        #[rust_name = "qvector_as_slice_qpointf"]
+        fn qvectorAsSlice(vector: &QVector_QPointF) -> &[QPointF];
+        #[rust_name = "qvector_clear_qpointf"]
+        fn qvectorClear(vector: Pin<&mut QVector_QPointF>);
+        #[rust_name = "qvector_clone_qpointf"]
+        fn qvectorClone(vector: &QVector_QPointF) -> UniquePtr<QVector_QPointF>;
+        #[rust_name = "qvector_contains_qpointf"]
+        fn qvectorContains(vector: &QVector_QPointF, value: &QPointF) -> bool;
+        #[rust_name = "qvector_default_qpointf"]
+        fn qvectorDefault() -> UniquePtr<QVector_QPointF>;
+        #[rust_name = "qvector_get_unchecked_qpointf"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QPointF, pos: usize) -> &QPointF;
+        #[rust_name = "qvector_index_of_qpointf"]
+        fn qvectorIndexOf(vector: &QVector_QPointF, value: &QPointF) -> isize;
+        #[rust_name = "qvector_insert_qpointf"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QPointF>, pos: usize, value: &QPointF);
+        #[rust_name = "qvector_len_qpointf"]
+        fn qvectorLen(vector: &QVector_QPointF) -> usize;
+        #[rust_name = "qvector_remove_qpointf"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QPointF>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QPointF> {}
+}
+
+impl crate::QVectorElement for QPointF {
+    type TypeId = type_id!("QVector_QPointF");
+
+    fn append(vector: Pin<&mut ffi::QVector_QPointF>, value: &Self) {
+        ffi::qvector_append_qpointf(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QPointF>) {
+        ffi::qvector_clear_qpointf(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QPointF) -> UniquePtr<ffi::QVector_QPointF> {
+        ffi::qvector_clone_qpointf(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QPointF, value: &Self) -> bool {
+        ffi::qvector_contains_qpointf(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QPointF> {
+        ffi::qvector_default_qpointf()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QPointF, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qpointf(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QPointF, value: &Self) -> isize {
+        ffi::qvector_index_of_qpointf(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QPointF>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qpointf(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QPointF) -> usize {
+        ffi::qvector_len_qpointf(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QPointF>, pos: usize) {
+        ffi::qvector_remove_qpointf(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QPointF {
+    fn as_slice(vector: &ffi::QVector_QPointF) -> &[Self] {
+        ffi::qvector_as_slice_qpointf(vector)
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QRect;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QRect = crate::QRect;
+
+        type QVector_QRect = crate::QVector<QRect>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qrect"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QRect>, value: &QRect);
+        #[rust_name = "qvector_as_slice_qrect"]
+        fn qvectorAsSlice(vector: &QVector_QRect) -> &[QRect];
+        #[rust_name = "qvector_clear_qrect"]
+        fn qvectorClear(vector: Pin<&mut QVector_QRect>);
+        #[rust_name = "qvector_clone_qrect"]
+        fn qvectorClone(vector: &QVector_QRect) -> UniquePtr<QVector_QRect>;
+        #[rust_name = "qvector_contains_qrect"]
+        fn qvectorContains(vector: &QVector_QRect, value: &QRect) -> bool;
+        #[rust_name = "qvector_default_qrect"]
+        fn qvectorDefault() -> UniquePtr<QVector_QRect>;
+        #[rust_name = "qvector_get_unchecked_qrect"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QRect, pos: usize) -> &QRect;
+        #[rust_name = "qvector_index_of_qrect"]
+        fn qvectorIndexOf(vector: &QVector_QRect, value: &QRect) -> isize;
+        #[rust_name = "qvector_insert_qrect"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QRect>, pos: usize, value: &QRect);
+        #[rust_name = "qvector_len_qrect"]
+        fn qvectorLen(vector: &QVector_QRect) -> usize;
+        #[rust_name = "qvector_remove_qrect"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QRect>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QRect> {}
+}
+
+impl crate::QVectorElement for QRect {
+    type TypeId = type_id!("QVector_QRect");
+
+    fn append(vector: Pin<&mut ffi::QVector_QRect>, value: &Self) {
+        ffi::qvector_append_qrect(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QRect>) {
+        ffi::qvector_clear_qrect(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QRect) -> UniquePtr<ffi::QVector_QRect> {
+        ffi::qvector_clone_qrect(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QRect, value: &Self) -> bool {
+        ffi::qvector_contains_qrect(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QRect> {
+        ffi::qvector_default_qrect()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QRect, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qrect(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QRect, value: &Self) -> isize {
+        ffi::qvector_index_of_qrect(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QRect>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qrect(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QRect) -> usize {
+        ffi::qvector_len_qrect(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QRect>, pos: usize) {
+        ffi::qvector_remove_qrect(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QRect {
+    fn as_slice(vector: &ffi::QVector_QRect) -> &[Self] {
+        ffi::qvector_as_slice_qrect(vector)
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QRectF;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QRectF = crate::QRectF;
+
+        type QVector_QRectF = crate::QVector<QRectF>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qrectf"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QRectF>, value: &QRectF);
+        #[rust_name = "qvector_as_slice_qrectf"]
+        fn qvectorAsSlice(vector: &QVector_QRectF) -> &[QRectF];
+        #[rust_name = "qvector_clear_qrectf"]
+        fn qvectorClear(vector: Pin<&mut QVector_QRectF>);
+        #[rust_name = "qvector_clone_qrectf"]
+        fn qvectorClone(vector: &QVector_QRectF) -> UniquePtr<QVector_QRectF>;
+        #[rust_name = "qvector_contains_qrectf"]
+        fn qvectorContains(vector: &QVector_QRectF, value: &QRectF) -> bool;
+        #[rust_name = "qvector_default_qrectf"]
+        fn qvectorDefault() -> UniquePtr<QVector_QRectF>;
+        #[rust_name = "qvector_get_unchecked_qrectf"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QRectF, pos: usize) -> &QRectF;
+        #[rust_name = "qvector_index_of_qrectf"]
+        fn qvectorIndexOf(vector: &QVector_QRectF, value: &QRectF) -> isize;
+        #[rust_name = "qvector_insert_qrectf"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QRectF>, pos: usize, value: &QRectF);
+        #[rust_name = "qvector_len_qrectf"]
+        fn qvectorLen(vector: &QVector_QRectF) -> usize;
+        #[rust_name = "qvector_remove_qrectf"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QRectF>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QRectF> {}
+}
+
+impl crate::QVectorElement for QRectF {
+    type TypeId = type_id!("QVector_QRectF");
+
+    fn append(vector: Pin<&mut ffi::QVector_QRectF>, value: &Self) {
+        ffi::qvector_append_qrectf(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QRectF>) {
+        ffi::qvector_clear_qrectf(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QRectF) -> UniquePtr<ffi::QVector_QRectF> {
+        ffi::qvector_clone_qrectf(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QRectF, value: &Self) -> bool {
+        ffi::qvector_contains_qrectf(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QRectF> {
+        ffi::qvector_default_qrectf()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QRectF, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qrectf(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QRectF, value: &Self) -> isize {
+        ffi::qvector_index_of_qrectf(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QRectF>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qrectf(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QRectF) -> usize {
+        ffi::qvector_len_qrectf(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QRectF>, pos: usize) {
+        ffi::qvector_remove_qrectf(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QRectF {
+    fn as_slice(vector: &ffi::QVector_QRectF) -> &[Self] {
+        ffi::qvector_as_slice_qrectf(vector)
+    }
+}
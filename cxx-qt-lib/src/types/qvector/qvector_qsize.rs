@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QSize;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QSize = crate::QSize;
+
+        type QVector_QSize = crate::QVector<QSize>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qsize"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QSize>, value: &QSize);
+        #[rust_name = "qvector_as_slice_qsize"]
+        fn qvectorAsSlice(vector: &QVector_QSize) -> &[QSize];
+        #[rust_name = "qvector_clear_qsize"]
+        fn qvectorClear(vector: Pin<&mut QVector_QSize>);
+        #[rust_name = "qvector_clone_qsize"]
+        fn qvectorClone(vector: &QVector_QSize) -> UniquePtr<QVector_QSize>;
+        #[rust_name = "qvector_contains_qsize"]
+        fn qvectorContains(vector: &QVector_QSize, value: &QSize) -> bool;
+        #[rust_name = "qvector_default_qsize"]
+        fn qvectorDefault() -> UniquePtr<QVector_QSize>;
+        #[rust_name = "qvector_get_unchecked_qsize"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QSize, pos: usize) -> &QSize;
+        #[rust_name = "qvector_index_of_qsize"]
+        fn qvectorIndexOf(vector: &QVector_QSize, value: &QSize) -> isize;
+        #[rust_name = "qvector_insert_qsize"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QSize>, pos: usize, value: &QSize);
+        #[rust_name = "qvector_len_qsize"]
+        fn qvectorLen(vector: &QVector_QSize) -> usize;
+        #[rust_name = "qvector_remove_qsize"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QSize>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QSize> {}
+}
+
+impl crate::QVectorElement for QSize {
+    type TypeId = type_id!("QVector_QSize");
+
+    fn append(vector: Pin<&mut ffi::QVector_QSize>, value: &Self) {
+        ffi::qvector_append_qsize(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QSize>) {
+        ffi::qvector_clear_qsize(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QSize) -> UniquePtr<ffi::QVector_QSize> {
+        ffi::qvector_clone_qsize(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QSize, value: &Self) -> bool {
+        ffi::qvector_contains_qsize(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QSize> {
+        ffi::qvector_default_qsize()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QSize, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qsize(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QSize, value: &Self) -> isize {
+        ffi::qvector_index_of_qsize(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QSize>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qsize(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QSize) -> usize {
+        ffi::qvector_len_qsize(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QSize>, pos: usize) {
+        ffi::qvector_remove_qsize(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QSize {
+    fn as_slice(vector: &ffi::QVector_QSize) -> &[Self] {
+        ffi::qvector_as_slice_qsize(vector)
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QSizeF;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QSizeF = crate::QSizeF;
+
+        type QVector_QSizeF = crate::QVector<QSizeF>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qsizef"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QSizeF>, value: &QSizeF);
+        #[rust_name = "qvector_as_slice_qsizef"]
+        fn qvectorAsSlice(vector: &QVector_QSizeF) -> &[QSizeF];
+        #[rust_name = "qvector_clear_qsizef"]
+        fn qvectorClear(vector: Pin<&mut QVector_QSizeF>);
+        #[rust_name = "qvector_clone_qsizef"]
+        fn qvectorClone(vector: &QVector_QSizeF) -> UniquePtr<QVector_QSizeF>;
+        #[rust_name = "qvector_contains_qsizef"]
+        fn qvectorContains(vector: &QVector_QSizeF, value: &QSizeF) -> bool;
+        #[rust_name = "qvector_default_qsizef"]
+        fn qvectorDefault() -> UniquePtr<QVector_QSizeF>;
+        #[rust_name = "qvector_get_unchecked_qsizef"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QSizeF, pos: usize) -> &QSizeF;
+        #[rust_name = "qvector_index_of_qsizef"]
+        fn qvectorIndexOf(vector: &QVector_QSizeF, value: &QSizeF) -> isize;
+        #[rust_name = "qvector_insert_qsizef"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QSizeF>, pos: usize, value: &QSizeF);
+        #[rust_name = "qvector_len_qsizef"]
+        fn qvectorLen(vector: &QVector_QSizeF) -> usize;
+        #[rust_name = "qvector_remove_qsizef"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QSizeF>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QSizeF> {}
+}
+
+impl crate::QVectorElement for QSizeF {
+    type TypeId = type_id!("QVector_QSizeF");
+
+    fn append(vector: Pin<&mut ffi::QVector_QSizeF>, value: &Self) {
+        ffi::qvector_append_qsizef(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QSizeF>) {
+        ffi::qvector_clear_qsizef(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QSizeF) -> UniquePtr<ffi::QVector_QSizeF> {
+        ffi::qvector_clone_qsizef(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QSizeF, value: &Self) -> bool {
+        ffi::qvector_contains_qsizef(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QSizeF> {
+        ffi::qvector_default_qsizef()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QSizeF, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qsizef(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QSizeF, value: &Self) -> isize {
+        ffi::qvector_index_of_qsizef(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QSizeF>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qsizef(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QSizeF) -> usize {
+        ffi::qvector_len_qsizef(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QSizeF>, pos: usize) {
+        ffi::qvector_remove_qsizef(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QSizeF {
+    fn as_slice(vector: &ffi::QVector_QSizeF) -> &[Self] {
+        ffi::qvector_as_slice_qsizef(vector)
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QString;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = crate::QString;
+
+        type QVector_QString = crate::QVector<QString>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qstring"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QString>, value: &QString);
+        #[rust_name = "qvector_clear_qstring"]
+        fn qvectorClear(vector: Pin<&mut QVector_QString>);
+        #[rust_name = "qvector_clone_qstring"]
+        fn qvectorClone(vector: &QVector_QString) -> UniquePtr<QVector_QString>;
+        #[rust_name = "qvector_contains_qstring"]
+        fn qvectorContains(vector: &QVector_QString, value: &QString) -> bool;
+        #[rust_name = "qvector_default_qstring"]
+        fn qvectorDefault() -> UniquePtr<QVector_QString>;
+        #[rust_name = "qvector_get_unchecked_qstring"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QString, pos: usize) -> &QString;
+        #[rust_name = "qvector_index_of_qstring"]
+        fn qvectorIndexOf(vector: &QVector_QString, value: &QString) -> isize;
+        #[rust_name = "qvector_insert_qstring"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QString>, pos: usize, value: &QString);
+        #[rust_name = "qvector_len_qstring"]
+        fn qvectorLen(vector: &QVector_QString) -> usize;
+        #[rust_name = "qvector_remove_qstring"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QString>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QString> {}
+}
+
+impl crate::QVectorElement for QString {
+    type TypeId = type_id!("QVector_QString");
+
+    fn append(vector: Pin<&mut ffi::QVector_QString>, value: &Self) {
+        ffi::qvector_append_qstring(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QString>) {
+        ffi::qvector_clear_qstring(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QString) -> UniquePtr<ffi::QVector_QString> {
+        ffi::qvector_clone_qstring(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QString, value: &Self) -> bool {
+        ffi::qvector_contains_qstring(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QString> {
+        ffi::qvector_default_qstring()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QString, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qstring(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QString, value: &Self) -> isize {
+        ffi::qvector_index_of_qstring(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QString>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qstring(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QString) -> usize {
+        ffi::qvector_len_qstring(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QString>, pos: usize) {
+        ffi::qvector_remove_qstring(vector, pos);
+    }
+}
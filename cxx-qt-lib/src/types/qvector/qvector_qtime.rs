@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QTime;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QTime = crate::QTime;
+
+        type QVector_QTime = crate::QVector<QTime>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qtime"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QTime>, value: &QTime);
+        #[rust_name = "qvector_as_slice_qtime"]
+        fn qvectorAsSlice(vector: &QVector_QTime) -> &[QTime];
+        #[rust_name = "qvector_clear_qtime"]
+        fn qvectorClear(vector: Pin<&mut QVector_QTime>);
+        #[rust_name = "qvector_clone_qtime"]
+        fn qvectorClone(vector: &QVector_QTime) -> UniquePtr<QVector_QTime>;
+        #[rust_name = "qvector_contains_qtime"]
+        fn qvectorContains(vector: &QVector_QTime, value: &QTime) -> bool;
+        #[rust_name = "qvector_default_qtime"]
+        fn qvectorDefault() -> UniquePtr<QVector_QTime>;
+        #[rust_name = "qvector_get_unchecked_qtime"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QTime, pos: usize) -> &QTime;
+        #[rust_name = "qvector_index_of_qtime"]
+        fn qvectorIndexOf(vector: &QVector_QTime, value: &QTime) -> isize;
+        #[rust_name = "qvector_insert_qtime"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QTime>, pos: usize, value: &QTime);
+        #[rust_name = "qvector_len_qtime"]
+        fn qvectorLen(vector: &QVector_QTime) -> usize;
+        #[rust_name = "qvector_remove_qtime"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QTime>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QTime> {}
+}
+
+impl crate::QVectorElement for QTime {
+    type TypeId = type_id!("QVector_QTime");
+
+    fn append(vector: Pin<&mut ffi::QVector_QTime>, value: &Self) {
+        ffi::qvector_append_qtime(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QTime>) {
+        ffi::qvector_clear_qtime(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QTime) -> UniquePtr<ffi::QVector_QTime> {
+        ffi::qvector_clone_qtime(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QTime, value: &Self) -> bool {
+        ffi::qvector_contains_qtime(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QTime> {
+        ffi::qvector_default_qtime()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QTime, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qtime(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QTime, value: &Self) -> isize {
+        ffi::qvector_index_of_qtime(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QTime>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qtime(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QTime) -> usize {
+        ffi::qvector_len_qtime(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QTime>, pos: usize) {
+        ffi::qvector_remove_qtime(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for QTime {
+    fn as_slice(vector: &ffi::QVector_QTime) -> &[Self] {
+        ffi::qvector_as_slice_qtime(vector)
+    }
+}
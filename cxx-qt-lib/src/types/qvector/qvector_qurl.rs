@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QUrl;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QUrl = crate::QUrl;
+
+        type QVector_QUrl = crate::QVector<QUrl>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qurl"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QUrl>, value: &QUrl);
+        #[rust_name = "qvector_clear_qurl"]
+        fn qvectorClear(vector: Pin<&mut QVector_QUrl>);
+        #[rust_name = "qvector_clone_qurl"]
+        fn qvectorClone(vector: &QVector_QUrl) -> UniquePtr<QVector_QUrl>;
+        #[rust_name = "qvector_contains_qurl"]
+        fn qvectorContains(vector: &QVector_QUrl, value: &QUrl) -> bool;
+        #[rust_name = "qvector_default_qurl"]
+        fn qvectorDefault() -> UniquePtr<QVector_QUrl>;
+        #[rust_name = "qvector_get_unchecked_qurl"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QUrl, pos: usize) -> &QUrl;
+        #[rust_name = "qvector_index_of_qurl"]
+        fn qvectorIndexOf(vector: &QVector_QUrl, value: &QUrl) -> isize;
+        #[rust_name = "qvector_insert_qurl"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QUrl>, pos: usize, value: &QUrl);
+        #[rust_name = "qvector_len_qurl"]
+        fn qvectorLen(vector: &QVector_QUrl) -> usize;
+        #[rust_name = "qvector_remove_qurl"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QUrl>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QUrl> {}
+}
+
+impl crate::QVectorElement for QUrl {
+    type TypeId = type_id!("QVector_QUrl");
+
+    fn append(vector: Pin<&mut ffi::QVector_QUrl>, value: &Self) {
+        ffi::qvector_append_qurl(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QUrl>) {
+        ffi::qvector_clear_qurl(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QUrl) -> UniquePtr<ffi::QVector_QUrl> {
+        ffi::qvector_clone_qurl(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QUrl, value: &Self) -> bool {
+        ffi::qvector_contains_qurl(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QUrl> {
+        ffi::qvector_default_qurl()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QUrl, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qurl(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QUrl, value: &Self) -> isize {
+        ffi::qvector_index_of_qurl(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QUrl>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qurl(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QUrl) -> usize {
+        ffi::qvector_len_qurl(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QUrl>, pos: usize) {
+        ffi::qvector_remove_qurl(vector, pos);
+    }
+}
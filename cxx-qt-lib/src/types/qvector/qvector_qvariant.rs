@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use crate::QVariant;
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QVariant = crate::QVariant;
+
+        type QVector_QVariant = crate::QVector<QVariant>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_qvariant"]
+        fn qvectorAppend(vector: Pin<&mut QVector_QVariant>, value: &QVariant);
+        #[rust_name = "qvector_clear_qvariant"]
+        fn qvectorClear(vector: Pin<&mut QVector_QVariant>);
+        #[rust_name = "qvector_clone_qvariant"]
+        fn qvectorClone(vector: &QVector_QVariant) -> UniquePtr<QVector_QVariant>;
+        #[rust_name = "qvector_contains_qvariant"]
+        fn qvectorContains(vector: &QVector_QVariant, value: &QVariant) -> bool;
+        #[rust_name = "qvector_default_qvariant"]
+        fn qvectorDefault() -> UniquePtr<QVector_QVariant>;
+        #[rust_name = "qvector_get_unchecked_qvariant"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_QVariant, pos: usize) -> &QVariant;
+        #[rust_name = "qvector_index_of_qvariant"]
+        fn qvectorIndexOf(vector: &QVector_QVariant, value: &QVariant) -> isize;
+        #[rust_name = "qvector_insert_qvariant"]
+        fn qvectorInsert(vector: Pin<&mut QVector_QVariant>, pos: usize, value: &QVariant);
+        #[rust_name = "qvector_len_qvariant"]
+        fn qvectorLen(vector: &QVector_QVariant) -> usize;
+        #[rust_name = "qvector_remove_qvariant"]
+        fn qvectorRemove(vector: Pin<&mut QVector_QVariant>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_QVariant> {}
+}
+
+impl crate::QVectorElement for QVariant {
+    type TypeId = type_id!("QVector_QVariant");
+
+    fn append(vector: Pin<&mut ffi::QVector_QVariant>, value: &Self) {
+        ffi::qvector_append_qvariant(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_QVariant>) {
+        ffi::qvector_clear_qvariant(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_QVariant) -> UniquePtr<ffi::QVector_QVariant> {
+        ffi::qvector_clone_qvariant(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_QVariant, value: &Self) -> bool {
+        ffi::qvector_contains_qvariant(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_QVariant> {
+        ffi::qvector_default_qvariant()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_QVariant, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_qvariant(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_QVariant, value: &Self) -> isize {
+        ffi::qvector_index_of_qvariant(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_QVariant>, pos: usize, value: &Self) {
+        ffi::qvector_insert_qvariant(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_QVariant) -> usize {
+        ffi::qvector_len_qvariant(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_QVariant>, pos: usize) {
+        ffi::qvector_remove_qvariant(vector, pos);
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        type QVector_u16 = crate::QVector<u16>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_u16"]
+        fn qvectorAppend(vector: Pin<&mut QVector_u16>, value: &u16);
+        #[rust_name = "qvector_as_slice_u16"]
+        fn qvectorAsSlice(vector: &QVector_u16) -> &[u16];
+        #[rust_name = "qvector_clear_u16"]
+        fn qvectorClear(vector: Pin<&mut QVector_u16>);
+        #[rust_name = "qvector_clone_u16"]
+        fn qvectorClone(vector: &QVector_u16) -> UniquePtr<QVector_u16>;
+        #[rust_name = "qvector_contains_u16"]
+        fn qvectorContains(vector: &QVector_u16, value: &u16) -> bool;
+        #[rust_name = "qvector_default_u16"]
+        fn qvectorDefault() -> UniquePtr<QVector_u16>;
+        #[rust_name = "qvector_get_unchecked_u16"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_u16, pos: usize) -> &u16;
+        #[rust_name = "qvector_index_of_u16"]
+        fn qvectorIndexOf(vector: &QVector_u16, value: &u16) -> isize;
+        #[rust_name = "qvector_insert_u16"]
+        fn qvectorInsert(vector: Pin<&mut QVector_u16>, pos: usize, value: &u16);
+        #[rust_name = "qvector_len_u16"]
+        fn qvectorLen(vector: &QVector_u16) -> usize;
+        #[rust_name = "qvector_remove_u16"]
+        fn qvectorRemove(vector: Pin<&mut QVector_u16>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_u16> {}
+}
+
+impl crate::QVectorElement for u16 {
+    type TypeId = type_id!("QVector_u16");
+
+    fn append(vector: Pin<&mut ffi::QVector_u16>, value: &Self) {
+        ffi::qvector_append_u16(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_u16>) {
+        ffi::qvector_clear_u16(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_u16) -> UniquePtr<ffi::QVector_u16> {
+        ffi::qvector_clone_u16(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_u16, value: &Self) -> bool {
+        ffi::qvector_contains_u16(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_u16> {
+        ffi::qvector_default_u16()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_u16, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_u16(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_u16, value: &Self) -> isize {
+        ffi::qvector_index_of_u16(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_u16>, pos: usize, value: &Self) {
+        ffi::qvector_insert_u16(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_u16) -> usize {
+        ffi::qvector_len_u16(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_u16>, pos: usize) {
+        ffi::qvector_remove_u16(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for u16 {
+    fn as_slice(vector: &ffi::QVector_u16) -> &[Self] {
+        ffi::qvector_as_slice_u16(vector)
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        type QVector_u32 = crate::QVector<u32>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_u32"]
+        fn qvectorAppend(vector: Pin<&mut QVector_u32>, value: &u32);
+        #[rust_name = "qvector_as_slice_u32"]
+        fn qvectorAsSlice(vector: &QVector_u32) -> &[u32];
+        #[rust_name = "qvector_clear_u32"]
+        fn qvectorClear(vector: Pin<&mut QVector_u32>);
+        #[rust_name = "qvector_clone_u32"]
+        fn qvectorClone(vector: &QVector_u32) -> UniquePtr<QVector_u32>;
+        #[rust_name = "qvector_contains_u32"]
+        fn qvectorContains(vector: &QVector_u32, value: &u32) -> bool;
+        #[rust_name = "qvector_default_u32"]
+        fn qvectorDefault() -> UniquePtr<QVector_u32>;
+        #[rust_name = "qvector_get_unchecked_u32"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_u32, pos: usize) -> &u32;
+        #[rust_name = "qvector_index_of_u32"]
+        fn qvectorIndexOf(vector: &QVector_u32, value: &u32) -> isize;
+        #[rust_name = "qvector_insert_u32"]
+        fn qvectorInsert(vector: Pin<&mut QVector_u32>, pos: usize, value: &u32);
+        #[rust_name = "qvector_len_u32"]
+        fn qvectorLen(vector: &QVector_u32) -> usize;
+        #[rust_name = "qvector_remove_u32"]
+        fn qvectorRemove(vector: Pin<&mut QVector_u32>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_u32> {}
+}
+
+impl crate::QVectorElement for u32 {
+    type TypeId = type_id!("QVector_u32");
+
+    fn append(vector: Pin<&mut ffi::QVector_u32>, value: &Self) {
+        ffi::qvector_append_u32(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_u32>) {
+        ffi::qvector_clear_u32(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_u32) -> UniquePtr<ffi::QVector_u32> {
+        ffi::qvector_clone_u32(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_u32, value: &Self) -> bool {
+        ffi::qvector_contains_u32(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_u32> {
+        ffi::qvector_default_u32()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_u32, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_u32(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_u32, value: &Self) -> isize {
+        ffi::qvector_index_of_u32(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_u32>, pos: usize, value: &Self) {
+        ffi::qvector_insert_u32(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_u32) -> usize {
+        ffi::qvector_len_u32(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_u32>, pos: usize) {
+        ffi::qvector_remove_u32(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for u32 {
+    fn as_slice(vector: &ffi::QVector_u32) -> &[Self] {
+        ffi::qvector_as_slice_u32(vector)
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated by generate_qlist_qvector.sh, do not edit it directly
+
+use core::pin::Pin;
+use cxx::{type_id, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qvector.h");
+
+        type QVector_u8 = crate::QVector<u8>;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvector"]
+    unsafe extern "C++" {
+        #[rust_name = "qvector_append_u8"]
+        fn qvectorAppend(vector: Pin<&mut QVector_u8>, value: &u8);
+        #[rust_name = "qvector_as_slice_u8"]
+        fn qvectorAsSlice(vector: &QVector_u8) -> &[u8];
+        #[rust_name = "qvector_clear_u8"]
+        fn qvectorClear(vector: Pin<&mut QVector_u8>);
+        #[rust_name = "qvector_clone_u8"]
+        fn qvectorClone(vector: &QVector_u8) -> UniquePtr<QVector_u8>;
+        #[rust_name = "qvector_contains_u8"]
+        fn qvectorContains(vector: &QVector_u8, value: &u8) -> bool;
+        #[rust_name = "qvector_default_u8"]
+        fn qvectorDefault() -> UniquePtr<QVector_u8>;
+        #[rust_name = "qvector_get_unchecked_u8"]
+        unsafe fn qvectorGetUnchecked(vector: &QVector_u8, pos: usize) -> &u8;
+        #[rust_name = "qvector_index_of_u8"]
+        fn qvectorIndexOf(vector: &QVector_u8, value: &u8) -> isize;
+        #[rust_name = "qvector_insert_u8"]
+        fn qvectorInsert(vector: Pin<&mut QVector_u8>, pos: usize, value: &u8);
+        #[rust_name = "qvector_len_u8"]
+        fn qvectorLen(vector: &QVector_u8) -> usize;
+        #[rust_name = "qvector_remove_u8"]
+        fn qvectorRemove(vector: Pin<&mut QVector_u8>, pos: usize);
+    }
+
+    impl UniquePtr<QVector_u8> {}
+}
+
+impl crate::QVectorElement for u8 {
+    type TypeId = type_id!("QVector_u8");
+
+    fn append(vector: Pin<&mut ffi::QVector_u8>, value: &Self) {
+        ffi::qvector_append_u8(vector, value);
+    }
+
+    fn clear(vector: Pin<&mut ffi::QVector_u8>) {
+        ffi::qvector_clear_u8(vector);
+    }
+
+    fn clone(vector: &ffi::QVector_u8) -> UniquePtr<ffi::QVector_u8> {
+        ffi::qvector_clone_u8(vector)
+    }
+
+    fn contains(vector: &ffi::QVector_u8, value: &Self) -> bool {
+        ffi::qvector_contains_u8(vector, value)
+    }
+
+    fn default() -> UniquePtr<ffi::QVector_u8> {
+        ffi::qvector_default_u8()
+    }
+
+    unsafe fn get_unchecked(vector: &ffi::QVector_u8, pos: usize) -> &Self {
+        ffi::qvector_get_unchecked_u8(vector, pos)
+    }
+
+    fn index_of(vector: &ffi::QVector_u8, value: &Self) -> isize {
+        ffi::qvector_index_of_u8(vector, value)
+    }
+
+    fn insert(vector: Pin<&mut ffi::QVector_u8>, pos: usize, value: &Self) {
+        ffi::qvector_insert_u8(vector, pos, value);
+    }
+
+    fn len(vector: &ffi::QVector_u8) -> usize {
+        ffi::qvector_len_u8(vector)
+    }
+
+    fn remove(vector: Pin<&mut ffi::QVector_u8>, pos: usize) {
+        ffi::qvector_remove_u8(vector, pos);
+    }
+}
+
+impl crate::QVectorSliceElement for u8 {
+    fn as_slice(vector: &ffi::QVector_u8) -> &[Self] {
+        ffi::qvector_as_slice_u8(vector)
+    }
+}
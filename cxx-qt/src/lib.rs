@@ -6,7 +6,7 @@
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, ItemMod};
 
-use cxx_qt_gen::{extract_qobject, generate_qobject_rs};
+use cxx_qt_gen::{extract_qobjects, generate_qobject_rs};
 
 /// A procedural macro which generates a QObject for a struct inside a module.
 ///
@@ -47,7 +47,7 @@ pub fn bridge(args: TokenStream, input: TokenStream) -> TokenStream {
     // add the attribute to the module before giving it to the parser.
     let args_input = format!("#[cxx_qt::bridge({})] mod dummy;", args);
     let attrs = syn::parse_str::<ItemMod>(&args_input).unwrap().attrs;
-    module.attrs = attrs.into_iter().chain(module.attrs.into_iter()).collect();
+    module.attrs = attrs.into_iter().chain(module.attrs).collect();
 
     // Extract and generate the rust code
     extract_and_generate(module)
@@ -73,6 +73,102 @@ pub fn signals(_args: TokenStream, _input: TokenStream) -> TokenStream {
     unreachable!("cxx_qt::signals should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
 }
 
+/// A macro which describes that an enum should be registered with Q_ENUM on a QObject.
+///
+/// Without a QObject the enum is registered with Q_ENUM_NS on the namespace of the bridge instead.
+///
+/// It should not be used by itself and instead should be used inside a cxx_qt::bridge definition.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+/// mod my_object {
+///     #[cxx_qt::qenum(MyObject)]
+///     enum State {
+///         Idle,
+///         Running,
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn qenum(_args: TokenStream, _input: TokenStream) -> TokenStream {
+    unreachable!("cxx_qt::qenum should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
+}
+
+/// A macro which describes that an extern "C++" block contains methods of a QObject, or its base class,
+/// which can then be called from Rust using the CppObj.
+///
+/// It should not be used by itself and instead should be used inside a cxx_qt::bridge definition.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+/// mod my_object {
+///     #[cxx_qt::cpp_methods(MyObject)]
+///     unsafe extern "C++" {
+///         #[rust_name = "set_object_name"]
+///         fn setObjectName(self: Pin<&mut Self>, name: &QString);
+///     }
+///
+///     #[cxx_qt::qobject]
+///     #[derive(Default)]
+///     struct MyObject;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn cpp_methods(_args: TokenStream, _input: TokenStream) -> TokenStream {
+    unreachable!("cxx_qt::cpp_methods should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
+}
+
+/// A macro which describes that a struct holds the initial property values for a QObject.
+///
+/// It should not be used by itself and instead should be used inside a cxx_qt::bridge definition.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+/// mod my_object {
+///     #[cxx_qt::data(MyObject)]
+///     #[derive(Default)]
+///     struct MyData {
+///         property: i32,
+///     }
+///
+///     #[cxx_qt::qobject]
+///     #[derive(Default)]
+///     struct MyObject;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn data(_args: TokenStream, _input: TokenStream) -> TokenStream {
+    unreachable!("cxx_qt::data should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
+}
+
+/// A macro which describes that a struct should be made into a Q_GADGET value type.
+///
+/// It should not be used by itself and instead should be used inside a cxx_qt::bridge definition.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+/// mod my_object {
+///     #[cxx_qt::qgadget]
+///     #[derive(Clone, Copy, Default)]
+///     struct Reading {
+///         sensor_id: i32,
+///         value: f64,
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn qgadget(_args: TokenStream, _input: TokenStream) -> TokenStream {
+    unreachable!("cxx_qt::qgadget should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
+}
+
 /// A macro which describes that a struct should be made into a QObject.
 ///
 /// It should not be used by itself and instead should be used inside a cxx_qt::bridge definition.
@@ -86,6 +182,29 @@ pub fn signals(_args: TokenStream, _input: TokenStream) -> TokenStream {
 ///     struct MyObject;
 /// }
 /// ```
+///
+/// A base class other than QObject can be specified with the `base` attribute.
+///
+/// ```ignore
+/// #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+/// mod my_object {
+///     #[cxx_qt::qobject(base = "QAbstractListModel")]
+///     struct MyObject;
+/// }
+/// ```
+///
+/// The QObject can be registered as a QML element with the `qml_uri`, `qml_version`, and `qml_name` attributes,
+/// where the version defaults to 1.0 and the name defaults to the name of the struct.
+/// The element can also be a `qml_singleton`, or `qml_uncreatable` with an optional reason.
+/// All of the QML elements of a crate are registered by the generated `cxx_qt::{crate_name}::registerQmlTypes()`.
+///
+/// ```ignore
+/// #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+/// mod my_object {
+///     #[cxx_qt::qobject(qml_uri = "com.kdab", qml_version = "1.0", qml_name = "Foo", qml_singleton)]
+///     struct MyObject;
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn qobject(_args: TokenStream, _input: TokenStream) -> TokenStream {
     unreachable!("cxx_qt::qobject should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
@@ -123,17 +242,34 @@ pub fn QObject(_input: TokenStream) -> TokenStream {
     unreachable!("cxx_qt::QObject should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
 }
 
+/// A macro which exports the entry points of a QML plugin from the shared library of the crate.
+///
+/// It should be used once in a `cdylib` crate which is built with `CxxQtBuilder::qml_plugin`,
+/// as a Rust shared library only exports the symbols of Rust functions.
+///
+/// # Example
+///
+/// ```ignore
+/// cxx_qt::qml_plugin!();
+/// ```
+#[proc_macro]
+pub fn qml_plugin(_input: TokenStream) -> TokenStream {
+    "include!(concat!(env!(\"OUT_DIR\"), \"/cxx-qt-gen/qml_plugin.rs\"));"
+        .parse()
+        .unwrap()
+}
+
 // Take the module and C++ namespace and generate the rust code
 fn extract_and_generate(module: ItemMod) -> TokenStream {
-    // Attempt to extract information about a QObject inside the module
-    let qobject = match extract_qobject(&module) {
+    // Attempt to extract information about the QObjects inside the module
+    let cxx_qt_module = match extract_qobjects(&module) {
         Ok(o) => o,
         Err(e) => return e.into(),
     };
 
-    // From the extracted QObject, generate the rust code that replaces the original code
-    // for the given QObject.
-    let gen_result = generate_qobject_rs(&qobject);
+    // From the extracted QObjects, generate the rust code that replaces the original code
+    // for the given module.
+    let gen_result = generate_qobject_rs(&cxx_qt_module);
     match gen_result {
         Ok(tokens) => tokens.into(),
         Err(tokens) => tokens.into(),
@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// ANCHOR: book_build_rs
+use clang_format::ClangFormatStyle;
+use cxx_qt_build::CxxQtBuilder;
+
+fn main() {
+    CxxQtBuilder::new()
+        .cpp_format(ClangFormatStyle::Mozilla)
+        .file("src/my_object.rs")
+        // The C++ which starts the Qt application
+        .cpp_file("src/cpp/run.cpp")
+        // Compile the QML files into the application
+        .qrc("src/qml/qml.qrc")
+        // Compile the QML ahead of time to speed up the start of the application
+        .qml_cache("src/qml/main.qml", "/qml/main.qml")
+        // Compile the generated C++ with cargo instead of CMake
+        .compile_cpp()
+        .build();
+}
+// ANCHOR_END: book_build_rs
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include <QtGui/QGuiApplication>
+#include <QtQml/QQmlApplicationEngine>
+
+#include "cxx-qt-gen/include/cargo_without_cmake.qml.h"
+#include "cxx-qt-gen/include/cargo_without_cmake.qrc.h"
+
+extern "C" int
+run_cpp()
+{
+  // QGuiApplication keeps a reference to argc, so it needs to outlive the application
+  static int argc = 1;
+  static char arg0[] = "cargo_without_cmake";
+  static char* argv[] = { arg0, nullptr };
+  QGuiApplication app(argc, argv);
+
+  // The resources are compiled into a static library, so they need to be initialised
+  cxx_qt::cargo_without_cmake::initResources();
+  cxx_qt::cargo_without_cmake::registerQmlTypes();
+
+  QQmlApplicationEngine engine;
+  engine.load(QUrl(QStringLiteral("qrc:/qml/main.qml")));
+  if (engine.rootObjects().isEmpty()) {
+    return -1;
+  }
+
+  return app.exec();
+}
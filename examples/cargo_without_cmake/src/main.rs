@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod my_object;
+
+// ANCHOR: book_run_cpp
+extern "C" {
+    // Defined in src/cpp/run.cpp, which is compiled by the build script
+    fn run_cpp() -> i32;
+}
+
+fn main() {
+    let exit_code = unsafe { run_cpp() };
+    std::process::exit(exit_code);
+}
+// ANCHOR_END: book_run_cpp
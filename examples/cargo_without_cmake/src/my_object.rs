@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    pub struct Data {
+        number: i32,
+        string: UniquePtr<QString>,
+    }
+
+    impl Default for Data {
+        fn default() -> Self {
+            Self {
+                number: 0,
+                string: QString::from_str(""),
+            }
+        }
+    }
+
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
+    #[derive(Default)]
+    pub struct MyObject;
+
+    impl cxx_qt::QObject<MyObject> {
+        #[qinvokable]
+        pub fn increment_number(&self, cpp: &mut CppObj) {
+            cpp.set_number(cpp.number() + 1);
+        }
+
+        #[qinvokable]
+        pub fn say_hi(&self, string: &QString, number: i32) {
+            println!(
+                "Hi from Rust! String is '{}' and number is {}",
+                string, number
+            );
+        }
+    }
+}
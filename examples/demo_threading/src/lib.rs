@@ -2,8 +2,10 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
+use futures::channel::oneshot;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     sync::mpsc::TrySendError,
     time::{Duration, SystemTime},
 };
@@ -11,8 +13,6 @@ use uuid::Uuid;
 
 /// The size of the network thread to update thread queue
 const CHANNEL_NETWORK_COUNT: usize = 1_024;
-/// The size of the update thread to Qt queue
-const CHANNEL_QT_COUNT: usize = 250;
 /// After how many milliseconds should a sensor be disconnected and considered missing
 const SENSOR_TIMEOUT: Duration = Duration::from_millis(10_000);
 /// How often should the timeout thread poll sensors
@@ -93,7 +93,9 @@ enum NetworkChannel {
     Disconnect { uuid: Uuid },
     Power { uuid: Uuid, value: f64 },
     TimeoutUpdate,
-    Update,
+    Update {
+        reply: oneshot::Sender<HashMap<Uuid, SensorData>>,
+    },
 }
 
 #[derive(Clone)]
@@ -119,13 +121,13 @@ mod ffi {
         prelude::*,
         task::spawn,
     };
-    use futures::{executor::block_on, stream::StreamExt};
+    use futures::{channel::oneshot, executor::block_on, stream::StreamExt};
     use futures_timer::Delay;
     use std::{
         collections::HashMap,
         sync::{
             atomic::{AtomicBool, Ordering},
-            mpsc::{sync_channel, Receiver, SyncSender},
+            mpsc::{sync_channel, SyncSender},
             Arc,
         },
         thread::JoinHandle,
@@ -150,21 +152,9 @@ mod ffi {
     }
 
     #[cxx_qt::qobject]
+    #[derive(Default)]
     pub struct EnergyUsage {
-        qt_rx: Receiver<Data>,
-        qt_tx: SyncSender<Data>,
-        join_handles: Option<[JoinHandle<()>; 4]>,
-    }
-
-    impl Default for EnergyUsage {
-        fn default() -> Self {
-            let (qt_tx, qt_rx) = sync_channel(super::CHANNEL_QT_COUNT);
-            Self {
-                qt_rx,
-                qt_tx,
-                join_handles: None,
-            }
-        }
+        join_handles: Option<[JoinHandle<()>; 3]>,
     }
 
     impl EnergyUsage {
@@ -227,7 +217,6 @@ mod ffi {
 
             let (network_tx, network_rx) = sync_channel(super::CHANNEL_NETWORK_COUNT);
             let (timeout_tx, timeout_rx) = sync_channel::<HashMap<Uuid, SensorData>>(0);
-            let (update_tx, update_rx) = sync_channel::<HashMap<Uuid, SensorData>>(0);
             let sensors_changed = Arc::new(AtomicBool::new(false));
 
             // Prepare our timeout thread, if a sensor is not seen for N seconds we remove it
@@ -261,25 +250,41 @@ mod ffi {
                 }
             };
 
-            // Prepare our update thread
+            // Prepare our update future, which is spawned onto the Qt thread
             //
-            // When values change this then requests an update to Qt
-            let qt_tx = self.qt_tx.clone();
+            // When values change this then queues an update of the QObject
             let update_network_tx = network_tx.clone();
-            let update_requester = cpp.update_requester();
+            // ANCHOR: book_qt_thread
+            let qt_thread = cpp.qt_thread();
+            // ANCHOR_END: book_qt_thread
             let update_sensors_changed = sensors_changed.clone();
             let run_update = async move {
                 loop {
                     Delay::new(super::SENSOR_UPDATE_POLL_RATE).await;
 
+                    // Queueing fails once the QObject has been destroyed, so queue a no-op
+                    // closure on every tick to stop the future even if no sensors change
+                    if qt_thread.queue(|_| {}).is_err() {
+                        break;
+                    }
+
                     if update_sensors_changed
                         .compare_exchange_weak(true, false, Ordering::SeqCst, Ordering::SeqCst)
                         .is_ok()
                     {
-                        update_network_tx.send(NetworkChannel::Update).unwrap();
+                        // Request a snapshot of the sensors, this is awaited rather than
+                        // blocking as the future is polled by the Qt event loop
+                        let (reply, snapshot) = oneshot::channel();
+                        if update_network_tx
+                            .try_send(NetworkChannel::Update { reply })
+                            .is_err()
+                        {
+                            update_sensors_changed.store(true, Ordering::SeqCst);
+                            continue;
+                        }
 
                         // If there is new sensor info then build average, count, total and inform Qt
-                        if let Ok(sensors) = update_rx.recv() {
+                        if let Ok(sensors) = snapshot.await {
                             let total_use = sensors.values().fold(0.0, |acc, x| acc + x.power);
                             let sensors = sensors.len() as u32;
                             let average_use = if sensors > 0 {
@@ -288,15 +293,22 @@ mod ffi {
                                 0.0
                             };
 
-                            qt_tx
-                                .send(Data {
-                                    average_use,
-                                    sensors,
-                                    total_use,
-                                })
-                                .unwrap();
+                            let data = Data {
+                                average_use,
+                                sensors,
+                                total_use,
+                            };
 
-                            update_requester.request_update();
+                            // ANCHOR: book_qt_thread_queue
+                            // Queue a closure which is run on the Qt thread, this fails
+                            // if the QObject has been destroyed so we stop the future
+                            if qt_thread
+                                .queue(move |mut cpp| cpp.grab_values_from_data(data))
+                                .is_err()
+                            {
+                                break;
+                            }
+                            // ANCHOR_END: book_qt_thread_queue
                         }
                     }
                 }
@@ -338,8 +350,8 @@ mod ffi {
                             NetworkChannel::TimeoutUpdate => {
                                 timeout_tx.send(sensors.clone()).unwrap();
                             }
-                            NetworkChannel::Update => {
-                                update_tx.send(sensors.clone()).unwrap();
+                            NetworkChannel::Update { reply } => {
+                                let _ = reply.send(sensors.clone());
                             }
                         }
                     }
@@ -359,25 +371,21 @@ mod ffi {
                     .await;
             };
 
+            // ANCHOR: book_spawn
+            // Spawn the update future onto the Qt event loop, as the future is polled
+            // from the Qt thread it does not block the GUI while it is waiting
+            if let Err(err) = cxx_qt_lib::spawn(run_update) {
+                println!("Failed to spawn the update future: {}", err);
+                return;
+            }
+            // ANCHOR_END: book_spawn
+
             // Start our threads
             self.join_handles = Some([
                 std::thread::spawn(move || block_on(run_timeout)),
-                std::thread::spawn(move || block_on(run_update)),
                 std::thread::spawn(move || block_on(run_sensors)),
                 std::thread::spawn(move || block_on(run_server)),
             ]);
         }
     }
-
-    impl UpdateRequestHandler<CppObj<'_>> for EnergyUsage {
-        fn handle_update_request(&mut self, cpp: &mut CppObj) {
-            // Process the new data from the background thread
-            if let Some(data) = self.qt_rx.try_iter().last() {
-                // Here we have constructed a new Data struct so can consume it's values
-                // for other uses we could have passed an Enum across the channel
-                // and then process the required action here
-                cpp.grab_values_from_data(data);
-            }
-        }
-    }
 }
@@ -7,9 +7,12 @@
 use clang_format::ClangFormatStyle;
 use cxx_qt_build::CxxQtBuilder;
 
+// ANCHOR: book_qml_plugin_build
 fn main() {
     CxxQtBuilder::new()
         .cpp_format(ClangFormatStyle::Mozilla)
         .file("src/lib.rs")
+        .qml_plugin("com.kdab.cxx_qt.demo")
         .build();
 }
+// ANCHOR_END: book_qml_plugin_build
@@ -24,6 +24,11 @@ impl From<Data> for DataSerde {
     }
 }
 
+// ANCHOR: book_qml_plugin_export
+// Export the entry points of the QML plugin which is generated by the build script
+cxx_qt::qml_plugin!();
+// ANCHOR_END: book_qml_plugin_export
+
 const DEFAULT_STR: &str = r#"{"number": 1, "string": "Hello World!"}"#;
 
 #[cxx_qt::bridge(namespace = "core")]
@@ -57,9 +62,11 @@ mod ffi {
         }
     }
 
-    #[cxx_qt::qobject]
+    // ANCHOR: book_qml_plugin_qobject
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
     #[derive(Default)]
     pub struct MyObject;
+    // ANCHOR_END: book_qml_plugin_qobject
 
     impl cxx_qt::QObject<MyObject> {
         #[qinvokable]
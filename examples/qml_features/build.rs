@@ -11,10 +11,14 @@ use cxx_qt_build::CxxQtBuilder;
 fn main() {
     CxxQtBuilder::new()
         .cpp_format(ClangFormatStyle::Mozilla)
+        .file("src/custom_base_class.rs")
         .file("src/data_struct_properties.rs")
         .file("src/empty.rs")
+        .file("src/enums.rs")
+        .file("src/gadgets.rs")
         .file("src/lib.rs")
         .file("src/mock_qt_types.rs")
+        .file("src/property_options.rs")
         .file("src/rust_obj_invokables.rs")
         .file("src/serialisation.rs")
         .file("src/signals.rs")
@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge]
+mod ffi {
+    // ANCHOR: book_base_include
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!(<QtCore/QAbstractListModel>);
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib/include/qhash.h");
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash_i32_QByteArray;
+    }
+    // ANCHOR_END: book_base_include
+
+    // ANCHOR: book_qobject_base
+    #[cxx_qt::qobject(base = "QAbstractListModel", qml_uri = "com.kdab.cxx_qt.demo")]
+    #[derive(Default)]
+    pub struct CustomBaseClass {
+        id: u32,
+        vector: Vec<(u32, f64)>,
+    }
+    // ANCHOR_END: book_qobject_base
+
+    impl cxx_qt::QObject<CustomBaseClass> {
+        // ANCHOR: book_helpers
+        #[qinvokable]
+        pub fn add(&mut self, cpp: &mut CppObj) {
+            let count = self.vector.len() as i32;
+            // Safety: the begin and end helpers can cause views to call back into this object
+            // but our state is not modified while the helpers are running
+            unsafe {
+                cpp.begin_insert_rows(&QModelIndex::default(), count, count);
+                self.id += 1;
+                self.vector.push((self.id, (self.id as f64) / 3.0));
+                cpp.end_insert_rows();
+            }
+        }
+        // ANCHOR_END: book_helpers
+
+        #[qinvokable]
+        pub fn clear(&mut self, cpp: &mut CppObj) {
+            unsafe {
+                cpp.begin_reset_model();
+                self.id = 0;
+                self.vector.clear();
+                cpp.end_reset_model();
+            }
+        }
+
+        #[qinvokable]
+        pub fn remove(&mut self, cpp: &mut CppObj, index: i32) {
+            if index < 0 || (index as usize) >= self.vector.len() {
+                return;
+            }
+
+            unsafe {
+                cpp.begin_remove_rows(&QModelIndex::default(), index, index);
+                self.vector.remove(index as usize);
+                cpp.end_remove_rows();
+            }
+        }
+
+        // ANCHOR: book_override
+        #[qinvokable(cxx_override)]
+        pub fn data(&self, index: &QModelIndex, role: i32) -> UniquePtr<QVariant> {
+            // The custom roles start from Qt::UserRole, these are named in role_names
+            const ID_ROLE: i32 = 0x0100;
+            const VALUE_ROLE: i32 = 0x0101;
+
+            if let Some((id, value)) = self.vector.get(index.row() as usize) {
+                return match role {
+                    ID_ROLE => QVariant::from(*id),
+                    VALUE_ROLE => QVariant::from(*value),
+                    _ => QVariant::null(),
+                };
+            }
+
+            QVariant::null()
+        }
+
+        #[qinvokable(cxx_override)]
+        pub fn role_names(&self) -> UniquePtr<QHash_i32_QByteArray> {
+            // The same roles as in data, QML accesses these by name, eg model.id in a delegate
+            const ID_ROLE: i32 = 0x0100;
+            const VALUE_ROLE: i32 = 0x0101;
+
+            let mut roles = QHash_i32_QByteArray::new();
+            roles
+                .pin_mut()
+                .insert(&ID_ROLE, &QByteArray::from_slice(b"id"));
+            roles
+                .pin_mut()
+                .insert(&VALUE_ROLE, &QByteArray::from_slice(b"value"));
+            roles
+        }
+
+        #[qinvokable(cxx_override)]
+        pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
+            self.vector.len() as i32
+        }
+        // ANCHOR_END: book_override
+    }
+}
+// ANCHOR_END: book_macro_code
@@ -11,7 +11,7 @@ mod ffi {
         number: i32,
     }
 
-    #[cxx_qt::qobject]
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
     #[derive(Default)]
     pub struct DataStructProperties;
 }
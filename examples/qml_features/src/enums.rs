@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge]
+mod ffi {
+    // ANCHOR: book_qenum
+    #[cxx_qt::qenum(Enums)]
+    pub enum TrafficLight {
+        Red,
+        Amber,
+        Green,
+    }
+    // ANCHOR_END: book_qenum
+
+    // ANCHOR: book_data_struct
+    pub struct Data {
+        light: TrafficLight,
+    }
+
+    // CXX enums do not implement Default, so the Data struct needs a manual implementation
+    impl Default for Data {
+        fn default() -> Self {
+            Self {
+                light: TrafficLight::Red,
+            }
+        }
+    }
+    // ANCHOR_END: book_data_struct
+
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
+    #[derive(Default)]
+    pub struct Enums;
+
+    // ANCHOR: book_rust_obj_impl
+    impl cxx_qt::QObject<Enums> {
+        #[qinvokable]
+        pub fn next_light(&self, light: TrafficLight) -> TrafficLight {
+            match light {
+                TrafficLight::Red => TrafficLight::Amber,
+                TrafficLight::Amber => TrafficLight::Green,
+                _others => TrafficLight::Red,
+            }
+        }
+    }
+    // ANCHOR_END: book_rust_obj_impl
+}
+// ANCHOR_END: book_macro_code
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge]
+mod ffi {
+    // ANCHOR: book_qgadget
+    #[cxx_qt::qgadget]
+    #[derive(Clone, Copy, Default)]
+    pub struct SensorReading {
+        sensor_id: i32,
+        timestamp: f64,
+        value: f64,
+    }
+    // ANCHOR_END: book_qgadget
+
+    #[cxx_qt::signals(Gadgets)]
+    pub enum Signal {
+        ReadingAdded { reading: SensorReading },
+    }
+
+    #[derive(Default)]
+    pub struct Data {
+        latest: SensorReading,
+    }
+
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
+    #[derive(Default)]
+    pub struct Gadgets;
+
+    // ANCHOR: book_rust_obj_impl
+    impl cxx_qt::QObject<Gadgets> {
+        #[qinvokable]
+        pub fn add_reading(&self, cpp: &mut CppObj, sensor_id: i32, value: f64) {
+            let reading = SensorReading {
+                sensor_id,
+                timestamp: cpp.latest().timestamp + 1.0,
+                value,
+            };
+            cpp.set_latest(reading);
+            cpp.emit_queued(Signal::ReadingAdded { reading });
+        }
+
+        #[qinvokable]
+        pub fn is_above(&self, reading: SensorReading, threshold: f64) -> bool {
+            reading.value > threshold
+        }
+    }
+    // ANCHOR_END: book_rust_obj_impl
+}
+// ANCHOR_END: book_macro_code
@@ -4,9 +4,13 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod custom_base_class;
 mod data_struct_properties;
 mod empty;
+mod enums;
+mod gadgets;
 mod mock_qt_types;
+mod property_options;
 mod rust_obj_invokables;
 mod serialisation;
 mod signals;
@@ -33,9 +37,15 @@ mod ffi {
         }
     }
 
-    #[cxx_qt::qobject]
+    // ANCHOR: book_qml_element
+    #[cxx_qt::qobject(
+        qml_uri = "com.kdab.cxx_qt.demo",
+        qml_version = "1.0",
+        qml_name = "MyObject"
+    )]
     #[derive(Default)]
     pub struct MyObject;
+    // ANCHOR_END: book_qml_element
 
     impl cxx_qt::QObject<MyObject> {
         #[qinvokable]
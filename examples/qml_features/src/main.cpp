@@ -8,10 +8,9 @@
 #include <QtGui/QGuiApplication>
 #include <QtQml/QQmlApplicationEngine>
 
-#include "cxx-qt-gen/include/data_struct_properties.cxxqt.h"
-#include "cxx-qt-gen/include/my_object.cxxqt.h"
-#include "cxx-qt-gen/include/serialisation.cxxqt.h"
-#include "cxx-qt-gen/include/types.cxxqt.h"
+// ANCHOR: book_qml_register_include
+#include "cxx-qt-gen/include/qml_features.qml.h"
+// ANCHOR_END: book_qml_register_include
 
 int
 main(int argc, char* argv[])
@@ -31,11 +30,9 @@ main(int argc, char* argv[])
     },
     Qt::QueuedConnection);
 
-  qmlRegisterType<DataStructProperties>(
-    "com.kdab.cxx_qt.demo", 1, 0, "DataStructProperties");
-  qmlRegisterType<MyObject>("com.kdab.cxx_qt.demo", 1, 0, "MyObject");
-  qmlRegisterType<Serialisation>("com.kdab.cxx_qt.demo", 1, 0, "Serialisation");
-  qmlRegisterType<Types>("com.kdab.cxx_qt.demo", 1, 0, "Types");
+  // ANCHOR: book_qml_register_call
+  cxx_qt::qml_features::registerQmlTypes();
+  // ANCHOR_END: book_qml_register_call
 
   engine.load(url);
 
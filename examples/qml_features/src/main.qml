@@ -21,6 +21,18 @@ Window {
         string: myObject.string
     }
 
+    Enums {
+        id: enums
+    }
+
+    Gadgets {
+        id: gadgets
+    }
+
+    PropertyOptions {
+        id: propertyOptions
+    }
+
     MyObject {
         id: myObject
         number: 1
@@ -57,6 +69,70 @@ Window {
 
             onClicked: console.warn(myData.asJsonStr())
         }
+
+        Button {
+            text: "Traffic Light: " + (enums.light === Enums.Red ? "Red" : enums.light === Enums.Amber ? "Amber" : "Green")
+
+            onClicked: enums.light = enums.nextLight(enums.light)
+        }
+
+        Button {
+            text: "Latest Reading: " + gadgets.latest.sensorId + " = " + gadgets.latest.value
+
+            onClicked: gadgets.addReading(gadgets.latest.sensorId + 1, Math.random())
+        }
+
+        Button {
+            text: "Clicks: " + propertyOptions.clicks + " / " + propertyOptions.maxClicks
+
+            onClicked: propertyOptions.click()
+        }
+
+        Button {
+            text: "Range: " + propertyOptions.range
+
+            onClicked: propertyOptions.range = propertyOptions.range / 2
+        }
+
+        Row {
+            spacing: 10
+
+            Button {
+                text: "Add Row"
+
+                onClicked: customBaseClass.add()
+            }
+
+            Button {
+                text: "Remove Row"
+
+                onClicked: customBaseClass.remove(listView.currentIndex)
+            }
+
+            Button {
+                text: "Clear Rows"
+
+                onClicked: customBaseClass.clear()
+            }
+        }
+
+        ListView {
+            id: listView
+            clip: true
+            height: 150
+            model: CustomBaseClass {
+                id: customBaseClass
+            }
+            width: parent.width
+
+            delegate: ItemDelegate {
+                highlighted: ListView.isCurrentItem
+                text: "%1: %2".arg(model.id).arg(model.value)
+                width: ListView.view.width
+
+                onClicked: ListView.view.currentIndex = index
+            }
+        }
     }
 
     Component.onCompleted: myObject.sayHi(myObject.string, myObject.number)
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge]
+mod ffi {
+    #[cxx_qt::signals(PropertyOptions)]
+    pub enum Signal {
+        BoundsChanged,
+    }
+
+    // ANCHOR: book_data_struct
+    pub struct Data {
+        #[qproperty(read_only)]
+        clicks: i32,
+        #[qproperty(constant)]
+        max_clicks: i32,
+        #[qproperty(notify = bounds_changed, reset = reset_bounds)]
+        minimum: f64,
+        #[qproperty(notify = bounds_changed, reset = reset_bounds)]
+        maximum: f64,
+    }
+    // ANCHOR_END: book_data_struct
+
+    impl Default for Data {
+        fn default() -> Self {
+            Self {
+                clicks: 0,
+                max_clicks: 10,
+                minimum: 0.0,
+                maximum: 100.0,
+            }
+        }
+    }
+
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
+    #[derive(Default)]
+    pub struct PropertyOptions;
+
+    // ANCHOR: book_rust_obj_impl
+    impl cxx_qt::QObject<PropertyOptions> {
+        #[qinvokable]
+        pub fn click(&self, cpp: &mut CppObj) {
+            // QML cannot write to clicks, but Rust can still use the setter
+            if cpp.clicks() < cpp.max_clicks() {
+                cpp.set_clicks(cpp.clicks() + 1);
+            }
+        }
+
+        #[qinvokable]
+        pub fn reset_bounds(&self, cpp: &mut CppObj) {
+            cpp.set_minimum(0.0);
+            cpp.set_maximum(100.0);
+        }
+
+        // ANCHOR: book_computed_property
+        #[qproperty(write = set_range, notify = bounds_changed)]
+        pub fn range(&self, cpp: &mut CppObj) -> f64 {
+            cpp.maximum() - cpp.minimum()
+        }
+
+        pub fn set_range(&self, cpp: &mut CppObj, value: f64) {
+            // A negative range is ignored, otherwise the maximum is moved to match
+            if value >= 0.0 {
+                let minimum = cpp.minimum();
+                cpp.set_maximum(minimum + value);
+            }
+        }
+        // ANCHOR_END: book_computed_property
+    }
+    // ANCHOR_END: book_rust_obj_impl
+}
+// ANCHOR_END: book_macro_code
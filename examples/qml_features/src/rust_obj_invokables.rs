@@ -6,6 +6,19 @@
 // ANCHOR: book_macro_code
 #[cxx_qt::bridge]
 pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/include/qt_types.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    // ANCHOR: book_cpp_methods
+    #[cxx_qt::cpp_methods(RustObjInvokables)]
+    unsafe extern "C++" {
+        #[rust_name = "set_object_name"]
+        fn setObjectName(self: Pin<&mut Self>, name: &QString);
+    }
+    // ANCHOR_END: book_cpp_methods
+
     #[derive(Default)]
     pub struct Data {
         number: i32,
@@ -30,6 +43,13 @@ pub mod ffi {
         }
         // ANCHOR_END: book_cpp_obj
 
+        // ANCHOR: book_cpp_methods_call
+        #[qinvokable]
+        pub fn invokable_rename(&self, cpp: &mut CppObj, name: &QString) {
+            cpp.set_object_name(name);
+        }
+        // ANCHOR_END: book_cpp_methods_call
+
         #[qinvokable]
         pub fn invokable_return(&self) -> i32 {
             self.rust_only_field
@@ -40,6 +60,30 @@ pub mod ffi {
             self.rust_only_method(factor);
             self.rust_only_field
         }
+
+        // ANCHOR: book_result_invokable
+        #[qinvokable]
+        pub fn invokable_divide(&mut self, divisor: i32) -> Result<i32, String> {
+            if divisor == 0 {
+                return Err("Cannot divide by zero".to_owned());
+            }
+
+            self.rust_only_field /= divisor;
+            Ok(self.rust_only_field)
+        }
+        // ANCHOR_END: book_result_invokable
+
+        // ANCHOR: book_async_invokable
+        #[qinvokable]
+        pub async fn invokable_sum_async(count: i32) -> i32 {
+            let (sender, receiver) = futures::channel::oneshot::channel();
+            // Perform the work on a background thread so that the Qt thread is not blocked
+            std::thread::spawn(move || {
+                let _ = sender.send((1..=count).sum());
+            });
+            receiver.await.unwrap_or_default()
+        }
+        // ANCHOR_END: book_async_invokable
     }
 
     impl RustObjInvokables {
@@ -40,7 +40,9 @@ pub mod ffi {
 
     #[cxx_qt::qobject]
     #[derive(Default)]
-    pub struct Signals;
+    pub struct Signals {
+        ready_connection: Option<cxx_qt_lib::QMetaObjectConnection>,
+    }
 
     // ANCHOR: book_rust_obj_impl
     impl cxx_qt::QObject<Signals> {
@@ -58,6 +60,23 @@ pub mod ffi {
                 opaque: QVariant::from_ref(cpp.opaque()),
             });
         }
+
+        // ANCHOR: book_connect_signal
+        #[qinvokable]
+        pub fn connect_ready(&mut self, cpp: &mut CppObj) {
+            // Count each time that the ready signal is emitted, the connection
+            // is disconnected when ready_connection is dropped or replaced
+            self.ready_connection = Some(cpp.connect_ready_queued(|cpp| {
+                let data = cpp.data();
+                cpp.set_data(data + 1);
+            }));
+        }
+
+        #[qinvokable]
+        pub fn disconnect_ready(&mut self) {
+            self.ready_connection = None;
+        }
+        // ANCHOR_END: book_connect_signal
     }
     // ANCHOR_END: book_rust_obj_impl
 }
@@ -25,7 +25,7 @@ mod ffi {
         }
     }
 
-    #[cxx_qt::qobject]
+    #[cxx_qt::qobject(qml_uri = "com.kdab.cxx_qt.demo")]
     #[derive(Default)]
     pub struct Types;
 
@@ -4,14 +4,20 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 //! This crate provides information about the Qt installation and can invoke Qt's
-//! [moc](https://doc.qt.io/qt-6/moc.html) code generator. This crate does not build
+//! [moc](https://doc.qt.io/qt-6/moc.html) code generator,
+//! [rcc](https://doc.qt.io/qt-6/rcc.html) resource compiler, and
+//! [qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html) QML compiler. This crate does not build
 //! any C++ code on its own. It is intended to be used in [build.rs scripts](https://doc.rust-lang.org/cargo/reference/build-scripts.html)
 //! together with
 //! [cc](https://docs.rs/cc/latest/cc/),
 //! [cxx_build](https://docs.rs/cxx-build/latest/cxx_build/), or
 //! [cpp_build](https://docs.rs/cpp_build/latest/cpp_build/).
 
-use std::{env, path::PathBuf, process::Command};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 pub use versions::SemVer;
 
@@ -285,4 +291,339 @@ impl QtBuild {
     pub fn version(&self) -> &SemVer {
         &self.version
     }
+
+    /// Get the path of a Qt tool which is run on the host, such as moc
+    fn host_tool(&self, name: &str) -> PathBuf {
+        // Qt 6 installs the tools which are not meant to be run by users in libexec
+        let directory = if self.version.major >= 6 {
+            self.qmake_query("QT_HOST_LIBEXECS")
+        } else {
+            self.qmake_query("QT_HOST_BINS")
+        };
+        PathBuf::from(directory).join(name)
+    }
+
+    /// Run moc on a C++ header file, returning the path of the generated C++ source file.
+    /// The generated source needs to be compiled and linked together with the rest of the C++ code.
+    ///
+    /// The source is written to `$OUT_DIR/qt-build/moc/moc_{path}.cpp`, where the path of the header
+    /// is relative to `CARGO_MANIFEST_DIR` with any characters other than ASCII letters and digits
+    /// replaced by `_`. This means that headers with the same file name in different directories
+    /// do not overwrite the source generated for each other.
+    pub fn moc(&self, input_file: impl AsRef<Path>) -> PathBuf {
+        let input_file = input_file.as_ref();
+        println!("cargo:rerun-if-changed={}", input_file.display());
+
+        let output_directory =
+            PathBuf::from(env::var("OUT_DIR").expect("Could not get OUT_DIR")).join("qt-build/moc");
+        std::fs::create_dir_all(&output_directory).expect("Could not create moc output dir");
+        // Headers outside of the crate, eg in OUT_DIR, use their full path instead
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+        let relative_path = input_file.strip_prefix(&manifest_dir).unwrap_or(input_file);
+        if relative_path.file_name().is_none() {
+            panic!("moc input file {} has no file name", input_file.display());
+        }
+        let output_file = output_directory.join(format!(
+            "moc_{}.cpp",
+            relative_path
+                .to_string_lossy()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>()
+                .trim_start_matches('_')
+        ));
+
+        let moc_executable = self.host_tool("moc");
+        let output = Command::new(&moc_executable)
+            .arg(input_file)
+            .arg("-o")
+            .arg(&output_file)
+            .output()
+            .unwrap_or_else(|e| panic!("Could not run {}: {}", moc_executable.display(), e));
+        if !output.status.success() {
+            panic!(
+                "moc failed for {}:\n{}",
+                input_file.display(),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        output_file
+    }
+
+    /// Get the paths of the files which are listed in a Qt resource (.qrc) file together
+    /// with their resource paths, eg `/qml/main.qml` for `<file>qml/main.qml</file>`.
+    ///
+    /// The paths of the files are relative to the directory of the .qrc file, and the resource
+    /// paths take the `prefix` of the `<qresource>` and the `alias` of the `<file>` into account.
+    pub fn qrc_resources(&self, qrc_file: impl AsRef<Path>) -> Vec<(PathBuf, String)> {
+        let qrc_file = qrc_file.as_ref();
+        let contents = std::fs::read_to_string(qrc_file)
+            .unwrap_or_else(|e| panic!("Could not read {}: {}", qrc_file.display(), e));
+        let directory = qrc_file.parent().unwrap_or_else(|| Path::new(""));
+        parse_qrc(&contents)
+            .into_iter()
+            .map(|(file, resource_path)| (directory.join(file), resource_path))
+            .collect()
+    }
+
+    /// Get the paths of the files which are listed in a Qt resource (.qrc) file,
+    /// the paths in the .qrc file are relative to the directory of the .qrc file
+    pub fn qrc_files(&self, qrc_file: impl AsRef<Path>) -> Vec<PathBuf> {
+        self.qrc_resources(qrc_file)
+            .into_iter()
+            .map(|(file, _)| file)
+            .collect()
+    }
+
+    /// Run rcc on a Qt resource (.qrc) file, returning the path of the generated C++ source file.
+    /// The generated source needs to be compiled and linked together with the rest of the C++ code.
+    ///
+    /// The resources are given the name `name`, which is used to initialise them with
+    /// `Q_INIT_RESOURCE(name)` when they are linked from a static library. The source is written
+    /// to `$OUT_DIR/qt-build/rcc/qrc_{name}.cpp` and cargo is told to rerun the build script
+    /// when the .qrc file or any of the files it lists change.
+    pub fn rcc(&self, qrc_file: impl AsRef<Path>, name: &str) -> PathBuf {
+        let qrc_file = qrc_file.as_ref();
+        println!("cargo:rerun-if-changed={}", qrc_file.display());
+        for file in self.qrc_files(qrc_file) {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+
+        let output_directory =
+            PathBuf::from(env::var("OUT_DIR").expect("Could not get OUT_DIR")).join("qt-build/rcc");
+        std::fs::create_dir_all(&output_directory).expect("Could not create rcc output dir");
+        let output_file = output_directory.join(format!("qrc_{}.cpp", name));
+
+        let rcc_executable = self.host_tool("rcc");
+        let output = Command::new(&rcc_executable)
+            .arg("--name")
+            .arg(name)
+            .arg(qrc_file)
+            .arg("-o")
+            .arg(&output_file)
+            .output()
+            .unwrap_or_else(|e| panic!("Could not run {}: {}", rcc_executable.display(), e));
+        if !output.status.success() {
+            panic!(
+                "rcc failed for {}:\n{}",
+                qrc_file.display(),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        output_file
+    }
+
+    /// Run qmlcachegen on a QML file to compile it ahead of time, returning the path of the
+    /// generated C++ source file. The generated source needs to be compiled and linked together
+    /// with the rest of the C++ code, and with the source of [QtBuild::qmlcachegen_loader].
+    ///
+    /// The `resource_path` is the path of the QML file in the Qt resources, eg `/qml/main.qml`,
+    /// the compiled QML is used instead of the file when it is loaded from `qrc:/qml/main.qml`.
+    /// The source is written to `$OUT_DIR/qt-build/qmlcache/{resource path}.cpp`.
+    pub fn qmlcachegen(&self, qml_file: impl AsRef<Path>, resource_path: &str) -> PathBuf {
+        let qml_file = qml_file.as_ref();
+        println!("cargo:rerun-if-changed={}", qml_file.display());
+
+        let output_directory = PathBuf::from(env::var("OUT_DIR").expect("Could not get OUT_DIR"))
+            .join("qt-build/qmlcache");
+        std::fs::create_dir_all(&output_directory).expect("Could not create qmlcache output dir");
+        let output_file = output_directory.join(format!(
+            "{}.cpp",
+            resource_path.trim_start_matches('/').replace('/', "_")
+        ));
+
+        let mut command = Command::new(self.host_tool("qmlcachegen"));
+        command
+            .arg("--resource-path")
+            .arg(resource_path)
+            .arg("-o")
+            .arg(&output_file)
+            .arg(qml_file);
+        // Qt 6 also compiles functions to C++, which needs private Qt headers
+        if self.version.major >= 6 {
+            command.arg("--only-bytecode");
+        }
+        Self::run_qmlcachegen(command, &qml_file.display().to_string());
+
+        output_file
+    }
+
+    /// Run qmlcachegen to generate the loader of the QML files which have been compiled
+    /// ahead of time with [QtBuild::qmlcachegen], returning the path of the generated C++
+    /// source file. The `qml_files` are the paths of the QML files and their resource paths.
+    ///
+    /// The loader is given the resource name `name`, which is used to initialise it with
+    /// `Q_INIT_RESOURCE(name)` when it is linked from a static library. The source is written
+    /// to `$OUT_DIR/qt-build/qmlcache/{name}_loader.cpp`.
+    pub fn qmlcachegen_loader(&self, name: &str, qml_files: &[(PathBuf, String)]) -> PathBuf {
+        let output_directory = PathBuf::from(env::var("OUT_DIR").expect("Could not get OUT_DIR"))
+            .join("qt-build/qmlcache");
+        std::fs::create_dir_all(&output_directory).expect("Could not create qmlcache output dir");
+
+        // The loader is generated for the QML files of .qrc files, so write a .qrc
+        // file which maps the resource paths to exactly the compiled QML files
+        let qrc_file = output_directory.join(format!("{}.qrc", name));
+        let mut qrc = String::from("<RCC>\n    <qresource prefix=\"/\">\n");
+        for (qml_file, resource_path) in qml_files {
+            qrc.push_str(&format!(
+                "        <file alias=\"{}\">{}</file>\n",
+                xml_escape(resource_path.trim_start_matches('/')),
+                xml_escape(&qml_file.display().to_string())
+            ));
+        }
+        qrc.push_str("    </qresource>\n</RCC>\n");
+        std::fs::write(&qrc_file, qrc).expect("Could not write qmlcache qrc file");
+
+        let output_file = output_directory.join(format!("{}_loader.cpp", name));
+        let mut command = Command::new(self.host_tool("qmlcachegen"));
+        command
+            .arg("--resource-name")
+            .arg(name)
+            .arg("-o")
+            .arg(&output_file)
+            .arg(&qrc_file);
+        Self::run_qmlcachegen(command, name);
+
+        output_file
+    }
+
+    /// Run a qmlcachegen command, panicking with its output if it fails
+    fn run_qmlcachegen(mut command: Command, input: &str) {
+        let output = command
+            .output()
+            .unwrap_or_else(|e| panic!("Could not run qmlcachegen: {}", e));
+        if !output.status.success() {
+            panic!(
+                "qmlcachegen failed for {}:\n{}",
+                input,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
+
+/// Parse the contents of a Qt resource (.qrc) file into the files it lists and their resource paths
+///
+/// A resource path is the `prefix` of the `<qresource>` joined with the `alias` of the `<file>`,
+/// or the file itself if there is no alias.
+fn parse_qrc(contents: &str) -> Vec<(String, String)> {
+    lazy_static::lazy_static! {
+        static ref COMMENT: regex::Regex = regex::Regex::new(r"(?s)<!--.*?-->").unwrap();
+        static ref QRESOURCE: regex::Regex =
+            regex::Regex::new(r"(?s)<qresource\b([^>]*)>(.*?)</qresource\s*>").unwrap();
+        static ref FILE: regex::Regex =
+            regex::Regex::new(r"(?s)<file\b([^>]*)>(.*?)</file\s*>").unwrap();
+    }
+
+    let contents = COMMENT.replace_all(contents, "");
+    let mut files = vec![];
+    for qresource in QRESOURCE.captures_iter(&contents) {
+        let prefix = xml_attribute(&qresource[1], "prefix").unwrap_or_default();
+        let prefix = prefix.trim_matches('/');
+        for file in FILE.captures_iter(&qresource[2]) {
+            let path = xml_unescape(file[2].trim());
+            let alias = xml_attribute(&file[1], "alias").unwrap_or_else(|| path.clone());
+            let alias = alias.trim_start_matches('/');
+            let resource_path = if prefix.is_empty() {
+                format!("/{}", alias)
+            } else {
+                format!("/{}/{}", prefix, alias)
+            };
+            files.push((path, resource_path));
+        }
+    }
+    files
+}
+
+/// Find the unescaped value of an attribute in the attributes of an XML element
+fn xml_attribute(attributes: &str, name: &str) -> Option<String> {
+    let attribute = regex::Regex::new(&format!(
+        r#"(?:^|\s){}\s*=\s*(?:"([^"]*)"|'([^']*)')"#,
+        regex::escape(name)
+    ))
+    .unwrap();
+    attribute.captures(attributes).map(|captures| {
+        let value = captures.get(1).or_else(|| captures.get(2)).unwrap();
+        xml_unescape(value.as_str())
+    })
+}
+
+/// Replace the predefined XML entities in the text with the characters they represent
+fn xml_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Escape the characters which have a special meaning in XML, so that the text can be
+/// written as the content or an attribute value of an element in a generated .qrc file
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_qrc() {
+        let contents = r#"<!DOCTYPE RCC>
+<RCC version="1.0">
+    <qresource>
+        <file>qml/main.qml</file>
+        <file alias="icon.png" >
+            images/icon-256.png
+        </file>
+    </qresource>
+    <!-- <qresource><file>commented.qml</file></qresource> -->
+    <qresource lang='en' prefix = '/translations/' >
+        <file compress="9" alias='en&amp;gb.qm'>i18n/en_GB.qm</file>
+        <file>i18n/a&amp;b.qm</file>
+    </qresource>
+</RCC>
+"#;
+        assert_eq!(
+            parse_qrc(contents),
+            vec![
+                ("qml/main.qml".to_owned(), "/qml/main.qml".to_owned()),
+                ("images/icon-256.png".to_owned(), "/icon.png".to_owned()),
+                (
+                    "i18n/en_GB.qm".to_owned(),
+                    "/translations/en&gb.qm".to_owned()
+                ),
+                (
+                    "i18n/a&b.qm".to_owned(),
+                    "/translations/i18n/a&b.qm".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("qml/main.qml"), "qml/main.qml");
+        assert_eq!(
+            xml_escape("a&b<c>\"d\"'e'"),
+            "a&amp;b&lt;c&gt;&quot;d&quot;&apos;e&apos;"
+        );
+        assert_eq!(
+            xml_unescape(&xml_escape("a&b<c>\"d\"'e'")),
+            "a&b<c>\"d\"'e'"
+        );
+    }
 }
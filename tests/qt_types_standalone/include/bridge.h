@@ -7,8 +7,12 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <QtCore/QByteArray>
 #include <QtCore/QDate>
 #include <QtCore/QDateTime>
+#include <QtCore/QHash>
+#include <QtCore/QList>
+#include <QtCore/QMap>
 #include <QtCore/QPoint>
 #include <QtCore/QPointF>
 #include <QtCore/QRect>
@@ -16,9 +20,11 @@
 #include <QtCore/QSize>
 #include <QtCore/QSizeF>
 #include <QtCore/QString>
+#include <QtCore/QStringList>
 #include <QtCore/QTime>
 #include <QtCore/QUrl>
 #include <QtCore/QVariant>
+#include <QtCore/QVector>
 #include <QtGui/QColor>
 
 #include "cxx-qt-gen/include/ffi.cxx.h"
@@ -6,9 +6,10 @@
 
 use core::pin::Pin;
 use cxx_qt_lib::{
-    QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl,
-    QVariant, QVariantValue,
+    QByteArray, QColor, QDate, QDateTime, QList, QPoint, QPointF, QRect, QRectF, QSize, QSizeF,
+    QString, QStringList, QTime, QUrl, QVariant, QVariantHash, QVariantMap, QVariantValue, QVector,
 };
+use std::collections::{BTreeMap, HashMap};
 
 #[cxx::bridge]
 mod ffi {
@@ -26,6 +27,8 @@ mod ffi {
         I8,
         I16,
         I32,
+        Null,
+        QByteArray,
         QColor,
         QDate,
         QDateTime,
@@ -38,6 +41,9 @@ mod ffi {
         QTime,
         QUrl,
         QString,
+        QStringList,
+        QVariantList,
+        QVariantMap,
         U8,
         U16,
         U32,
@@ -47,10 +53,12 @@ mod ffi {
         include!("cxx-qt-lib/include/qt_types.h");
         include!("bridge.h");
 
+        type QByteArray = cxx_qt_lib::QByteArray;
         type QColor = cxx_qt_lib::QColor;
         type QDate = cxx_qt_lib::QDate;
         type QDateTime = cxx_qt_lib::QDateTime;
         type QString = cxx_qt_lib::QString;
+        type QStringList = cxx_qt_lib::QStringList;
         type QUrl = cxx_qt_lib::QUrl;
         type QVariant = cxx_qt_lib::QVariant;
         type QSize = cxx_qt_lib::QSize;
@@ -61,6 +69,19 @@ mod ffi {
         type QRect = cxx_qt_lib::QRect;
         type QTime = cxx_qt_lib::QTime;
 
+        include!("cxx-qt-lib/include/qlist.h");
+        type QList_i32 = cxx_qt_lib::QList_i32;
+        type QList_QString = cxx_qt_lib::QList_QString;
+        include!("cxx-qt-lib/include/qhash.h");
+        type QVariantHash = cxx_qt_lib::QVariantHash;
+        include!("cxx-qt-lib/include/qmap.h");
+        type QVariantMap = cxx_qt_lib::QVariantMap;
+        include!("cxx-qt-lib/include/qvector.h");
+        type QVector_i32 = cxx_qt_lib::QVector_i32;
+        type QVector_QPoint = cxx_qt_lib::QVector_QPoint;
+        type QVector_QString = cxx_qt_lib::QVector_QString;
+        type QVector_QVariant = cxx_qt_lib::QVector_QVariant;
+
         fn test_constructed_qstring(s: &QString) -> bool;
         fn assign_to_qstring(s: Pin<&mut QString>, v: &QString);
 
@@ -132,6 +153,37 @@ mod ffi {
         fn read_qtime(p: &QTime) -> bool;
         fn copy_qtime(p: &QTime) -> QTime;
         fn copy_value_qtime(p: QTime) -> QTime;
+
+        fn construct_qlist_i32() -> UniquePtr<QList_i32>;
+        fn read_qlist_i32(l: &QList_i32) -> bool;
+        fn modify_qlist_i32(l: Pin<&mut QList_i32>);
+
+        fn construct_qlist_qstring() -> UniquePtr<QList_QString>;
+        fn read_qlist_qstring(l: &QList_QString) -> bool;
+
+        fn read_qvector_i32_as_slice(v: &QVector_i32) -> bool;
+        fn read_qvector_qpoint_as_slice(v: &QVector_QPoint) -> bool;
+
+        fn construct_qvector_qstring() -> UniquePtr<QVector_QString>;
+        fn read_qvector_qstring(v: &QVector_QString) -> bool;
+
+        fn construct_qvector_qvariant() -> UniquePtr<QVector_QVariant>;
+        fn read_qvector_qvariant(v: &QVector_QVariant) -> bool;
+
+        fn construct_qstringlist() -> UniquePtr<QStringList>;
+        fn read_qstringlist(l: &QStringList) -> bool;
+        fn modify_qstringlist(l: Pin<&mut QStringList>);
+
+        fn construct_qbytearray() -> UniquePtr<QByteArray>;
+        fn read_qbytearray(b: &QByteArray) -> bool;
+        fn modify_qbytearray(b: Pin<&mut QByteArray>);
+
+        fn construct_qvariantmap() -> UniquePtr<QVariantMap>;
+        fn read_qvariantmap(m: &QVariantMap) -> bool;
+        fn modify_qvariantmap(m: Pin<&mut QVariantMap>);
+
+        fn construct_qvarianthash() -> UniquePtr<QVariantHash>;
+        fn read_qvarianthash(h: &QVariantHash) -> bool;
     }
 }
 
@@ -232,6 +284,9 @@ fn make_variant(test: VariantTest) -> cxx::UniquePtr<cxx_qt_lib::QVariant> {
         VariantTest::I8 => QVariant::from(12_i8),
         VariantTest::I16 => QVariant::from(123_i16),
         VariantTest::I32 => QVariant::from(123_i32),
+        VariantTest::QByteArray => {
+            QVariant::from(QByteArray::from_slice(b"Rust bytes").as_ref().unwrap())
+        }
         VariantTest::QColor => QVariant::from(QColor::from_rgba(255, 0, 0, 255).as_ref().unwrap()),
         VariantTest::QDate => QVariant::from(QDate::new(2022, 1, 1)),
         VariantTest::QDateTime => QVariant::from(
@@ -245,11 +300,26 @@ fn make_variant(test: VariantTest) -> cxx::UniquePtr<cxx_qt_lib::QVariant> {
         VariantTest::QRectF => QVariant::from(QRectF::new(1.23, 4.56, 2.46, 9.12)),
         VariantTest::QSize => QVariant::from(QSize::new(1, 3)),
         VariantTest::QSizeF => QVariant::from(QSizeF::new(1.0, 3.0)),
+        VariantTest::Null => QVariant::null(),
         VariantTest::QString => QVariant::from(QString::from_str("Rust string").as_ref().unwrap()),
+        VariantTest::QStringList => {
+            QVariant::from(QStringList::from_slice(&["Rust", "list"]).as_ref().unwrap())
+        }
         VariantTest::QTime => QVariant::from(QTime::new(1, 2, 3, 4)),
         VariantTest::QUrl => {
             QVariant::from(QUrl::from_str("https://github.com/KDAB").as_ref().unwrap())
         }
+        VariantTest::QVariantList => QVariant::from(QVariantValue::List(vec![
+            QVariantValue::I32(1),
+            QVariantValue::QString(QString::from_str("Rust")),
+        ])),
+        VariantTest::QVariantMap => QVariant::from(QVariantValue::Map(BTreeMap::from([
+            (
+                "list".to_owned(),
+                QVariantValue::List(vec![QVariantValue::Bool(true)]),
+            ),
+            ("number".to_owned(), QVariantValue::I32(1)),
+        ]))),
         VariantTest::U8 => QVariant::from(12_u8),
         VariantTest::U16 => QVariant::from(123_u16),
         VariantTest::U32 => QVariant::from(123_u32),
@@ -289,6 +359,10 @@ fn can_read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
             QVariantValue::I32(i) => i == 8910,
             _others => false,
         },
+        VariantTest::QByteArray => match variant {
+            QVariantValue::QByteArray(bytes) => bytes.as_slice() == b"C++ bytes",
+            _others => false,
+        },
         VariantTest::QColor => match variant {
             QVariantValue::QColor(color) => {
                 color.alpha() == 255
@@ -347,10 +421,17 @@ fn can_read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
             QVariantValue::QSizeF(sizef) => sizef.width() == 8.0 && sizef.height() == 9.0,
             _others => false,
         },
+        VariantTest::Null => matches!(variant, QVariantValue::Null),
         VariantTest::QString => match variant {
             QVariantValue::QString(s) => s.to_string() == "C++ string",
             _others => false,
         },
+        VariantTest::QStringList => match variant {
+            QVariantValue::QStringList(list) => {
+                Vec::from(list.as_ref().unwrap()) == vec!["C++", "list"]
+            }
+            _others => false,
+        },
         VariantTest::QTime => match variant {
             QVariantValue::QTime(time) => {
                 time.hour() == 4 && time.minute() == 3 && time.second() == 2 && time.msec() == 1
@@ -363,6 +444,26 @@ fn can_read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
             }
             _others => false,
         },
+        VariantTest::QVariantList => match variant {
+            QVariantValue::List(values) => match values.as_slice() {
+                [QVariantValue::I32(1), QVariantValue::QString(s)] => s.to_string() == "C++",
+                _others => false,
+            },
+            _others => false,
+        },
+        VariantTest::QVariantMap => match variant {
+            QVariantValue::Map(values) => {
+                values.len() == 2
+                    && matches!(values.get("number"), Some(QVariantValue::I32(1)))
+                    && match values.get("list") {
+                        Some(QVariantValue::List(list)) => {
+                            matches!(list.as_slice(), [QVariantValue::Bool(true)])
+                        }
+                        _others => false,
+                    }
+            }
+            _others => false,
+        },
         VariantTest::U8 => match variant {
             QVariantValue::U8(i) => i == 89,
             _others => false,
@@ -509,3 +610,152 @@ fn copy_qtime(s: &QTime) -> QTime {
 fn copy_value_qtime(s: QTime) -> QTime {
     s
 }
+
+fn construct_qlist_i32() -> cxx::UniquePtr<cxx_qt_lib::QList_i32> {
+    let mut list = QList::from_slice(&[1, 2, 3]);
+    list.pin_mut().push(&5);
+    list.pin_mut().insert(3, &4);
+    list
+}
+
+fn read_qlist_i32(l: &cxx_qt_lib::QList_i32) -> bool {
+    l.len() == 3
+        && l.contains(&2)
+        && l.index_of(&3) == Some(2)
+        && l.get(3).is_none()
+        && l.iter().sum::<i32>() == 6
+        && Vec::from(l) == vec![1, 2, 3]
+}
+
+fn modify_qlist_i32(mut l: Pin<&mut cxx_qt_lib::QList_i32>) {
+    l.as_mut().remove(0);
+    l.push(&4);
+}
+
+fn construct_qlist_qstring() -> cxx::UniquePtr<cxx_qt_lib::QList_QString> {
+    QList::from_strs(&["Hello", "World"])
+}
+
+fn read_qlist_qstring(l: &cxx_qt_lib::QList_QString) -> bool {
+    let strings = Vec::<String>::from(l);
+    let copy = QList::from_unique_ptrs(&Vec::<cxx::UniquePtr<QString>>::from(l));
+    strings == vec!["Hello", "World"] && Vec::<String>::from(copy.as_ref().unwrap()) == strings
+}
+
+fn read_qvector_i32_as_slice(v: &cxx_qt_lib::QVector_i32) -> bool {
+    v.as_slice() == [1, 2, 3]
+}
+
+fn read_qvector_qpoint_as_slice(v: &cxx_qt_lib::QVector_QPoint) -> bool {
+    matches!(v.as_slice(), [a, b] if a.x() == 1 && a.y() == 2 && b.x() == 3 && b.y() == 4)
+}
+
+fn construct_qvector_qstring() -> cxx::UniquePtr<cxx_qt_lib::QVector_QString> {
+    let mut vector = QVector::new();
+    for value in ["Hello", "World"] {
+        vector.pin_mut().push(&QString::from_str(value));
+    }
+    vector
+}
+
+fn read_qvector_qstring(v: &cxx_qt_lib::QVector_QString) -> bool {
+    v.iter().map(|s| s.to_string()).collect::<Vec<String>>() == vec!["Hello", "World"]
+}
+
+fn construct_qvector_qvariant() -> cxx::UniquePtr<cxx_qt_lib::QVector_QVariant> {
+    QVector::from_unique_ptrs(&[QVariant::from(1), QVariant::from(true)])
+}
+
+fn read_qvector_qvariant(v: &cxx_qt_lib::QVector_QVariant) -> bool {
+    let values = Vec::<cxx::UniquePtr<QVariant>>::from(v);
+    values.len() == 2
+        && matches!(values[0].value(), QVariantValue::I32(1))
+        && matches!(values[1].value(), QVariantValue::Bool(true))
+}
+
+fn construct_qstringlist() -> cxx::UniquePtr<cxx_qt_lib::QStringList> {
+    let mut list = QStringList::from_slice(&["c", "a"]);
+    list.pin_mut().push(&QString::from_str("b"));
+    list.pin_mut().sort();
+    list
+}
+
+fn read_qstringlist(l: &cxx_qt_lib::QStringList) -> bool {
+    let split = QString::from_str("a,b,c").split(",");
+    l.len() == 3
+        && l.contains(&QString::from_str("b"))
+        && l.join(", ").to_string() == "a, b, c"
+        && Vec::from(l) == vec!["a", "b", "c"]
+        && Vec::from(split.as_ref().unwrap()) == Vec::from(l)
+}
+
+fn modify_qstringlist(mut l: Pin<&mut cxx_qt_lib::QStringList>) {
+    l.as_mut().remove(0);
+    l.insert(0, &QString::from_str("d"));
+}
+
+fn construct_qbytearray() -> cxx::UniquePtr<cxx_qt_lib::QByteArray> {
+    let mut bytes = QByteArray::from_vec(vec![0, 1, 2, 255]);
+    bytes.pin_mut().as_mut_slice()[1] = 42;
+    bytes
+}
+
+fn read_qbytearray(b: &cxx_qt_lib::QByteArray) -> bool {
+    b.len() == 4
+        && b.as_slice() == [0, 1, 2, 255]
+        && Vec::from(b) == vec![0, 1, 2, 255]
+        && b.to_hex().as_slice() == b"000102ff"
+        && b.to_base64().as_slice() == b"AAEC/w=="
+        && QByteArray::from_hex(b"000102ff").as_slice() == b.as_slice()
+        && QByteArray::from_base64(b"AAEC/w==").as_slice() == b.as_slice()
+}
+
+fn modify_qbytearray(b: Pin<&mut cxx_qt_lib::QByteArray>) {
+    b.as_mut_slice().reverse();
+}
+
+fn construct_qvariantmap() -> cxx::UniquePtr<cxx_qt_lib::QVariantMap> {
+    let mut map = QVariantMap::from(HashMap::from([("a", 1), ("b", 2)]));
+    map.pin_mut()
+        .insert(&QString::from_str("c"), &QVariant::from(3));
+    map.pin_mut().remove(&QString::from_str("a"));
+    map
+}
+
+fn read_qvariantmap(m: &cxx_qt_lib::QVariantMap) -> bool {
+    let values = BTreeMap::from(m);
+    m.len() == 2
+        && m.contains(&QString::from_str("b"))
+        && m.get(&QString::from_str("c")).is_none()
+        && matches!(
+            m.get(&QString::from_str("a")).map(|v| v.value()),
+            Some(QVariantValue::I32(1))
+        )
+        && m.iter()
+            .map(|(k, _)| k.to_string())
+            .collect::<Vec<String>>()
+            == vec!["a", "b"]
+        && values.keys().collect::<Vec<&String>>() == vec!["a", "b"]
+        && matches!(values["b"].value(), QVariantValue::Bool(true))
+}
+
+fn modify_qvariantmap(mut m: Pin<&mut cxx_qt_lib::QVariantMap>) {
+    m.as_mut().remove(&QString::from_str("a"));
+    m.insert(
+        &QString::from_str("c"),
+        &QVariant::from(QString::from_str("d").as_ref().unwrap()),
+    );
+}
+
+fn construct_qvarianthash() -> cxx::UniquePtr<cxx_qt_lib::QVariantHash> {
+    QVariantHash::from(BTreeMap::from([("a", 1), ("b", 2)]))
+}
+
+fn read_qvarianthash(h: &cxx_qt_lib::QVariantHash) -> bool {
+    let values = HashMap::from(h);
+    h.len() == 2
+        && h.contains(&QString::from_str("a"))
+        && h.iter().count() == 2
+        && matches!(values["a"].value(), QVariantValue::I32(1))
+        && matches!(values["b"].value(), QVariantValue::Bool(true))
+}
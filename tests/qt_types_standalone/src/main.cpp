@@ -160,6 +160,8 @@ TEST_CASE("Can construct a QVariant on the Rust side")
   CHECK(can_construct_qvariant(VariantTest::I8));
   CHECK(can_construct_qvariant(VariantTest::I16));
   CHECK(can_construct_qvariant(VariantTest::I32));
+  CHECK(can_construct_qvariant(VariantTest::Null));
+  CHECK(can_construct_qvariant(VariantTest::QByteArray));
   CHECK(can_construct_qvariant(VariantTest::QColor));
   CHECK(can_construct_qvariant(VariantTest::QDate));
   CHECK(can_construct_qvariant(VariantTest::QDateTime));
@@ -170,8 +172,11 @@ TEST_CASE("Can construct a QVariant on the Rust side")
   CHECK(can_construct_qvariant(VariantTest::QSize));
   CHECK(can_construct_qvariant(VariantTest::QSizeF));
   CHECK(can_construct_qvariant(VariantTest::QString));
+  CHECK(can_construct_qvariant(VariantTest::QStringList));
   CHECK(can_construct_qvariant(VariantTest::QTime));
   CHECK(can_construct_qvariant(VariantTest::QUrl));
+  CHECK(can_construct_qvariant(VariantTest::QVariantList));
+  CHECK(can_construct_qvariant(VariantTest::QVariantMap));
   CHECK(can_construct_qvariant(VariantTest::U8));
   CHECK(can_construct_qvariant(VariantTest::U16));
   CHECK(can_construct_qvariant(VariantTest::U32));
@@ -193,6 +198,10 @@ test_constructed_qvariant(const QVariant& v, VariantTest test)
       return v.value<qint16>() == 123;
     case VariantTest::I32:
       return v.value<qint32>() == 123;
+    case VariantTest::Null:
+      return v.isNull();
+    case VariantTest::QByteArray:
+      return v.toByteArray() == QByteArrayLiteral("Rust bytes");
     case VariantTest::QColor:
       return v.value<QColor>().alpha() == 255 &&
              v.value<QColor>().red() == 255 && v.value<QColor>().green() == 0 &&
@@ -228,12 +237,23 @@ test_constructed_qvariant(const QVariant& v, VariantTest test)
              v.value<QSize>().height() == 3.0;
     case VariantTest::QString:
       return v.toString() == QStringLiteral("Rust string");
+    case VariantTest::QStringList:
+      return v.toStringList() ==
+             QStringList({ QStringLiteral("Rust"), QStringLiteral("list") });
     case VariantTest::QTime:
       return v.value<QTime>().hour() == 1 && v.value<QTime>().minute() == 2 &&
              v.value<QTime>().second() == 3 && v.value<QTime>().msec() == 4;
     case VariantTest::QUrl:
       return v.value<QUrl>().toString() ==
              QStringLiteral("https://github.com/KDAB");
+    case VariantTest::QVariantList:
+      return v.toList() ==
+             QVariantList({ QVariant(1), QVariant(QStringLiteral("Rust")) });
+    case VariantTest::QVariantMap:
+      return v.toMap() ==
+             QVariantMap(
+               { { QStringLiteral("list"), QVariantList({ QVariant(true) }) },
+                 { QStringLiteral("number"), QVariant(1) } });
     case VariantTest::U8:
       return v.value<quint8>() == 12;
     case VariantTest::U16:
@@ -258,6 +278,8 @@ TEST_CASE("Can convert Rust Variant to QVariant")
   CHECK(runTest(VariantTest::I8));
   CHECK(runTest(VariantTest::I16));
   CHECK(runTest(VariantTest::I32));
+  CHECK(runTest(VariantTest::Null));
+  CHECK(runTest(VariantTest::QByteArray));
   CHECK(runTest(VariantTest::QColor));
   CHECK(runTest(VariantTest::QDate));
   CHECK(runTest(VariantTest::QDateTime));
@@ -268,8 +290,11 @@ TEST_CASE("Can convert Rust Variant to QVariant")
   CHECK(runTest(VariantTest::QSize));
   CHECK(runTest(VariantTest::QSizeF));
   CHECK(runTest(VariantTest::QString));
+  CHECK(runTest(VariantTest::QStringList));
   CHECK(runTest(VariantTest::QTime));
   CHECK(runTest(VariantTest::QUrl));
+  CHECK(runTest(VariantTest::QVariantList));
+  CHECK(runTest(VariantTest::QVariantMap));
   CHECK(runTest(VariantTest::U8));
   CHECK(runTest(VariantTest::U16));
   CHECK(runTest(VariantTest::U32));
@@ -284,6 +309,9 @@ TEST_CASE("Can read a QVariant on the Rust side")
   CHECK(can_read_qvariant(QVariant::fromValue<qint8>(89), VariantTest::I8));
   CHECK(can_read_qvariant(QVariant::fromValue<qint16>(8910), VariantTest::I16));
   CHECK(can_read_qvariant(QVariant::fromValue(8910), VariantTest::I32));
+  CHECK(can_read_qvariant(QVariant(), VariantTest::Null));
+  CHECK(can_read_qvariant(QVariant::fromValue(QByteArrayLiteral("C++ bytes")),
+                          VariantTest::QByteArray));
   CHECK(can_read_qvariant(QVariant::fromValue<QColor>(QColor(0, 255, 0, 255)),
                           VariantTest::QColor));
   CHECK(can_read_qvariant(QVariant::fromValue<QDate>(QDate(2021, 12, 31)),
@@ -306,11 +334,29 @@ TEST_CASE("Can read a QVariant on the Rust side")
                           VariantTest::QSizeF));
   CHECK(can_read_qvariant(QVariant::fromValue(QStringLiteral("C++ string")),
                           VariantTest::QString));
+  CHECK(can_read_qvariant(
+    QVariant::fromValue(
+      QStringList({ QStringLiteral("C++"), QStringLiteral("list") })),
+    VariantTest::QStringList));
   CHECK(can_read_qvariant(QVariant::fromValue<QTime>(QTime(4, 3, 2, 1)),
                           VariantTest::QTime));
   CHECK(can_read_qvariant(QVariant::fromValue<QUrl>(QUrl(
                             QStringLiteral("https://github.com/KDAB/cxx-qt"))),
                           VariantTest::QUrl));
+  CHECK(can_read_qvariant(
+    QVariant::fromValue(
+      QVariantList({ QVariant(1), QVariant(QStringLiteral("C++")) })),
+    VariantTest::QVariantList));
+  CHECK(can_read_qvariant(
+    QVariant::fromValue(QVariantHash(
+      { { QStringLiteral("list"), QVariantList({ QVariant(true) }) },
+        { QStringLiteral("number"), QVariant(1) } })),
+    VariantTest::QVariantMap));
+  CHECK(can_read_qvariant(
+    QVariant::fromValue(QVariantMap(
+      { { QStringLiteral("list"), QVariantList({ QVariant(true) }) },
+        { QStringLiteral("number"), QVariant(1) } })),
+    VariantTest::QVariantMap));
   CHECK(can_read_qvariant(QVariant::fromValue<quint8>(89), VariantTest::U8));
   CHECK(
     can_read_qvariant(QVariant::fromValue<quint16>(8910), VariantTest::U16));
@@ -570,3 +616,159 @@ TEST_CASE("Can copy a value QTime on the Rust side")
   CHECK(t.second() == 3);
   CHECK(t.msec() == 4);
 }
+
+TEST_CASE("Can construct a QList<qint32> on the Rust side")
+{
+  const auto l = construct_qlist_i32();
+  CHECK(*l == QList<qint32>({ 1, 2, 3, 4, 5 }));
+}
+
+TEST_CASE("Can read a QList<qint32> on the Rust side")
+{
+  const auto l = QList<qint32>({ 1, 2, 3 });
+  CHECK(read_qlist_i32(l));
+}
+
+TEST_CASE("Can modify a QList<qint32> on the Rust side")
+{
+  auto l = QList<qint32>({ 1, 2, 3 });
+  modify_qlist_i32(l);
+  CHECK(l == QList<qint32>({ 2, 3, 4 }));
+}
+
+TEST_CASE("Can construct a QList<QString> on the Rust side")
+{
+  const auto l = construct_qlist_qstring();
+  CHECK(*l ==
+        QList<QString>({ QStringLiteral("Hello"), QStringLiteral("World") }));
+}
+
+TEST_CASE("Can read a QList<QString> on the Rust side")
+{
+  const auto l =
+    QList<QString>({ QStringLiteral("Hello"), QStringLiteral("World") });
+  CHECK(read_qlist_qstring(l));
+}
+
+TEST_CASE("Can borrow a QVector<qint32> as a slice on the Rust side")
+{
+  const auto v = QVector<qint32>({ 1, 2, 3 });
+  CHECK(read_qvector_i32_as_slice(v));
+}
+
+TEST_CASE("Can borrow a QVector<QPoint> as a slice on the Rust side")
+{
+  const auto v = QVector<QPoint>({ QPoint(1, 2), QPoint(3, 4) });
+  CHECK(read_qvector_qpoint_as_slice(v));
+}
+
+TEST_CASE("Can construct a QVector<QString> on the Rust side")
+{
+  const auto v = construct_qvector_qstring();
+  CHECK(*v == QVector<QString>({ QStringLiteral("Hello"),
+                                 QStringLiteral("World") }));
+}
+
+TEST_CASE("Can read a QVector<QString> on the Rust side")
+{
+  const auto v =
+    QVector<QString>({ QStringLiteral("Hello"), QStringLiteral("World") });
+  CHECK(read_qvector_qstring(v));
+}
+
+TEST_CASE("Can construct a QVector<QVariant> on the Rust side")
+{
+  const auto v = construct_qvector_qvariant();
+  CHECK(*v == QVector<QVariant>({ QVariant(1), QVariant(true) }));
+}
+
+TEST_CASE("Can read a QVector<QVariant> on the Rust side")
+{
+  const auto v = QVector<QVariant>({ QVariant(1), QVariant(true) });
+  CHECK(read_qvector_qvariant(v));
+}
+
+TEST_CASE("Can construct a QStringList on the Rust side")
+{
+  const auto l = construct_qstringlist();
+  CHECK(*l == QStringList({ QStringLiteral("a"),
+                            QStringLiteral("b"),
+                            QStringLiteral("c") }));
+}
+
+TEST_CASE("Can read a QStringList on the Rust side")
+{
+  const auto l = QStringList(
+    { QStringLiteral("a"), QStringLiteral("b"), QStringLiteral("c") });
+  CHECK(read_qstringlist(l));
+}
+
+TEST_CASE("Can modify a QStringList on the Rust side")
+{
+  auto l = QStringList(
+    { QStringLiteral("a"), QStringLiteral("b"), QStringLiteral("c") });
+  modify_qstringlist(l);
+  CHECK(l == QStringList({ QStringLiteral("d"),
+                           QStringLiteral("b"),
+                           QStringLiteral("c") }));
+}
+
+TEST_CASE("Can construct a QByteArray on the Rust side")
+{
+  const auto b = construct_qbytearray();
+  CHECK(*b == QByteArray("\x00\x2a\x02\xff", 4));
+}
+
+TEST_CASE("Can read a QByteArray on the Rust side")
+{
+  const auto b = QByteArray("\x00\x01\x02\xff", 4);
+  CHECK(read_qbytearray(b));
+}
+
+TEST_CASE("Can modify a QByteArray on the Rust side")
+{
+  auto b = QByteArray("\x00\x01\x02\xff", 4);
+  const auto shared = b;
+  modify_qbytearray(b);
+  CHECK(b == QByteArray("\xff\x02\x01\x00", 4));
+  // Writing through the slice detaches the data from other copies
+  CHECK(shared == QByteArray("\x00\x01\x02\xff", 4));
+}
+
+TEST_CASE("Can construct a QVariantMap on the Rust side")
+{
+  const auto m = construct_qvariantmap();
+  CHECK(*m == QVariantMap({ { QStringLiteral("b"), QVariant(2) },
+                            { QStringLiteral("c"), QVariant(3) } }));
+}
+
+TEST_CASE("Can read a QVariantMap on the Rust side")
+{
+  const auto m = QVariantMap({ { QStringLiteral("a"), QVariant(1) },
+                               { QStringLiteral("b"), QVariant(true) } });
+  CHECK(read_qvariantmap(m));
+}
+
+TEST_CASE("Can modify a QVariantMap on the Rust side")
+{
+  auto m = QVariantMap({ { QStringLiteral("a"), QVariant(1) },
+                         { QStringLiteral("b"), QVariant(2) } });
+  modify_qvariantmap(m);
+  CHECK(m == QVariantMap({ { QStringLiteral("b"), QVariant(2) },
+                           { QStringLiteral("c"),
+                             QVariant(QStringLiteral("d")) } }));
+}
+
+TEST_CASE("Can construct a QVariantHash on the Rust side")
+{
+  const auto h = construct_qvarianthash();
+  CHECK(*h == QVariantHash({ { QStringLiteral("a"), QVariant(1) },
+                             { QStringLiteral("b"), QVariant(2) } }));
+}
+
+TEST_CASE("Can read a QVariantHash on the Rust side")
+{
+  const auto h = QVariantHash({ { QStringLiteral("a"), QVariant(1) },
+                                { QStringLiteral("b"), QVariant(true) } });
+  CHECK(read_qvarianthash(h));
+}